@@ -100,7 +100,8 @@ impl App {
                 Marker::Dot => Marker::Braille,
                 Marker::Braille => Marker::Block,
                 Marker::Block => Marker::HalfBlock,
-                Marker::HalfBlock => Marker::Bar,
+                Marker::HalfBlock => Marker::Quadrant,
+                Marker::Quadrant => Marker::Bar,
                 Marker::Bar => Marker::Dot,
             };
         }
@@ -153,6 +153,7 @@ impl App {
             | Constraint::Fill(v)
             | Constraint::Percentage(v) => *v = v.saturating_add(1),
             Constraint::Ratio(_n, d) => *d = d.saturating_add(1),
+            Constraint::Fit => {}
         };
     }
 
@@ -167,6 +168,7 @@ impl App {
             | Constraint::Fill(v)
             | Constraint::Percentage(v) => *v = v.saturating_sub(1),
             Constraint::Ratio(_n, d) => *d = d.saturating_sub(1),
+            Constraint::Fit => {}
         };
     }
 
@@ -242,7 +244,7 @@ impl From<Constraint> for ConstraintName {
             Length(_) => Self::Length,
             Percentage(_) => Self::Percentage,
             Ratio(_, _) => Self::Ratio,
-            Min(_) => Self::Min,
+            Min(_) | Constraint::Fit => Self::Min,
             Max(_) => Self::Max,
             Fill(_) => Self::Fill,
         }
@@ -83,12 +83,12 @@ fn render_inbox(selected_index: usize, area: Rect, buf: &mut Buffer) {
     let vertical = Layout::vertical([Constraint::Length(1), Constraint::Min(0)]);
     let [tabs, inbox] = vertical.areas(area);
     let theme = THEME.email;
-    Tabs::new(vec![" Inbox ", " Sent ", " Drafts "])
+    let tabs_widget = Tabs::new(vec![" Inbox ", " Sent ", " Drafts "])
         .style(theme.tabs)
         .highlight_style(theme.tabs_selected)
         .select(0)
-        .divider("")
-        .render(tabs, buf);
+        .divider("");
+    Widget::render(tabs_widget, tabs, buf);
 
     let highlight_symbol = ">>";
     let from_width = EMAILS
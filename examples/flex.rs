@@ -511,7 +511,7 @@ impl Example {
 const fn color_for_constraint(constraint: Constraint) -> Color {
     use tailwind::{BLUE, SLATE};
     match constraint {
-        Constraint::Min(_) => BLUE.c900,
+        Constraint::Min(_) | Constraint::Fit => BLUE.c900,
         Constraint::Max(_) => BLUE.c800,
         Constraint::Length(_) => SLATE.c700,
         Constraint::Percentage(_) => SLATE.c800,
@@ -184,5 +184,6 @@ fn constraint_label(constraint: Constraint) -> String {
         | Constraint::Max(n)
         | Constraint::Percentage(n)
         | Constraint::Fill(n) => format!("{n}"),
+        Constraint::Fit => "fit".to_string(),
     }
 }
@@ -19,9 +19,9 @@
 use color_eyre::Result;
 use ratatui::{
     crossterm::event::{self, Event, KeyCode, KeyEventKind},
-    layout::{Constraint, Flex, Layout, Rect},
+    layout::{Constraint, Layout},
     style::Stylize,
-    widgets::{Block, Clear, Paragraph, Wrap},
+    widgets::{Block, Paragraph, Popup, Wrap},
     DefaultTerminal, Frame,
 };
 
@@ -75,19 +75,11 @@ impl App {
         frame.render_widget(block, content);
 
         if self.show_popup {
-            let block = Block::bordered().title("Popup");
-            let area = popup_area(area, 60, 20);
-            frame.render_widget(Clear, area); //this clears out the background
-            frame.render_widget(block, area);
+            // the popup clears out the background before rendering the block
+            let popup = Popup::new(Block::bordered().title("Popup"))
+                .width(Constraint::Percentage(60))
+                .height(Constraint::Percentage(20));
+            frame.render_widget(popup, area);
         }
     }
 }
-
-/// helper function to create a centered rect using up certain percentage of the available rect `r`
-fn popup_area(area: Rect, percent_x: u16, percent_y: u16) -> Rect {
-    let vertical = Layout::vertical([Constraint::Percentage(percent_y)]).flex(Flex::Center);
-    let horizontal = Layout::horizontal([Constraint::Percentage(percent_x)]).flex(Flex::Center);
-    let [area] = vertical.areas(area);
-    let [area] = horizontal.areas(area);
-    area
-}
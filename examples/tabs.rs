@@ -133,12 +133,12 @@ impl App {
         let titles = SelectedTab::iter().map(SelectedTab::title);
         let highlight_style = (Color::default(), self.selected_tab.palette().c700);
         let selected_tab_index = self.selected_tab as usize;
-        Tabs::new(titles)
+        let tabs = Tabs::new(titles)
             .highlight_style(highlight_style)
             .select(selected_tab_index)
             .padding("", "")
-            .divider(" ")
-            .render(area, buf);
+            .divider(" ");
+        Widget::render(tabs, area, buf);
     }
 }
 
@@ -0,0 +1,142 @@
+//! Provides a semantic description of the rendered widgets for assistive technologies.
+//!
+//! The cells of a [`Buffer`](crate::buffer::Buffer) describe what is displayed, but not what it
+//! means: a screen reader can't tell a list item from a table cell or a paragraph of text. To fill
+//! this gap, widgets can describe themselves as a tree of [`AccessNode`]s by implementing
+//! [`Widget::accessibility_node`](crate::widgets::Widget::accessibility_node) (or its
+//! [`StatefulWidget`](crate::widgets::StatefulWidget::accessibility_node) equivalent). Once
+//! enabled with [`Terminal::enable_accessibility`], the nodes of the widgets rendered with a
+//! [`Frame`] are collected and available from [`Frame::accessibility_tree`].
+//!
+//! The [`Paragraph`](crate::widgets::Paragraph), [`List`](crate::widgets::List) and
+//! [`Table`](crate::widgets::Table) widgets describe their text content. Lists and tables only
+//! describe the items and rows which are displayed.
+//!
+//! # Example
+//!
+//! ```
+//! use ratatui::{accessibility::Role, backend::TestBackend, prelude::*, widgets::*};
+//!
+//! let mut terminal = Terminal::new(TestBackend::new(10, 3))?;
+//! terminal.enable_accessibility();
+//! terminal.draw(|frame| {
+//!     frame.render_widget(List::new(["Apple", "Banana"]), frame.area());
+//!
+//!     let tree = frame.accessibility_tree();
+//!     assert_eq!(tree[0].role, Role::List);
+//!     assert_eq!(tree[0].children[1].text, "Banana");
+//!     assert_eq!(tree[0].children[1].area, Rect::new(0, 1, 10, 1));
+//! })?;
+//! # Ok::<(), std::io::Error>(())
+//! ```
+//!
+//! [`Frame`]: crate::Frame
+//! [`Terminal::enable_accessibility`]: crate::Terminal::enable_accessibility
+//! [`Frame::accessibility_tree`]: crate::Frame::accessibility_tree
+
+use strum::{Display, EnumString};
+
+use crate::layout::Rect;
+
+/// A node of the accessibility tree, describing a widget or a part of a widget.
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub struct AccessNode {
+    /// What the node is
+    pub role: Role,
+    /// The text content of the node, with lines separated by `\n`
+    pub text: String,
+    /// The area of the screen where the node is displayed
+    pub area: Rect,
+    /// Whether the node is selected, e.g. the selected item of a list
+    pub selected: bool,
+    /// The nodes contained in this node, e.g. the items of a list
+    pub children: Vec<Self>,
+}
+
+/// The role of an [`AccessNode`], which tells assistive technologies how to present it.
+#[derive(Debug, Default, Display, EnumString, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum Role {
+    /// A widget without a more specific role
+    #[default]
+    Generic,
+    /// A block of text
+    Paragraph,
+    /// A list of items
+    List,
+    /// An item of a [`Role::List`]
+    ListItem,
+    /// A table of rows
+    Table,
+    /// The header row of a [`Role::Table`]
+    Header,
+    /// The footer row of a [`Role::Table`]
+    Footer,
+    /// A row of a [`Role::Table`]
+    Row,
+    /// A cell of a row
+    Cell,
+    /// A control that triggers an action when activated
+    Button,
+}
+
+impl AccessNode {
+    /// Creates a new node with the given role, text and area, which is not selected and has no
+    /// children.
+    pub fn new<T: Into<String>>(role: Role, text: T, area: Rect) -> Self {
+        Self {
+            role,
+            text: text.into(),
+            area,
+            selected: false,
+            children: Vec::new(),
+        }
+    }
+
+    /// Sets whether the node is selected.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn selected(mut self, selected: bool) -> Self {
+        self.selected = selected;
+        self
+    }
+
+    /// Sets the children of the node.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn children<I: IntoIterator<Item = Self>>(mut self, children: I) -> Self {
+        self.children = children.into_iter().collect();
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new() {
+        let node = AccessNode::new(Role::Paragraph, "hello", Rect::new(1, 2, 3, 4));
+        assert_eq!(
+            node,
+            AccessNode {
+                role: Role::Paragraph,
+                text: "hello".to_string(),
+                area: Rect::new(1, 2, 3, 4),
+                selected: false,
+                children: vec![],
+            }
+        );
+    }
+
+    #[test]
+    fn builder() {
+        let child = AccessNode::new(Role::ListItem, "item", Rect::new(0, 0, 4, 1)).selected(true);
+        let node = AccessNode::new(Role::List, "", Rect::new(0, 0, 4, 2)).children([child.clone()]);
+        assert_eq!(node.children, vec![child]);
+        assert!(node.children[0].selected);
+    }
+
+    #[test]
+    fn role_to_string() {
+        assert_eq!(Role::ListItem.to_string(), "ListItem");
+        assert_eq!("Button".parse::<Role>(), Ok(Role::Button));
+    }
+}
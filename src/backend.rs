@@ -107,6 +107,7 @@ use strum::{Display, EnumString};
 use crate::{
     buffer::Cell,
     layout::{Position, Size},
+    text::LineSize,
 };
 
 #[cfg(all(not(windows), feature = "termion"))]
@@ -180,6 +181,20 @@ pub trait Backend {
         Ok(())
     }
 
+    /// Set the size at which the terminal displays the row `y`.
+    ///
+    /// This is called by the [`Terminal`] before drawing whenever the [`LineSize`] of a row
+    /// changes. The cells of the row are drawn afterwards, so the backend only needs to set the
+    /// row attribute.
+    ///
+    /// This method is optional and may not be implemented by all backends. Backends that do not
+    /// support line attributes display every row at its normal size.
+    ///
+    /// [`Terminal`]: crate::terminal::Terminal
+    fn set_line_size(&mut self, _y: u16, _size: LineSize) -> io::Result<()> {
+        Ok(())
+    }
+
     /// Hide the cursor on the terminal screen.
     ///
     ///
@@ -318,6 +333,15 @@ pub trait Backend {
 
     /// Flush any buffered content to the terminal screen.
     fn flush(&mut self) -> io::Result<()>;
+
+    /// Returns the number of bytes written to the terminal since the backend was created, or
+    /// `None` if the backend doesn't keep track of it.
+    ///
+    /// This is used by [`Terminal::draw_with_stats`](crate::Terminal::draw_with_stats) to report
+    /// the bytes written for each frame. The default implementation returns `None`.
+    fn bytes_written(&self) -> Option<u64> {
+        None
+    }
 }
 
 #[cfg(test)]
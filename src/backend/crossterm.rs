@@ -6,10 +6,11 @@ use std::io::{self, Write};
 
 #[cfg(feature = "underline-color")]
 use crossterm::style::SetUnderlineColor;
+use unicode_width::UnicodeWidthStr;
 
 use crate::{
     backend::{Backend, ClearType, WindowSize},
-    buffer::Cell,
+    buffer::{Cell, Hyperlink},
     crossterm::{
         cursor::{Hide, MoveTo, Show},
         execute, queue,
@@ -20,7 +21,8 @@ use crate::{
         terminal::{self, Clear},
     },
     layout::{Position, Size},
-    style::{Color, Modifier, Style},
+    style::{Color, ColorDepth, Modifier, Style},
+    text::LineSize,
 };
 
 /// A [`Backend`] implementation that uses [Crossterm] to render to the terminal.
@@ -84,7 +86,12 @@ use crate::{
 #[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
 pub struct CrosstermBackend<W: Write> {
     /// The writer used to send commands to the terminal.
-    writer: W,
+    writer: CountingWriter<W>,
+    /// Whether [`Modifier::DIM`] is simulated by blending the foreground color toward the
+    /// background color.
+    simulate_dim: bool,
+    /// The colors are converted to the nearest color which can be displayed with this depth.
+    color_depth: ColorDepth,
 }
 
 impl<W> CrosstermBackend<W>
@@ -106,7 +113,63 @@ where
     /// let backend = CrosstermBackend::new(stdout());
     /// ```
     pub const fn new(writer: W) -> Self {
-        Self { writer }
+        Self {
+            writer: CountingWriter {
+                inner: writer,
+                count: 0,
+            },
+            simulate_dim: false,
+            color_depth: ColorDepth::TrueColor,
+        }
+    }
+
+    /// Sets the color depth of the terminal.
+    ///
+    /// Colors which can't be displayed with the given depth are converted to the nearest color
+    /// which can, using [`Color::to_depth`], when the cells are drawn. E.g. with
+    /// [`ColorDepth::Ansi16`], RGB and indexed colors are sent as one of the 16 standard colors.
+    ///
+    /// Defaults to [`ColorDepth::TrueColor`], where colors are sent as is. Use
+    /// [`ColorDepth::detect`] to guess the depth of the terminal from the environment.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use std::io::stdout;
+    /// # use ratatui::{prelude::*, style::ColorDepth};
+    /// let backend = CrosstermBackend::new(stdout()).color_depth(ColorDepth::detect());
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn color_depth(mut self, color_depth: ColorDepth) -> Self {
+        self.color_depth = color_depth;
+        self
+    }
+
+    /// Sets whether [`Modifier::DIM`] is simulated instead of being sent to the terminal.
+    ///
+    /// Some terminals ignore the dim attribute (SGR 2), which makes dimmed text indistinguishable
+    /// from normal text. When enabled, cells with the `DIM` modifier and both an
+    /// [RGB](Color::Rgb) foreground and background color are drawn with the foreground color
+    /// blended halfway toward the background color, without the dim attribute. The dim attribute
+    /// is still sent for other colors, as the actual color displayed by the terminal is unknown.
+    ///
+    /// This is disabled by default.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use std::io::stdout;
+    /// # use ratatui::prelude::*;
+    /// let backend = CrosstermBackend::new(stdout()).simulate_dim(true);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn simulate_dim(mut self, simulate_dim: bool) -> Self {
+        self.simulate_dim = simulate_dim;
+        self
     }
 
     /// Gets the writer.
@@ -115,7 +178,7 @@ where
         issue = "https://github.com/ratatui/ratatui/pull/991"
     )]
     pub const fn writer(&self) -> &W {
-        &self.writer
+        &self.writer.inner
     }
 
     /// Gets the writer as a mutable reference.
@@ -127,7 +190,7 @@ where
         issue = "https://github.com/ratatui/ratatui/pull/991"
     )]
     pub fn writer_mut(&mut self) -> &mut W {
-        &mut self.writer
+        &mut self.writer.inner
     }
 }
 
@@ -159,54 +222,87 @@ where
         #[cfg(feature = "underline-color")]
         let mut underline_color = Color::Reset;
         let mut modifier = Modifier::empty();
-        let mut last_pos: Option<Position> = None;
+        let mut hyperlink: Option<&Hyperlink> = None;
+        // The position of the cursor after printing the previous cell
+        let mut cursor: Option<Position> = None;
+        // Contiguous symbols sharing the same style are printed at once
+        let mut run = String::new();
         for (x, y, cell) in content {
-            // Move the cursor if the previous location was not (x - 1, y)
-            if !matches!(last_pos, Some(p) if x == p.x + 1 && y == p.y) {
+            let (foreground, cell_modifier) = if self.simulate_dim {
+                simulated_dim(cell)
+            } else {
+                (cell.fg, cell.modifier)
+            };
+            let foreground = foreground.to_depth(self.color_depth);
+            let background = cell.bg.to_depth(self.color_depth);
+            // Move the cursor only if the cell does not follow the previous one. Terminals may
+            // not agree on the width of symbols that are not one column wide (e.g. emoji
+            // sequences or ambiguous CJK characters), so the cursor is always moved after them.
+            if cursor != Some(Position { x, y }) {
+                print_run(&mut self.writer, &mut run)?;
                 queue!(self.writer, MoveTo(x, y))?;
             }
-            last_pos = Some(Position { x, y });
-            if cell.modifier != modifier {
+            cursor = (cell.symbol().width() == 1).then(|| Position {
+                x: x.saturating_add(1),
+                y,
+            });
+            if cell_modifier != modifier {
+                print_run(&mut self.writer, &mut run)?;
                 let diff = ModifierDiff {
                     from: modifier,
-                    to: cell.modifier,
+                    to: cell_modifier,
                 };
                 diff.queue(&mut self.writer)?;
-                modifier = cell.modifier;
+                modifier = cell_modifier;
             }
-            if cell.fg != fg || cell.bg != bg {
-                queue!(
-                    self.writer,
-                    SetColors(Colors::new(cell.fg.into(), cell.bg.into()))
-                )?;
-                fg = cell.fg;
-                bg = cell.bg;
+            if foreground != fg || background != bg {
+                print_run(&mut self.writer, &mut run)?;
+                if self.color_depth == ColorDepth::Ansi16 {
+                    queue_ansi16_colors(&mut self.writer, foreground, background)?;
+                } else {
+                    queue!(
+                        self.writer,
+                        SetColors(Colors::new(foreground.into(), background.into()))
+                    )?;
+                }
+                fg = foreground;
+                bg = background;
             }
             #[cfg(feature = "underline-color")]
-            if cell.underline_color != underline_color {
-                let color = CColor::from(cell.underline_color);
-                queue!(self.writer, SetUnderlineColor(color))?;
-                underline_color = cell.underline_color;
+            {
+                let cell_underline_color = cell.underline_color.to_depth(self.color_depth);
+                if cell_underline_color != underline_color {
+                    print_run(&mut self.writer, &mut run)?;
+                    let color = CColor::from(cell_underline_color);
+                    queue!(self.writer, SetUnderlineColor(color))?;
+                    underline_color = cell_underline_color;
+                }
+            }
+            if cell.hyperlink.as_ref() != hyperlink {
+                print_run(&mut self.writer, &mut run)?;
+                queue_hyperlink(&mut self.writer, cell.hyperlink())?;
+                hyperlink = cell.hyperlink.as_ref();
             }
 
-            queue!(self.writer, Print(cell.symbol()))?;
+            run.push_str(cell.symbol());
+        }
+        print_run(&mut self.writer, &mut run)?;
+        if hyperlink.is_some() {
+            queue_hyperlink(&mut self.writer, None)?;
         }
 
-        #[cfg(feature = "underline-color")]
-        return queue!(
-            self.writer,
-            SetForegroundColor(CColor::Reset),
-            SetBackgroundColor(CColor::Reset),
-            SetUnderlineColor(CColor::Reset),
-            SetAttribute(CAttribute::Reset),
-        );
-        #[cfg(not(feature = "underline-color"))]
-        return queue!(
+        queue!(
             self.writer,
             SetForegroundColor(CColor::Reset),
             SetBackgroundColor(CColor::Reset),
-            SetAttribute(CAttribute::Reset),
-        );
+        )?;
+        // Only reset the underline color if it was changed, so that terminals which don't support
+        // underline colors never receive the sequence.
+        #[cfg(feature = "underline-color")]
+        if underline_color != Color::Reset {
+            queue!(self.writer, SetUnderlineColor(CColor::Reset))?;
+        }
+        queue!(self.writer, SetAttribute(CAttribute::Reset))
     }
 
     fn hide_cursor(&mut self) -> io::Result<()> {
@@ -252,6 +348,18 @@ where
         self.writer.flush()
     }
 
+    fn set_line_size(&mut self, y: u16, size: LineSize) -> io::Result<()> {
+        // The DEC line attributes apply to the row the cursor is on
+        queue!(self.writer, MoveTo(0, y))?;
+        let attribute = match size {
+            LineSize::Normal => '5',
+            LineSize::DoubleWidth => '6',
+            LineSize::DoubleHeightTop => '3',
+            LineSize::DoubleHeightBottom => '4',
+        };
+        write!(self.writer, "\x1b#{attribute}")
+    }
+
     fn size(&self) -> io::Result<Size> {
         let (width, height) = terminal::size()?;
         Ok(Size { width, height })
@@ -276,6 +384,77 @@ where
     fn flush(&mut self) -> io::Result<()> {
         self.writer.flush()
     }
+
+    fn bytes_written(&self) -> Option<u64> {
+        Some(self.writer.count)
+    }
+}
+
+/// A writer which counts the bytes written to the inner writer
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+struct CountingWriter<W> {
+    inner: W,
+    count: u64,
+}
+
+impl<W: Write> Write for CountingWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let written = self.inner.write(buf)?;
+        self.count += written as u64;
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+/// Queues the pending run of symbols, if any, and clears it
+fn print_run<W: Write>(writer: &mut W, run: &mut String) -> io::Result<()> {
+    if !run.is_empty() {
+        queue!(writer, Print(&run))?;
+        run.clear();
+    }
+    Ok(())
+}
+
+/// Returns the foreground color and modifier to draw a cell with when [`Modifier::DIM`] is
+/// simulated
+///
+/// A dimmed cell with RGB colors is drawn with its foreground color blended halfway toward its
+/// background color, without the `DIM` modifier.
+fn simulated_dim(cell: &Cell) -> (Color, Modifier) {
+    match (cell.fg, cell.bg) {
+        (Color::Rgb(fr, fg, fb), Color::Rgb(br, bg, bb))
+            if cell.modifier.contains(Modifier::DIM) =>
+        {
+            let blend = |from: u8, to: u8| ((u16::from(from) + u16::from(to)) / 2) as u8;
+            let color = Color::Rgb(blend(fr, br), blend(fg, bg), blend(fb, bb));
+            (color, cell.modifier - Modifier::DIM)
+        }
+        _ => (cell.fg, cell.modifier),
+    }
+}
+
+/// Sets the foreground and background colors with the SGR parameters of the 16 standard colors
+///
+/// Crossterm sends the standard colors as indexed colors (e.g. `38;5;3`), which terminals that
+/// only support 16 colors may not understand. The colors are expected to have been converted with
+/// [`Color::to_depth`], other colors reset the default color.
+fn queue_ansi16_colors<W: Write>(writer: &mut W, fg: Color, bg: Color) -> io::Result<()> {
+    // the bright colors have their own range of parameters
+    let code = |color: Color| match color.ansi_index() {
+        Some(index @ 0..=7) => 30 + index,
+        Some(index) => 90 + index - 8,
+        None => 39,
+    };
+    // the background parameters are the foreground ones plus 10
+    write!(writer, "\x1b[{};{}m", code(fg), code(bg) + 10)
+}
+
+/// Starts an OSC 8 hyperlink to the given URL, or ends the current one if `url` is `None`.
+fn queue_hyperlink<W: Write>(writer: &mut W, url: Option<&str>) -> io::Result<()> {
+    write!(writer, "\x1b]8;;{}\x1b\\", url.unwrap_or_default())
 }
 
 impl From<Color> for CColor {
@@ -343,16 +522,16 @@ impl ModifierDiff {
     where
         W: io::Write,
     {
-        //use crossterm::Attribute;
         let removed = self.from - self.to;
+        let mut added = self.to - self.from;
         if removed.contains(Modifier::REVERSED) {
             queue!(w, SetAttribute(CAttribute::NoReverse))?;
         }
-        if removed.contains(Modifier::BOLD) {
+        if removed.intersects(Modifier::BOLD | Modifier::DIM) {
+            // `NormalIntensity` resets both bold and dim, so whichever of them is still active
+            // needs to be enabled again afterwards
             queue!(w, SetAttribute(CAttribute::NormalIntensity))?;
-            if self.to.contains(Modifier::DIM) {
-                queue!(w, SetAttribute(CAttribute::Dim))?;
-            }
+            added |= self.to & (Modifier::BOLD | Modifier::DIM);
         }
         if removed.contains(Modifier::ITALIC) {
             queue!(w, SetAttribute(CAttribute::NoItalic))?;
@@ -360,17 +539,18 @@ impl ModifierDiff {
         if removed.contains(Modifier::UNDERLINED) {
             queue!(w, SetAttribute(CAttribute::NoUnderline))?;
         }
-        if removed.contains(Modifier::DIM) {
-            queue!(w, SetAttribute(CAttribute::NormalIntensity))?;
-        }
         if removed.contains(Modifier::CROSSED_OUT) {
             queue!(w, SetAttribute(CAttribute::NotCrossedOut))?;
         }
-        if removed.contains(Modifier::SLOW_BLINK) || removed.contains(Modifier::RAPID_BLINK) {
+        if removed.intersects(Modifier::SLOW_BLINK | Modifier::RAPID_BLINK) {
+            // `NoBlink` resets both blink speeds
             queue!(w, SetAttribute(CAttribute::NoBlink))?;
+            added |= self.to & (Modifier::SLOW_BLINK | Modifier::RAPID_BLINK);
+        }
+        if removed.contains(Modifier::HIDDEN) {
+            queue!(w, SetAttribute(CAttribute::NoHidden))?;
         }
 
-        let added = self.to - self.from;
         if added.contains(Modifier::REVERSED) {
             queue!(w, SetAttribute(CAttribute::Reverse))?;
         }
@@ -395,6 +575,9 @@ impl ModifierDiff {
         if added.contains(Modifier::RAPID_BLINK) {
             queue!(w, SetAttribute(CAttribute::RapidBlink))?;
         }
+        if added.contains(Modifier::HIDDEN) {
+            queue!(w, SetAttribute(CAttribute::Hidden))?;
+        }
 
         Ok(())
     }
@@ -489,7 +672,10 @@ impl From<ContentStyle> for Style {
 
 #[cfg(test)]
 mod tests {
+    use rstest::rstest;
+
     use super::*;
+    use crate::style::Stylize;
 
     #[test]
     fn from_crossterm_color() {
@@ -527,8 +713,47 @@ mod tests {
     }
 
     mod modifier {
+
         use super::*;
 
+        #[rstest]
+        #[case::add_bold_keep_underlined(
+            Modifier::UNDERLINED,
+            Modifier::UNDERLINED | Modifier::BOLD,
+            "\x1b[1m"
+        )]
+        #[case::remove_underlined_keep_crossed_out(
+            Modifier::UNDERLINED | Modifier::CROSSED_OUT,
+            Modifier::CROSSED_OUT,
+            "\x1b[24m"
+        )]
+        #[case::remove_crossed_out_keep_underlined(
+            Modifier::UNDERLINED | Modifier::CROSSED_OUT,
+            Modifier::UNDERLINED,
+            "\x1b[29m"
+        )]
+        #[case::add_crossed_out_and_underlined(
+            Modifier::empty(),
+            Modifier::UNDERLINED | Modifier::CROSSED_OUT,
+            "\x1b[4m\x1b[9m"
+        )]
+        #[case::remove_bold_keep_dim(Modifier::BOLD | Modifier::DIM, Modifier::DIM, "\x1b[22m\x1b[2m")]
+        #[case::remove_dim_keep_bold(Modifier::BOLD | Modifier::DIM, Modifier::BOLD, "\x1b[22m\x1b[1m")]
+        #[case::remove_bold_and_dim(Modifier::BOLD | Modifier::DIM, Modifier::empty(), "\x1b[22m")]
+        #[case::replace_bold_with_dim(Modifier::BOLD, Modifier::DIM, "\x1b[22m\x1b[2m")]
+        #[case::remove_slow_blink_keep_rapid_blink(
+            Modifier::SLOW_BLINK | Modifier::RAPID_BLINK,
+            Modifier::RAPID_BLINK,
+            "\x1b[25m\x1b[6m"
+        )]
+        #[case::add_and_remove_hidden(Modifier::HIDDEN, Modifier::ITALIC, "\x1b[28m\x1b[3m")]
+        #[case::unchanged(Modifier::all(), Modifier::all(), "")]
+        fn modifier_diff(#[case] from: Modifier, #[case] to: Modifier, #[case] expected: &str) {
+            let mut bytes = Vec::new();
+            ModifierDiff { from, to }.queue(&mut bytes).unwrap();
+            assert_eq!(String::from_utf8(bytes).unwrap(), expected);
+        }
+
         #[test]
         fn from_crossterm_attribute() {
             assert_eq!(Modifier::from(CAttribute::Reset), Modifier::empty());
@@ -698,4 +923,203 @@ mod tests {
             Style::default().underline_color(Color::Red)
         );
     }
+
+    #[test]
+    fn draw_coalesces_contiguous_cells() {
+        let mut backend = CrosstermBackend::new(Vec::new());
+        let (a, b, c) = (Cell::new("a"), Cell::new("b"), Cell::new("c"));
+        backend
+            .draw([(0, 0, &a), (1, 0, &b), (3, 0, &c)].into_iter())
+            .unwrap();
+        let output = String::from_utf8(backend.writer.inner).unwrap();
+        assert_eq!(output, "\x1b[1;1Hab\x1b[1;4Hc\x1b[39m\x1b[49m\x1b[0m");
+    }
+
+    #[test]
+    fn draw_moves_cursor_after_wide_symbols() {
+        let mut backend = CrosstermBackend::new(Vec::new());
+        let (wide, a) = (Cell::new("你"), Cell::new("a"));
+        backend
+            .draw([(0, 0, &wide), (2, 0, &a), (3, 0, &a)].into_iter())
+            .unwrap();
+        let output = String::from_utf8(backend.writer.inner).unwrap();
+        assert_eq!(output, "\x1b[1;1H你\x1b[1;3Haa\x1b[39m\x1b[49m\x1b[0m");
+    }
+
+    #[test]
+    fn draw_moves_cursor_after_emoji_sequences() {
+        let mut backend = CrosstermBackend::new(Vec::new());
+        let mut family = Cell::EMPTY;
+        family.set_symbol("👨‍👩‍👧‍👦");
+        let a = Cell::new("a");
+        backend
+            .draw([(0, 0, &family), (2, 0, &a), (3, 0, &a)].into_iter())
+            .unwrap();
+        let output = String::from_utf8(backend.writer.inner).unwrap();
+        assert_eq!(output, "\x1b[1;1H👨‍👩‍👧‍👦\x1b[1;3Haa\x1b[39m\x1b[49m\x1b[0m");
+    }
+
+    #[test]
+    fn draw_splits_runs_on_style_changes() {
+        let mut backend = CrosstermBackend::new(Vec::new());
+        let a = Cell::new("a");
+        let mut b = Cell::new("b");
+        b.set_style(Style::new().add_modifier(Modifier::BOLD));
+        backend
+            .draw([(0, 0, &a), (1, 0, &a), (2, 0, &b)].into_iter())
+            .unwrap();
+        let output = String::from_utf8(backend.writer.inner).unwrap();
+        assert_eq!(output, "\x1b[1;1Haa\x1b[1mb\x1b[39m\x1b[49m\x1b[0m");
+    }
+
+    #[test]
+    fn draw_hyperlinks() {
+        let mut backend = CrosstermBackend::new(Vec::new());
+        let mut link = Cell::new("a");
+        link.set_hyperlink(Some("https://ratatui.rs"));
+        let plain = Cell::new("b");
+        backend
+            .draw([(0, 0, &link), (1, 0, &link), (2, 0, &plain), (3, 0, &link)].into_iter())
+            .unwrap();
+        let output = String::from_utf8(backend.writer.inner).unwrap();
+        assert_eq!(
+            output,
+            "\x1b[1;1H\x1b]8;;https://ratatui.rs\x1b\\aa\x1b]8;;\x1b\\b\
+             \x1b]8;;https://ratatui.rs\x1b\\a\x1b]8;;\x1b\\\x1b[39m\x1b[49m\x1b[0m"
+        );
+    }
+
+    #[test]
+    fn draw_hyperlinks_without_control_characters() {
+        let mut backend = CrosstermBackend::new(Vec::new());
+        let mut link = Cell::new("a");
+        link.set_hyperlink(Some("https://ratatui.rs\x1b\\\x1b[2J"));
+        backend.draw([(0, 0, &link)].into_iter()).unwrap();
+        let output = String::from_utf8(backend.writer.inner).unwrap();
+        assert_eq!(
+            output,
+            "\x1b[1;1H\x1b]8;;https://ratatui.rs\\[2J\x1b\\a\x1b]8;;\x1b\\\x1b[39m\x1b[49m\x1b[0m"
+        );
+    }
+
+    #[test]
+    fn set_line_size() {
+        let mut backend = CrosstermBackend::new(Vec::new());
+        backend.set_line_size(2, LineSize::DoubleWidth).unwrap();
+        backend.set_line_size(3, LineSize::DoubleHeightTop).unwrap();
+        backend
+            .set_line_size(4, LineSize::DoubleHeightBottom)
+            .unwrap();
+        backend.set_line_size(5, LineSize::Normal).unwrap();
+        let output = String::from_utf8(backend.writer.inner).unwrap();
+        assert_eq!(
+            output,
+            "\x1b[3;1H\x1b#6\x1b[4;1H\x1b#3\x1b[5;1H\x1b#4\x1b[6;1H\x1b#5"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "underline-color")]
+    fn draw_underline_color() {
+        let mut backend = CrosstermBackend::new(Vec::new());
+        let mut underlined = Cell::new("a");
+        underlined.set_style(Style::default().underline_color(Color::Red));
+        let plain = Cell::new("b");
+        backend
+            .draw([(0, 0, &underlined), (1, 0, &plain)].into_iter())
+            .unwrap();
+        let output = String::from_utf8(backend.writer.inner).unwrap();
+        assert!(output.contains("\x1b[58;5;1ma"));
+        assert!(output.contains("\x1b[59mb"));
+    }
+
+    #[test]
+    #[cfg(feature = "underline-color")]
+    fn draw_without_underline_color_emits_no_underline_sequence() {
+        let mut backend = CrosstermBackend::new(Vec::new());
+        let cell = Cell::new("a");
+        backend.draw([(0, 0, &cell)].into_iter()).unwrap();
+        let output = String::from_utf8(backend.writer.inner).unwrap();
+        assert!(!output.contains("\x1b[58"));
+        assert!(!output.contains("\x1b[59m"));
+    }
+
+    #[test]
+    fn draw_simulated_dim() {
+        let mut backend = CrosstermBackend::new(Vec::new()).simulate_dim(true);
+        let mut rgb = Cell::new("a");
+        rgb.set_style(
+            Style::new()
+                .fg(Color::Rgb(200, 100, 0))
+                .bg(Color::Rgb(0, 0, 50))
+                .dim(),
+        );
+        let mut indexed = Cell::new("b");
+        indexed.set_style(Style::new().red().dim());
+        backend
+            .draw([(0, 0, &rgb), (1, 0, &indexed)].into_iter())
+            .unwrap();
+        let output = String::from_utf8(backend.writer.inner).unwrap();
+        assert_eq!(
+            output,
+            "\x1b[1;1H\x1b[38;2;100;50;25;48;2;0;0;50ma\x1b[2m\x1b[38;5;1;49mb\
+             \x1b[39m\x1b[49m\x1b[0m"
+        );
+    }
+
+    #[test]
+    fn draw_dim_is_not_simulated_by_default() {
+        let mut backend = CrosstermBackend::new(Vec::new());
+        let mut cell = Cell::new("a");
+        cell.set_style(
+            Style::new()
+                .fg(Color::Rgb(200, 100, 0))
+                .bg(Color::Rgb(0, 0, 50))
+                .dim(),
+        );
+        backend.draw([(0, 0, &cell)].into_iter()).unwrap();
+        let output = String::from_utf8(backend.writer.inner).unwrap();
+        assert!(output.starts_with("\x1b[1;1H\x1b[2m\x1b[38;2;200;100;0;48;2;0;0;50ma"));
+    }
+
+    #[rstest]
+    #[case::true_color(ColorDepth::TrueColor, "\x1b[1;1H\x1b[38;2;255;130;10;48;5;208ma")]
+    #[case::indexed(ColorDepth::Indexed256, "\x1b[1;1H\x1b[38;5;208;48;5;208ma")]
+    #[case::ansi(ColorDepth::Ansi16, "\x1b[1;1H\x1b[33;43ma")]
+    #[case::monochrome(ColorDepth::Monochrome, "\x1b[1;1Ha")]
+    fn draw_with_color_depth(#[case] depth: ColorDepth, #[case] expected: &str) {
+        let mut backend = CrosstermBackend::new(Vec::new()).color_depth(depth);
+        let mut cell = Cell::new("a");
+        cell.set_style(
+            Style::new()
+                .fg(Color::Rgb(255, 130, 10))
+                .bg(Color::Indexed(208)),
+        );
+        backend.draw([(0, 0, &cell)].into_iter()).unwrap();
+        let output = String::from_utf8(backend.writer.inner).unwrap();
+        assert!(output.starts_with(expected), "{output:?}");
+    }
+
+    #[test]
+    fn draw_ansi16_bright_colors() {
+        let mut backend = CrosstermBackend::new(Vec::new()).color_depth(ColorDepth::Ansi16);
+        let mut cell = Cell::new("a");
+        cell.set_style(Style::new().fg(Color::LightRed));
+        backend.draw([(0, 0, &cell)].into_iter()).unwrap();
+        let output = String::from_utf8(backend.writer.inner).unwrap();
+        assert!(output.starts_with("\x1b[1;1H\x1b[91;49ma"), "{output:?}");
+    }
+
+    #[test]
+    fn bytes_written() {
+        let mut backend = CrosstermBackend::new(Vec::new());
+        assert_eq!(backend.bytes_written(), Some(0));
+        backend.draw([(0, 0, &Cell::new("a"))].into_iter()).unwrap();
+        backend.hide_cursor().unwrap();
+        assert_eq!(
+            backend.bytes_written(),
+            Some(backend.writer.inner.len() as u64)
+        );
+        assert!(backend.bytes_written() > Some(0));
+    }
 }
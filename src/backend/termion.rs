@@ -410,11 +410,14 @@ impl fmt::Display for ModifierDiff {
         if remove.contains(Modifier::CROSSED_OUT) {
             write!(f, "{}", termion::style::NoCrossedOut)?;
         }
-        if remove.contains(Modifier::SLOW_BLINK) || remove.contains(Modifier::RAPID_BLINK) {
+        let mut add = self.to - self.from;
+        if remove.intersects(Modifier::SLOW_BLINK | Modifier::RAPID_BLINK) {
             write!(f, "{}", termion::style::NoBlink)?;
+
+            // NoBlink disables both blink speeds, so re-enable blinking if it is still wanted
+            add |= self.to & (Modifier::SLOW_BLINK | Modifier::RAPID_BLINK);
         }
 
-        let add = self.to - self.from;
         if add.contains(Modifier::REVERSED) {
             write!(f, "{}", termion::style::Invert)?;
         }
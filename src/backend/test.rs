@@ -12,6 +12,7 @@ use crate::{
     backend::{Backend, ClearType, WindowSize},
     buffer::{Buffer, Cell},
     layout::{Position, Rect, Size},
+    text::LineSize,
 };
 
 /// A [`Backend`] implementation used for integration testing that renders to an memory buffer.
@@ -145,13 +146,10 @@ impl TestBackend {
     /// When the scrollback buffer is not equal, a panic occurs with a detailed error message
     /// showing the differences between the expected and actual buffers.
     pub fn assert_scrollback_empty(&self) {
-        let expected = Buffer {
-            area: Rect {
-                width: self.scrollback.area.width,
-                ..Rect::ZERO
-            },
-            content: vec![],
-        };
+        let expected = Buffer::empty(Rect {
+            width: self.scrollback.area.width,
+            ..Rect::ZERO
+        });
         self.assert_scrollback(&expected);
     }
 
@@ -223,6 +221,11 @@ impl Backend for TestBackend {
         Ok(())
     }
 
+    fn set_line_size(&mut self, y: u16, size: LineSize) -> io::Result<()> {
+        self.buffer.set_line_size(y, size);
+        Ok(())
+    }
+
     fn hide_cursor(&mut self) -> io::Result<()> {
         self.cursor = false;
         Ok(())
@@ -870,6 +873,7 @@ mod tests {
             Buffer {
                 area: Rect::new(0, 0, 10, 5),
                 content: backend.scrollback.content[0..10 * 5].to_vec(),
+                line_sizes: vec![],
             },
             Buffer::with_lines([
                 "         6",
@@ -885,6 +889,7 @@ mod tests {
             Buffer {
                 area: Rect::new(0, 0, 10, 5),
                 content: backend.scrollback.content[10 * 65530..10 * 65535].to_vec(),
+                line_sizes: vec![],
             },
             Buffer::with_lines([
                 "     65536",
@@ -4,6 +4,11 @@
 mod assert;
 mod buffer;
 mod cell;
+mod hyperlink;
+mod view;
 
 pub use buffer::Buffer;
 pub use cell::Cell;
+#[cfg(feature = "crossterm")]
+pub(crate) use hyperlink::Hyperlink;
+pub use view::BufferView;
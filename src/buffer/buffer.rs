@@ -6,7 +6,13 @@ use std::{
 use unicode_segmentation::UnicodeSegmentation;
 use unicode_width::UnicodeWidthStr;
 
-use crate::{buffer::Cell, layout::Position, prelude::*};
+use super::hyperlink::Hyperlink;
+use crate::{
+    buffer::{BufferView, Cell},
+    layout::Position,
+    prelude::*,
+    text::LineSize,
+};
 
 /// A buffer that maps to the desired content of the terminal after the draw call
 ///
@@ -68,6 +74,16 @@ pub struct Buffer {
     /// The content of the buffer. The length of this Vec should always be equal to area.width *
     /// area.height
     pub content: Vec<Cell>,
+    /// The size of each row, from the top of the buffer, up to the last row which is not
+    /// displayed at [`LineSize::Normal`]
+    ///
+    /// The rows past the end are displayed at [`LineSize::Normal`], so buffers which don't use
+    /// line sizes don't allocate.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
+    pub(crate) line_sizes: Vec<LineSize>,
 }
 
 impl Buffer {
@@ -82,7 +98,11 @@ impl Buffer {
     pub fn filled(area: Rect, cell: Cell) -> Self {
         let size = area.area() as usize;
         let content = vec![cell; size];
-        Self { area, content }
+        Self {
+            area,
+            content,
+            line_sizes: Vec::new(),
+        }
     }
 
     /// Returns a Buffer containing the given lines
@@ -102,6 +122,116 @@ impl Buffer {
         buffer
     }
 
+    /// Returns a Buffer of the given area with the content drawn by the given closure
+    ///
+    /// The closure receives a [`Frame`] like the one passed to [`Terminal::draw`], except that it
+    /// renders to a buffer instead of a terminal. This makes it possible to render any widget
+    /// without a backend, e.g. to generate screenshots or to convert the result to another format.
+    /// The position of the cursor set on the frame is discarded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::{prelude::*, widgets::Block};
+    ///
+    /// let buffer = Buffer::render_frame(Rect::new(0, 0, 5, 3), |frame| {
+    ///     frame.render_widget(Block::bordered(), frame.area());
+    /// });
+    /// assert_eq!(buffer, Buffer::with_lines(["┌───┐", "│   │", "└───┘"]));
+    /// ```
+    pub fn render_frame<F>(area: Rect, render: F) -> Self
+    where
+        F: FnOnce(&mut Frame),
+    {
+        let mut buffer = Self::empty(area);
+        let mut frame = Frame {
+            cursor_position: None,
+            viewport_area: area,
+            buffer: &mut buffer,
+            count: 0,
+            accessibility_tree: None,
+            render_cache: None,
+        };
+        render(&mut frame);
+        buffer
+    }
+
+    /// Renders a [`Widget`] to the buffer using [`Widget::render`]
+    ///
+    /// This is the same as calling `widget.render(area, buffer)`, which reads more naturally when
+    /// rendering several widgets to a buffer outside of a [`Terminal`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::prelude::*;
+    ///
+    /// let mut buffer = Buffer::empty(Rect::new(0, 0, 5, 1));
+    /// buffer.render_widget(Line::from("Hello"), buffer.area);
+    /// assert_eq!(buffer, Buffer::with_lines(["Hello"]));
+    /// ```
+    pub fn render_widget<W: Widget>(&mut self, widget: W, area: Rect) {
+        widget.render(area, self);
+    }
+
+    /// Renders each widget into the area at the same position
+    ///
+    /// This is useful to render several widgets into the areas returned by a [`Layout`]. If there
+    /// are more widgets than areas, or more areas than widgets, the extra ones are ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::prelude::*;
+    ///
+    /// let mut buffer = Buffer::empty(Rect::new(0, 0, 6, 1));
+    /// let areas = Layout::horizontal([2, 4]).split(buffer.area);
+    /// buffer.render_widgets(["ab", "cdef"], areas.iter().copied());
+    /// assert_eq!(buffer, Buffer::with_lines(["abcdef"]));
+    /// ```
+    pub fn render_widgets<I, A>(&mut self, widgets: I, areas: A)
+    where
+        I: IntoIterator,
+        I::Item: Widget,
+        A: IntoIterator<Item = Rect>,
+    {
+        for (widget, area) in widgets.into_iter().zip(areas) {
+            widget.render(area, self);
+        }
+    }
+
+    /// Renders a [`StatefulWidget`] to the buffer using [`StatefulWidget::render`]
+    ///
+    /// This is the same as calling `widget.render(area, buffer, state)`.
+    pub fn render_stateful_widget<W: StatefulWidget>(
+        &mut self,
+        widget: W,
+        area: Rect,
+        state: &mut W::State,
+    ) {
+        widget.render(area, self, state);
+    }
+
+    /// Returns a mutable [`BufferView`] of the given area of the buffer
+    ///
+    /// The coordinates used with the view are relative to the top-left corner of `area`, and
+    /// anything written outside of the view is clipped. If `area` is not fully contained in the
+    /// buffer, the view only covers the part of `area` which lies inside the buffer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::prelude::*;
+    ///
+    /// let mut buffer = Buffer::empty(Rect::new(0, 0, 5, 2));
+    /// let mut view = buffer.view_mut(Rect::new(1, 1, 3, 1));
+    /// view.render_widget(Line::from("Hello"), view.area());
+    /// assert_eq!(buffer, Buffer::with_lines(["     ", " Hel "]));
+    /// ```
+    pub fn view_mut(&mut self, area: Rect) -> BufferView<'_> {
+        BufferView::new(self, area)
+    }
+
     /// Returns the content of the buffer as a slice
     pub fn content(&self) -> &[Cell] {
         &self.content
@@ -362,15 +492,80 @@ impl Buffer {
                 line.style.patch(span.style),
             );
             let w = pos.0.saturating_sub(x);
+            self.set_hyperlink(Rect::new(x, y, w, 1), span.hyperlink.as_deref());
             x = pos.0;
             remaining_width = remaining_width.saturating_sub(w);
         }
         (x, y)
     }
 
+    /// Print a line vertically, one grapheme per row, starting at the position (x, y) and going
+    /// down for at most `max_height` rows
+    ///
+    /// The graphemes are displayed upright, as characters can't be rotated in a terminal. This is
+    /// useful for labels on the side of a widget, e.g. the title of a vertical axis. Wide
+    /// graphemes take two columns, and the line is cut at the first grapheme which doesn't fit in
+    /// the buffer. When `rotate_symbols` is set, the [line symbols](crate::symbols::line) are
+    /// [rotated](crate::symbols::line::rotate) a quarter turn, so that e.g. `─` is displayed as
+    /// `│`.
+    ///
+    /// Returns the position below the last printed grapheme.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui::prelude::*;
+    ///
+    /// let mut buf = Buffer::empty(Rect::new(0, 0, 1, 4));
+    /// buf.set_line_vertical(0, 0, &Line::from("ab─"), 4, true);
+    /// assert_eq!(buf, Buffer::with_lines(["a", "b", "│", " "]));
+    /// ```
+    pub fn set_line_vertical(
+        &mut self,
+        x: u16,
+        y: u16,
+        line: &Line<'_>,
+        max_height: u16,
+        rotate_symbols: bool,
+    ) -> (u16, u16) {
+        if !self.area.contains(Position::new(x, y)) {
+            return (x, y);
+        }
+        let bottom = y.saturating_add(max_height).min(self.area.bottom());
+        let mut row = y;
+        for span in line {
+            let style = line.style.patch(span.style);
+            let graphemes = UnicodeSegmentation::graphemes(span.content.as_ref(), true)
+                .filter(|symbol| !symbol.contains(|char: char| char.is_control()))
+                .map(|symbol| (symbol, symbol.width() as u16))
+                .filter(|(_symbol, width)| *width > 0);
+            for (symbol, width) in graphemes {
+                if row >= bottom || x + width > self.area.right() {
+                    return (x, row);
+                }
+                let symbol = if rotate_symbols {
+                    crate::symbols::line::rotate(symbol).unwrap_or(symbol)
+                } else {
+                    symbol
+                };
+                self[(x, row)].set_symbol(symbol).set_style(style);
+                // Reset following cells if multi-width (they would be hidden by the grapheme)
+                for hidden in x + 1..x + width {
+                    self[(hidden, row)].reset();
+                }
+                self.set_hyperlink(Rect::new(x, row, width, 1), span.hyperlink.as_deref());
+                row += 1;
+            }
+        }
+        (x, row)
+    }
+
     /// Print a span, starting at the position (x, y)
     pub fn set_span(&mut self, x: u16, y: u16, span: &Span<'_>, max_width: u16) -> (u16, u16) {
-        self.set_stringn(x, y, &span.content, max_width as usize, span.style)
+        let end = self.set_stringn(x, y, &span.content, max_width as usize, span.style);
+        let area = Rect::new(x, y, end.0.saturating_sub(x), 1);
+        self.set_hyperlink(area, span.hyperlink.as_deref());
+        end
     }
 
     /// Set the style of all cells in the given area.
@@ -387,6 +582,60 @@ impl Buffer {
         }
     }
 
+    /// Set the URL that all cells in the given area link to, or remove their link if `url` is
+    /// `None`.
+    ///
+    /// The URL is allocated once and shared by all the cells. See [`Cell::set_hyperlink`].
+    pub fn set_hyperlink(&mut self, area: Rect, url: Option<&str>) {
+        let area = self.area.intersection(area);
+        let hyperlink = url.map(Hyperlink::new);
+        for y in area.top()..area.bottom() {
+            for x in area.left()..area.right() {
+                self[(x, y)].hyperlink.clone_from(&hyperlink);
+            }
+        }
+    }
+
+    /// Set the size at which the terminal displays the row `y`.
+    ///
+    /// The size applies to the whole row, regardless of the area that was rendered to. Rows
+    /// outside of the buffer are ignored. See [`LineSize`] for more information.
+    pub fn set_line_size(&mut self, y: u16, size: LineSize) {
+        if y < self.area.top() || y >= self.area.bottom() {
+            return;
+        }
+        let row = usize::from(y - self.area.top());
+        if size != LineSize::Normal && row >= self.line_sizes.len() {
+            self.line_sizes.resize(row + 1, LineSize::Normal);
+        }
+        if let Some(row_size) = self.line_sizes.get_mut(row) {
+            *row_size = size;
+        }
+        self.trim_line_sizes();
+    }
+
+    /// Drops the sizes of the last rows which are displayed at [`LineSize::Normal`], so that
+    /// equal buffers have equal line sizes
+    fn trim_line_sizes(&mut self) {
+        let len = self
+            .line_sizes
+            .iter()
+            .rposition(|&size| size != LineSize::Normal)
+            .map_or(0, |row| row + 1);
+        self.line_sizes.truncate(len);
+    }
+
+    /// Returns the size at which the terminal displays the row `y`.
+    ///
+    /// Rows outside of the buffer, and empty buffers, are displayed at [`LineSize::Normal`].
+    pub fn line_size(&self, y: u16) -> LineSize {
+        if y < self.area.top() || y >= self.area.bottom() {
+            return LineSize::Normal;
+        }
+        let row = usize::from(y - self.area.top());
+        self.line_sizes.get(row).copied().unwrap_or_default()
+    }
+
     /// Resize the buffer so that the mapped area matches the given area and that the buffer
     /// length is equal to area.width * area.height
     pub fn resize(&mut self, area: Rect) {
@@ -396,6 +645,8 @@ impl Buffer {
         } else {
             self.content.resize(length, Cell::EMPTY);
         }
+        self.line_sizes.truncate(usize::from(area.height));
+        self.trim_line_sizes();
         self.area = area;
     }
 
@@ -404,11 +655,22 @@ impl Buffer {
         for cell in &mut self.content {
             cell.reset();
         }
+        self.line_sizes.clear();
     }
 
     /// Merge an other buffer into this one
     pub fn merge(&mut self, other: &Self) {
         let area = self.area.union(other.area);
+        // the rows of the other buffer take its line sizes, as its content is drawn over them
+        let line_sizes = (area.top()..area.bottom())
+            .map(|y| {
+                if y >= other.area.top() && y < other.area.bottom() {
+                    other.line_size(y)
+                } else {
+                    self.line_size(y)
+                }
+            })
+            .collect();
         self.content.resize(area.area() as usize, Cell::EMPTY);
 
         // Move original content to the appropriate space
@@ -433,6 +695,142 @@ impl Buffer {
             self.content[k] = other.content[i].clone();
         }
         self.area = area;
+        self.line_sizes = line_sizes;
+        self.trim_line_sizes();
+    }
+
+    /// Composites an other buffer on top of this one, at the given area
+    ///
+    /// This makes it possible to render independent layers into their own buffers and to stack
+    /// them afterwards. The top left cell of `other` is drawn at the top left corner of `area`, and
+    /// the cells which fall outside of `area` or of this buffer are clipped. Unlike
+    /// [`Buffer::merge`], the area of this buffer doesn't change.
+    ///
+    /// Blank cells of `other` (a space with no background color and no modifier) are transparent,
+    /// so the content of this buffer shows through them. The other cells replace the cells of this
+    /// buffer, except for their background: a cell of `other` with the [`Color::Reset`] background
+    /// keeps the background of the cell it is drawn over. A wide character is copied together with
+    /// the cells it covers, and is left out when it doesn't fit entirely in the area.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui::{buffer::Cell, prelude::*};
+    ///
+    /// let mut buffer = Buffer::filled(Rect::new(0, 0, 5, 1), Cell::new("."));
+    /// buffer.set_style(buffer.area, Style::new().bg(Color::Blue));
+    /// let mut layer = Buffer::empty(Rect::new(0, 0, 3, 1));
+    /// layer.set_string(0, 0, "a c", Style::new());
+    /// buffer.composite(&layer, Rect::new(1, 0, 3, 1));
+    ///
+    /// let mut expected = Buffer::with_lines([".a.c."]);
+    /// expected.set_style(expected.area, Style::new().bg(Color::Blue));
+    /// assert_eq!(buffer, expected);
+    /// ```
+    pub fn composite(&mut self, other: &Self, area: Rect) {
+        let clipped = area.intersection(self.area);
+        for y in clipped.top()..clipped.bottom() {
+            let source_y = other.area.y.saturating_add(y - area.y);
+            if source_y >= other.area.bottom() {
+                break;
+            }
+            let line_size = other.line_size(source_y);
+            if line_size != LineSize::Normal {
+                self.set_line_size(y, line_size);
+            }
+            let mut x = clipped.left();
+            while x < clipped.right() {
+                let source_x = other.area.x.saturating_add(x - area.x);
+                if source_x >= other.area.right() {
+                    break;
+                }
+                let cell = &other[(source_x, source_y)];
+                let width = (cell.symbol().width() as u16).max(1);
+                if !cell.is_blank() {
+                    if x.saturating_add(width) > clipped.right()
+                        || source_x.saturating_add(width) > other.area.right()
+                    {
+                        break;
+                    }
+                    // the cells covered by a wide character are copied even though they are blank
+                    for dx in 0..width {
+                        let source = &other[(source_x + dx, source_y)];
+                        let target = &mut self[(x + dx, y)];
+                        let bg = target.bg;
+                        *target = source.clone();
+                        if source.bg == Color::Reset {
+                            target.bg = bg;
+                        }
+                    }
+                }
+                x = x.saturating_add(width);
+            }
+        }
+    }
+
+    /// Collapses each pair of rows of the buffer into a single row of half block cells.
+    ///
+    /// This doubles the vertical resolution of colored content: render widgets into a buffer that
+    /// is twice as tall as the target area, then collapse it and copy the result to the target.
+    /// Rows `2n` and `2n + 1` of the buffer become row `n` of the returned buffer, which has the
+    /// same position and width and half the height (rounded up) of this buffer.
+    ///
+    /// Only the color of each cell is kept: the foreground color of cells containing a full block
+    /// (`█`), and the background color of any other cell. Each pair of colors is displayed with an
+    /// upper half block (`▀`), with the upper color as foreground and the lower color as background.
+    /// Cells where a color is [`Color::Reset`] use a lower half block or a space instead, so that
+    /// they show the default background of the terminal, and cells where both colors are the same
+    /// use a full block. A missing last row of a buffer with an odd height is treated as
+    /// [`Color::Reset`].
+    ///
+    /// See also [`HalfBlockCanvas`](crate::widgets::HalfBlockCanvas), which manages the double
+    /// height buffer.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::{buffer::Buffer, prelude::*};
+    ///
+    /// let mut buf = Buffer::empty(Rect::new(0, 0, 2, 2));
+    /// buf.set_style(Rect::new(0, 0, 2, 1), Style::new().on_red());
+    /// buf.set_style(Rect::new(1, 1, 1, 1), Style::new().on_blue());
+    ///
+    /// let half_blocks = buf.to_half_blocks();
+    /// assert_eq!(half_blocks.area, Rect::new(0, 0, 2, 1));
+    /// assert_eq!(half_blocks[(0, 0)].symbol(), "▀");
+    /// assert_eq!(half_blocks[(1, 0)].fg, Color::Red);
+    /// assert_eq!(half_blocks[(1, 0)].bg, Color::Blue);
+    /// ```
+    #[must_use]
+    pub fn to_half_blocks(&self) -> Self {
+        let area = Rect {
+            height: self.area.height.div_ceil(2),
+            ..self.area
+        };
+        let mut buf = Self::empty(area);
+        let pixel = |x: u16, y: u16| {
+            self.cell((x, y)).map_or(Color::Reset, |cell| {
+                if cell.symbol() == symbols::block::FULL {
+                    cell.fg
+                } else {
+                    cell.bg
+                }
+            })
+        };
+        for Position { x, y } in area.positions() {
+            let upper_y = self.area.y + (y - area.y) * 2;
+            let (upper, lower) = (pixel(x, upper_y), pixel(x, upper_y.saturating_add(1)));
+            // the default foreground color is not the default background color, so a reset upper
+            // half is displayed as the background of a lower half block
+            let (symbol, fg, bg) = match (upper, lower) {
+                (Color::Reset, Color::Reset) => (' ', Color::Reset, Color::Reset),
+                (Color::Reset, lower) => (symbols::half_block::LOWER, lower, Color::Reset),
+                (upper, lower) if upper == lower => (symbols::half_block::FULL, upper, lower),
+                (upper, lower) => (symbols::half_block::UPPER, upper, lower),
+            };
+            buf[(x, y)].set_char(symbol).set_fg(fg).set_bg(bg);
+        }
+        buf
     }
 
     /// Builds a minimal sequence of coordinates and Cells necessary to update the UI from
@@ -486,6 +884,238 @@ impl Buffer {
         }
         updates
     }
+
+    /// Returns the content of the buffer as a string with embedded ANSI escape sequences.
+    ///
+    /// Each row of the buffer is a line of the string. The foreground and background colors and
+    /// the modifiers of each cell are encoded as SGR sequences, which are only written when the
+    /// style changes, and the style is reset at the end of each row that isn't unstyled. The
+    /// cells hidden by multi-width symbols are skipped.
+    ///
+    /// This is useful for snapshot testing, as the string can be printed to a terminal to see the
+    /// styled buffer. Use the [`Display`](fmt::Display) implementation to get the text only.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::prelude::*;
+    ///
+    /// let mut buffer = Buffer::with_lines(["ab"]);
+    /// buffer[(1, 0)].set_style(Style::new().red().bold());
+    /// assert_eq!(buffer.to_ansi_string(), "a\x1b[0;1;31mb\x1b[0m");
+    /// ```
+    pub fn to_ansi_string(&self) -> String {
+        let mut string = String::new();
+        if self.area.is_empty() {
+            return string;
+        }
+        for (y, line) in self.content.chunks(self.area.width as usize).enumerate() {
+            if y > 0 {
+                string.push('\n');
+            }
+            let mut style = Cell::EMPTY.style();
+            let mut skip: usize = 0;
+            for cell in line {
+                if skip == 0 {
+                    if cell.style() != style {
+                        style = cell.style();
+                        string.push_str(&sgr_sequence(style));
+                    }
+                    string.push_str(cell.symbol());
+                }
+                skip = std::cmp::max(skip, cell.symbol().width()).saturating_sub(1);
+            }
+            if style != Cell::EMPTY.style() {
+                string.push_str("\x1b[0m");
+            }
+        }
+        string
+    }
+
+    /// Returns the content of the buffer as styled [`Text`].
+    ///
+    /// Each row of the buffer is a [`Line`] of the text, with a [`Span`] for each run of cells
+    /// which have the same style. The blank cells at the end of each row (spaces with no
+    /// background color and no modifier) are trimmed, and the cells hidden by multi-width symbols
+    /// are skipped, so that a wide character is a single grapheme of the text.
+    ///
+    /// This is useful to export or search the content of the screen, see
+    /// [`Terminal::displayed_buffer`](crate::Terminal::displayed_buffer). Use the `Display`
+    /// implementation of [`Text`] to get the plain text.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::prelude::*;
+    ///
+    /// let mut buffer = Buffer::empty(Rect::new(0, 0, 6, 2));
+    /// buffer.set_string(0, 0, "a", Style::new().red());
+    /// buffer.set_string(1, 0, "界b", Style::new());
+    /// buffer.set_string(0, 1, "c", Style::new());
+    /// let text = buffer.to_text();
+    /// assert_eq!(text.to_string(), "a界b\nc");
+    /// assert_eq!(text.lines[0].spans[0].content, "a");
+    /// assert_eq!(text.lines[0].spans[1].content, "界b");
+    /// ```
+    pub fn to_text(&self) -> Text<'static> {
+        if self.area.is_empty() {
+            return Text::default();
+        }
+        let lines = self.content.chunks(self.area.width as usize).map(|row| {
+            let end = row
+                .iter()
+                .rposition(|cell| !cell.is_blank())
+                .map_or(0, |i| i + 1);
+            let mut spans: Vec<Span<'static>> = vec![];
+            let mut skip: usize = 0;
+            for cell in &row[..end] {
+                if skip == 0 {
+                    match spans.last_mut() {
+                        Some(span) if span.style == cell.style() => {
+                            span.content.to_mut().push_str(cell.symbol());
+                        }
+                        _ => spans.push(Span::styled(cell.symbol().to_string(), cell.style())),
+                    }
+                }
+                skip = std::cmp::max(skip, cell.symbol().width()).saturating_sub(1);
+            }
+            Line::from(spans)
+        });
+        Text::from(lines.collect::<Vec<_>>())
+    }
+
+    /// Returns a report of the differences between this buffer and another one.
+    ///
+    /// Each line of the report describes a cell that differs, with its position and the symbol and
+    /// style of the cell in both buffers. A difference of area is reported first. The report is
+    /// empty if the buffers are equal.
+    ///
+    /// This is useful to make test failures easier to read than the whole content of the buffers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::prelude::*;
+    ///
+    /// let buffer = Buffer::with_lines(["ab"]);
+    /// let other = Buffer::with_lines(["ac"]);
+    /// assert_eq!(
+    ///     buffer.diff_report(&other),
+    ///     "x: 1, y: 0: \"b\" (fg: Reset, bg: Reset, modifier: NONE) != \"c\" (fg: Reset, bg: Reset, modifier: NONE)\n",
+    /// );
+    /// ```
+    pub fn diff_report(&self, other: &Self) -> String {
+        use fmt::Write;
+
+        let mut report = String::new();
+        if self.area != other.area {
+            let _ = writeln!(report, "area: {:?} != {:?}", self.area, other.area);
+        }
+        let area = self.area.intersection(other.area);
+        for y in area.top()..area.bottom() {
+            for x in area.left()..area.right() {
+                let (cell, other_cell) = (&self[(x, y)], &other[(x, y)]);
+                if cell != other_cell {
+                    let _ = writeln!(
+                        report,
+                        "x: {x}, y: {y}: {} != {}",
+                        describe_cell(cell),
+                        describe_cell(other_cell)
+                    );
+                }
+            }
+        }
+        report
+    }
+}
+
+/// Returns the SGR escape sequence that resets the style and applies the given one.
+fn sgr_sequence(style: Style) -> String {
+    let mut params = vec!["0".to_string()];
+    let modifier = style.add_modifier;
+    for (flag, code) in [
+        (Modifier::BOLD, "1"),
+        (Modifier::DIM, "2"),
+        (Modifier::ITALIC, "3"),
+        (Modifier::UNDERLINED, "4"),
+        (Modifier::SLOW_BLINK, "5"),
+        (Modifier::RAPID_BLINK, "6"),
+        (Modifier::REVERSED, "7"),
+        (Modifier::HIDDEN, "8"),
+        (Modifier::CROSSED_OUT, "9"),
+    ] {
+        if modifier.contains(flag) {
+            params.push(code.to_string());
+        }
+    }
+    if let Some(fg) = style.fg.filter(|&fg| fg != Color::Reset) {
+        params.push(sgr_color(fg, 30));
+    }
+    if let Some(bg) = style.bg.filter(|&bg| bg != Color::Reset) {
+        params.push(sgr_color(bg, 40));
+    }
+    #[cfg(feature = "underline-color")]
+    if let Some(color) = style.underline_color.filter(|&color| color != Color::Reset) {
+        params.push(match color {
+            Color::Rgb(r, g, b) => format!("58;2;{r};{g};{b}"),
+            Color::Indexed(i) => format!("58;5;{i}"),
+            color => format!("58;5;{}", color.ansi_index().unwrap_or_default()),
+        });
+    }
+    format!("\x1b[{}m", params.join(";"))
+}
+
+/// Returns the SGR parameters of a color, where `base` is 30 for the foreground and 40 for the
+/// background.
+fn sgr_color(color: Color, base: u8) -> String {
+    match color {
+        Color::Rgb(r, g, b) => format!("{};2;{r};{g};{b}", base + 8),
+        Color::Indexed(i) => format!("{};5;{i}", base + 8),
+        Color::Reset => (base + 9).to_string(),
+        color => {
+            let index = color.ansi_index().unwrap_or_default();
+            if index < 8 {
+                (base + index).to_string()
+            } else {
+                (base + 60 + index - 8).to_string()
+            }
+        }
+    }
+}
+
+/// Describes the symbol and style of a cell for [`Buffer::diff_report`].
+fn describe_cell(cell: &Cell) -> String {
+    format!(
+        "{:?} (fg: {:?}, bg: {:?}, modifier: {:?})",
+        cell.symbol(),
+        cell.fg,
+        cell.bg,
+        cell.modifier
+    )
+}
+
+impl fmt::Display for Buffer {
+    /// Writes the text of the buffer, one line per row, without any style.
+    ///
+    /// The cells hidden by multi-width symbols are skipped.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.area.is_empty() {
+            return Ok(());
+        }
+        for (y, line) in self.content.chunks(self.area.width as usize).enumerate() {
+            if y > 0 {
+                f.write_str("\n")?;
+            }
+            let mut skip: usize = 0;
+            for cell in line {
+                if skip == 0 {
+                    f.write_str(cell.symbol())?;
+                }
+                skip = std::cmp::max(skip, cell.symbol().width()).saturating_sub(1);
+            }
+        }
+        Ok(())
+    }
 }
 
 impl<P: Into<Position>> Index<P> for Buffer {
@@ -550,6 +1180,8 @@ impl fmt::Debug for Buffer {
     /// * `content`: displayed as a list of strings representing the content of the buffer
     /// * `styles`: displayed as a list of: `{ x: 1, y: 2, fg: Color::Red, bg: Color::Blue,
     ///   modifier: Modifier::BOLD }` only showing a value when there is a change in style.
+    /// * `line_sizes`: displayed as a list of the sizes of the rows, from the top row up to the
+    ///   last row which is not displayed at [`LineSize::Normal`], and omitted when there is none.
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.write_fmt(format_args!("Buffer {{\n    area: {:?}", &self.area))?;
 
@@ -609,7 +1241,11 @@ impl fmt::Debug for Buffer {
                 s.0, s.1, s.2, s.3, s.4
             ))?;
         }
-        f.write_str("    ]\n}")?;
+        f.write_str("    ]")?;
+        if !self.line_sizes.is_empty() {
+            f.write_fmt(format_args!(",\n    line_sizes: {:?}", self.line_sizes))?;
+        }
+        f.write_str("\n}")?;
         Ok(())
     }
 }
@@ -941,6 +1577,196 @@ mod tests {
         assert_eq!(actual_styles, expected_styles);
     }
 
+    #[test]
+    fn render_frame() {
+        let area = Rect::new(2, 1, 4, 2);
+        let buffer = Buffer::render_frame(area, |frame| {
+            assert_eq!(frame.area(), area);
+            frame.render_widget(Line::from("ab"), Rect::new(2, 1, 4, 1));
+            frame.render_widget(Line::from("cd").right_aligned(), Rect::new(2, 2, 4, 1));
+            frame.set_cursor_position((3, 2));
+        });
+        let mut expected = Buffer::with_lines(["ab  ", "  cd"]);
+        expected.area = area;
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn render_widget() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 5, 2));
+        buffer.render_widget(Line::from("abc"), Rect::new(1, 1, 3, 1));
+        assert_eq!(buffer, Buffer::with_lines(["     ", " abc "]));
+    }
+
+    #[test]
+    fn render_widgets() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 3, 3));
+        let areas = Layout::vertical([1; 3]).split(buffer.area);
+        buffer.render_widgets([Some("a"), None, Some("c")], areas.iter().copied());
+        assert_eq!(buffer, Buffer::with_lines(["a  ", "   ", "c  "]));
+    }
+
+    #[test]
+    fn render_widgets_ignores_extra_widgets_and_areas() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 3, 2));
+        buffer.render_widgets(
+            ["a", "b", "c"],
+            [Rect::new(0, 0, 1, 1), Rect::new(2, 1, 1, 1)],
+        );
+        assert_eq!(buffer, Buffer::with_lines(["a  ", "  b"]));
+
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 3, 1));
+        buffer.render_widgets(["a"], [Rect::new(0, 0, 1, 1), Rect::new(2, 0, 1, 1)]);
+        assert_eq!(buffer, Buffer::with_lines(["a  "]));
+    }
+
+    #[test]
+    fn to_half_blocks() {
+        let mut buffer = Buffer::empty(Rect::new(2, 1, 4, 3));
+        buffer.set_style(Rect::new(2, 1, 2, 1), Style::new().on_red());
+        buffer.set_style(Rect::new(3, 2, 2, 1), Style::new().on_blue());
+        buffer.set_string(5, 2, "█", Style::new().green());
+        buffer.set_style(Rect::new(2, 3, 4, 1), Style::new().on_yellow());
+        let half_blocks = buffer.to_half_blocks();
+
+        let mut expected = Buffer::with_lines(["▀▀▄▄", "▀▀▀▀"]);
+        expected.area = Rect::new(2, 1, 4, 2);
+        expected.set_style(Rect::new(2, 1, 1, 1), Style::new().red());
+        expected.set_style(Rect::new(3, 1, 1, 1), Style::new().red().on_blue());
+        expected.set_style(Rect::new(4, 1, 1, 1), Style::new().blue());
+        expected.set_style(Rect::new(5, 1, 1, 1), Style::new().green());
+        expected.set_style(Rect::new(2, 2, 4, 1), Style::new().yellow());
+        assert_eq!(half_blocks, expected);
+    }
+
+    #[test]
+    fn render_stateful_widget() {
+        use crate::widgets::{List, ListState};
+
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 3, 2));
+        let mut state = ListState::default().with_offset(1);
+        buffer.render_stateful_widget(List::new(["a", "b", "c"]), buffer.area, &mut state);
+        assert_eq!(buffer, Buffer::with_lines(["b  ", "c  "]));
+    }
+
+    #[rstest]
+    #[case::empty("", 5, [" ", " ", " "], (0, 0))]
+    #[case::one("a", 5, ["a", " ", " "], (0, 1))]
+    #[case::full("abc", 5, ["a", "b", "c"], (0, 3))]
+    #[case::overflow("abcd", 5, ["a", "b", "c"], (0, 3))]
+    #[case::max_height("abc", 2, ["a", "b", " "], (0, 2))]
+    #[case::zero_width("a\u{200B}b", 5, ["a", "b", " "], (0, 2))]
+    fn set_line_vertical(
+        #[case] content: &str,
+        #[case] max_height: u16,
+        #[case] expected: [&str; 3],
+        #[case] end: (u16, u16),
+    ) {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 1, 3));
+        let position = buffer.set_line_vertical(0, 0, &Line::raw(content), max_height, false);
+        assert_eq!(buffer, Buffer::with_lines(expected));
+        assert_eq!(position, end);
+    }
+
+    #[test]
+    fn set_line_vertical_styled() {
+        let mut buffer = Buffer::empty(Rect::new(1, 1, 2, 4));
+        let line = Line::from(vec!["a".red(), "bc".into()]).on_blue();
+        let position = buffer.set_line_vertical(2, 1, &line, 4, false);
+        let mut expected = Buffer::with_lines([" a", " b", " c", "  "]);
+        expected.area = Rect::new(1, 1, 2, 4);
+        expected.set_style(Rect::new(2, 1, 1, 3), Style::new().on_blue());
+        expected.set_style(Rect::new(2, 1, 1, 1), Style::new().red());
+        assert_eq!(buffer, expected);
+        assert_eq!(position, (2, 4));
+    }
+
+    #[test]
+    fn set_line_vertical_wide_graphemes() {
+        let mut buffer = Buffer::with_lines(["xxx", "xxx", "xxx"]);
+        buffer.set_line_vertical(0, 0, &Line::raw("a称b"), 3, false);
+        assert_eq!(buffer, Buffer::with_lines(["axx", "称x", "bxx"]));
+
+        // a wide grapheme which doesn't fit in the last column ends the line
+        let mut buffer = Buffer::with_lines(["xxx", "xxx", "xxx"]);
+        let position = buffer.set_line_vertical(2, 0, &Line::raw("a称b"), 3, false);
+        assert_eq!(buffer, Buffer::with_lines(["xxa", "xxx", "xxx"]));
+        assert_eq!(position, (2, 1));
+    }
+
+    #[test]
+    fn set_line_vertical_rotate_symbols() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 2, 4));
+        let line = Line::raw("┌─a┐");
+        buffer.set_line_vertical(0, 0, &line, 4, true);
+        buffer.set_line_vertical(1, 0, &line, 4, false);
+        assert_eq!(buffer, Buffer::with_lines(["┐┌", "│─", "aa", "┘┐"]));
+    }
+
+    #[test]
+    fn set_line_vertical_outside_of_the_buffer() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 2, 2));
+        assert_eq!(
+            buffer.set_line_vertical(2, 0, &Line::raw("ab"), 2, false),
+            (2, 0)
+        );
+        assert_eq!(
+            buffer.set_line_vertical(0, 5, &Line::raw("ab"), 2, false),
+            (0, 5)
+        );
+        assert_eq!(buffer, Buffer::empty(Rect::new(0, 0, 2, 2)));
+    }
+
+    #[test]
+    fn set_span_hyperlink() {
+        let mut buffer = Buffer::with_lines(["xxxxx"]);
+        let span = Span::raw("abc").hyperlink("https://ratatui.rs");
+        buffer.set_span(1, 0, &span, 5);
+        let links = buffer.content.iter().map(Cell::hyperlink).collect_vec();
+        let link = Some("https://ratatui.rs");
+        assert_eq!(links, [None, link, link, link, None]);
+    }
+
+    #[test]
+    fn set_line_size() {
+        let mut buffer = Buffer::empty(Rect::new(0, 1, 3, 3));
+        buffer.set_line_size(2, LineSize::DoubleWidth);
+        buffer.set_line_size(0, LineSize::DoubleWidth);
+        buffer.set_line_size(4, LineSize::DoubleWidth);
+        let sizes = (0..5).map(|y| buffer.line_size(y)).collect_vec();
+        assert_eq!(
+            sizes,
+            [
+                LineSize::Normal,
+                LineSize::Normal,
+                LineSize::DoubleWidth,
+                LineSize::Normal,
+                LineSize::Normal,
+            ]
+        );
+
+        // equal buffers have equal line sizes, whatever the order they are set in
+        buffer.set_line_size(3, LineSize::DoubleWidth);
+        buffer.set_line_size(3, LineSize::Normal);
+        let mut expected = Buffer::empty(Rect::new(0, 1, 3, 3));
+        expected.set_line_size(2, LineSize::DoubleWidth);
+        assert_eq!(buffer, expected);
+
+        buffer.reset();
+        assert_eq!(buffer.line_size(2), LineSize::Normal);
+        assert_eq!(buffer, Buffer::empty(Rect::new(0, 1, 3, 3)));
+    }
+
+    #[test]
+    fn set_hyperlink() {
+        let mut buffer = Buffer::with_lines(["aaa", "bbb"]);
+        buffer.set_hyperlink(Rect::new(1, 0, 5, 5), Some("https://ratatui.rs"));
+        buffer.set_hyperlink(Rect::new(2, 1, 1, 1), None);
+        let links = buffer.content.iter().map(Cell::hyperlink).collect_vec();
+        let link = Some("https://ratatui.rs");
+        assert_eq!(links, [None, link, link, None, link, None]);
+    }
+
     #[test]
     fn set_style() {
         let mut buffer = Buffer::with_lines(["aaaaa", "bbbbb", "ccccc"]);
@@ -1166,6 +1992,99 @@ mod tests {
         assert_eq!(skipped, expected);
     }
 
+    #[rstest]
+    #[case::inside(Rect::new(1, 1, 2, 2), ["....", ".ab.", ".cd.", "...."])]
+    #[case::clipped_by_area(Rect::new(1, 1, 1, 1), ["....", ".a..", "....", "...."])]
+    #[case::clipped_by_buffer(Rect::new(3, 3, 2, 2), ["....", "....", "....", "...a"])]
+    #[case::larger_than_other(Rect::new(0, 0, 4, 4), ["ab..", "cd..", "....", "...."])]
+    #[case::outside_buffer(Rect::new(4, 0, 2, 2), ["....", "....", "....", "...."])]
+    fn composite(#[case] area: Rect, #[case] expected: [&str; 4]) {
+        let mut buffer = Buffer::filled(Rect::new(0, 0, 4, 4), Cell::new("."));
+        let other = Buffer::with_lines(["ab", "cd"]);
+        buffer.composite(&other, area);
+        assert_eq!(buffer, Buffer::with_lines(expected));
+    }
+
+    #[test]
+    fn composite_with_offset_buffers() {
+        let mut buffer = Buffer::filled(Rect::new(10, 10, 3, 1), Cell::new("."));
+        let mut other = Buffer::empty(Rect::new(5, 5, 2, 1));
+        other.set_string(5, 5, "ab", Style::new());
+        buffer.composite(&other, Rect::new(11, 10, 2, 1));
+        let mut expected = Buffer::with_lines([".ab"]);
+        expected.area = Rect::new(10, 10, 3, 1);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn composite_transparency() {
+        let mut buffer = Buffer::with_lines(["abcd"]);
+        buffer.set_style(buffer.area, Style::new().on_blue());
+        let mut other = Buffer::empty(Rect::new(0, 0, 4, 1));
+        other.set_string(0, 0, "x", Style::new().red());
+        other.set_string(1, 0, " ", Style::new().red());
+        other.set_string(2, 0, " ", Style::new().on_green());
+        other.set_string(3, 0, " ", Style::new().reversed());
+        buffer.composite(&other, buffer.area);
+
+        let mut expected = Buffer::with_lines(["xb  "]);
+        expected.set_style(buffer.area, Style::new().on_blue());
+        expected.set_style(Rect::new(0, 0, 1, 1), Style::new().red());
+        expected.set_style(Rect::new(2, 0, 1, 1), Style::new().on_green());
+        expected.set_style(Rect::new(3, 0, 1, 1), Style::new().reversed());
+        assert_eq!(buffer, expected);
+    }
+
+    #[rstest]
+    #[case::fits(Rect::new(0, 0, 4, 1), ["界b."])]
+    #[case::over_wide_character(Rect::new(1, 0, 3, 1), [".界b"])]
+    #[case::clipped(Rect::new(0, 0, 1, 1), ["...."])]
+    fn composite_wide_characters(#[case] area: Rect, #[case] expected: [&str; 1]) {
+        let mut buffer = Buffer::filled(Rect::new(0, 0, 4, 1), Cell::new("."));
+        let other = Buffer::with_lines(["界b"]);
+        buffer.composite(&other, area);
+        assert_eq!(buffer, Buffer::with_lines(expected));
+    }
+
+    #[test]
+    fn to_text() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 6, 4));
+        buffer.set_string(0, 0, "ab", Style::new().red());
+        buffer.set_string(2, 0, "cd", Style::new());
+        buffer.set_string(0, 1, "界 ", Style::new());
+        buffer.set_string(4, 2, " ", Style::new().on_blue());
+        let unstyled = Cell::EMPTY.style();
+        assert_eq!(
+            buffer.to_text(),
+            Text::from(vec![
+                Line::from(vec![
+                    Span::styled("ab", unstyled.red()),
+                    Span::styled("cd", unstyled),
+                ]),
+                Line::from(vec![Span::styled("界", unstyled)]),
+                Line::from(vec![
+                    Span::styled("    ", unstyled),
+                    Span::styled(" ", unstyled.on_blue()),
+                ]),
+                Line::default(),
+            ])
+        );
+    }
+
+    #[test]
+    fn to_text_renders_back_to_the_buffer() {
+        let mut buffer = Buffer::with_lines(["a界b ", "  c  "]);
+        buffer.set_style(Rect::new(0, 1, 3, 1), Style::new().bold().on_red());
+        let mut rendered = Buffer::empty(buffer.area);
+        buffer.to_text().render(rendered.area, &mut rendered);
+        assert_eq!(rendered, buffer);
+    }
+
+    #[test]
+    fn to_text_of_empty_buffer() {
+        assert_eq!(Buffer::empty(Rect::ZERO).to_text(), Text::default());
+    }
+
     #[test]
     fn with_lines_accepts_into_lines() {
         use crate::style::Stylize;
@@ -1244,4 +2163,57 @@ mod tests {
         let expected = Buffer::with_lines([expected]);
         assert_eq!(buffer, expected);
     }
+
+    #[test]
+    fn display_skips_hidden_cells() {
+        let buffer = Buffer::with_lines(["a称b", "cdef"]);
+        assert_eq!(buffer.to_string(), "a称b\ncdef");
+    }
+
+    #[test]
+    fn display_empty_buffer() {
+        assert_eq!(Buffer::empty(Rect::ZERO).to_string(), "");
+    }
+
+    #[test]
+    fn to_ansi_string_unstyled() {
+        let buffer = Buffer::with_lines(["ab", "称"]);
+        assert_eq!(buffer.to_ansi_string(), "ab\n称");
+    }
+
+    #[test]
+    fn to_ansi_string_styled() {
+        let mut buffer = Buffer::with_lines(["abc", "def"]);
+        buffer.set_style(Rect::new(1, 0, 2, 1), Style::new().red().on_blue().bold());
+        buffer.set_style(Rect::new(0, 1, 1, 1), Style::new().fg(Color::Rgb(1, 2, 3)));
+        buffer.set_style(Rect::new(1, 1, 1, 1), Style::new().bg(Color::Indexed(42)));
+        buffer.set_style(Rect::new(2, 1, 1, 1), Style::new().light_green().italic());
+        assert_eq!(
+            buffer.to_ansi_string(),
+            "a\x1b[0;1;31;44mbc\x1b[0m\n\
+             \x1b[0;38;2;1;2;3md\x1b[0;48;5;42me\x1b[0;3;92mf\x1b[0m"
+        );
+    }
+
+    #[test]
+    fn diff_report_equal_buffers() {
+        let buffer = Buffer::with_lines(["ab"]);
+        assert_eq!(buffer.diff_report(&buffer.clone()), "");
+    }
+
+    #[test]
+    fn diff_report_differences() {
+        let buffer = Buffer::with_lines(["ab", "cd"]);
+        let mut other = Buffer::with_lines(["ab", "cx", "ef"]);
+        other.set_style(Rect::new(0, 0, 1, 1), Style::new().red());
+        assert_eq!(
+            buffer.diff_report(&other),
+            "area: Rect { x: 0, y: 0, width: 2, height: 2 } != \
+             Rect { x: 0, y: 0, width: 2, height: 3 }\n\
+             x: 0, y: 0: \"a\" (fg: Reset, bg: Reset, modifier: NONE) != \
+             \"a\" (fg: Red, bg: Reset, modifier: NONE)\n\
+             x: 1, y: 1: \"d\" (fg: Reset, bg: Reset, modifier: NONE) != \
+             \"x\" (fg: Reset, bg: Reset, modifier: NONE)\n"
+        );
+    }
 }
@@ -1,8 +1,32 @@
 use compact_str::CompactString;
 
+use super::hyperlink::Hyperlink;
 use crate::prelude::*;
 
 /// A buffer cell
+///
+/// A cell holds the symbol drawn at a position of a [`Buffer`] along with its style. Cells are
+/// usually written by widgets, but they can also be read back, e.g. to inspect what was rendered
+/// in tests, snapshot tools or accessibility bridges. Use [`Buffer::cell`] to get the cell at a
+/// position (which returns `None` when the position is outside the buffer) and read its content
+/// with [`Cell::symbol`], [`Cell::style`] and [`Cell::hyperlink`]. The size of a row is held by
+/// the buffer, see [`Buffer::line_size`].
+///
+/// # Example
+///
+/// ```
+/// use ratatui::prelude::*;
+///
+/// let mut buffer = Buffer::empty(Rect::new(0, 0, 5, 1));
+/// buffer.set_string(0, 0, "Hi", Style::new().red().bold());
+///
+/// let cell = buffer.cell((1, 0)).unwrap();
+/// assert_eq!(cell.symbol(), "i");
+/// assert_eq!(cell.style().fg, Some(Color::Red));
+/// assert!(cell.style().add_modifier.contains(Modifier::BOLD));
+///
+/// assert_eq!(buffer.cell((5, 0)), None);
+/// ```
 #[derive(Debug, Clone, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Cell {
@@ -31,6 +55,12 @@ pub struct Cell {
 
     /// Whether the cell should be skipped when copying (diffing) the buffer to the screen.
     pub skip: bool,
+
+    /// The URL the cell links to, if any.
+    ///
+    /// The URL is reference counted, so the cells of a link share it. This still makes every
+    /// cell a pointer larger, whether it links somewhere or not.
+    pub(crate) hyperlink: Option<Hyperlink>,
 }
 
 impl Cell {
@@ -52,10 +82,15 @@ impl Cell {
             underline_color: Color::Reset,
             modifier: Modifier::empty(),
             skip: false,
+            hyperlink: None,
         }
     }
 
     /// Gets the symbol of the cell.
+    ///
+    /// The symbol is a single grapheme cluster, which may be wider than one column (e.g. `あ`), in
+    /// which case the following cells are hidden by it. Cells that were never written to contain a
+    /// space.
     #[must_use]
     pub fn symbol(&self) -> &str {
         self.symbol.as_str()
@@ -116,6 +151,10 @@ impl Cell {
     }
 
     /// Returns the style of the cell.
+    ///
+    /// As a cell always has colors and modifiers, the foreground, background (and underline)
+    /// colors of the returned style are always set, and its modifiers are the ones that are
+    /// currently applied to the cell.
     #[must_use]
     pub const fn style(&self) -> Style {
         Style {
@@ -137,6 +176,23 @@ impl Cell {
         self
     }
 
+    /// Returns the URL the cell links to, if any.
+    #[must_use]
+    pub fn hyperlink(&self) -> Option<&str> {
+        self.hyperlink.as_ref().map(Hyperlink::url)
+    }
+
+    /// Sets the URL the cell links to.
+    ///
+    /// Backends that support it render the cell as part of an OSC 8 hyperlink, which makes it
+    /// clickable in terminals such as Kitty. Contiguous cells sharing the same
+    /// URL form a single link. Use [`Buffer::set_hyperlink`] to set the URL of many cells at
+    /// once.
+    pub fn set_hyperlink(&mut self, url: Option<&str>) -> &mut Self {
+        self.hyperlink = url.map(Hyperlink::new);
+        self
+    }
+
     /// Resets the cell to the empty state.
     pub fn reset(&mut self) {
         self.symbol = CompactString::const_new(" ");
@@ -148,6 +204,13 @@ impl Cell {
         }
         self.modifier = Modifier::empty();
         self.skip = false;
+        self.hyperlink = None;
+    }
+
+    /// Returns whether the cell displays nothing: a space with no background color and no
+    /// modifier.
+    pub(crate) fn is_blank(&self) -> bool {
+        self.symbol == " " && self.bg == Color::Reset && self.modifier.is_empty()
     }
 }
 
@@ -182,6 +245,7 @@ mod tests {
                 underline_color: Color::Reset,
                 modifier: Modifier::empty(),
                 skip: false,
+                hyperlink: None,
             }
         );
     }
@@ -0,0 +1,108 @@
+use std::{
+    fmt,
+    hash::{Hash, Hasher},
+    sync::Arc,
+};
+
+/// The URL of a hyperlink, shared by all the [`Cell`](super::Cell)s that link to it
+///
+/// Cloning a hyperlink only increments a reference count, and the URL is dropped once no cell
+/// uses it anymore. Two hyperlinks are equal when their URLs are, which is checked by comparing
+/// pointers first so that the cells of a same link are compared without reading the URL.
+///
+/// The control characters of the URL are removed, as they could end the escape sequence the URL
+/// is written in and send what follows to the terminal.
+#[derive(Clone, Eq)]
+pub(crate) struct Hyperlink(Arc<str>);
+
+impl Hyperlink {
+    /// Returns a hyperlink to the given URL, without its control characters
+    pub(crate) fn new(url: &str) -> Self {
+        if url.contains(char::is_control) {
+            Self(
+                url.chars()
+                    .filter(|c| !c.is_control())
+                    .collect::<String>()
+                    .into(),
+            )
+        } else {
+            Self(url.into())
+        }
+    }
+
+    /// Returns the URL of the hyperlink
+    pub(crate) fn url(&self) -> &str {
+        &self.0
+    }
+}
+
+impl PartialEq for Hyperlink {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0) || self.0 == other.0
+    }
+}
+
+impl Hash for Hyperlink {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.hash(state);
+    }
+}
+
+impl fmt::Debug for Hyperlink {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Hyperlink").field(&self.url()).finish()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Hyperlink {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.url())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Hyperlink {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let url = String::deserialize(deserializer)?;
+        Ok(Self::new(&url))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn compares_urls() {
+        let link = Hyperlink::new("https://ratatui.rs/shared");
+        assert_eq!(link, link.clone());
+        assert_eq!(link, Hyperlink::new("https://ratatui.rs/shared"));
+        assert_ne!(link, Hyperlink::new("https://ratatui.rs/other"));
+        assert_eq!(link.url(), "https://ratatui.rs/shared");
+    }
+
+    #[test]
+    fn removes_control_characters() {
+        let link = Hyperlink::new("https://ratatui.rs\x1b\\\x1b[2J\x07\u{9b}");
+        assert_eq!(link.url(), "https://ratatui.rs\\[2J");
+    }
+
+    #[test]
+    fn drops_unused_urls() {
+        let link = Hyperlink::new("https://ratatui.rs");
+        let clone = link.clone();
+        assert_eq!(Arc::strong_count(&link.0), 2);
+        drop(clone);
+        assert_eq!(Arc::strong_count(&link.0), 1);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serializes_the_url() {
+        let link = Hyperlink::new("https://ratatui.rs");
+        let json = serde_json::to_string(&link).unwrap();
+        assert_eq!(json, r#""https://ratatui.rs""#);
+        assert_eq!(serde_json::from_str::<Hyperlink>(&json).unwrap(), link);
+    }
+}
@@ -0,0 +1,316 @@
+use crate::{buffer::Cell, layout::Position, prelude::*};
+
+/// A mutable window into a part of a [`Buffer`]
+///
+/// All coordinates used with a `BufferView` are relative to the top-left corner of its area, so
+/// `(0, 0)` is the first cell of the window rather than the first cell of the buffer. Anything
+/// written outside of the window is silently clipped. This makes it possible to write reusable
+/// components which don't need to know where they are rendered.
+///
+/// A `BufferView` is created with [`Buffer::view_mut`].
+///
+/// # Examples
+///
+/// ```
+/// use ratatui::prelude::*;
+///
+/// let mut buffer = Buffer::empty(Rect::new(0, 0, 8, 3));
+/// let mut view = buffer.view_mut(Rect::new(2, 1, 4, 1));
+/// view.set_string(0, 0, "Hello", Style::new());
+/// view.set_string(0, 1, "World", Style::new()); // outside of the view
+/// assert_eq!(buffer, Buffer::with_lines(["        ", "  Hell  ", "        "]));
+/// ```
+#[derive(Debug, Eq, PartialEq, Hash)]
+pub struct BufferView<'a> {
+    buffer: &'a mut Buffer,
+    /// The absolute area of the window, always contained in the area of the buffer
+    area: Rect,
+}
+
+impl<'a> BufferView<'a> {
+    /// Creates a new view of the part of `area` which lies inside the buffer
+    pub(crate) fn new(buffer: &'a mut Buffer, area: Rect) -> Self {
+        let area = buffer.area.intersection(area);
+        Self { buffer, area }
+    }
+
+    /// Returns the area of the view in its own coordinates
+    ///
+    /// The returned [`Rect`] always starts at `(0, 0)` and can be passed to a [`Layout`] to split
+    /// the view into smaller areas.
+    pub const fn area(&self) -> Rect {
+        Rect {
+            x: 0,
+            y: 0,
+            ..self.area
+        }
+    }
+
+    /// Returns the absolute area of the view in the underlying [`Buffer`]
+    pub const fn absolute_area(&self) -> Rect {
+        self.area
+    }
+
+    /// Returns a reference to the [`Cell`] at the given relative position or [`None`] if the
+    /// position is outside the view.
+    #[must_use]
+    pub fn cell<P: Into<Position>>(&self, position: P) -> Option<&Cell> {
+        let position = self.to_absolute(position.into())?;
+        self.buffer.cell(position)
+    }
+
+    /// Returns a mutable reference to the [`Cell`] at the given relative position or [`None`] if
+    /// the position is outside the view.
+    #[must_use]
+    pub fn cell_mut<P: Into<Position>>(&mut self, position: P) -> Option<&mut Cell> {
+        let position = self.to_absolute(position.into())?;
+        self.buffer.cell_mut(position)
+    }
+
+    /// Print a string, starting at the relative position (x, y)
+    pub fn set_string<T, S>(&mut self, x: u16, y: u16, string: T, style: S)
+    where
+        T: AsRef<str>,
+        S: Into<Style>,
+    {
+        self.set_stringn(x, y, string, usize::MAX, style);
+    }
+
+    /// Print at most the first n characters of a string, starting at the relative position (x, y)
+    ///
+    /// The string is clipped at the right edge of the view. Returns the relative position after
+    /// the last printed character.
+    pub fn set_stringn<T, S>(
+        &mut self,
+        x: u16,
+        y: u16,
+        string: T,
+        max_width: usize,
+        style: S,
+    ) -> (u16, u16)
+    where
+        T: AsRef<str>,
+        S: Into<Style>,
+    {
+        let Some(position) = self.to_absolute(Position::new(x, y)) else {
+            return (x, y);
+        };
+        let max_width = max_width.min(usize::from(self.area.right() - position.x));
+        let (end, _) = self
+            .buffer
+            .set_stringn(position.x, position.y, string, max_width, style);
+        (end - self.area.x, y)
+    }
+
+    /// Print a line, starting at the relative position (x, y)
+    ///
+    /// The line is clipped at the right edge of the view. Returns the relative position after the
+    /// last printed character.
+    pub fn set_line(&mut self, x: u16, y: u16, line: &Line<'_>, max_width: u16) -> (u16, u16) {
+        let Some(position) = self.to_absolute(Position::new(x, y)) else {
+            return (x, y);
+        };
+        let max_width = max_width.min(self.area.right() - position.x);
+        let (end, _) = self
+            .buffer
+            .set_line(position.x, position.y, line, max_width);
+        (end - self.area.x, y)
+    }
+
+    /// Print a span, starting at the relative position (x, y)
+    ///
+    /// The span is clipped at the right edge of the view. Returns the relative position after the
+    /// last printed character.
+    pub fn set_span(&mut self, x: u16, y: u16, span: &Span<'_>, max_width: u16) -> (u16, u16) {
+        let Some(position) = self.to_absolute(Position::new(x, y)) else {
+            return (x, y);
+        };
+        let max_width = max_width.min(self.area.right() - position.x);
+        let (end, _) = self
+            .buffer
+            .set_span(position.x, position.y, span, max_width);
+        (end - self.area.x, y)
+    }
+
+    /// Set the style of all cells in the given relative area.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    pub fn set_style<S: Into<Style>>(&mut self, area: Rect, style: S) {
+        let area = self.to_absolute_area(area);
+        self.buffer.set_style(area, style);
+    }
+
+    /// Renders a [`Widget`] to the given relative area of the view
+    ///
+    /// The area is clipped to the view before the widget is rendered, so widgets which stay
+    /// within their area can never write outside of the view.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::{prelude::*, widgets::Block};
+    ///
+    /// let mut buffer = Buffer::empty(Rect::new(0, 0, 6, 4));
+    /// let mut view = buffer.view_mut(Rect::new(1, 1, 5, 3));
+    /// view.render_widget(Block::bordered(), Rect::new(0, 0, 3, 10));
+    /// assert_eq!(
+    ///     buffer,
+    ///     Buffer::with_lines(["      ", " ┌─┐  ", " │ │  ", " └─┘  "])
+    /// );
+    /// ```
+    pub fn render_widget<W: Widget>(&mut self, widget: W, area: Rect) {
+        let area = self.to_absolute_area(area);
+        widget.render(area, self.buffer);
+    }
+
+    /// Renders a [`StatefulWidget`] to the given relative area of the view
+    ///
+    /// The area is clipped to the view before the widget is rendered, so widgets which stay
+    /// within their area can never write outside of the view.
+    pub fn render_stateful_widget<W: StatefulWidget>(
+        &mut self,
+        widget: W,
+        area: Rect,
+        state: &mut W::State,
+    ) {
+        let area = self.to_absolute_area(area);
+        widget.render(area, self.buffer, state);
+    }
+
+    /// Converts a relative position to an absolute position in the buffer, or [`None`] if the
+    /// position is outside the view
+    fn to_absolute(&self, position: Position) -> Option<Position> {
+        (position.x < self.area.width && position.y < self.area.height).then(|| Position {
+            x: self.area.x + position.x,
+            y: self.area.y + position.y,
+        })
+    }
+
+    /// Converts a relative area to an absolute area in the buffer, clipped to the view
+    fn to_absolute_area(&self, area: Rect) -> Rect {
+        let area = Rect {
+            x: self.area.x.saturating_add(area.x),
+            y: self.area.y.saturating_add(area.y),
+            ..area
+        };
+        area.intersection(self.area)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        layout::Offset,
+        widgets::{Block, Paragraph},
+    };
+
+    #[test]
+    fn area() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 10));
+        let view = buffer.view_mut(Rect::new(2, 3, 4, 5));
+        assert_eq!(view.area(), Rect::new(0, 0, 4, 5));
+        assert_eq!(view.absolute_area(), Rect::new(2, 3, 4, 5));
+    }
+
+    #[test]
+    fn area_is_clipped_to_buffer() {
+        let mut buffer = Buffer::empty(Rect::new(10, 10, 10, 10));
+        let view = buffer.view_mut(Rect::new(15, 5, 10, 10));
+        assert_eq!(view.absolute_area(), Rect::new(15, 10, 5, 5));
+
+        let view = buffer.view_mut(Rect::new(0, 0, 5, 5));
+        assert_eq!(view.area(), Rect::ZERO);
+    }
+
+    #[test]
+    fn cell() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 4, 4));
+        let mut view = buffer.view_mut(Rect::new(1, 1, 2, 2));
+        view.cell_mut((1, 1)).unwrap().set_symbol("a");
+        assert_eq!(view.cell((1, 1)).unwrap().symbol(), "a");
+        assert!(view.cell_mut((2, 0)).is_none());
+        assert!(view.cell((0, 2)).is_none());
+        assert_eq!(buffer, Buffer::with_lines(["    ", "    ", "  a ", "    "]));
+    }
+
+    #[test]
+    fn set_string_is_clipped() {
+        let mut buffer = Buffer::with_lines(["xxxxxx"; 4]);
+        let mut view = buffer.view_mut(Rect::new(1, 1, 4, 2));
+        assert_eq!(
+            view.set_stringn(2, 0, "abc", usize::MAX, Style::new()),
+            (4, 0)
+        );
+        view.set_string(0, 1, "abcdefgh", Style::new());
+        view.set_string(0, 2, "below", Style::new());
+        view.set_string(4, 0, "right", Style::new());
+        view.set_string(u16::MAX, u16::MAX, "overflow", Style::new());
+        assert_eq!(
+            buffer,
+            Buffer::with_lines(["xxxxxx", "xxxabx", "xabcdx", "xxxxxx"])
+        );
+    }
+
+    #[test]
+    fn set_string_clips_wide_characters() {
+        let mut buffer = Buffer::with_lines(["xxxx"]);
+        let mut view = buffer.view_mut(Rect::new(0, 0, 3, 1));
+        assert_eq!(
+            view.set_stringn(0, 0, "a界", usize::MAX, Style::new()),
+            (3, 0)
+        );
+        view.set_string(1, 0, "界界", Style::new());
+        assert_eq!(buffer, Buffer::with_lines(["a界x"]));
+    }
+
+    #[test]
+    fn set_line_and_span() {
+        let mut buffer = Buffer::with_lines(["xxxxxx"; 3]);
+        let mut view = buffer.view_mut(Rect::new(1, 1, 4, 2));
+        let line = Line::from(vec!["ab".red(), "cdef".blue()]);
+        assert_eq!(view.set_line(1, 0, &line, u16::MAX), (4, 0));
+        assert_eq!(view.set_span(0, 1, &"12345".green(), 2), (2, 1));
+        let mut expected = Buffer::with_lines(["xxxxxx", "xxabcx", "x12xxx"]);
+        expected.set_style(Rect::new(2, 1, 2, 1), Style::new().red());
+        expected.set_style(Rect::new(4, 1, 1, 1), Style::new().blue());
+        expected.set_style(Rect::new(1, 2, 2, 1), Style::new().green());
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn set_style() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 4, 3));
+        let mut view = buffer.view_mut(Rect::new(1, 1, 2, 2));
+        view.set_style(Rect::new(1, 0, 10, 10), Style::new().red());
+        let mut expected = Buffer::empty(Rect::new(0, 0, 4, 3));
+        expected.set_style(Rect::new(2, 1, 1, 2), Style::new().red());
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn render_widget() {
+        let mut buffer = Buffer::with_lines(["xxxxxxx"; 5]);
+        let mut view = buffer.view_mut(Rect::new(1, 1, 5, 3));
+        let [left, right] = Layout::horizontal([3, 2]).areas(view.area());
+        view.render_widget(Block::bordered(), left);
+        view.render_widget(
+            Paragraph::new("hello\nworld"),
+            right.offset(Offset { x: 0, y: 1 }),
+        );
+        assert_eq!(
+            buffer,
+            Buffer::with_lines(["xxxxxxx", "x┌─┐xxx", "x│x│hex", "x└─┘wox", "xxxxxxx"])
+        );
+    }
+
+    #[test]
+    fn render_widget_outside_view() {
+        let mut buffer = Buffer::with_lines(["xxx"; 3]);
+        let mut view = buffer.view_mut(Rect::new(1, 1, 1, 1));
+        view.render_widget(Block::bordered(), Rect::new(5, 5, 3, 3));
+        view.render_widget(Block::bordered(), Rect::new(u16::MAX, u16::MAX, 3, 3));
+        assert_eq!(buffer, Buffer::with_lines(["xxx"; 3]));
+    }
+}
@@ -21,6 +21,9 @@ use strum::EnumIs;
 /// 5. [`Constraint::Ratio`]
 /// 6. [`Constraint::Fill`]
 ///
+/// A [`Constraint::Fit`] has the priority of a [`Constraint::Length`] once its content is measured,
+/// and of a `Min(0)` otherwise.
+///
 /// # Examples
 ///
 /// `Constraint` provides helper methods to create lists of constraints from various input formats.
@@ -117,7 +120,9 @@ pub enum Constraint {
     /// Applies a percentage of the available space to the element
     ///
     /// Converts the given percentage to a floating-point value and multiplies that with area. This
-    /// value is rounded back to a integer as part of the layout split calculation.
+    /// value is rounded back to a integer as part of the layout split calculation. The cells left
+    /// over by rounding down are given to the elements with the largest fractional parts, so the
+    /// sizes of all the elements always add up to the size of the area.
     ///
     /// **Note**: As this value only accepts a `u16`, certain percentages that cannot be
     /// represented exactly (e.g. 1/3) are not possible. You might want to use
@@ -191,6 +196,26 @@ pub enum Constraint {
     /// └───────────┘└───────────────────────┘└──────────┘
     /// ```
     Fill(u16),
+
+    /// Sizes the element to fit its content
+    ///
+    /// The size of the content is measured when the layout is split with [`Layout::split_fit`],
+    /// which calls a measurement function for each `Fit` element and then treats the element as a
+    /// [`Constraint::Length`] of the measured size. When the layout is split any other way, there is
+    /// nothing to measure and the element is treated as a `Min(0)`.
+    ///
+    /// # Examples
+    ///
+    /// `[Fit, Fill(1)]` with a content of 12 px
+    ///
+    /// ```plain
+    /// ┌──────────┐┌──────────────────────────────────────┐
+    /// │  12 px   ││                38 px                 │
+    /// └──────────┘└──────────────────────────────────────┘
+    /// ```
+    ///
+    /// [`Layout::split_fit`]: crate::layout::Layout::split_fit
+    Fit,
 }
 
 impl Constraint {
@@ -215,6 +240,7 @@ impl Constraint {
             Self::Length(l) | Self::Fill(l) => length.min(l),
             Self::Max(m) => length.min(m),
             Self::Min(m) => length.max(m),
+            Self::Fit => length,
         }
     }
 
@@ -368,6 +394,7 @@ impl fmt::Display for Constraint {
             Self::Fill(l) => write!(f, "Fill({l})"),
             Self::Max(m) => write!(f, "Max({m})"),
             Self::Min(m) => write!(f, "Min({m})"),
+            Self::Fit => write!(f, "Fit"),
         }
     }
 }
@@ -388,6 +415,7 @@ mod tests {
         assert_eq!(Constraint::Length(10).to_string(), "Length(10)");
         assert_eq!(Constraint::Max(10).to_string(), "Max(10)");
         assert_eq!(Constraint::Min(10).to_string(), "Min(10)");
+        assert_eq!(Constraint::Fit.to_string(), "Fit");
     }
 
     #[test]
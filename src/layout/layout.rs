@@ -1,4 +1,4 @@
-use std::{cell::RefCell, collections::HashMap, iter, num::NonZeroUsize, rc::Rc};
+use std::{cell::RefCell, cmp::Reverse, collections::HashMap, iter, num::NonZeroUsize, rc::Rc};
 
 use cassowary::{
     strength::REQUIRED,
@@ -48,8 +48,8 @@ thread_local! {
 /// A layout is composed of:
 /// - a direction (horizontal or vertical)
 /// - a set of constraints (length, ratio, percentage, fill, min, max)
-/// - a margin (horizontal and vertical), the space between the edge of the main area and the split
-///   areas
+/// - a margin (top, right, bottom and left), the space between the edge of the main area and the
+///   split areas
 /// - a flex option
 /// - a spacing option
 ///
@@ -81,6 +81,7 @@ thread_local! {
 /// - [`Layout::margin`]: set the margin of the layout
 /// - [`Layout::horizontal_margin`]: set the horizontal margin of the layout
 /// - [`Layout::vertical_margin`]: set the vertical margin of the layout
+/// - [`Layout::margin_sides`]: set the margin of each side of the layout
 /// - [`Layout::flex`]: set the way the space is distributed when the constraints are satisfied
 /// - [`Layout::spacing`]: sets the gap between the constraints of the layout
 ///
@@ -112,11 +113,38 @@ thread_local! {
 pub struct Layout {
     direction: Direction,
     constraints: Vec<Constraint>,
-    margin: Margin,
+    margin: Sides,
     flex: Flex,
     spacing: u16,
 }
 
+/// The margin on each side of a [`Layout`]
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+struct Sides {
+    top: u16,
+    right: u16,
+    bottom: u16,
+    left: u16,
+}
+
+impl Sides {
+    /// Returns the area inside the margin, or an empty area if the margin does not fit in `area`
+    const fn inner(self, area: Rect) -> Rect {
+        let horizontal = self.left.saturating_add(self.right);
+        let vertical = self.top.saturating_add(self.bottom);
+        if area.width < horizontal || area.height < vertical {
+            Rect::ZERO
+        } else {
+            Rect {
+                x: area.x.saturating_add(self.left),
+                y: area.y.saturating_add(self.top),
+                width: area.width - horizontal,
+                height: area.height - vertical,
+            }
+        }
+    }
+}
+
 impl Layout {
     /// This is a somewhat arbitrary size for the layout cache based on adding the columns and rows
     /// on my laptop's terminal (171+51 = 222) and doubling it for good measure and then adding a
@@ -308,9 +336,11 @@ impl Layout {
     /// ```
     #[must_use = "method moves the value of self and returns the modified value"]
     pub const fn margin(mut self, margin: u16) -> Self {
-        self.margin = Margin {
-            horizontal: margin,
-            vertical: margin,
+        self.margin = Sides {
+            top: margin,
+            right: margin,
+            bottom: margin,
+            left: margin,
         };
         self
     }
@@ -329,7 +359,8 @@ impl Layout {
     /// ```
     #[must_use = "method moves the value of self and returns the modified value"]
     pub const fn horizontal_margin(mut self, horizontal: u16) -> Self {
-        self.margin.horizontal = horizontal;
+        self.margin.left = horizontal;
+        self.margin.right = horizontal;
         self
     }
 
@@ -347,7 +378,34 @@ impl Layout {
     /// ```
     #[must_use = "method moves the value of self and returns the modified value"]
     pub const fn vertical_margin(mut self, vertical: u16) -> Self {
-        self.margin.vertical = vertical;
+        self.margin.top = vertical;
+        self.margin.bottom = vertical;
+        self
+    }
+
+    /// Set the margin of each side of the layout.
+    ///
+    /// The margin of each side is subtracted from the area before the constraints are applied. If
+    /// the margins are larger than the area, the layout splits an empty area.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::prelude::*;
+    /// let layout = Layout::default()
+    ///     .constraints([Constraint::Min(0)])
+    ///     .margin_sides(1, 0, 3, 2)
+    ///     .split(Rect::new(0, 0, 10, 10));
+    /// assert_eq!(layout[..], [Rect::new(2, 1, 8, 6)]);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn margin_sides(mut self, top: u16, right: u16, bottom: u16, left: u16) -> Self {
+        self.margin = Sides {
+            top,
+            right,
+            bottom,
+            left,
+        };
         self
     }
 
@@ -514,6 +572,45 @@ impl Layout {
         self.split_with_spacers(area).0
     }
 
+    /// Splits the given area like [`Layout::split`], sizing the [`Constraint::Fit`] areas to their
+    /// content.
+    ///
+    /// The `measure` function is called for each `Fit` constraint with the index of the
+    /// constraint and the area available to the layout (the given area without the margin). It
+    /// returns the length the content needs in the direction of the layout (e.g. the height of a
+    /// [`Paragraph`] in a vertical layout), and the constraint is then treated as a
+    /// [`Constraint::Length`] of that length. The other constraints are not changed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ratatui::{prelude::*, widgets::Paragraph};
+    /// let paragraph = Paragraph::new("one\ntwo\nthree");
+    /// let layout = Layout::vertical([Constraint::Fit, Constraint::Fill(1)]);
+    /// let areas = layout.split_fit(Rect::new(0, 0, 10, 10), |_, available| {
+    ///     paragraph.line_count(available.width) as u16
+    /// });
+    /// assert_eq!(areas[..], [Rect::new(0, 0, 10, 3), Rect::new(0, 3, 10, 7)]);
+    /// ```
+    ///
+    /// [`Paragraph`]: crate::widgets::Paragraph
+    pub fn split_fit<F>(&self, area: Rect, mut measure: F) -> Rects
+    where
+        F: FnMut(usize, Rect) -> u16,
+    {
+        let available = self.margin.inner(area);
+        let constraints = self
+            .constraints
+            .iter()
+            .enumerate()
+            .map(|(index, &constraint)| match constraint {
+                Constraint::Fit => Constraint::Length(measure(index, available)),
+                constraint => constraint,
+            })
+            .collect_vec();
+        self.clone().constraints(constraints).split(area)
+    }
+
     /// Wrapper function around the cassowary-r solver that splits the given area into smaller ones
     /// based on the preferred widths or heights and the direction, with the ability to include
     /// spacers between the areas.
@@ -567,6 +664,30 @@ impl Layout {
         })
     }
 
+    /// Returns the index of the area that contains the given position, if any.
+    ///
+    /// This is useful to find which part of a layout a mouse event happened in. The areas are
+    /// usually the result of [`Layout::areas`] or [`Layout::split`], but any slice of [`Rect`]s can
+    /// be used. If several areas contain the position, the index of the first one is returned.
+    ///
+    /// The right and bottom edges of an area are exclusive (see [`Rect::contains`]), so a position
+    /// at `x + width` or `y + height` is outside of the area, and an area with no width or height
+    /// never contains any position.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::prelude::*;
+    /// let layout = Layout::horizontal([Constraint::Length(5), Constraint::Min(0)]);
+    /// let areas = layout.split(Rect::new(0, 0, 10, 2));
+    /// assert_eq!(Layout::hit_test(&areas, Position::new(2, 1)), Some(0));
+    /// assert_eq!(Layout::hit_test(&areas, Position::new(5, 1)), Some(1));
+    /// assert_eq!(Layout::hit_test(&areas, Position::new(10, 1)), None);
+    /// ```
+    pub fn hit_test(areas: &[Rect], position: Position) -> Option<usize> {
+        areas.iter().position(|area| area.contains(position))
+    }
+
     fn try_split(&self, area: Rect) -> Result<(Segments, Spacers), AddConstraintError> {
         // To take advantage of all of cassowary features, we would want to store the `Solver` in
         // one of the fields of the Layout struct. And we would want to set it up such that we could
@@ -590,7 +711,7 @@ impl Layout {
         // This is equivalent to storing the solver in `Layout` and calling `solver.reset()` here.
         let mut solver = Solver::new();
 
-        let inner_area = area.inner(self.margin);
+        let inner_area = self.margin.inner(area);
         let (area_start, area_end) = match self.direction {
             Direction::Horizontal => (
                 f64::from(inner_area.x) * FLOAT_PRECISION_MULTIPLIER,
@@ -639,7 +760,15 @@ impl Layout {
 
         let flex = self.flex;
         let spacing = self.spacing;
-        let constraints = &self.constraints;
+        // without a measurement of their content, `Fit` segments take whatever space is left
+        let constraints = &self
+            .constraints
+            .iter()
+            .map(|&constraint| match constraint {
+                Constraint::Fit => Constraint::Min(0),
+                constraint => constraint,
+            })
+            .collect_vec();
 
         let area_size = Element::from((*variables.first().unwrap(), *variables.last().unwrap()));
         configure_area(&mut solver, area_size, area_start, area_end)?;
@@ -658,8 +787,9 @@ impl Layout {
         let changes: HashMap<Variable, f64> = solver.fetch_changes().iter().copied().collect();
         // debug_segments(&segments, &changes);
 
-        let segment_rects = changes_to_rects(&changes, &segments, inner_area, self.direction);
-        let spacer_rects = changes_to_rects(&changes, &spacers, inner_area, self.direction);
+        let positions = round_positions(&changes, &variables, inner_area, self.direction);
+        let segment_rects = positions_to_rects(&positions[1..], inner_area, self.direction);
+        let spacer_rects = positions_to_rects(&positions, inner_area, self.direction);
 
         Ok((segment_rects, spacer_rects))
     }
@@ -732,6 +862,7 @@ fn configure_constraints(
                 // given no other constraints, this segment will grow as much as possible.
                 solver.add_constraint(element.has_size(area, FILL_GROW))?;
             }
+            Constraint::Fit => unreachable!("Fit constraints are replaced before solving"),
         }
     }
     Ok(())
@@ -862,20 +993,57 @@ fn configure_fill_constraints(
     Ok(())
 }
 
-fn changes_to_rects(
+/// Rounds the positions of the variables found by the solver to whole cells
+///
+/// The sizes of the elements between consecutive variables are rounded using the largest remainder
+/// method: each element gets the whole part of its size, and the cells that are left over are given
+/// to the elements with the largest fractional parts (the first elements win ties). This ensures
+/// that the rounded sizes always add up to the size of the area, so that no cell is dropped, and
+/// that elements with a whole size (e.g. the spacing between segments) are never changed.
+fn round_positions(
     changes: &HashMap<Variable, f64>,
-    elements: &[Element],
+    variables: &[Variable],
     area: Rect,
     direction: Direction,
-) -> Rects {
-    // convert to Rects
-    elements
+) -> Vec<u16> {
+    let (area_start, area_size) = match direction {
+        Direction::Horizontal => (area.x, area.width),
+        Direction::Vertical => (area.y, area.height),
+    };
+    let multiplier = FLOAT_PRECISION_MULTIPLIER as u32;
+    // the positions are rounded to the precision of the solver first to ignore its float errors
+    let positions = variables
+        .iter()
+        .map(|variable| changes.get(variable).unwrap_or(&0.0).round().max(0.0) as u32)
+        .collect_vec();
+    let sizes = positions
+        .iter()
+        .tuple_windows()
+        .map(|(start, end)| end.saturating_sub(*start))
+        .collect_vec();
+    let mut cells = sizes.iter().map(|size| size / multiplier).collect_vec();
+    let leftover = u32::from(area_size).saturating_sub(cells.iter().sum());
+    let by_remainder = (0..sizes.len())
+        .filter(|&i| sizes[i] % multiplier > 0)
+        .sorted_by_key(|&i| (Reverse(sizes[i] % multiplier), i));
+    for i in by_remainder.take(leftover as usize) {
+        cells[i] += 1;
+    }
+    iter::once(area_start)
+        .chain(cells.iter().scan(area_start, |position, &size| {
+            *position = position.saturating_add(size as u16);
+            Some(*position)
+        }))
+        .collect()
+}
+
+/// Converts the pairs of positions starting at the first position, the third position and so on to
+/// `Rect`s
+fn positions_to_rects(positions: &[u16], area: Rect, direction: Direction) -> Rects {
+    positions
         .iter()
-        .map(|element| {
-            let start = changes.get(&element.start).unwrap_or(&0.0);
-            let end = changes.get(&element.end).unwrap_or(&0.0);
-            let start = (start.round() / FLOAT_PRECISION_MULTIPLIER).round() as u16;
-            let end = (end.round() / FLOAT_PRECISION_MULTIPLIER).round() as u16;
+        .tuples()
+        .map(|(&start, &end)| {
             let size = end.saturating_sub(start);
             match direction {
                 Direction::Horizontal => Rect {
@@ -1098,7 +1266,7 @@ mod tests {
             Layout::default(),
             Layout {
                 direction: Direction::Vertical,
-                margin: Margin::new(0, 0),
+                margin: Sides::default(),
                 constraints: vec![],
                 flex: Flex::default(),
                 spacing: 0,
@@ -1143,7 +1311,7 @@ mod tests {
             Layout::vertical([Constraint::Min(0)]),
             Layout {
                 direction: Direction::Vertical,
-                margin: Margin::new(0, 0),
+                margin: Sides::default(),
                 constraints: vec![Constraint::Min(0)],
                 flex: Flex::default(),
                 spacing: 0,
@@ -1157,7 +1325,7 @@ mod tests {
             Layout::horizontal([Constraint::Min(0)]),
             Layout {
                 direction: Direction::Horizontal,
-                margin: Margin::new(0, 0),
+                margin: Sides::default(),
                 constraints: vec![Constraint::Min(0)],
                 flex: Flex::default(),
                 spacing: 0,
@@ -1241,21 +1409,51 @@ mod tests {
 
     #[test]
     fn margins() {
-        assert_eq!(Layout::default().margin(10).margin, Margin::new(10, 10));
+        let sides = |top, right, bottom, left| Sides {
+            top,
+            right,
+            bottom,
+            left,
+        };
+        assert_eq!(Layout::default().margin(10).margin, sides(10, 10, 10, 10));
         assert_eq!(
             Layout::default().horizontal_margin(10).margin,
-            Margin::new(10, 0)
+            sides(0, 10, 0, 10)
         );
         assert_eq!(
             Layout::default().vertical_margin(10).margin,
-            Margin::new(0, 10)
+            sides(10, 0, 10, 0)
         );
         assert_eq!(
             Layout::default()
                 .horizontal_margin(10)
                 .vertical_margin(20)
                 .margin,
-            Margin::new(10, 20)
+            sides(20, 10, 20, 10)
+        );
+        assert_eq!(
+            Layout::default().margin_sides(1, 2, 3, 4).margin,
+            sides(1, 2, 3, 4)
+        );
+    }
+
+    #[test]
+    fn margin_sides_split() {
+        let layout =
+            Layout::horizontal([Constraint::Fill(1), Constraint::Fill(1)]).margin_sides(1, 0, 0, 2);
+        assert_eq!(
+            layout.split(Rect::new(0, 0, 10, 3))[..],
+            [Rect::new(2, 1, 4, 2), Rect::new(6, 1, 4, 2)]
+        );
+    }
+
+    #[test]
+    fn margin_sides_larger_than_area() {
+        let layout =
+            Layout::vertical([Constraint::Length(1), Constraint::Min(0)]).margin_sides(0, 6, 0, 6);
+        assert_eq!(
+            layout.split(Rect::new(0, 0, 10, 10))[..],
+            [Rect::ZERO, Rect::ZERO]
         );
     }
 
@@ -1271,6 +1469,29 @@ mod tests {
         assert_eq!(Layout::default().spacing(0).spacing, 0);
     }
 
+    #[test]
+    fn hit_test() {
+        let areas = Layout::vertical([
+            Constraint::Length(2),
+            Constraint::Length(0),
+            Constraint::Min(0),
+        ])
+        .spacing(1)
+        .split(Rect::new(1, 1, 4, 6));
+        assert_eq!(Layout::hit_test(&areas, Position::new(1, 1)), Some(0));
+        assert_eq!(Layout::hit_test(&areas, Position::new(4, 2)), Some(0));
+        // the zero height area never matches, and the spacing belongs to no area
+        assert_eq!(Layout::hit_test(&areas, Position::new(1, 3)), None);
+        assert_eq!(Layout::hit_test(&areas, Position::new(1, 4)), None);
+        assert_eq!(Layout::hit_test(&areas, Position::new(1, 5)), Some(2));
+        assert_eq!(Layout::hit_test(&areas, Position::new(4, 6)), Some(2));
+        // right and bottom edges are exclusive
+        assert_eq!(Layout::hit_test(&areas, Position::new(5, 6)), None);
+        assert_eq!(Layout::hit_test(&areas, Position::new(4, 7)), None);
+        assert_eq!(Layout::hit_test(&areas, Position::new(0, 0)), None);
+        assert_eq!(Layout::hit_test(&[], Position::new(0, 0)), None);
+    }
+
     /// Tests for the `Layout::split()` function.
     ///
     /// There are many tests in this as the number of edge cases that are caused by the interaction
@@ -1289,6 +1510,7 @@ mod tests {
     /// - underflow: constraint is for less than the full space
     /// - overflow: constraint is for more than the full space
     mod split {
+        use itertools::Itertools;
         use pretty_assertions::assert_eq;
         use rstest::rstest;
 
@@ -1632,7 +1854,7 @@ mod tests {
         #[case(Flex::SpaceBetween, 10, &[Percentage(25),  Percentage(100)], "aaabbbbbbb" )]
         #[case(Flex::SpaceBetween, 10, &[Percentage(25),  Percentage(200)], "aaabbbbbbb" )]
         #[case(Flex::SpaceBetween, 10, &[Percentage(33),  Percentage(0)],   "aaa       " )]
-        #[case(Flex::SpaceBetween, 10, &[Percentage(33),  Percentage(25)],  "aaa     bb" )]
+        #[case(Flex::SpaceBetween, 10, &[Percentage(33),  Percentage(25)],  "aaa    bbb" )]
         #[case(Flex::SpaceBetween, 10, &[Percentage(33),  Percentage(50)],  "aaa  bbbbb" )]
         #[case(Flex::SpaceBetween, 10, &[Percentage(33),  Percentage(100)], "aaabbbbbbb" )]
         #[case(Flex::SpaceBetween, 10, &[Percentage(33),  Percentage(200)], "aaabbbbbbb" )]
@@ -1809,7 +2031,7 @@ mod tests {
         #[case(Flex::SpaceBetween, 10, &[Ratio(1, 4), Ratio(1, 1)],  "aaabbbbbbb" )]
         #[case(Flex::SpaceBetween, 10, &[Ratio(1, 4), Ratio(2, 1)],  "aaabbbbbbb" )]
         #[case(Flex::SpaceBetween, 10, &[Ratio(1, 3), Ratio(0, 1)],  "aaa       " )]
-        #[case(Flex::SpaceBetween, 10, &[Ratio(1, 3), Ratio(1, 4)],  "aaa     bb" )]
+        #[case(Flex::SpaceBetween, 10, &[Ratio(1, 3), Ratio(1, 4)],  "aaa    bbb" )]
         #[case(Flex::SpaceBetween, 10, &[Ratio(1, 3), Ratio(1, 2)],  "aaa  bbbbb" )]
         #[case(Flex::SpaceBetween, 10, &[Ratio(1, 3), Ratio(1, 1)],  "aaabbbbbbb" )]
         #[case(Flex::SpaceBetween, 10, &[Ratio(1, 3), Ratio(2, 1)],  "aaabbbbbbb" )]
@@ -2120,6 +2342,56 @@ mod tests {
             assert_eq!(expected, r);
         }
 
+        #[rstest]
+        #[case::without_measure(vec![Fit, Length(20)], vec![80, 20])]
+        #[case::with_fill(vec![Fit, Fill(1)], vec![50, 50])]
+        #[case::with_min(vec![Fit, Min(10)], vec![50, 50])]
+        fn fit_without_measure(#[case] constraints: Vec<Constraint>, #[case] expected: Vec<u16>) {
+            let widths = Layout::horizontal(constraints)
+                .split(Rect::new(0, 0, 100, 1))
+                .iter()
+                .map(|r| r.width)
+                .collect_vec();
+            assert_eq!(widths, expected);
+        }
+
+        #[rstest]
+        #[case::fit_fill(vec![Fit, Fill(1)], vec![12, 88])]
+        #[case::fill_fit(vec![Fill(1), Fit], vec![88, 12])]
+        #[case::fit_fit(vec![Fit, Fit, Fill(1)], vec![12, 12, 76])]
+        #[case::fit_length(vec![Fit, Length(20)], vec![12, 20])]
+        #[case::too_large(vec![Fit, Min(95)], vec![5, 95])]
+        fn split_fit(#[case] constraints: Vec<Constraint>, #[case] expected: Vec<u16>) {
+            let widths = Layout::horizontal(constraints)
+                .split_fit(Rect::new(0, 0, 100, 1), |_, _| 12)
+                .iter()
+                .map(|r| r.width)
+                .collect_vec();
+            assert_eq!(widths, expected);
+        }
+
+        #[test]
+        fn split_fit_measures_fit_constraints_in_available_area() {
+            let mut calls = Vec::new();
+            let areas = Layout::vertical([Length(1), Fit, Min(0), Fit])
+                .margin(1)
+                .split_fit(Rect::new(0, 0, 10, 20), |index, available| {
+                    calls.push((index, available));
+                    u16::try_from(index).unwrap()
+                });
+            let available = Rect::new(1, 1, 8, 18);
+            assert_eq!(calls, [(1, available), (3, available)]);
+            assert_eq!(
+                areas[..],
+                [
+                    Rect::new(1, 1, 8, 1),
+                    Rect::new(1, 2, 8, 1),
+                    Rect::new(1, 3, 8, 13),
+                    Rect::new(1, 16, 8, 3),
+                ]
+            );
+        }
+
         #[rstest]
         #[case::min_percentage(vec![80, 20], vec![Min(0), Percentage(20)])]
         #[case::max_percentage(vec![0, 100], vec![Max(0), Percentage(20)])]
@@ -2439,6 +2711,72 @@ mod tests {
             assert_eq!(expected, result);
         }
 
+        #[rstest]
+        fn rounding_fills_area(
+            #[values(2, 3, 5, 7, 11, 13, 97, 101)] width: u16,
+            #[values(
+                vec![Percentage(33), Percentage(33), Percentage(34)],
+                vec![Percentage(25), Percentage(25), Percentage(25), Percentage(25)],
+                vec![Ratio(1, 3), Ratio(1, 3), Ratio(1, 3)],
+                vec![Length(3), Percentage(50), Percentage(50)],
+                vec![Percentage(30), Length(1), Fill(1), Percentage(30)]
+            )]
+            constraints: Vec<Constraint>,
+            #[values(Flex::Legacy, Flex::Start, Flex::Center, Flex::SpaceBetween)] flex: Flex,
+        ) {
+            let rect = Rect::new(0, 0, width, 1);
+            let (segments, spacers) = Layout::horizontal(&constraints)
+                .flex(flex)
+                .split_with_spacers(rect);
+            let elements = spacers.iter().interleave(segments.iter()).collect_vec();
+            let mut x = 0;
+            for element in elements {
+                assert_eq!(element.x, x, "elements must be contiguous");
+                x += element.width;
+            }
+            assert_eq!(x, width, "elements must fill the area");
+            let segments_width: u16 = segments.iter().map(|r| r.width).sum();
+            assert_eq!(segments_width, width, "segments must fill the area");
+        }
+
+        #[rstest]
+        #[case::quarters(7, vec![Percentage(25); 4], Flex::Start, vec![2, 2, 2, 1])]
+        #[case::thirds(100, vec![Percentage(33); 3], Flex::Legacy, vec![33, 33, 34])]
+        #[case::thirds_start(100, vec![Percentage(33); 3], Flex::Start, vec![33, 33, 33])]
+        #[case::halves_tie(7, vec![Percentage(50); 2], Flex::Start, vec![4, 3])]
+        #[case::mixed(11, vec![Length(3), Percentage(50), Percentage(50)], Flex::Start, vec![3, 4, 4])]
+        fn rounding_uses_largest_remainder(
+            #[case] width: u16,
+            #[case] constraints: Vec<Constraint>,
+            #[case] flex: Flex,
+            #[case] expected: Vec<u16>,
+        ) {
+            let rect = Rect::new(0, 0, width, 1);
+            let widths = Layout::horizontal(constraints)
+                .flex(flex)
+                .split(rect)
+                .iter()
+                .map(|r| r.width)
+                .collect_vec();
+            assert_eq!(widths, expected);
+        }
+
+        #[rstest]
+        fn rounding_keeps_spacing(
+            #[values(Flex::Start, Flex::Center, Flex::End)] flex: Flex,
+            #[values(10, 13, 17)] width: u16,
+        ) {
+            let rect = Rect::new(0, 0, width, 1);
+            let (segments, spacers) = Layout::horizontal([Ratio(1, 3); 3])
+                .flex(flex)
+                .spacing(1)
+                .split_with_spacers(rect);
+            assert_eq!(spacers[1].width, 1);
+            assert_eq!(spacers[2].width, 1);
+            let segments_width: u16 = segments.iter().map(|r| r.width).sum();
+            assert_eq!(segments_width, width - 2);
+        }
+
         #[rstest]
         #[case::spacers(vec![(0, 0), (0, 100), (100, 0)], vec![Length(10), Length(10)], Flex::Legacy, 200)]
         #[case::spacers(vec![(0, 0), (0, 100), (100, 0)], vec![Length(10), Length(10)], Flex::SpaceBetween, 200)]
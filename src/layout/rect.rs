@@ -175,7 +175,23 @@ impl Rect {
 
     /// Returns a new `Rect` that is the intersection of the current one and the given one.
     ///
-    /// If the two `Rect`s do not intersect, the returned `Rect` will have no area.
+    /// If the two `Rect`s do not intersect, the returned `Rect` will have no area. Use
+    /// [`Rect::is_empty`] to check for this case, or [`Rect::intersects`] beforehand.
+    ///
+    /// This is useful to clip the area of a widget to the visible part of the screen. To move a
+    /// `Rect` inside another one instead of truncating it, see [`Rect::clamp`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::prelude::*;
+    /// let frame = Rect::new(0, 0, 80, 24);
+    /// let popup = Rect::new(70, 20, 20, 10);
+    /// assert_eq!(popup.intersection(frame), Rect::new(70, 20, 10, 4));
+    ///
+    /// let outside = Rect::new(100, 30, 10, 10);
+    /// assert!(outside.intersection(frame).is_empty());
+    /// ```
     #[must_use = "method returns the modified value"]
     pub fn intersection(self, other: Self) -> Self {
         let x1 = max(self.x, other.x);
@@ -460,6 +476,17 @@ mod tests {
         );
     }
 
+    #[rstest]
+    #[case::nested(Rect::new(0, 0, 10, 10), Rect::new(2, 3, 4, 5), Rect::new(2, 3, 4, 5))]
+    #[case::containing(Rect::new(2, 3, 4, 5), Rect::new(0, 0, 10, 10), Rect::new(2, 3, 4, 5))]
+    #[case::partial_overlap(Rect::new(0, 0, 5, 5), Rect::new(3, 2, 5, 5), Rect::new(3, 2, 2, 3))]
+    #[case::touching(Rect::new(0, 0, 5, 5), Rect::new(5, 0, 5, 5), Rect::new(5, 0, 0, 5))]
+    #[case::disjoint(Rect::new(0, 0, 2, 2), Rect::new(5, 6, 2, 2), Rect::new(5, 6, 0, 0))]
+    fn intersection_cases(#[case] rect: Rect, #[case] other: Rect, #[case] expected: Rect) {
+        assert_eq!(rect.intersection(other), expected);
+        assert_eq!(rect.intersection(other).is_empty(), !rect.intersects(other));
+    }
+
     #[test]
     fn intersection_underflow() {
         assert_eq!(
@@ -582,6 +609,8 @@ mod tests {
     #[case::too_wide(Rect::new(5, 20, 200, 10), Rect::new(10, 20, 100, 10))]
     #[case::too_tall(Rect::new(20, 5, 10, 200), Rect::new(20, 10, 10, 100))]
     #[case::too_large(Rect::new(0, 0, 200, 200), Rect::new(10, 10, 100, 100))]
+    #[case::nested(Rect::new(50, 50, 5, 5), Rect::new(50, 50, 5, 5))]
+    #[case::disjoint(Rect::new(200, 300, 10, 10), Rect::new(100, 100, 10, 10))]
     fn clamp(#[case] rect: Rect, #[case] expected: Rect) {
         let other = Rect::new(10, 10, 100, 100);
         assert_eq!(rect.clamp(other), expected);
@@ -1,6 +1,8 @@
 use crate::prelude::*;
 
 /// An iterator over rows within a `Rect`.
+///
+/// The iterator yields nothing for a `Rect` with a zero width or height.
 pub struct Rows {
     /// The `Rect` associated with the rows.
     pub rect: Rect,
@@ -25,7 +27,7 @@ impl Iterator for Rows {
     ///
     /// Returns `None` when there are no more rows to iterate through.
     fn next(&mut self) -> Option<Self::Item> {
-        if self.current_row >= self.rect.bottom() {
+        if self.rect.is_empty() || self.current_row >= self.rect.bottom() {
             return None;
         }
         let row = Rect::new(self.rect.x, self.current_row, self.rect.width, 1);
@@ -35,6 +37,8 @@ impl Iterator for Rows {
 }
 
 /// An iterator over columns within a `Rect`.
+///
+/// The iterator yields nothing for a `Rect` with a zero width or height.
 pub struct Columns {
     /// The `Rect` associated with the columns.
     pub rect: Rect,
@@ -59,7 +63,7 @@ impl Iterator for Columns {
     ///
     /// Returns `None` when there are no more columns to iterate through.
     fn next(&mut self) -> Option<Self::Item> {
-        if self.current_column >= self.rect.right() {
+        if self.rect.is_empty() || self.current_column >= self.rect.right() {
             return None;
         }
         let column = Rect::new(self.current_column, self.rect.y, 1, self.rect.height);
@@ -70,7 +74,8 @@ impl Iterator for Columns {
 
 /// An iterator over positions within a `Rect`.
 ///
-/// The iterator will yield all positions within the `Rect` in a row-major order.
+/// The iterator will yield all positions within the `Rect` in a row-major order, and nothing for a
+/// `Rect` with a zero width or height.
 pub struct Positions {
     /// The `Rect` associated with the positions.
     pub rect: Rect,
@@ -95,7 +100,7 @@ impl Iterator for Positions {
     ///
     /// Returns `None` when there are no more positions to iterate through.
     fn next(&mut self) -> Option<Self::Item> {
-        if self.current_position.y >= self.rect.bottom() {
+        if self.rect.is_empty() || self.current_position.y >= self.rect.bottom() {
             return None;
         }
         let position = self.current_position;
@@ -110,6 +115,8 @@ impl Iterator for Positions {
 
 #[cfg(test)]
 mod tests {
+    use rstest::rstest;
+
     use super::*;
 
     #[test]
@@ -140,4 +147,47 @@ mod tests {
         assert_eq!(positions.next(), Some(Position::new(1, 1)));
         assert_eq!(positions.next(), None);
     }
+
+    #[test]
+    fn offset_rect() {
+        let rect = Rect::new(3, 5, 2, 3);
+        assert_eq!(
+            rect.rows().collect::<Vec<_>>(),
+            [
+                Rect::new(3, 5, 2, 1),
+                Rect::new(3, 6, 2, 1),
+                Rect::new(3, 7, 2, 1)
+            ]
+        );
+        assert_eq!(
+            rect.columns().collect::<Vec<_>>(),
+            [Rect::new(3, 5, 1, 3), Rect::new(4, 5, 1, 3)]
+        );
+        assert_eq!(rect.positions().count(), 6);
+        assert_eq!(rect.positions().last(), Some(Position::new(4, 7)));
+    }
+
+    #[rstest]
+    #[case::zero_width(Rect::new(1, 1, 0, 2))]
+    #[case::zero_height(Rect::new(1, 1, 2, 0))]
+    #[case::zero_size(Rect::new(1, 1, 0, 0))]
+    fn empty_rect(#[case] rect: Rect) {
+        assert_eq!(rect.rows().next(), None);
+        assert_eq!(rect.columns().next(), None);
+        assert_eq!(rect.positions().next(), None);
+    }
+
+    #[test]
+    fn at_max_bounds() {
+        let rect = Rect::new(u16::MAX - 2, u16::MAX - 1, 2, 1);
+        assert_eq!(rect.rows().count(), 1);
+        assert_eq!(rect.columns().count(), 2);
+        assert_eq!(
+            rect.positions().collect::<Vec<_>>(),
+            [
+                Position::new(u16::MAX - 2, u16::MAX - 1),
+                Position::new(u16::MAX - 1, u16::MAX - 1)
+            ]
+        );
+    }
 }
@@ -297,9 +297,10 @@
 pub use crossterm;
 #[cfg(feature = "crossterm")]
 pub use terminal::{
-    init, init_with_options, restore, try_init, try_init_with_options, try_restore, DefaultTerminal,
+    init, init_with_options, restore, set_panic_hook, try_init, try_init_with_options, try_restore,
+    DefaultTerminal,
 };
-pub use terminal::{CompletedFrame, Frame, Terminal, TerminalOptions, Viewport};
+pub use terminal::{CompletedFrame, Frame, FrameStats, Terminal, TerminalOptions, Viewport};
 /// re-export the `termion` crate so that users don't have to add it as a dependency
 #[cfg(all(not(windows), feature = "termion"))]
 pub use termion;
@@ -307,6 +308,7 @@ pub use termion;
 #[cfg(feature = "termwiz")]
 pub use termwiz;
 
+pub mod accessibility;
 pub mod backend;
 pub mod buffer;
 pub mod layout;
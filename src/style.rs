@@ -71,7 +71,7 @@
 use std::fmt;
 
 use bitflags::bitflags;
-pub use color::{Color, ParseColorError};
+pub use color::{Color, ColorDepth, ParseColorError};
 pub use stylize::{Styled, Stylize};
 
 mod color;
@@ -95,7 +95,6 @@ bitflags! {
     ///
     /// let m = Modifier::BOLD | Modifier::ITALIC;
     /// ```
-    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[derive(Default, Clone, Copy, Eq, PartialEq, Hash)]
     pub struct Modifier: u16 {
         const BOLD              = 0b0000_0000_0001;
@@ -124,6 +123,96 @@ impl fmt::Debug for Modifier {
     }
 }
 
+#[cfg(feature = "serde")]
+impl serde::Serialize for Modifier {
+    /// Human readable formats use the names of the flags separated by `|` (e.g. `"BOLD |
+    /// ITALIC"`), other formats use the bits of the modifier.
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        if serializer.is_human_readable() {
+            serializer.collect_str(&self.0)
+        } else {
+            serializer.serialize_u16(self.bits())
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Modifier {
+    /// This is used to deserialize a value into Modifier via serde.
+    ///
+    /// Human readable formats accept the names of the flags separated by `|`, a list of flag
+    /// names, or the bits of the modifier. Unknown flag names are rejected.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::prelude::*;
+    ///
+    /// # fn get_modifiers() -> Result<(), serde_json::Error> {
+    /// let modifier: Modifier = serde_json::from_str(r#""BOLD | ITALIC""#)?;
+    /// assert_eq!(modifier, Modifier::BOLD | Modifier::ITALIC);
+    ///
+    /// let modifier: Modifier = serde_json::from_str(r#"["BOLD", "ITALIC"]"#)?;
+    /// assert_eq!(modifier, Modifier::BOLD | Modifier::ITALIC);
+    ///
+    /// let modifier: Modifier = serde_json::from_str("5")?;
+    /// assert_eq!(modifier, Modifier::BOLD | Modifier::ITALIC);
+    ///
+    /// let err = serde_json::from_str::<Modifier>(r#"["BOLD", "SHINY"]"#).unwrap_err();
+    /// assert_eq!(
+    ///     err.to_string(),
+    ///     "unknown modifier `SHINY` at line 1 column 17"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        use serde::de::{self, Unexpected};
+
+        struct ModifierVisitor;
+
+        impl<'de> de::Visitor<'de> for ModifierVisitor {
+            type Value = Modifier;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("modifier names separated by `|`, a list of modifier names or bits")
+            }
+
+            fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+                bitflags::parser::from_str(value).map_err(|err| E::custom(format!("{err}")))
+            }
+
+            fn visit_u64<E: de::Error>(self, value: u64) -> Result<Self::Value, E> {
+                u16::try_from(value)
+                    .ok()
+                    .and_then(Modifier::from_bits)
+                    .ok_or_else(|| E::invalid_value(Unexpected::Unsigned(value), &self))
+            }
+
+            fn visit_seq<A: de::SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+                let mut modifier = Modifier::empty();
+                while let Some(name) = seq.next_element::<std::borrow::Cow<str>>()? {
+                    modifier |= Modifier::from_name(&name)
+                        .ok_or_else(|| de::Error::custom(format!("unknown modifier `{name}`")))?;
+                }
+                Ok(modifier)
+            }
+        }
+
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(ModifierVisitor)
+        } else {
+            deserializer.deserialize_u16(ModifierVisitor)
+        }
+    }
+}
+
 /// Style lets you control the main characteristics of the displayed elements.
 ///
 /// ```rust
@@ -225,6 +314,7 @@ impl fmt::Debug for Modifier {
 /// ```
 #[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 pub struct Style {
     pub fg: Option<Color>,
     pub bg: Option<Color>,
@@ -798,4 +888,60 @@ mod tests {
                 .remove_modifier(Modifier::DIM)
         );
     }
+
+    #[cfg(feature = "serde")]
+    #[rstest]
+    #[case::names(r#""BOLD | ITALIC""#)]
+    #[case::list(r#"["BOLD", "ITALIC"]"#)]
+    #[case::bits("5")]
+    fn deserialize_modifier(#[case] json: &str) -> Result<(), serde_json::Error> {
+        let modifier: Modifier = serde_json::from_str(json)?;
+        assert_eq!(modifier, Modifier::BOLD | Modifier::ITALIC);
+        Ok(())
+    }
+
+    #[cfg(feature = "serde")]
+    #[rstest]
+    #[case::names(r#""BOLD | SHINY""#)]
+    #[case::list(r#"["BOLD", "SHINY"]"#)]
+    #[case::bits("4096")]
+    fn deserialize_modifier_error(#[case] json: &str) {
+        assert!(serde_json::from_str::<Modifier>(json).is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serialize_modifier() -> Result<(), serde_json::Error> {
+        let modifier = Modifier::BOLD | Modifier::UNDERLINED;
+        let json = serde_json::to_string(&modifier)?;
+        assert_eq!(json, r#""BOLD | UNDERLINED""#);
+        assert_eq!(serde_json::from_str::<Modifier>(&json)?, modifier);
+        assert_eq!(serde_json::to_string(&Modifier::empty())?, r#""""#);
+        assert_eq!(
+            serde_json::from_str::<Modifier>(r#""""#)?,
+            Modifier::empty()
+        );
+        Ok(())
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn style_round_trip() -> Result<(), serde_json::Error> {
+        let style = Style::new()
+            .fg(Color::Rgb(1, 2, 3))
+            .bg(Color::Indexed(42))
+            .add_modifier(Modifier::BOLD | Modifier::ITALIC)
+            .remove_modifier(Modifier::DIM);
+        let json = serde_json::to_string(&style)?;
+        assert_eq!(serde_json::from_str::<Style>(&json)?, style);
+        Ok(())
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn deserialize_style_with_missing_fields() -> Result<(), serde_json::Error> {
+        let style: Style = serde_json::from_str(r#"{"fg": "red", "add_modifier": ["BOLD"]}"#)?;
+        assert_eq!(style, Style::new().red().bold());
+        Ok(())
+    }
 }
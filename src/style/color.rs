@@ -2,6 +2,8 @@
 
 use std::{fmt, str::FromStr};
 
+use strum::{Display, EnumString};
+
 /// ANSI Color
 ///
 /// All colors from the [ANSI color table] are supported (though some names are not exactly the
@@ -187,7 +189,7 @@ impl<'de> serde::Deserialize<'de> for Color {
     /// assert!(err.is_data());
     /// assert_eq!(
     ///     err.to_string(),
-    ///     "Failed to parse Colors at line 1 column 20"
+    ///     r#"unknown color "invalid", expected a color name, #rrggbb hex value or an index at line 1 column 20"#
     /// );
     ///
     /// // Deserializing from the previous serialization implementation
@@ -227,7 +229,11 @@ impl<'de> serde::Deserialize<'de> for Color {
         let multi_type = ColorFormat::deserialize(deserializer)
             .map_err(|err| serde::de::Error::custom(format!("Failed to parse Colors: {err}")))?;
         match multi_type {
-            ColorFormat::V2(s) => FromStr::from_str(&s).map_err(serde::de::Error::custom),
+            ColorFormat::V2(s) => FromStr::from_str(&s).map_err(|_err: ParseColorError| {
+                serde::de::Error::custom(format!(
+                    "unknown color {s:?}, expected a color name, #rrggbb hex value or an index"
+                ))
+            }),
             ColorFormat::V1(color_wrapper) => match color_wrapper {
                 ColorWrapper::Rgb(red, green, blue) => Ok(Self::Rgb(red, green, blue)),
                 ColorWrapper::Indexed(index) => Ok(Self::Indexed(index)),
@@ -366,9 +372,9 @@ impl Color {
     /// The `from_hsl` function converts the Hue, Saturation and Lightness values to a
     /// corresponding `Color` RGB equivalent.
     ///
-    /// Hue values should be in the range [0, 360].
-    /// Saturation and L values should be in the range [0, 100].
-    /// Values that are not in the range are clamped to be within the range.
+    /// Hue values are in degrees and wrap around, so `-120.0`, `240.0` and `600.0` are the same
+    /// hue. Saturation and L values should be in the range [0, 100]. Values that are not in the
+    /// range are clamped to be within the range.
     ///
     /// # Examples
     ///
@@ -380,99 +386,399 @@ impl Color {
     ///
     /// let color: Color = Color::from_hsl(0.0, 0.0, 0.0);
     /// assert_eq!(color, Color::Rgb(0, 0, 0));
+    ///
+    /// // rotate the hue to get distinct colors
+    /// let colors: Vec<Color> = (0..3)
+    ///     .map(|i| Color::from_hsl(f64::from(i) * 120.0, 100.0, 50.0))
+    ///     .collect();
+    /// assert_eq!(
+    ///     colors,
+    ///     [
+    ///         Color::Rgb(255, 0, 0),
+    ///         Color::Rgb(0, 255, 0),
+    ///         Color::Rgb(0, 0, 255)
+    ///     ]
+    /// );
     /// ```
     pub fn from_hsl(h: f64, s: f64, l: f64) -> Self {
-        // Clamp input values to valid ranges
-        let h = h.clamp(0.0, 360.0);
-        let s = s.clamp(0.0, 100.0);
-        let l = l.clamp(0.0, 100.0);
+        // Wrap the hue and clamp the other values to valid ranges
+        let h = h.rem_euclid(360.0);
+        let s = s.clamp(0.0, 100.0) / 100.0;
+        let l = l.clamp(0.0, 100.0) / 100.0;
 
-        // Delegate to the function for normalized HSL to RGB conversion
-        normalized_hsl_to_rgb(h / 360.0, s / 100.0, l / 100.0)
+        let chroma = (1.0 - (2.0 * l - 1.0).abs()) * s;
+        hue_chroma_to_rgb(h, chroma, l - chroma / 2.0)
     }
-}
 
-/// Converts normalized HSL (Hue, Saturation, Lightness) values to RGB (Red, Green, Blue) color
-/// representation. H, S, and L values should be in the range [0, 1].
-///
-/// Based on <https://github.com/killercup/hsl-rs/blob/b8a30e11afd75f262e0550725333293805f4ead0/src/lib.rs>
-fn normalized_hsl_to_rgb(hue: f64, saturation: f64, lightness: f64) -> Color {
-    // This function can be made into `const` in the future.
-    // This comment contains the relevant information for making it `const`.
-    //
-    // If it is `const` and made public, users can write the following:
-    //
-    // ```rust
-    // const SLATE_50: Color = normalized_hsl_to_rgb(0.210, 0.40, 0.98);
-    // ```
-    //
-    // For it to be const now, we need `#![feature(const_fn_floating_point_arithmetic)]`
-    // Tracking issue: https://github.com/rust-lang/rust/issues/57241
-    //
-    // We would also need to remove the use of `.round()` in this function, i.e.:
-    //
-    // ```rust
-    // Color::Rgb((r * 255.0) as u8, (g * 255.0) as u8, (b * 255.0) as u8)
-    // ```
-
-    // Initialize RGB components
-    let red: f64;
-    let green: f64;
-    let blue: f64;
-
-    // Check if the color is achromatic (grayscale)
-    if saturation == 0.0 {
-        red = lightness;
-        green = lightness;
-        blue = lightness;
-    } else {
-        // Calculate RGB components for colored cases
-        let q = if lightness < 0.5 {
-            lightness * (1.0 + saturation)
-        } else {
-            lightness + saturation - lightness * saturation
+    /// Converts a HSV representation to a `Color::Rgb` instance.
+    ///
+    /// The `from_hsv` function converts the Hue, Saturation and Value values to a corresponding
+    /// `Color` RGB equivalent.
+    ///
+    /// Hue values are in degrees and wrap around, so `-120.0`, `240.0` and `600.0` are the same
+    /// hue. Saturation and V values should be in the range [0, 100]. Values that are not in the
+    /// range are clamped to be within the range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::prelude::*;
+    ///
+    /// let color: Color = Color::from_hsv(0.0, 100.0, 100.0);
+    /// assert_eq!(color, Color::Rgb(255, 0, 0));
+    ///
+    /// let color: Color = Color::from_hsv(120.0, 100.0, 50.0);
+    /// assert_eq!(color, Color::Rgb(0, 128, 0));
+    /// ```
+    pub fn from_hsv(h: f64, s: f64, v: f64) -> Self {
+        // Wrap the hue and clamp the other values to valid ranges
+        let h = h.rem_euclid(360.0);
+        let s = s.clamp(0.0, 100.0) / 100.0;
+        let v = v.clamp(0.0, 100.0) / 100.0;
+
+        let chroma = v * s;
+        hue_chroma_to_rgb(h, chroma, v - chroma)
+    }
+
+    /// Returns the RGB components of the color or `None` for [`Color::Reset`].
+    ///
+    /// Named and [indexed](Color::Indexed) colors are resolved using the standard xterm 256-color
+    /// palette. Note that terminals are free to use other values, in particular for the 16 named
+    /// colors which are often customized by the terminal theme, so the result is only an
+    /// approximation of what is displayed.
+    ///
+    /// This is useful to compute new colors from any color, e.g. to interpolate between colors.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::prelude::*;
+    ///
+    /// assert_eq!(Color::Rgb(1, 2, 3).to_rgb(), Some((1, 2, 3)));
+    /// assert_eq!(Color::Red.to_rgb(), Some((205, 0, 0)));
+    /// assert_eq!(Color::Indexed(196).to_rgb(), Some((255, 0, 0)));
+    /// assert_eq!(Color::Indexed(244).to_rgb(), Some((128, 128, 128)));
+    /// assert_eq!(Color::Reset.to_rgb(), None);
+    /// ```
+    pub const fn to_rgb(self) -> Option<(u8, u8, u8)> {
+        let index = match self {
+            Self::Rgb(r, g, b) => return Some((r, g, b)),
+            Self::Indexed(index) => index,
+            _ => match self.ansi_index() {
+                Some(index) => index,
+                None => return None,
+            },
         };
-        let p = 2.0 * lightness - q;
-        red = hue_to_rgb(p, q, hue + 1.0 / 3.0);
-        green = hue_to_rgb(p, q, hue);
-        blue = hue_to_rgb(p, q, hue - 1.0 / 3.0);
+        Some(indexed_to_rgb(index))
     }
 
-    // Scale RGB components to the range [0, 255] and create a Color::Rgb instance
-    Color::Rgb(
-        (red * 255.0).round() as u8,
-        (green * 255.0).round() as u8,
-        (blue * 255.0).round() as u8,
-    )
+    /// Returns the index of a named color in the 16 color ANSI palette, or `None` for the other
+    /// colors.
+    pub(crate) const fn ansi_index(self) -> Option<u8> {
+        let index = match self {
+            Self::Black => 0,
+            Self::Red => 1,
+            Self::Green => 2,
+            Self::Yellow => 3,
+            Self::Blue => 4,
+            Self::Magenta => 5,
+            Self::Cyan => 6,
+            Self::Gray => 7,
+            Self::DarkGray => 8,
+            Self::LightRed => 9,
+            Self::LightGreen => 10,
+            Self::LightYellow => 11,
+            Self::LightBlue => 12,
+            Self::LightMagenta => 13,
+            Self::LightCyan => 14,
+            Self::White => 15,
+            Self::Reset | Self::Indexed(_) | Self::Rgb(..) => return None,
+        };
+        Some(index)
+    }
+
+    /// Linearly interpolates between this color and `other`.
+    ///
+    /// `t` is the position between the two colors, from `0.0` (this color) to `1.0` (`other`), and
+    /// is clamped to this range. Both colors are resolved with [`Color::to_rgb`] and each component
+    /// is interpolated separately, so the result is always an [RGB](Color::Rgb) color. This makes
+    /// it possible to animate the colors of a UI by calling this method on each frame.
+    ///
+    /// If either color can't be resolved to RGB (i.e. [`Color::Reset`]), the nearest color is
+    /// returned instead: this color if `t` is less than `0.5`, `other` otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::prelude::*;
+    ///
+    /// let black = Color::Rgb(0, 0, 0);
+    /// let white = Color::Rgb(255, 255, 255);
+    /// assert_eq!(black.lerp(white, 0.5), Color::Rgb(128, 128, 128));
+    /// assert_eq!(black.lerp(white, 2.0), white);
+    /// assert_eq!(Color::Black.lerp(Color::Blue, 1.0), Color::Rgb(0, 0, 238));
+    /// assert_eq!(Color::Reset.lerp(white, 0.2), Color::Reset);
+    /// ```
+    #[must_use]
+    pub fn lerp(self, other: Self, t: f64) -> Self {
+        let t = if t.is_nan() { 0.0 } else { t.clamp(0.0, 1.0) };
+        let (Some((r1, g1, b1)), Some((r2, g2, b2))) = (self.to_rgb(), other.to_rgb()) else {
+            return if t < 0.5 { self } else { other };
+        };
+        let lerp = |a: u8, b: u8| (f64::from(a) + (f64::from(b) - f64::from(a)) * t).round() as u8;
+        Self::Rgb(lerp(r1, r2), lerp(g1, g2), lerp(b1, b2))
+    }
+
+    /// Converts the color to the nearest color of the xterm 256-color palette.
+    ///
+    /// [RGB](Color::Rgb) colors are converted to the nearest entry of the 6x6x6 color cube or of
+    /// the grayscale ramp of the palette (indices 16-255). The 16 standard colors are not used as
+    /// their values are often customized by the terminal theme. Other colors are returned as is.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::prelude::*;
+    ///
+    /// assert_eq!(Color::Rgb(255, 130, 10).to_indexed(), Color::Indexed(208));
+    /// assert_eq!(Color::Rgb(100, 100, 100).to_indexed(), Color::Indexed(241));
+    /// assert_eq!(Color::Red.to_indexed(), Color::Red);
+    /// ```
+    #[must_use]
+    pub fn to_indexed(self) -> Self {
+        let Self::Rgb(r, g, b) = self else {
+            return self;
+        };
+        let nearest_level = |component: u8| {
+            (0..CUBE_LEVELS.len())
+                .min_by_key(|&level| CUBE_LEVELS[level].abs_diff(component))
+                .unwrap_or_default() as u8
+        };
+        let cube = 16 + 36 * nearest_level(r) + 6 * nearest_level(g) + nearest_level(b);
+        let average = ((u16::from(r) + u16::from(g) + u16::from(b)) / 3) as u8;
+        let gray = 232 + (average.saturating_sub(3) / 10).min(23);
+        let index = [cube, gray]
+            .into_iter()
+            .min_by_key(|&index| rgb_distance((r, g, b), indexed_to_rgb(index)))
+            .unwrap_or(cube);
+        Self::Indexed(index)
+    }
+
+    /// Converts the color to the nearest of the 16 standard ANSI colors.
+    ///
+    /// [RGB](Color::Rgb) and [indexed](Color::Indexed) colors are converted to the named color
+    /// whose value in the standard xterm palette is the nearest. Indexed colors 0-15 are the
+    /// standard colors themselves. Named colors and [`Color::Reset`] are returned as is.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::prelude::*;
+    ///
+    /// assert_eq!(Color::Rgb(200, 10, 10).to_ansi(), Color::Red);
+    /// assert_eq!(Color::Indexed(9).to_ansi(), Color::LightRed);
+    /// assert_eq!(Color::Indexed(208).to_ansi(), Color::Yellow);
+    /// assert_eq!(Color::Blue.to_ansi(), Color::Blue);
+    /// ```
+    #[must_use]
+    pub fn to_ansi(self) -> Self {
+        let rgb = match self {
+            Self::Indexed(index @ 0..=15) => return STANDARD_NAMED_COLORS[index as usize],
+            Self::Rgb(r, g, b) => (r, g, b),
+            Self::Indexed(index) => indexed_to_rgb(index),
+            _ => return self,
+        };
+        STANDARD_NAMED_COLORS
+            .into_iter()
+            .zip(STANDARD_COLORS)
+            .min_by_key(|&(_, standard)| rgb_distance(rgb, standard))
+            .map_or(self, |(color, _)| color)
+    }
+
+    /// Converts the color to the nearest color which can be displayed with the given
+    /// [`ColorDepth`].
+    ///
+    /// - [`ColorDepth::TrueColor`]: the color is returned as is.
+    /// - [`ColorDepth::Indexed256`]: RGB colors are converted with [`Color::to_indexed`].
+    /// - [`ColorDepth::Ansi16`]: RGB and indexed colors are converted with [`Color::to_ansi`].
+    /// - [`ColorDepth::Monochrome`]: all colors are converted to [`Color::Reset`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::{prelude::*, style::ColorDepth};
+    ///
+    /// let color = Color::Rgb(255, 130, 10);
+    /// assert_eq!(color.to_depth(ColorDepth::TrueColor), color);
+    /// assert_eq!(color.to_depth(ColorDepth::Indexed256), Color::Indexed(208));
+    /// assert_eq!(color.to_depth(ColorDepth::Ansi16), Color::Yellow);
+    /// assert_eq!(color.to_depth(ColorDepth::Monochrome), Color::Reset);
+    /// ```
+    #[must_use]
+    pub fn to_depth(self, depth: ColorDepth) -> Self {
+        match depth {
+            ColorDepth::TrueColor => self,
+            ColorDepth::Indexed256 => self.to_indexed(),
+            ColorDepth::Ansi16 => self.to_ansi(),
+            ColorDepth::Monochrome => Self::Reset,
+        }
+    }
 }
 
-/// Helper function to calculate RGB component for a specific hue value.
-fn hue_to_rgb(p: f64, q: f64, t: f64) -> f64 {
-    // Adjust the hue value to be within the valid range [0, 1]
-    let mut t = t;
-    if t < 0.0 {
-        t += 1.0;
+/// The number of colors a terminal can display
+///
+/// Colors which can't be displayed can be converted to the nearest available color with
+/// [`Color::to_depth`]. The [`CrosstermBackend`](crate::backend::CrosstermBackend) does this for
+/// the depth set with its `color_depth` method.
+#[derive(Debug, Default, Display, EnumString, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum ColorDepth {
+    /// 24-bit RGB colors. This is the default.
+    #[default]
+    TrueColor,
+    /// The xterm 256-color palette
+    Indexed256,
+    /// The 16 standard ANSI colors
+    Ansi16,
+    /// No colors
+    Monochrome,
+}
+
+impl ColorDepth {
+    /// Detects the color depth of the terminal from the `COLORTERM` and `TERM` environment
+    /// variables.
+    ///
+    /// - `COLORTERM` set to `truecolor` or `24bit`: [`ColorDepth::TrueColor`]
+    /// - `TERM` set to `dumb`: [`ColorDepth::Monochrome`]
+    /// - `TERM` containing `256color`: [`ColorDepth::Indexed256`]
+    /// - `TERM` set to any other value: [`ColorDepth::Ansi16`]
+    /// - otherwise, e.g. on Windows where `TERM` is usually not set: [`ColorDepth::TrueColor`]
+    ///
+    /// This is only a heuristic, as terminals are not required to set these variables
+    /// accurately.
+    pub fn detect() -> Self {
+        let colorterm = std::env::var("COLORTERM").ok();
+        let term = std::env::var("TERM").ok();
+        Self::from_env(colorterm.as_deref(), term.as_deref())
     }
-    if t > 1.0 {
-        t -= 1.0;
+
+    /// Returns the color depth for the given values of the `COLORTERM` and `TERM` environment
+    /// variables
+    fn from_env(colorterm: Option<&str>, term: Option<&str>) -> Self {
+        if matches!(colorterm, Some("truecolor" | "24bit")) {
+            return Self::TrueColor;
+        }
+        match term {
+            None | Some("") => Self::TrueColor,
+            Some("dumb") => Self::Monochrome,
+            Some(term) if term.contains("256color") => Self::Indexed256,
+            Some(_) => Self::Ansi16,
+        }
     }
+}
+
+/// The named colors in the order of their index in the xterm palette
+const STANDARD_NAMED_COLORS: [Color; 16] = [
+    Color::Black,
+    Color::Red,
+    Color::Green,
+    Color::Yellow,
+    Color::Blue,
+    Color::Magenta,
+    Color::Cyan,
+    Color::Gray,
+    Color::DarkGray,
+    Color::LightRed,
+    Color::LightGreen,
+    Color::LightYellow,
+    Color::LightBlue,
+    Color::LightMagenta,
+    Color::LightCyan,
+    Color::White,
+];
+
+/// Returns the squared euclidean distance between two RGB colors
+fn rgb_distance((r1, g1, b1): (u8, u8, u8), (r2, g2, b2): (u8, u8, u8)) -> u32 {
+    [(r1, r2), (g1, g2), (b1, b2)]
+        .into_iter()
+        .map(|(a, b)| u32::from(a.abs_diff(b)).pow(2))
+        .sum()
+}
+
+/// The RGB values of the 16 standard colors in the xterm palette
+const STANDARD_COLORS: [(u8, u8, u8); 16] = [
+    (0, 0, 0),
+    (205, 0, 0),
+    (0, 205, 0),
+    (205, 205, 0),
+    (0, 0, 238),
+    (205, 0, 205),
+    (0, 205, 205),
+    (229, 229, 229),
+    (127, 127, 127),
+    (255, 0, 0),
+    (0, 255, 0),
+    (255, 255, 0),
+    (92, 92, 255),
+    (255, 0, 255),
+    (0, 255, 255),
+    (255, 255, 255),
+];
+
+/// The intensities of each component of the 6x6x6 color cube of the xterm palette
+const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
 
-    // Calculate the RGB component based on the hue value
-    if t < 1.0 / 6.0 {
-        p + (q - p) * 6.0 * t
-    } else if t < 1.0 / 2.0 {
-        q
-    } else if t < 2.0 / 3.0 {
-        p + (q - p) * (2.0 / 3.0 - t) * 6.0
-    } else {
-        p
+/// Converts an index of the xterm 256-color palette to RGB values
+///
+/// Indices 0-15 are the standard colors, 16-231 a 6x6x6 color cube and 232-255 a grayscale ramp
+/// from 8 to 238.
+const fn indexed_to_rgb(index: u8) -> (u8, u8, u8) {
+    match index {
+        0..=15 => STANDARD_COLORS[index as usize],
+        16..=231 => {
+            let index = index - 16;
+            (
+                CUBE_LEVELS[(index / 36) as usize],
+                CUBE_LEVELS[(index / 6 % 6) as usize],
+                CUBE_LEVELS[(index % 6) as usize],
+            )
+        }
+        _ => {
+            let level = 8 + (index - 232) * 10;
+            (level, level, level)
+        }
     }
 }
 
+/// Converts a hue in degrees, a chroma and the value of the smallest RGB component, all but the hue
+/// in the range [0, 1], to a `Color::Rgb` instance.
+///
+/// This is the common part of the HSL and HSV conversions, see
+/// <https://en.wikipedia.org/wiki/HSL_and_HSV#Color_conversion_formulae>
+fn hue_chroma_to_rgb(hue: f64, chroma: f64, min: f64) -> Color {
+    let sector = hue / 60.0;
+    let x = chroma * (1.0 - (sector % 2.0 - 1.0).abs());
+    let (red, green, blue) = match sector as u8 {
+        0 => (chroma, x, 0.0),
+        1 => (x, chroma, 0.0),
+        2 => (0.0, chroma, x),
+        3 => (0.0, x, chroma),
+        4 => (x, 0.0, chroma),
+        _ => (chroma, 0.0, x),
+    };
+
+    // Scale RGB components to the range [0, 255]
+    Color::Rgb(
+        ((red + min) * 255.0).round() as u8,
+        ((green + min) * 255.0).round() as u8,
+        ((blue + min) * 255.0).round() as u8,
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use std::error::Error;
 
+    use rstest::rstest;
     #[cfg(feature = "serde")]
     use serde::de::{Deserialize, IntoDeserializer};
 
@@ -488,9 +794,9 @@ mod tests {
         let color = Color::from_hsl(360.0, 50.0, 75.0);
         assert_eq!(color, Color::Rgb(223, 159, 159));
 
-        // Test with H value exceeding the upper bound
+        // Test with H value exceeding the upper bound wraps around
         let color = Color::from_hsl(400.0, 50.0, 75.0);
-        assert_eq!(color, Color::Rgb(223, 159, 159));
+        assert_eq!(color, Color::Rgb(223, 202, 159));
 
         // Test with S and L values exceeding the upper bound
         let color = Color::from_hsl(240.0, 120.0, 150.0);
@@ -505,6 +811,167 @@ mod tests {
         assert_eq!(color, Color::Rgb(0, 0, 0));
     }
 
+    #[test]
+    fn from_hsl_known_values() {
+        assert_eq!(Color::from_hsl(0.0, 100.0, 50.0), Color::Rgb(255, 0, 0));
+        assert_eq!(Color::from_hsl(39.0, 100.0, 50.0), Color::Rgb(255, 166, 0));
+        assert_eq!(Color::from_hsl(60.0, 100.0, 50.0), Color::Rgb(255, 255, 0));
+        assert_eq!(Color::from_hsl(120.0, 100.0, 25.0), Color::Rgb(0, 128, 0));
+        assert_eq!(Color::from_hsl(240.0, 100.0, 50.0), Color::Rgb(0, 0, 255));
+        assert_eq!(Color::from_hsl(300.0, 100.0, 25.0), Color::Rgb(128, 0, 128));
+        assert_eq!(Color::from_hsl(0.0, 0.0, 75.0), Color::Rgb(191, 191, 191));
+    }
+
+    #[test]
+    fn from_hsl_wraps_hue() {
+        assert_eq!(
+            Color::from_hsl(480.0, 100.0, 50.0),
+            Color::from_hsl(120.0, 100.0, 50.0)
+        );
+        assert_eq!(
+            Color::from_hsl(-120.0, 100.0, 50.0),
+            Color::from_hsl(240.0, 100.0, 50.0)
+        );
+    }
+
+    #[test]
+    fn from_hsv() {
+        assert_eq!(Color::from_hsv(0.0, 100.0, 100.0), Color::Rgb(255, 0, 0));
+        assert_eq!(Color::from_hsv(30.0, 100.0, 100.0), Color::Rgb(255, 128, 0));
+        assert_eq!(Color::from_hsv(60.0, 100.0, 100.0), Color::Rgb(255, 255, 0));
+        assert_eq!(Color::from_hsv(120.0, 100.0, 50.0), Color::Rgb(0, 128, 0));
+        assert_eq!(
+            Color::from_hsv(180.0, 100.0, 100.0),
+            Color::Rgb(0, 255, 255)
+        );
+        assert_eq!(Color::from_hsv(240.0, 100.0, 100.0), Color::Rgb(0, 0, 255));
+        assert_eq!(Color::from_hsv(300.0, 100.0, 50.0), Color::Rgb(128, 0, 128));
+        assert_eq!(Color::from_hsv(0.0, 0.0, 75.0), Color::Rgb(191, 191, 191));
+    }
+
+    #[test]
+    fn from_hsv_wraps_hue_and_clamps() {
+        assert_eq!(Color::from_hsv(360.0, 100.0, 100.0), Color::Rgb(255, 0, 0));
+        assert_eq!(
+            Color::from_hsv(-60.0, 100.0, 100.0),
+            Color::Rgb(255, 0, 255)
+        );
+        assert_eq!(
+            Color::from_hsv(90.0, -10.0, 150.0),
+            Color::Rgb(255, 255, 255)
+        );
+        assert_eq!(Color::from_hsv(90.0, 150.0, -10.0), Color::Rgb(0, 0, 0));
+    }
+
+    #[test]
+    fn to_rgb() {
+        assert_eq!(Color::Reset.to_rgb(), None);
+        assert_eq!(Color::Rgb(10, 20, 30).to_rgb(), Some((10, 20, 30)));
+        assert_eq!(Color::Black.to_rgb(), Some((0, 0, 0)));
+        assert_eq!(Color::Gray.to_rgb(), Some((229, 229, 229)));
+        assert_eq!(Color::DarkGray.to_rgb(), Some((127, 127, 127)));
+        assert_eq!(Color::LightBlue.to_rgb(), Some((92, 92, 255)));
+        assert_eq!(Color::White.to_rgb(), Some((255, 255, 255)));
+    }
+
+    #[test]
+    fn indexed_to_rgb() {
+        assert_eq!(Color::Indexed(1).to_rgb(), Color::Red.to_rgb());
+        assert_eq!(Color::Indexed(16).to_rgb(), Some((0, 0, 0)));
+        assert_eq!(Color::Indexed(21).to_rgb(), Some((0, 0, 255)));
+        assert_eq!(Color::Indexed(67).to_rgb(), Some((95, 135, 175)));
+        assert_eq!(Color::Indexed(208).to_rgb(), Some((255, 135, 0)));
+        assert_eq!(Color::Indexed(231).to_rgb(), Some((255, 255, 255)));
+        assert_eq!(Color::Indexed(232).to_rgb(), Some((8, 8, 8)));
+        assert_eq!(Color::Indexed(255).to_rgb(), Some((238, 238, 238)));
+    }
+
+    #[rstest]
+    #[case::start(0.0, Color::Rgb(10, 200, 0))]
+    #[case::quarter(0.25, Color::Rgb(20, 175, 25))]
+    #[case::middle(0.5, Color::Rgb(30, 150, 50))]
+    #[case::end(1.0, Color::Rgb(50, 100, 100))]
+    #[case::below(-1.0, Color::Rgb(10, 200, 0))]
+    #[case::above(3.0, Color::Rgb(50, 100, 100))]
+    #[case::nan(f64::NAN, Color::Rgb(10, 200, 0))]
+    fn lerp(#[case] t: f64, #[case] expected: Color) {
+        assert_eq!(
+            Color::Rgb(10, 200, 0).lerp(Color::Rgb(50, 100, 100), t),
+            expected
+        );
+    }
+
+    #[rstest]
+    #[case::named(Color::Black, Color::White, Color::Rgb(128, 128, 128))]
+    #[case::indexed(Color::Indexed(16), Color::Indexed(21), Color::Rgb(0, 0, 128))]
+    #[case::mixed(Color::Red, Color::Rgb(0, 0, 0), Color::Rgb(103, 0, 0))]
+    fn lerp_resolves_colors(#[case] from: Color, #[case] to: Color, #[case] expected: Color) {
+        assert_eq!(from.lerp(to, 0.5), expected);
+    }
+
+    #[rstest]
+    #[case::from_reset_start(Color::Reset, Color::Red, 0.49, Color::Reset)]
+    #[case::from_reset_end(Color::Reset, Color::Red, 0.5, Color::Red)]
+    #[case::to_reset_start(Color::Red, Color::Reset, 0.2, Color::Red)]
+    #[case::to_reset_end(Color::Red, Color::Reset, 0.8, Color::Reset)]
+    fn lerp_reset(#[case] from: Color, #[case] to: Color, #[case] t: f64, #[case] expected: Color) {
+        assert_eq!(from.lerp(to, t), expected);
+    }
+
+    #[rstest]
+    #[case::black(Color::Rgb(0, 0, 0), Color::Indexed(16))]
+    #[case::white(Color::Rgb(255, 255, 255), Color::Indexed(231))]
+    #[case::cube(Color::Rgb(100, 140, 170), Color::Indexed(67))]
+    #[case::orange(Color::Rgb(255, 130, 10), Color::Indexed(208))]
+    #[case::gray(Color::Rgb(100, 100, 100), Color::Indexed(241))]
+    #[case::dark_gray(Color::Rgb(9, 9, 9), Color::Indexed(232))]
+    #[case::light_gray(Color::Rgb(240, 240, 240), Color::Indexed(255))]
+    #[case::named(Color::Red, Color::Red)]
+    #[case::indexed(Color::Indexed(42), Color::Indexed(42))]
+    #[case::reset(Color::Reset, Color::Reset)]
+    fn to_indexed(#[case] color: Color, #[case] expected: Color) {
+        assert_eq!(color.to_indexed(), expected);
+    }
+
+    #[rstest]
+    #[case::black(Color::Rgb(10, 10, 10), Color::Black)]
+    #[case::red(Color::Rgb(200, 10, 10), Color::Red)]
+    #[case::light_blue(Color::Rgb(90, 90, 250), Color::LightBlue)]
+    #[case::white(Color::Rgb(250, 250, 250), Color::White)]
+    #[case::standard_index(Color::Indexed(12), Color::LightBlue)]
+    #[case::cube_index(Color::Indexed(46), Color::LightGreen)]
+    #[case::gray_index(Color::Indexed(244), Color::DarkGray)]
+    #[case::named(Color::Magenta, Color::Magenta)]
+    #[case::reset(Color::Reset, Color::Reset)]
+    fn to_ansi(#[case] color: Color, #[case] expected: Color) {
+        assert_eq!(color.to_ansi(), expected);
+    }
+
+    #[test]
+    fn to_depth() {
+        let color = Color::Indexed(46);
+        assert_eq!(color.to_depth(ColorDepth::TrueColor), color);
+        assert_eq!(color.to_depth(ColorDepth::Indexed256), color);
+        assert_eq!(color.to_depth(ColorDepth::Ansi16), Color::LightGreen);
+        assert_eq!(color.to_depth(ColorDepth::Monochrome), Color::Reset);
+    }
+
+    #[rstest]
+    #[case::truecolor(Some("truecolor"), Some("xterm"), ColorDepth::TrueColor)]
+    #[case::bit24(Some("24bit"), None, ColorDepth::TrueColor)]
+    #[case::xterm_256(None, Some("xterm-256color"), ColorDepth::Indexed256)]
+    #[case::xterm(Some(""), Some("xterm"), ColorDepth::Ansi16)]
+    #[case::linux(None, Some("linux"), ColorDepth::Ansi16)]
+    #[case::dumb(None, Some("dumb"), ColorDepth::Monochrome)]
+    #[case::unset(None, None, ColorDepth::TrueColor)]
+    fn color_depth_from_env(
+        #[case] colorterm: Option<&str>,
+        #[case] term: Option<&str>,
+        #[case] expected: ColorDepth,
+    ) {
+        assert_eq!(ColorDepth::from_env(colorterm, term), expected);
+    }
+
     #[test]
     fn from_u32() {
         assert_eq!(Color::from_u32(0x000000), Color::Rgb(0, 0, 0));
@@ -689,6 +1156,43 @@ mod tests {
         Ok(())
     }
 
+    #[cfg(feature = "serde")]
+    #[rstest]
+    #[case::reset(Color::Reset)]
+    #[case::black(Color::Black)]
+    #[case::red(Color::Red)]
+    #[case::green(Color::Green)]
+    #[case::yellow(Color::Yellow)]
+    #[case::blue(Color::Blue)]
+    #[case::magenta(Color::Magenta)]
+    #[case::cyan(Color::Cyan)]
+    #[case::gray(Color::Gray)]
+    #[case::dark_gray(Color::DarkGray)]
+    #[case::light_red(Color::LightRed)]
+    #[case::light_green(Color::LightGreen)]
+    #[case::light_yellow(Color::LightYellow)]
+    #[case::light_blue(Color::LightBlue)]
+    #[case::light_magenta(Color::LightMagenta)]
+    #[case::light_cyan(Color::LightCyan)]
+    #[case::white(Color::White)]
+    #[case::indexed(Color::Indexed(200))]
+    #[case::rgb(Color::Rgb(1, 2, 3))]
+    fn serialize_round_trip(#[case] color: Color) -> Result<(), serde_json::Error> {
+        let json = serde_json::to_string(&color)?;
+        assert_eq!(serde_json::from_str::<Color>(&json)?, color);
+        Ok(())
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn deserialize_unknown_color_error() {
+        let err = serde_json::from_str::<Color>(r#""purple""#).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            r#"unknown color "purple", expected a color name, #rrggbb hex value or an index"#
+        );
+    }
+
     #[cfg(feature = "serde")]
     #[test]
     fn deserialize_with_previous_format() -> Result<(), serde_json::Error> {
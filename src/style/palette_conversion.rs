@@ -3,9 +3,9 @@
 use ::palette::{
     bool_mask::LazySelect,
     num::{Arithmetics, MulSub, PartialCmp, Powf, Real},
-    LinSrgb,
+    LinSrgb, LinSrgba,
 };
-use palette::{stimulus::IntoStimulus, Srgb};
+use palette::{stimulus::IntoStimulus, Srgb, Srgba};
 
 use super::Color;
 
@@ -52,6 +52,49 @@ where
     }
 }
 
+/// Convert an [`palette::Srgba`] color to a [`Color`].
+///
+/// Terminals can't blend colors, so the alpha component is ignored.
+///
+/// # Examples
+///
+/// ```
+/// use palette::Srgba;
+/// use ratatui::style::Color;
+///
+/// let color = Color::from(Srgba::new(255u8, 0, 0, 128));
+/// assert_eq!(color, Color::Rgb(255, 0, 0));
+/// ```
+impl<T: IntoStimulus<u8>> From<Srgba<T>> for Color {
+    fn from(color: Srgba<T>) -> Self {
+        Self::from(color.color)
+    }
+}
+
+/// Convert a [`palette::LinSrgba`] color to a [`Color`].
+///
+/// Terminals can't blend colors, so the alpha component is ignored. Like the [`LinSrgb`]
+/// conversion, this only works for floating point linear sRGB colors.
+///
+/// # Examples
+///
+/// ```
+/// use palette::LinSrgba;
+/// use ratatui::style::Color;
+///
+/// let color = Color::from(LinSrgba::new(1.0f32, 0.0, 0.0, 0.5));
+/// assert_eq!(color, Color::Rgb(255, 0, 0));
+/// ```
+impl<T: IntoStimulus<u8>> From<LinSrgba<T>> for Color
+where
+    T: Real + Powf + MulSub + Arithmetics + PartialCmp + Clone,
+    T::Mask: LazySelect<T>,
+{
+    fn from(color: LinSrgba<T>) -> Self {
+        Self::from(color.color)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -69,6 +112,30 @@ mod tests {
         );
     }
 
+    #[test]
+    fn from_srgb_round_trips_u8() {
+        for (red, green, blue) in [(0, 0, 0), (255, 255, 255), (1, 128, 254), (12, 34, 56)] {
+            let color = Color::from(Srgb::new(red, green, blue));
+            assert_eq!(color, Color::Rgb(red, green, blue));
+        }
+    }
+
+    #[test]
+    fn from_srgba() {
+        const RED: Color = Color::Rgb(255, 0, 0);
+        assert_eq!(Color::from(Srgba::new(255u8, 0, 0, 255)), RED);
+        assert_eq!(Color::from(Srgba::new(255u8, 0, 0, 0)), RED);
+        assert_eq!(Color::from(Srgba::new(1.0f32, 0.0, 0.0, 0.5)), RED);
+    }
+
+    #[test]
+    fn from_lin_srgba() {
+        assert_eq!(
+            Color::from(LinSrgba::new(0.5f32, 0.5, 0.5, 0.1)),
+            Color::Rgb(188, 188, 188)
+        );
+    }
+
     #[test]
     fn from_lin_srgb() {
         const RED: Color = Color::Rgb(255, 0, 0);
@@ -1,3 +1,5 @@
+use std::fmt;
+
 use strum::{Display, EnumString};
 
 pub mod border;
@@ -147,11 +149,107 @@ pub enum Marker {
     /// Note: Support for this marker is limited to terminals and fonts that support Unicode
     /// Braille Patterns. If your terminal does not support this, you will see unicode replacement
     /// characters (`�`) instead of Braille dots (`⠓`, `⣇`, `⣿`).
+    ///
+    /// All the dots of a cell share a single foreground color, the color of the last point
+    /// painted in the cell.
     Braille,
     /// Use the unicode block and half block characters (`█`, `▄`, and `▀`) to represent points in
     /// a grid that is double the resolution of the terminal. Because each terminal cell is
     /// generally about twice as tall as it is wide, this allows for a square grid of pixels.
+    ///
+    /// Each of the two pixels of a cell has its own color, as the upper pixel is drawn with the
+    /// foreground color and the lower pixel with the background color.
     HalfBlock,
+    /// Use the unicode quadrant block characters (`▘`, `▚`, `▙`, ...) to represent points in a
+    /// grid of 2x2 pixels per cell.
+    ///
+    /// This is a middle ground between [`HalfBlock`](Marker::HalfBlock) and
+    /// [`Braille`](Marker::Braille): the pixels are solid blocks, but like with braille patterns
+    /// all the pixels of a cell share a single foreground color, the color of the last point
+    /// painted in the cell.
+    Quadrant,
+}
+
+/// A set of glyphs used to plot points, e.g. in a [`Canvas`] or a [`Chart`].
+///
+/// Each terminal cell is divided into a grid of sub positions given by
+/// [`resolution`](PointSymbol::resolution), and the glyph of a cell is chosen by
+/// [`symbol`](PointSymbol::symbol) from the position of the point within the cell. This makes it
+/// possible to use custom glyphs (e.g. Nerd Font icons) with a sub-cell resolution similar to the
+/// built-in [`Marker`]s.
+///
+/// When several points fall into the same cell, the last one painted decides the glyph. Only
+/// [`Marker::Braille`] combines the points of a cell into a single pattern.
+///
+/// # Example
+///
+/// ```
+/// use ratatui::symbols::PointSymbol;
+///
+/// /// Uses the left or right half of a cell depending on the position of the point
+/// #[derive(Debug)]
+/// struct HalfCircle;
+///
+/// impl PointSymbol for HalfCircle {
+///     fn resolution(&self) -> (u16, u16) {
+///         (2, 1)
+///     }
+///
+///     fn symbol(&self, sub_x: u16, _sub_y: u16) -> &str {
+///         if sub_x == 0 {
+///             "◖"
+///         } else {
+///             "◗"
+///         }
+///     }
+/// }
+/// ```
+///
+/// [`Canvas`]: crate::widgets::canvas::Canvas
+/// [`Chart`]: crate::widgets::Chart
+pub trait PointSymbol: fmt::Debug + Send + Sync {
+    /// Returns the number of sub positions of a cell, horizontally and vertically.
+    ///
+    /// Defaults to a single position per cell.
+    fn resolution(&self) -> (u16, u16) {
+        (1, 1)
+    }
+
+    /// Returns the glyph of a point at the given sub position of a cell.
+    ///
+    /// `sub_x` and `sub_y` are always lower than the [`resolution`](PointSymbol::resolution),
+    /// starting from the top left corner of the cell. Only the first grapheme of the returned
+    /// string is used.
+    fn symbol(&self, sub_x: u16, sub_y: u16) -> &str;
+}
+
+impl PointSymbol for Marker {
+    fn resolution(&self) -> (u16, u16) {
+        match self {
+            Self::Dot | Self::Block | Self::Bar => (1, 1),
+            Self::Braille => (2, 4),
+            Self::HalfBlock => (1, 2),
+            Self::Quadrant => (2, 2),
+        }
+    }
+
+    fn symbol(&self, sub_x: u16, sub_y: u16) -> &str {
+        const BRAILLE_DOTS: [[&str; 2]; 4] = [["⠁", "⠈"], ["⠂", "⠐"], ["⠄", "⠠"], ["⡀", "⢀"]];
+        match self {
+            Self::Dot => DOT,
+            Self::Block => block::FULL,
+            Self::Bar => bar::HALF,
+            Self::Braille => BRAILLE_DOTS[usize::from(sub_y.min(3))][usize::from(sub_x.min(1))],
+            Self::HalfBlock if sub_y == 0 => "▀",
+            Self::HalfBlock => "▄",
+            Self::Quadrant => match (sub_x, sub_y) {
+                (0, 0) => "▘",
+                (_, 0) => "▝",
+                (0, _) => "▖",
+                _ => "▗",
+            },
+        }
+    }
 }
 
 pub mod scrollbar {
@@ -225,4 +323,23 @@ mod tests {
         assert_eq!("Braille".parse::<Marker>(), Ok(Marker::Braille));
         assert_eq!("".parse::<Marker>(), Err(ParseError::VariantNotFound));
     }
+
+    #[test]
+    fn marker_point_symbol() {
+        assert_eq!(Marker::Dot.resolution(), (1, 1));
+        assert_eq!(Marker::Dot.symbol(0, 0), "•");
+        assert_eq!(Marker::Block.symbol(0, 0), "█");
+        assert_eq!(Marker::Bar.symbol(0, 0), "▄");
+        assert_eq!(Marker::Braille.resolution(), (2, 4));
+        assert_eq!(Marker::Braille.symbol(0, 0), "⠁");
+        assert_eq!(Marker::Braille.symbol(1, 3), "⢀");
+        assert_eq!(Marker::HalfBlock.resolution(), (1, 2));
+        assert_eq!(Marker::HalfBlock.symbol(0, 0), "▀");
+        assert_eq!(Marker::HalfBlock.symbol(0, 1), "▄");
+        assert_eq!(Marker::Quadrant.resolution(), (2, 2));
+        assert_eq!(Marker::Quadrant.symbol(0, 0), "▘");
+        assert_eq!(Marker::Quadrant.symbol(1, 0), "▝");
+        assert_eq!(Marker::Quadrant.symbol(0, 1), "▖");
+        assert_eq!(Marker::Quadrant.symbol(1, 1), "▗");
+    }
 }
@@ -94,6 +94,38 @@ pub const THICK: Set = Set {
     horizontal_bottom: line::THICK.horizontal,
 };
 
+/// Border Set with dashed lines and plain corners
+///
+/// ```text
+/// ┌┄┄┄┄┄┐
+/// ┆xxxxx┆
+/// ┆xxxxx┆
+/// └┄┄┄┄┄┘
+/// ```
+pub const DASHED: Set = Set {
+    vertical_left: line::DASHED_VERTICAL,
+    vertical_right: line::DASHED_VERTICAL,
+    horizontal_top: line::DASHED_HORIZONTAL,
+    horizontal_bottom: line::DASHED_HORIZONTAL,
+    ..PLAIN
+};
+
+/// Border Set with dotted lines and plain corners
+///
+/// ```text
+/// ┌┈┈┈┈┈┐
+/// ┊xxxxx┊
+/// ┊xxxxx┊
+/// └┈┈┈┈┈┘
+/// ```
+pub const DOTTED: Set = Set {
+    vertical_left: line::DOTTED_VERTICAL,
+    vertical_right: line::DOTTED_VERTICAL,
+    horizontal_top: line::DOTTED_HORIZONTAL,
+    horizontal_bottom: line::DOTTED_HORIZONTAL,
+    ..PLAIN
+};
+
 pub const QUADRANT_TOP_LEFT: &str = "▘";
 pub const QUADRANT_TOP_RIGHT: &str = "▝";
 pub const QUADRANT_BOTTOM_LEFT: &str = "▖";
@@ -1,10 +1,14 @@
 pub const VERTICAL: &str = "│";
 pub const DOUBLE_VERTICAL: &str = "║";
 pub const THICK_VERTICAL: &str = "┃";
+pub const DASHED_VERTICAL: &str = "┆";
+pub const DOTTED_VERTICAL: &str = "┊";
 
 pub const HORIZONTAL: &str = "─";
 pub const DOUBLE_HORIZONTAL: &str = "═";
 pub const THICK_HORIZONTAL: &str = "━";
+pub const DASHED_HORIZONTAL: &str = "┄";
+pub const DOTTED_HORIZONTAL: &str = "┈";
 
 pub const TOP_RIGHT: &str = "┐";
 pub const ROUNDED_TOP_RIGHT: &str = "╮";
@@ -117,9 +121,94 @@ pub const THICK: Set = Set {
     cross: THICK_CROSS,
 };
 
+impl Set {
+    /// Returns the symbols of the set with their arms, as whether they go up, down, left and right
+    const fn arms(&self) -> [(&'static str, [bool; 4]); 11] {
+        [
+            (self.vertical, [true, true, false, false]),
+            (self.horizontal, [false, false, true, true]),
+            (self.top_right, [false, true, true, false]),
+            (self.top_left, [false, true, false, true]),
+            (self.bottom_right, [true, false, true, false]),
+            (self.bottom_left, [true, false, false, true]),
+            (self.vertical_left, [true, true, true, false]),
+            (self.vertical_right, [true, true, false, true]),
+            (self.horizontal_down, [false, true, true, true]),
+            (self.horizontal_up, [true, false, true, true]),
+            (self.cross, [true, true, true, true]),
+        ]
+    }
+}
+
+/// Finds the set of a line symbol and its arms
+fn find_arms(symbol: &str) -> Option<(Set, [bool; 4])> {
+    [NORMAL, THICK, DOUBLE, ROUNDED]
+        .into_iter()
+        .find_map(|set| {
+            set.arms()
+                .into_iter()
+                .find(|(candidate, _)| *candidate == symbol)
+                .map(|(_, arms)| (set, arms))
+        })
+}
+
+/// Returns the symbol of the line sets which is equal to `symbol`, if any
+pub(crate) fn find(symbol: &str) -> Option<&'static str> {
+    [NORMAL, THICK, DOUBLE, ROUNDED]
+        .into_iter()
+        .flat_map(|set| set.arms())
+        .map(|(candidate, _)| candidate)
+        .find(|&candidate| candidate == symbol)
+}
+
+/// Merges a line symbol drawn over another one into the junction of both, e.g. `┐` drawn over
+/// `┌` gives `┬`.
+///
+/// The junction is taken from the set of the `new` symbol. Returns `None` when either symbol is
+/// not one of the symbols of the line sets, or when the `new` symbol already has all the arms of
+/// the `existing` one.
+pub(crate) fn merge(existing: &str, new: &str) -> Option<&'static str> {
+    let (_, existing_arms) = find_arms(existing)?;
+    let (set, new_arms) = find_arms(new)?;
+    let mut arms = new_arms;
+    for (arm, existing) in arms.iter_mut().zip(existing_arms) {
+        *arm |= existing;
+    }
+    if arms == new_arms {
+        return None;
+    }
+    set.arms()
+        .into_iter()
+        .find(|(_, candidate)| *candidate == arms)
+        .map(|(symbol, _)| symbol)
+}
+
+/// Rotates a line symbol a quarter turn clockwise, e.g. `─` gives `│` and `┌` gives `┐`.
+///
+/// Returns `None` when the symbol is not one of the symbols of the line sets.
+///
+/// # Example
+///
+/// ```
+/// use ratatui::symbols::line;
+///
+/// assert_eq!(line::rotate(line::HORIZONTAL), Some(line::VERTICAL));
+/// assert_eq!(line::rotate(line::THICK_TOP_LEFT), Some(line::THICK_TOP_RIGHT));
+/// assert_eq!(line::rotate("a"), None);
+/// ```
+pub fn rotate(symbol: &str) -> Option<&'static str> {
+    let (set, [up, down, left, right]) = find_arms(symbol)?;
+    let arms = [left, right, down, up];
+    set.arms()
+        .into_iter()
+        .find(|(_, candidate)| *candidate == arms)
+        .map(|(symbol, _)| symbol)
+}
+
 #[cfg(test)]
 mod tests {
     use indoc::{formatdoc, indoc};
+    use rstest::rstest;
 
     use super::*;
 
@@ -205,4 +294,35 @@ mod tests {
             )
         );
     }
+
+    #[rstest]
+    #[case::corners_side_by_side(TOP_RIGHT, TOP_LEFT, Some(HORIZONTAL_DOWN))]
+    #[case::bottom_corners(BOTTOM_RIGHT, BOTTOM_LEFT, Some(HORIZONTAL_UP))]
+    #[case::vertical_sides(VERTICAL, VERTICAL, None)]
+    #[case::tee(VERTICAL_LEFT, VERTICAL_RIGHT, Some(CROSS))]
+    #[case::corner_on_side(VERTICAL, TOP_LEFT, Some(VERTICAL_RIGHT))]
+    #[case::stacked_corners(BOTTOM_LEFT, TOP_LEFT, Some(VERTICAL_RIGHT))]
+    #[case::rounded_corners(ROUNDED_TOP_RIGHT, ROUNDED_TOP_LEFT, Some(HORIZONTAL_DOWN))]
+    #[case::new_weight_wins(TOP_RIGHT, THICK_TOP_LEFT, Some(THICK_HORIZONTAL_DOWN))]
+    #[case::double(DOUBLE_VERTICAL, DOUBLE_HORIZONTAL, Some(DOUBLE_CROSS))]
+    #[case::not_a_line("x", VERTICAL, None)]
+    #[case::new_not_a_line(VERTICAL, "x", None)]
+    fn merge(#[case] existing: &str, #[case] new: &str, #[case] expected: Option<&str>) {
+        assert_eq!(super::merge(existing, new), expected);
+    }
+
+    #[rstest]
+    #[case::horizontal(HORIZONTAL, Some(VERTICAL))]
+    #[case::vertical(VERTICAL, Some(HORIZONTAL))]
+    #[case::corner(TOP_LEFT, Some(TOP_RIGHT))]
+    #[case::last_corner(BOTTOM_LEFT, Some(TOP_LEFT))]
+    #[case::junction(VERTICAL_LEFT, Some(HORIZONTAL_UP))]
+    #[case::cross(CROSS, Some(CROSS))]
+    #[case::rounded(ROUNDED_TOP_LEFT, Some(ROUNDED_TOP_RIGHT))]
+    #[case::thick(THICK_HORIZONTAL_DOWN, Some(THICK_VERTICAL_LEFT))]
+    #[case::double(DOUBLE_VERTICAL, Some(DOUBLE_HORIZONTAL))]
+    #[case::not_a_line("x", None)]
+    fn rotate(#[case] symbol: &str, #[case] expected: Option<&str>) {
+        assert_eq!(super::rotate(symbol), expected);
+    }
 }
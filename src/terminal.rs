@@ -34,13 +34,15 @@
 mod frame;
 #[cfg(feature = "crossterm")]
 mod init;
+mod render_cache;
 mod terminal;
 mod viewport;
 
-pub use frame::{CompletedFrame, Frame};
+pub use frame::{CompletedFrame, Frame, FrameStats};
 #[cfg(feature = "crossterm")]
 pub use init::{
-    init, init_with_options, restore, try_init, try_init_with_options, try_restore, DefaultTerminal,
+    init, init_with_options, restore, set_panic_hook, try_init, try_init_with_options, try_restore,
+    DefaultTerminal,
 };
 pub use terminal::{Options as TerminalOptions, Terminal};
 pub use viewport::Viewport;
@@ -1,4 +1,7 @@
-use crate::prelude::*;
+use std::{hash::Hash, time::Duration};
+
+use super::render_cache::RenderCache;
+use crate::{accessibility::AccessNode, prelude::*};
 
 /// A consistent view into the terminal state for rendering a single frame.
 ///
@@ -26,6 +29,14 @@ pub struct Frame<'a> {
 
     /// The frame count indicating the sequence number of this frame.
     pub(crate) count: usize,
+
+    /// The accessibility nodes of the widgets rendered in this frame, or `None` if they are not
+    /// collected
+    pub(crate) accessibility_tree: Option<Vec<AccessNode>>,
+
+    /// The cells rendered with [`Frame::render_cached`] during the previous frames, if the frame
+    /// is drawn by a [`Terminal`]
+    pub(crate) render_cache: Option<&'a mut RenderCache>,
 }
 
 /// `CompletedFrame` represents the state of the terminal after all changes performed in the last
@@ -41,6 +52,25 @@ pub struct CompletedFrame<'a> {
     pub count: usize,
 }
 
+/// `FrameStats` holds statistics about a frame drawn with [`Terminal::draw_with_stats`].
+///
+/// These can be used to monitor the cost of rendering, e.g. to display the render time in a debug
+/// overlay or to find out which frames send many updates to the terminal.
+///
+/// [`Terminal::draw_with_stats`]: crate::Terminal::draw_with_stats
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct FrameStats {
+    /// The time spent in the render callback.
+    pub render_time: Duration,
+    /// The number of cells which changed since the previous frame and were sent to the backend.
+    pub cells_changed: usize,
+    /// The number of bytes written to the terminal for this frame, or `None` if the backend
+    /// doesn't keep track of it (see [`Backend::bytes_written`]).
+    ///
+    /// [`Backend::bytes_written`]: crate::backend::Backend::bytes_written
+    pub bytes_written: Option<u64>,
+}
+
 impl Frame<'_> {
     /// The area of the current frame
     ///
@@ -84,9 +114,98 @@ impl Frame<'_> {
     ///
     /// [`Layout`]: crate::layout::Layout
     pub fn render_widget<W: Widget>(&mut self, widget: W, area: Rect) {
+        if let Some(tree) = &mut self.accessibility_tree {
+            tree.extend(widget.accessibility_node(area));
+        }
         widget.render(area, self.buffer);
     }
 
+    /// Render a [`Widget`] to the current buffer, reusing the cells of the previous frame when its
+    /// key didn't change.
+    ///
+    /// The `widget` closure is only called, and the widget rendered, when nothing was rendered to
+    /// this `area` with an equal `key` during the previous frame. Otherwise, the cells rendered then
+    /// are copied to the buffer instead. This avoids the cost of building and rendering widgets
+    /// which display data that rarely changes, e.g. the static panes of a dashboard.
+    ///
+    /// The key is any value which changes whenever the widget needs to be rendered again, such as
+    /// a version number of the data the widget displays, or the data itself. Keys are compared
+    /// through their [`Hash`].
+    ///
+    /// The cells of the area are captured after the widget is rendered, including anything which
+    /// was rendered to the area before, so everything displayed in the area must be covered by
+    /// the key. Cached cells are only kept for the areas rendered during the last frame.
+    ///
+    /// When the frame isn't drawn by a [`Terminal`] (e.g. with [`Buffer::render_frame`]), the
+    /// widget is always rendered.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ratatui::{backend::TestBackend, prelude::*, widgets::Paragraph};
+    /// # let backend = TestBackend::new(20, 1);
+    /// # let mut terminal = Terminal::new(backend).unwrap();
+    /// let log = vec!["started".to_string()];
+    /// terminal.draw(|frame| {
+    ///     // the paragraph is only rendered again when the log changes
+    ///     frame.render_cached(&log, frame.area(), || Paragraph::new(log.join("\n")));
+    /// })?;
+    /// # std::io::Result::Ok(())
+    /// ```
+    pub fn render_cached<K, W, F>(&mut self, key: K, area: Rect, widget: F)
+    where
+        K: Hash,
+        W: Widget,
+        F: FnOnce() -> W,
+    {
+        let Some(cache) = self.render_cache.as_deref_mut() else {
+            self.render_widget(widget(), area);
+            return;
+        };
+        let key = RenderCache::hash_key(&key);
+        if cache.restore(area, key, self.buffer, self.accessibility_tree.as_mut()) {
+            return;
+        }
+        let widget = widget();
+        let mut node = None;
+        if let Some(tree) = &mut self.accessibility_tree {
+            node = widget.accessibility_node(area);
+            tree.extend(node.clone());
+        }
+        widget.render(area, self.buffer);
+        let collected = self.accessibility_tree.is_some();
+        cache.store(area, key, self.buffer, collected, node);
+    }
+
+    /// Render each widget into the area at the same position using [`Widget::render`].
+    ///
+    /// This is useful to render several widgets into the areas returned by a [`Layout`]. If there
+    /// are more widgets than areas, or more areas than widgets, the extra ones are ignored.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ratatui::{backend::TestBackend, prelude::*, widgets::Paragraph};
+    /// # let backend = TestBackend::new(5, 5);
+    /// # let mut terminal = Terminal::new(backend).unwrap();
+    /// # let mut frame = terminal.get_frame();
+    /// let areas = Layout::vertical([1, 1, 1]).split(frame.area());
+    /// let names = ["one", "two", "three"];
+    /// frame.render_widgets(names.map(Paragraph::new), areas.iter().copied());
+    /// ```
+    ///
+    /// [`Layout`]: crate::layout::Layout
+    pub fn render_widgets<I, A>(&mut self, widgets: I, areas: A)
+    where
+        I: IntoIterator,
+        I::Item: Widget,
+        A: IntoIterator<Item = Rect>,
+    {
+        for (widget, area) in widgets.into_iter().zip(areas) {
+            self.render_widget(widget, area);
+        }
+    }
+
     /// Render a [`WidgetRef`] to the current buffer using [`WidgetRef::render_ref`].
     ///
     /// Usually the area argument is the size of the current frame or a sub-area of the current
@@ -108,6 +227,9 @@ impl Frame<'_> {
     #[allow(clippy::needless_pass_by_value)]
     #[instability::unstable(feature = "widget-ref")]
     pub fn render_widget_ref<W: WidgetRef>(&mut self, widget: W, area: Rect) {
+        if let Some(tree) = &mut self.accessibility_tree {
+            tree.extend(widget.accessibility_node(area));
+        }
         widget.render_ref(area, self.buffer);
     }
 
@@ -137,6 +259,9 @@ impl Frame<'_> {
     where
         W: StatefulWidget,
     {
+        if let Some(tree) = &mut self.accessibility_tree {
+            tree.extend(widget.accessibility_node(area, state));
+        }
         widget.render(area, self.buffer, state);
     }
 
@@ -169,6 +294,9 @@ impl Frame<'_> {
     where
         W: StatefulWidgetRef,
     {
+        if let Some(tree) = &mut self.accessibility_tree {
+            tree.extend(widget.accessibility_node(area, state));
+        }
         widget.render_ref(area, self.buffer, state);
     }
 
@@ -225,4 +353,31 @@ impl Frame<'_> {
     pub const fn count(&self) -> usize {
         self.count
     }
+
+    /// Returns the accessibility nodes of the widgets rendered so far in this frame.
+    ///
+    /// Each widget rendered with one of the `render_*` methods of the frame contributes the node
+    /// returned by its [`Widget::accessibility_node`] method, in rendering order. The nodes of
+    /// widgets rendered directly into the [`buffer`](Frame::buffer_mut) are not collected. See
+    /// the [`accessibility`](crate::accessibility) module for more information.
+    ///
+    /// The nodes are only collected once enabled with [`Terminal::enable_accessibility`], so that
+    /// the widgets don't build them otherwise. The tree is empty until then.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{accessibility::Role, backend::TestBackend, prelude::*, widgets::*};
+    /// # let backend = TestBackend::new(5, 5);
+    /// # let mut terminal = Terminal::new(backend).unwrap();
+    /// terminal.enable_accessibility();
+    /// let mut frame = terminal.get_frame();
+    /// frame.render_widget(Paragraph::new("Hello"), frame.area());
+    /// let tree = frame.accessibility_tree();
+    /// assert_eq!(tree[0].role, Role::Paragraph);
+    /// assert_eq!(tree[0].text, "Hello");
+    /// ```
+    pub fn accessibility_tree(&self) -> &[AccessNode] {
+        self.accessibility_tree.as_deref().unwrap_or_default()
+    }
 }
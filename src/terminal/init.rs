@@ -1,7 +1,14 @@
-use std::io::{self, stdout, Stdout};
+use std::{
+    io::{self, stdout, Stdout, Write},
+    sync::atomic::{AtomicBool, Ordering},
+};
 
 use crossterm::{
-    execute,
+    event::{
+        DisableMouseCapture, EnableMouseCapture, KeyboardEnhancementFlags,
+        PopKeyboardEnhancementFlags, PushKeyboardEnhancementFlags,
+    },
+    execute, queue,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
 
@@ -15,6 +22,14 @@ use crate::{backend::CrosstermBackend, Terminal};
 /// use [`Terminal`] and a [backend][`crate::backend`] of your choice directly.
 pub type DefaultTerminal = Terminal<CrosstermBackend<Stdout>>;
 
+/// Whether mouse capture was enabled on [`Stdout`] with [`DefaultTerminal::enable_mouse_capture`],
+/// so that it is disabled by [`try_restore`]
+static MOUSE_CAPTURE_ENABLED: AtomicBool = AtomicBool::new(false);
+
+/// Whether the keyboard enhancement flags were pushed on [`Stdout`] with
+/// [`DefaultTerminal::enable_enhanced_keyboard`], so that they are popped by [`try_restore`]
+static ENHANCED_KEYBOARD_ENABLED: AtomicBool = AtomicBool::new(false);
+
 /// Initialize a terminal with reasonable defaults for most applications.
 ///
 /// This will create a new [`DefaultTerminal`] and initialize it with the following defaults:
@@ -183,9 +198,12 @@ pub fn try_init_with_options(options: TerminalOptions) -> io::Result<DefaultTerm
 /// following steps:
 ///
 /// 1. Raw mode is disabled.
-/// 2. The alternate screen buffer is left.
+/// 2. The enhanced keyboard reporting is disabled, if it was enabled with
+///    [`DefaultTerminal::enable_enhanced_keyboard`].
+/// 3. Mouse capture is disabled, if it was enabled with [`DefaultTerminal::enable_mouse_capture`].
+/// 4. The alternate screen buffer is left.
 ///
-/// If either of these steps fail, the error is printed to stderr and ignored.
+/// If any of these steps fail, the error is printed to stderr and ignored.
 ///
 /// Use this function over [`try_restore`] when you don't need to handle the error yourself, as
 /// ignoring the error is generally the correct behavior when cleaning up before exiting. If you
@@ -209,9 +227,12 @@ pub fn restore() {
 /// following steps:
 ///
 /// 1. Raw mode is disabled.
-/// 2. The alternate screen buffer is left.
+/// 2. The enhanced keyboard reporting is disabled, if it was enabled with
+///    [`DefaultTerminal::enable_enhanced_keyboard`].
+/// 3. Mouse capture is disabled, if it was enabled with [`DefaultTerminal::enable_mouse_capture`].
+/// 4. The alternate screen buffer is left.
 ///
-/// If either of these steps fail, the error is returned.
+/// If any of these steps fail, the error is returned.
 ///
 /// Use [`restore`] instead of this function when you don't need to handle the error yourself, as
 /// ignoring the error is generally the correct behavior when cleaning up before exiting. If you
@@ -227,18 +248,269 @@ pub fn try_restore() -> io::Result<()> {
     // disabling raw mode first is important as it has more side effects than leaving the alternate
     // screen buffer
     disable_raw_mode()?;
-    execute!(stdout(), LeaveAlternateScreen)?;
+    let mut stdout = stdout();
+    // the keyboard enhancement flags are kept for each screen, so they are popped before leaving
+    // the alternate screen buffer
+    if ENHANCED_KEYBOARD_ENABLED.swap(false, Ordering::SeqCst) {
+        queue!(stdout, PopKeyboardEnhancementFlags)?;
+    }
+    if MOUSE_CAPTURE_ENABLED.swap(false, Ordering::SeqCst) {
+        queue!(stdout, DisableMouseCapture)?;
+    }
+    execute!(stdout, LeaveAlternateScreen)?;
     Ok(())
 }
 
 /// Sets a panic hook that restores the terminal before panicking.
 ///
-/// Replaces the panic hook with a one that will restore the terminal state before calling the
-/// original panic hook. This ensures that the terminal is left in a good state when a panic occurs.
-fn set_panic_hook() {
+/// Replaces the panic hook with a one that will restore the terminal state with [`restore`] before
+/// calling the original panic hook. This ensures that the terminal is left in a good state when a
+/// panic occurs.
+///
+/// This hook is installed by [`init`] and the other initialization functions. Call it directly
+/// when setting up the terminal manually, e.g. with [`Terminal::new`]. Ensure that this function is
+/// called *after* your app installs any other panic hooks to ensure the terminal is restored before
+/// the other hooks are called.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use std::io::stdout;
+///
+/// use ratatui::{
+///     crossterm::{execute, terminal::EnterAlternateScreen},
+///     prelude::*,
+/// };
+///
+/// ratatui::set_panic_hook();
+/// ratatui::crossterm::terminal::enable_raw_mode()?;
+/// execute!(stdout(), EnterAlternateScreen)?;
+/// let terminal = Terminal::new(CrosstermBackend::new(stdout()))?;
+/// # std::io::Result::Ok(())
+/// ```
+pub fn set_panic_hook() {
     let hook = std::panic::take_hook();
     std::panic::set_hook(Box::new(move |info| {
         restore();
         hook(info);
     }));
 }
+
+impl DefaultTerminal {
+    /// Initializes a terminal with reasonable defaults for most applications.
+    ///
+    /// This is the same as [`init`]: raw mode is enabled, the alternate screen buffer is entered
+    /// and a panic hook which restores the terminal is installed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any of the initialization steps fail. See [`init`] for more details.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use ratatui::DefaultTerminal;
+    ///
+    /// let mut terminal = DefaultTerminal::init();
+    /// // draw frames and handle events
+    /// terminal.restore();
+    /// ```
+    pub fn init() -> Self {
+        init()
+    }
+
+    /// Tries to initialize a terminal with reasonable defaults for most applications.
+    ///
+    /// This is the same as [`try_init`]. If any of the initialization steps fail, the error is
+    /// returned.
+    pub fn try_init() -> io::Result<Self> {
+        try_init()
+    }
+
+    /// Restores the terminal to its original state, consuming it.
+    ///
+    /// The terminal is dropped, which shows the cursor if it was hidden, and [`restore`] is called
+    /// to disable raw mode, leave the alternate screen buffer and disable mouse capture and the
+    /// enhanced keyboard reporting if they were enabled with
+    /// [`DefaultTerminal::enable_mouse_capture`] and [`DefaultTerminal::enable_enhanced_keyboard`].
+    /// As the terminal writes to [`Stdout`], this is where [`restore`] writes too. Errors are
+    /// printed to stderr and ignored.
+    pub fn restore(self) {
+        drop(self);
+        restore();
+    }
+
+    /// Tries to restore the terminal to its original state, consuming it.
+    ///
+    /// This is the same as [`DefaultTerminal::restore`], except that the error of [`try_restore`]
+    /// is returned.
+    pub fn try_restore(self) -> io::Result<()> {
+        drop(self);
+        try_restore()
+    }
+
+    /// Enables mouse capture, so that mouse events are reported by
+    /// [`crossterm::event::read`](crossterm::event::read).
+    ///
+    /// Mouse capture is disabled by [`restore`], or can be disabled with
+    /// [`DefaultTerminal::disable_mouse_capture`]. As [`restore`] writes to [`Stdout`], this is
+    /// only available on a terminal writing to it. On other terminals, execute
+    /// [`EnableMouseCapture`] on the backend and disable it yourself.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// let mut terminal = ratatui::init();
+    /// terminal.enable_mouse_capture()?;
+    /// // draw frames and handle events
+    /// ratatui::restore();
+    /// # std::io::Result::Ok(())
+    /// ```
+    pub fn enable_mouse_capture(&mut self) -> io::Result<()> {
+        self.set_mouse_capture(true)?;
+        MOUSE_CAPTURE_ENABLED.store(true, Ordering::SeqCst);
+        Ok(())
+    }
+
+    /// Disables mouse capture.
+    pub fn disable_mouse_capture(&mut self) -> io::Result<()> {
+        self.set_mouse_capture(false)?;
+        MOUSE_CAPTURE_ENABLED.store(false, Ordering::SeqCst);
+        Ok(())
+    }
+
+    /// Enables the enhanced keyboard reporting of the [kitty keyboard protocol].
+    ///
+    /// This pushes the [`DISAMBIGUATE_ESCAPE_CODES`] and [`REPORT_ALTERNATE_KEYS`] flags, so that
+    /// the key events read with [`crossterm::event::read`](crossterm::event::read) carry richer
+    /// information: keys which are otherwise ambiguous (e.g. `Esc`, `Ctrl+I` and `Tab`, or
+    /// `Ctrl+Shift` combinations) are reported with their actual code and modifiers. Key release
+    /// and repeat events are not enabled. To push other flags, execute
+    /// [`PushKeyboardEnhancementFlags`] on the backend instead.
+    ///
+    /// Terminals which don't support the protocol ignore this. Use
+    /// [`crossterm::terminal::supports_keyboard_enhancement`] to check whether it is supported.
+    ///
+    /// The flags are popped by [`restore`], which is also called by the panic hook, or can be
+    /// popped with [`DefaultTerminal::disable_enhanced_keyboard`]. As [`restore`] writes to
+    /// [`Stdout`], this is only available on a terminal writing to it. This is not supported by the
+    /// legacy Windows API, where an error is returned.
+    ///
+    /// [kitty keyboard protocol]: https://sw.kovidgoyal.net/kitty/keyboard-protocol/
+    /// [`DISAMBIGUATE_ESCAPE_CODES`]: KeyboardEnhancementFlags::DISAMBIGUATE_ESCAPE_CODES
+    /// [`REPORT_ALTERNATE_KEYS`]: KeyboardEnhancementFlags::REPORT_ALTERNATE_KEYS
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// let mut terminal = ratatui::init();
+    /// terminal.enable_enhanced_keyboard()?;
+    /// // draw frames and handle events
+    /// ratatui::restore();
+    /// # std::io::Result::Ok(())
+    /// ```
+    pub fn enable_enhanced_keyboard(&mut self) -> io::Result<()> {
+        self.set_enhanced_keyboard(true)?;
+        ENHANCED_KEYBOARD_ENABLED.store(true, Ordering::SeqCst);
+        Ok(())
+    }
+
+    /// Disables the enhanced keyboard reporting enabled with
+    /// [`DefaultTerminal::enable_enhanced_keyboard`], by popping its flags.
+    pub fn disable_enhanced_keyboard(&mut self) -> io::Result<()> {
+        self.set_enhanced_keyboard(false)?;
+        ENHANCED_KEYBOARD_ENABLED.store(false, Ordering::SeqCst);
+        Ok(())
+    }
+}
+
+impl<W: Write> Terminal<CrosstermBackend<W>> {
+    /// Enters the alternate screen buffer and clears it.
+    ///
+    /// The terminal is cleared with [`Terminal::clear`] so that the next frame is fully drawn on
+    /// the alternate screen.
+    pub fn enter_alternate_screen(&mut self) -> io::Result<()> {
+        execute!(self.backend_mut(), EnterAlternateScreen)?;
+        self.clear()
+    }
+
+    /// Leaves the alternate screen buffer, returning to the main screen, and clears it.
+    ///
+    /// The terminal is cleared with [`Terminal::clear`] so that the next frame is fully drawn on
+    /// the main screen.
+    pub fn leave_alternate_screen(&mut self) -> io::Result<()> {
+        execute!(self.backend_mut(), LeaveAlternateScreen)?;
+        self.clear()
+    }
+
+    /// Enables or disables mouse capture, without recording it for [`restore`]
+    fn set_mouse_capture(&mut self, enabled: bool) -> io::Result<()> {
+        if enabled {
+            execute!(self.backend_mut(), EnableMouseCapture)
+        } else {
+            execute!(self.backend_mut(), DisableMouseCapture)
+        }
+    }
+
+    /// Pushes or pops the enhanced keyboard flags, without recording it for [`restore`]
+    fn set_enhanced_keyboard(&mut self, enabled: bool) -> io::Result<()> {
+        if enabled {
+            let flags = KeyboardEnhancementFlags::DISAMBIGUATE_ESCAPE_CODES
+                | KeyboardEnhancementFlags::REPORT_ALTERNATE_KEYS;
+            execute!(self.backend_mut(), PushKeyboardEnhancementFlags(flags))
+        } else {
+            execute!(self.backend_mut(), PopKeyboardEnhancementFlags)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{layout::Rect, Viewport};
+
+    fn terminal() -> Terminal<CrosstermBackend<Vec<u8>>> {
+        let viewport = Viewport::Fixed(Rect::new(0, 0, 4, 2));
+        let options = TerminalOptions { viewport };
+        Terminal::with_options(CrosstermBackend::new(Vec::new()), options).unwrap()
+    }
+
+    fn output(terminal: &Terminal<CrosstermBackend<Vec<u8>>>) -> String {
+        String::from_utf8(terminal.backend().writer().clone()).unwrap()
+    }
+
+    #[test]
+    fn alternate_screen() {
+        let mut terminal = terminal();
+        terminal.enter_alternate_screen().unwrap();
+        assert!(output(&terminal).starts_with("\x1b[?1049h"));
+
+        terminal.backend_mut().writer_mut().clear();
+        terminal.leave_alternate_screen().unwrap();
+        assert!(output(&terminal).starts_with("\x1b[?1049l"));
+    }
+
+    #[test]
+    #[cfg(not(windows))]
+    fn mouse_capture() {
+        let mut terminal = terminal();
+        terminal.set_mouse_capture(true).unwrap();
+        assert!(output(&terminal).contains("\x1b[?1000h"));
+
+        terminal.backend_mut().writer_mut().clear();
+        terminal.set_mouse_capture(false).unwrap();
+        assert!(output(&terminal).contains("\x1b[?1000l"));
+    }
+
+    #[test]
+    #[cfg(not(windows))]
+    fn enhanced_keyboard() {
+        let mut terminal = terminal();
+        terminal.set_enhanced_keyboard(true).unwrap();
+        assert_eq!(output(&terminal), "\x1b[>5u");
+
+        terminal.backend_mut().writer_mut().clear();
+        terminal.set_enhanced_keyboard(false).unwrap();
+        assert_eq!(output(&terminal), "\x1b[<1u");
+    }
+}
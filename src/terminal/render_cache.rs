@@ -0,0 +1,119 @@
+use std::{
+    collections::hash_map::DefaultHasher,
+    hash::{Hash, Hasher},
+};
+
+use crate::{accessibility::AccessNode, prelude::*, text::LineSize};
+
+/// The cells rendered by [`Frame::render_cached`], kept by the [`Terminal`] between frames.
+///
+/// Each entry is identified by the area it was rendered to. Entries which are not used during a
+/// frame are dropped at the end of the frame, so the cache only holds the areas which are still
+/// rendered.
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub(crate) struct RenderCache {
+    entries: Vec<CacheEntry>,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+struct CacheEntry {
+    /// The area the widget was rendered to
+    area: Rect,
+    /// The hash of the key the widget was rendered with
+    key: u64,
+    /// The cells of the area, clipped to the buffer, after the widget was rendered
+    cells: Buffer,
+    /// The accessibility node of the widget
+    accessibility_node: Option<AccessNode>,
+    /// Whether the accessibility tree was collected when the widget was rendered
+    accessibility_collected: bool,
+    /// Whether the entry was used during the current frame
+    used: bool,
+}
+
+impl RenderCache {
+    /// Hashes the key given to [`Frame::render_cached`]
+    pub(crate) fn hash_key<K: Hash>(key: &K) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        key.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    /// Copies the cells cached for the area and key to the buffer and adds the cached
+    /// accessibility node to the tree, if it is collected, or returns `false` if nothing was
+    /// cached for them.
+    ///
+    /// An entry cached while the accessibility tree was not collected is not reused when it is, as
+    /// it has no node to add.
+    pub(crate) fn restore(
+        &mut self,
+        area: Rect,
+        key: u64,
+        buf: &mut Buffer,
+        accessibility_tree: Option<&mut Vec<AccessNode>>,
+    ) -> bool {
+        let Some(entry) = self.entries.iter_mut().find(|entry| {
+            entry.area == area
+                && entry.key == key
+                && (accessibility_tree.is_none() || entry.accessibility_collected)
+        }) else {
+            return false;
+        };
+        let cells = entry.cells.area.intersection(buf.area);
+        for y in cells.top()..cells.bottom() {
+            for x in cells.left()..cells.right() {
+                buf[(x, y)] = entry.cells[(x, y)].clone();
+            }
+            // the rows of the buffer are reset to the normal size before each frame
+            let line_size = entry.cells.line_size(y);
+            if line_size != LineSize::Normal {
+                buf.set_line_size(y, line_size);
+            }
+        }
+        entry.used = true;
+        if let Some(tree) = accessibility_tree {
+            tree.extend(entry.accessibility_node.clone());
+        }
+        true
+    }
+
+    /// Caches the cells of the area, which the widget was just rendered to, with the given key
+    /// and the accessibility node of the widget, if the accessibility tree was collected
+    ///
+    /// This replaces any entry cached for the area.
+    pub(crate) fn store(
+        &mut self,
+        area: Rect,
+        key: u64,
+        buf: &Buffer,
+        accessibility_collected: bool,
+        accessibility_node: Option<AccessNode>,
+    ) {
+        let clipped = area.intersection(buf.area);
+        let mut cells = Buffer::empty(clipped);
+        for y in clipped.top()..clipped.bottom() {
+            for x in clipped.left()..clipped.right() {
+                cells[(x, y)] = buf[(x, y)].clone();
+            }
+            cells.set_line_size(y, buf.line_size(y));
+        }
+        self.entries.retain(|entry| entry.area != area);
+        self.entries.push(CacheEntry {
+            area,
+            key,
+            cells,
+            accessibility_node,
+            accessibility_collected,
+            used: true,
+        });
+    }
+
+    /// Drops the entries which were not used during the frame and prepares the others for the
+    /// next frame
+    pub(crate) fn end_frame(&mut self) {
+        self.entries.retain(|entry| entry.used);
+        for entry in &mut self.entries {
+            entry.used = false;
+        }
+    }
+}
@@ -1,7 +1,9 @@
-use std::io;
+use std::{io, time::Instant};
 
+use super::render_cache::RenderCache;
 use crate::{
-    backend::ClearType, buffer::Cell, prelude::*, CompletedFrame, TerminalOptions, Viewport,
+    backend::ClearType, buffer::Cell, prelude::*, CompletedFrame, FrameStats, TerminalOptions,
+    Viewport,
 };
 
 /// An interface to interact and draw [`Frame`]s on the user's terminal.
@@ -75,6 +77,10 @@ where
     last_known_cursor_pos: Position,
     /// Number of frames rendered up until current time.
     frame_count: usize,
+    /// The cells rendered with [`Frame::render_cached`] during the previous frames
+    render_cache: RenderCache,
+    /// Whether the accessibility tree of the frames is collected
+    accessibility: bool,
 }
 
 /// Options to pass to [`Terminal::with_options`]
@@ -158,6 +164,8 @@ where
             last_known_area: area,
             last_known_cursor_pos: cursor_pos,
             frame_count: 0,
+            render_cache: RenderCache::default(),
+            accessibility: false,
         })
     }
 
@@ -167,16 +175,46 @@ where
         Frame {
             cursor_position: None,
             viewport_area: self.viewport_area,
-            buffer: self.current_buffer_mut(),
+            buffer: &mut self.buffers[self.current],
             count,
+            accessibility_tree: self.accessibility.then(Vec::new),
+            render_cache: Some(&mut self.render_cache),
         }
     }
 
+    /// Gets the current buffer.
+    ///
+    /// The current buffer is the one the next frame is drawn into. It is reset after each call to
+    /// [`Terminal::draw`], use [`Terminal::displayed_buffer`] to get the content of the screen.
+    pub const fn current_buffer(&self) -> &Buffer {
+        &self.buffers[self.current]
+    }
+
     /// Gets the current buffer as a mutable reference.
     pub fn current_buffer_mut(&mut self) -> &mut Buffer {
         &mut self.buffers[self.current]
     }
 
+    /// Gets the buffer which was last flushed to the backend, i.e. the content of the screen.
+    ///
+    /// This is the buffer of the last frame drawn with [`Terminal::draw`]. It can be turned into
+    /// [`Text`](crate::text::Text) with [`Buffer::to_text`], e.g. to copy the screen to the
+    /// clipboard or to search its content.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ratatui::{backend::TestBackend, prelude::*};
+    /// let mut terminal = Terminal::new(TestBackend::new(10, 2))?;
+    /// terminal.draw(|frame| frame.render_widget("Hello", frame.area()))?;
+    /// let screen = terminal.displayed_buffer().to_text().to_string();
+    /// assert_eq!(screen, "Hello\n");
+    /// # std::io::Result::Ok(())
+    /// ```
+    pub const fn displayed_buffer(&self) -> &Buffer {
+        &self.buffers[1 - self.current]
+    }
+
     /// Gets the backend
     pub const fn backend(&self) -> &B {
         &self.backend
@@ -190,19 +228,39 @@ where
     /// Obtains a difference between the previous and the current buffer and passes it to the
     /// current backend for drawing.
     pub fn flush(&mut self) -> io::Result<()> {
+        self.flush_updates().map(|_| ())
+    }
+
+    /// Flushes the difference between the previous and the current buffer to the backend and
+    /// returns the number of cells which were drawn.
+    fn flush_updates(&mut self) -> io::Result<usize> {
         let previous_buffer = &self.buffers[1 - self.current];
         let current_buffer = &self.buffers[self.current];
+        let area = current_buffer.area;
+        for y in area.top()..area.bottom() {
+            let size = current_buffer.line_size(y);
+            if size != previous_buffer.line_size(y) {
+                self.backend.set_line_size(y, size)?;
+            }
+        }
         let updates = previous_buffer.diff(current_buffer);
         if let Some((col, row, _)) = updates.last() {
             self.last_known_cursor_pos = Position { x: *col, y: *row };
         }
-        self.backend.draw(updates.into_iter())
+        let cells_changed = updates.len();
+        self.backend.draw(updates.into_iter())?;
+        Ok(cells_changed)
     }
 
     /// Updates the Terminal so that internal buffers match the requested area.
     ///
     /// Requested area will be saved to remain consistent when rendering. This leads to a full clear
-    /// of the screen.
+    /// of the screen (or of the viewport) and of both internal buffers, so the next
+    /// [`draw`](Terminal::draw) redraws every cell and no stale content is left on the screen,
+    /// whether the terminal shrinks or grows.
+    ///
+    /// Applications usually don't need to call this directly, as [`draw`](Terminal::draw) calls
+    /// [`autoresize`](Terminal::autoresize) before rendering each frame.
     pub fn resize(&mut self, area: Rect) -> io::Result<()> {
         let next_area = match self.viewport {
             Viewport::Inline(height) => {
@@ -221,6 +279,9 @@ where
             Viewport::Fixed(_) | Viewport::Fullscreen => area,
         };
         self.set_viewport_area(next_area);
+        // the content of the current buffer doesn't match the new area, so it is discarded along
+        // with the back buffer, which is reset by `clear`
+        self.buffers[self.current].reset();
         self.clear()?;
 
         self.last_known_area = area;
@@ -234,6 +295,14 @@ where
     }
 
     /// Queries the backend for size and resizes if it doesn't match the previous size.
+    ///
+    /// This is called by [`draw`](Terminal::draw) and [`try_draw`](Terminal::try_draw) before
+    /// each frame is rendered, so applications don't need to handle resize events themselves to
+    /// keep the output consistent. It can be called beforehand to know the size of the next frame.
+    ///
+    /// When the size changed, the terminal is [resized](Terminal::resize), which clears the screen
+    /// and both internal buffers. Nothing happens if the size didn't change or if the viewport is
+    /// [fixed](Viewport::Fixed).
     pub fn autoresize(&mut self) -> io::Result<()> {
         // fixed viewports do not get autoresized
         if matches!(self.viewport, Viewport::Fullscreen | Viewport::Inline(_)) {
@@ -372,6 +441,59 @@ where
     /// # io::Result::Ok(())
     /// ```
     pub fn try_draw<F, E>(&mut self, render_callback: F) -> io::Result<CompletedFrame>
+    where
+        F: FnOnce(&mut Frame) -> Result<(), E>,
+        E: Into<io::Error>,
+    {
+        self.draw_frame(render_callback, None)
+    }
+
+    /// Draws a single frame to the terminal and returns statistics about it.
+    ///
+    /// This is the equivalent of [`Terminal::draw`] but also returns a [`FrameStats`] containing
+    /// the time spent in the render callback, the number of cells which changed since the previous
+    /// frame and the number of bytes written to the terminal, if the backend keeps track of it
+    /// (see [`Backend::bytes_written`]).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ratatui::{prelude::*, backend::TestBackend, widgets::Paragraph};
+    /// # let backend = TestBackend::new(10, 10);
+    /// # let mut terminal = Terminal::new(backend)?;
+    /// let (_, stats) = terminal.draw_with_stats(|frame| {
+    ///     frame.render_widget(Paragraph::new("Hello"), frame.area());
+    /// })?;
+    /// assert_eq!(stats.cells_changed, 5);
+    /// # std::io::Result::Ok(())
+    /// ```
+    pub fn draw_with_stats<F>(
+        &mut self,
+        render_callback: F,
+    ) -> io::Result<(CompletedFrame<'_>, FrameStats)>
+    where
+        F: FnOnce(&mut Frame),
+    {
+        let mut stats = FrameStats::default();
+        let completed_frame = self.draw_frame(
+            |frame| {
+                render_callback(frame);
+                io::Result::Ok(())
+            },
+            Some(&mut stats),
+        )?;
+        Ok((completed_frame, stats))
+    }
+
+    /// Draws a single frame, see [`Terminal::try_draw`].
+    ///
+    /// The statistics of the frame are only measured when `stats` is given, so that drawing
+    /// without them doesn't read the clock.
+    fn draw_frame<F, E>(
+        &mut self,
+        render_callback: F,
+        mut stats: Option<&mut FrameStats>,
+    ) -> io::Result<CompletedFrame<'_>>
     where
         F: FnOnce(&mut Frame) -> Result<(), E>,
         E: Into<io::Error>,
@@ -380,17 +502,31 @@ where
         // and the terminal (if growing), which may OOB.
         self.autoresize()?;
 
+        let bytes_written_before = stats
+            .is_some()
+            .then(|| self.backend.bytes_written())
+            .flatten();
+
         let mut frame = self.get_frame();
 
-        render_callback(&mut frame).map_err(Into::into)?;
+        let render_start = stats.is_some().then(Instant::now);
+        let rendered = render_callback(&mut frame).map_err(Into::into);
+        if let Some((stats, render_start)) = stats.as_deref_mut().zip(render_start) {
+            stats.render_time = render_start.elapsed();
+        }
 
         // We can't change the cursor position right away because we have to flush the frame to
         // stdout first. But we also can't keep the frame around, since it holds a &mut to
         // Buffer. Thus, we're taking the important data out of the Frame and dropping it.
         let cursor_position = frame.cursor_position;
 
+        // Drop the cached renders which were not used during this frame, even if the render
+        // callback failed, so that they don't outlive another frame
+        self.render_cache.end_frame();
+        rendered?;
+
         // Draw to stdout
-        self.flush()?;
+        let cells_changed = self.flush_updates()?;
 
         match cursor_position {
             None => self.hide_cursor()?,
@@ -405,6 +541,15 @@ where
         // Flush
         self.backend.flush()?;
 
+        if let Some(stats) = stats {
+            stats.cells_changed = cells_changed;
+            stats.bytes_written = self
+                .backend
+                .bytes_written()
+                .zip(bytes_written_before)
+                .map(|(after, before)| after.wrapping_sub(before));
+        }
+
         let completed_frame = CompletedFrame {
             buffer: &self.buffers[1 - self.current],
             area: self.last_known_area,
@@ -417,6 +562,21 @@ where
         Ok(completed_frame)
     }
 
+    /// Enables the collection of the accessibility tree of the frames.
+    ///
+    /// Once enabled, the widgets rendered with the `render_*` methods of [`Frame`] describe
+    /// themselves, and the nodes are available from [`Frame::accessibility_tree`]. Building these
+    /// nodes has a cost, so they are not collected by default.
+    pub fn enable_accessibility(&mut self) {
+        self.accessibility = true;
+    }
+
+    /// Disables the collection of the accessibility tree enabled with
+    /// [`Terminal::enable_accessibility`].
+    pub fn disable_accessibility(&mut self) {
+        self.accessibility = false;
+    }
+
     /// Hides the cursor.
     pub fn hide_cursor(&mut self) -> io::Result<()> {
         self.backend.hide_cursor()?;
@@ -44,12 +44,21 @@
 //! ]);
 //! ```
 
+mod cursor;
+pub use cursor::{grapheme_width, next_grapheme_boundary, prev_grapheme_boundary};
+
+mod duration;
+pub use duration::format_duration;
+
 mod grapheme;
 pub use grapheme::StyledGrapheme;
 
 mod line;
 pub use line::{Line, ToLine};
 
+mod line_size;
+pub use line_size::LineSize;
+
 mod masked;
 pub use masked::Masked;
 
@@ -0,0 +1,141 @@
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+/// Returns the byte index of the grapheme boundary following `index` in `s`.
+///
+/// This is the index a text cursor at `index` moves to when it is moved one character to the
+/// right. Graphemes are extended grapheme clusters, so that emoji sequences and letters with
+/// combining marks are moved over as a single character. When `index` is inside a grapheme, the end
+/// of that grapheme is returned. At or past the end of `s`, the length of `s` is returned.
+///
+/// # Examples
+///
+/// ```rust
+/// use ratatui::text::next_grapheme_boundary;
+///
+/// // "e" followed by a combining acute accent
+/// let s = "ae\u{301}b";
+/// assert_eq!(next_grapheme_boundary(s, 0), 1);
+/// assert_eq!(next_grapheme_boundary(s, 1), 4);
+/// assert_eq!(next_grapheme_boundary(s, 4), 5);
+/// assert_eq!(next_grapheme_boundary(s, 5), 5);
+/// ```
+pub fn next_grapheme_boundary(s: &str, index: usize) -> usize {
+    s.grapheme_indices(true)
+        .map(|(start, grapheme)| start + grapheme.len())
+        .find(|&end| end > index)
+        .unwrap_or(s.len())
+}
+
+/// Returns the byte index of the grapheme boundary preceding `index` in `s`.
+///
+/// This is the index a text cursor at `index` moves to when it is moved one character to the
+/// left. Graphemes are extended grapheme clusters, so that emoji sequences and letters with
+/// combining marks are moved over as a single character. When `index` is inside a grapheme, the
+/// start of that grapheme is returned. At the start of `s`, 0 is returned.
+///
+/// # Examples
+///
+/// ```rust
+/// use ratatui::text::prev_grapheme_boundary;
+///
+/// // "e" followed by a combining acute accent
+/// let s = "ae\u{301}b";
+/// assert_eq!(prev_grapheme_boundary(s, 5), 4);
+/// assert_eq!(prev_grapheme_boundary(s, 4), 1);
+/// assert_eq!(prev_grapheme_boundary(s, 1), 0);
+/// assert_eq!(prev_grapheme_boundary(s, 0), 0);
+/// ```
+pub fn prev_grapheme_boundary(s: &str, index: usize) -> usize {
+    s.grapheme_indices(true)
+        .map(|(start, _)| start)
+        .take_while(|&start| start < index)
+        .last()
+        .unwrap_or(0)
+}
+
+/// Returns the number of columns a grapheme occupies when it is displayed in the terminal.
+///
+/// This is the width used by the [`Buffer`](crate::buffer::Buffer) to lay out the graphemes of the
+/// text, e.g. 1 for a letter with combining marks and 2 for wide characters such as CJK
+/// characters and most emoji.
+///
+/// # Examples
+///
+/// ```rust
+/// use ratatui::text::grapheme_width;
+///
+/// assert_eq!(grapheme_width("a"), 1);
+/// assert_eq!(grapheme_width("e\u{301}"), 1);
+/// assert_eq!(grapheme_width("你"), 2);
+/// ```
+pub fn grapheme_width(grapheme: &str) -> usize {
+    grapheme.width()
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    // a family emoji made of 3 emoji joined with zero width joiners
+    const FAMILY: &str = "👨\u{200d}👩\u{200d}👧";
+    // "é" made of an "e" followed by a combining acute accent
+    const ACCENTED: &str = "e\u{301}";
+
+    #[rstest]
+    #[case::ascii("abc", 0, 1)]
+    #[case::end("abc", 3, 3)]
+    #[case::past_end("abc", 10, 3)]
+    #[case::empty("", 0, 0)]
+    #[case::combining_accent("ae\u{301}b", 1, 4)]
+    #[case::inside_combining_accent("ae\u{301}b", 2, 4)]
+    #[case::zwj_emoji("a👨\u{200d}👩\u{200d}👧b", 1, 19)]
+    #[case::inside_zwj_emoji("a👨\u{200d}👩\u{200d}👧b", 5, 19)]
+    #[case::wide("你好", 0, 3)]
+    fn next_boundary(#[case] s: &str, #[case] index: usize, #[case] expected: usize) {
+        assert_eq!(next_grapheme_boundary(s, index), expected);
+    }
+
+    #[rstest]
+    #[case::ascii("abc", 3, 2)]
+    #[case::start("abc", 0, 0)]
+    #[case::past_end("abc", 10, 2)]
+    #[case::empty("", 0, 0)]
+    #[case::combining_accent("ae\u{301}b", 4, 1)]
+    #[case::inside_combining_accent("ae\u{301}b", 2, 1)]
+    #[case::zwj_emoji("a👨\u{200d}👩\u{200d}👧b", 19, 1)]
+    #[case::inside_zwj_emoji("a👨\u{200d}👩\u{200d}👧b", 5, 1)]
+    #[case::wide("你好", 6, 3)]
+    fn prev_boundary(#[case] s: &str, #[case] index: usize, #[case] expected: usize) {
+        assert_eq!(prev_grapheme_boundary(s, index), expected);
+    }
+
+    #[test]
+    fn moves_over_whole_graphemes() {
+        let s = format!("{ACCENTED}{FAMILY}x");
+        let forward: Vec<_> = std::iter::successors(Some(0), |&index| {
+            Some(next_grapheme_boundary(&s, index)).filter(|&next| next != index)
+        })
+        .collect();
+        assert_eq!(forward, [0, 3, 21, 22]);
+
+        let backward: Vec<_> = std::iter::successors(Some(s.len()), |&index| {
+            Some(prev_grapheme_boundary(&s, index)).filter(|&prev| prev != index)
+        })
+        .collect();
+        assert_eq!(backward, [22, 21, 3, 0]);
+    }
+
+    #[rstest]
+    #[case::ascii("a", 1)]
+    #[case::combining_accent(ACCENTED, 1)]
+    #[case::wide("你", 2)]
+    #[case::emoji("👍", 2)]
+    #[case::zwj_emoji(FAMILY, 2)]
+    #[case::empty("", 0)]
+    fn width(#[case] grapheme: &str, #[case] expected: usize) {
+        assert_eq!(grapheme_width(grapheme), expected);
+    }
+}
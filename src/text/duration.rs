@@ -0,0 +1,65 @@
+use std::time::Duration;
+
+/// Formats a [`Duration`] as a compact human readable string.
+///
+/// The duration is truncated to whole seconds and formatted with the two most significant units:
+///
+/// - less than a minute: seconds, e.g. `0s` or `42s`
+/// - less than an hour: minutes and seconds, e.g. `2m 13s`
+/// - an hour or more: hours and minutes, e.g. `1h 03m`
+///
+/// This is useful to display the elapsed or remaining time of a long operation, e.g. in the label
+/// of a [`Gauge`].
+///
+/// # Examples
+///
+/// ```rust
+/// use std::time::Duration;
+///
+/// use ratatui::{prelude::*, text::format_duration, widgets::Gauge};
+///
+/// assert_eq!(format_duration(Duration::from_millis(800)), "0s");
+/// assert_eq!(format_duration(Duration::from_secs(133)), "2m 13s");
+/// assert_eq!(format_duration(Duration::from_secs(3780)), "1h 03m");
+///
+/// let remaining = Duration::from_secs(133);
+/// let gauge = Gauge::default()
+///     .ratio(0.4)
+///     .label(format!("ETA {}", format_duration(remaining)));
+/// ```
+///
+/// [`Gauge`]: crate::widgets::Gauge
+pub fn format_duration(duration: Duration) -> String {
+    let seconds = duration.as_secs();
+    let (hours, minutes, seconds) = (seconds / 3600, seconds / 60 % 60, seconds % 60);
+    if hours > 0 {
+        format!("{hours}h {minutes:02}m")
+    } else if minutes > 0 {
+        format!("{minutes}m {seconds:02}s")
+    } else {
+        format!("{seconds}s")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case::zero(Duration::ZERO, "0s")]
+    #[case::sub_second(Duration::from_millis(999), "0s")]
+    #[case::seconds(Duration::from_secs(42), "42s")]
+    #[case::truncates_fraction(Duration::from_millis(59_900), "59s")]
+    #[case::minute(Duration::from_secs(60), "1m 00s")]
+    #[case::minutes(Duration::from_secs(133), "2m 13s")]
+    #[case::minutes_pads_seconds(Duration::from_secs(605), "10m 05s")]
+    #[case::hour(Duration::from_secs(3600), "1h 00m")]
+    #[case::hours_pads_minutes(Duration::from_secs(3780), "1h 03m")]
+    #[case::hours_drops_seconds(Duration::from_secs(3 * 3600 + 25 * 60 + 59), "3h 25m")]
+    #[case::many_hours(Duration::from_secs(100 * 3600), "100h 00m")]
+    fn format(#[case] duration: Duration, #[case] expected: &str) {
+        assert_eq!(format_duration(duration), expected);
+    }
+}
@@ -1,10 +1,14 @@
 #![deny(missing_docs)]
 #![warn(clippy::pedantic, clippy::nursery, clippy::arithmetic_side_effects)]
-use std::{borrow::Cow, fmt};
+use std::{borrow::Cow, fmt, ops::Range};
 
 use unicode_truncate::UnicodeTruncateStr;
 
-use crate::{prelude::*, style::Styled, text::StyledGrapheme};
+use crate::{
+    prelude::*,
+    style::Styled,
+    text::{LineSize, StyledGrapheme},
+};
 
 /// A line of text, consisting of one or more [`Span`]s.
 ///
@@ -159,6 +163,9 @@ pub struct Line<'a> {
 
     /// The alignment of this line of text.
     pub alignment: Option<Alignment>,
+
+    /// The size at which the terminal displays this line of text.
+    pub size: LineSize,
 }
 
 fn cow_to_spans<'a>(content: impl Into<Cow<'a, str>>) -> Vec<Span<'a>> {
@@ -168,6 +175,15 @@ fn cow_to_spans<'a>(content: impl Into<Cow<'a, str>>) -> Vec<Span<'a>> {
     }
 }
 
+/// Returns the given byte range of the content, borrowing from the original if possible
+#[allow(clippy::string_slice)] // Is safe as the ranges come from matches in the content
+fn slice_cow<'a>(content: &Cow<'a, str>, range: Range<usize>) -> Cow<'a, str> {
+    match content {
+        Cow::Borrowed(content) => Cow::Borrowed(&content[range]),
+        Cow::Owned(content) => Cow::Owned(content[range].to_string()),
+    }
+}
+
 impl<'a> Line<'a> {
     /// Create a line with the default style.
     ///
@@ -299,6 +315,29 @@ impl<'a> Line<'a> {
         }
     }
 
+    /// Sets the size at which the terminal displays this line of text.
+    ///
+    /// Defaults to [`LineSize::Normal`]. Other sizes display every character at twice its normal
+    /// width (and height), using the DEC line attributes supported by most terminals. The size
+    /// applies to the whole row of the terminal the line is rendered on, and is ignored by
+    /// backends that do not support it. See [`LineSize`] for more information.
+    ///
+    /// When rendered, the line is laid out in the columns of its area that remain visible once
+    /// each character takes up two columns.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::{prelude::*, text::LineSize};
+    ///
+    /// let line = Line::from("Dashboard").size(LineSize::DoubleWidth);
+    /// assert_eq!(line.size, LineSize::DoubleWidth);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn size(self, size: LineSize) -> Self {
+        Self { size, ..self }
+    }
+
     /// Left-aligns this line of text.
     ///
     /// Convenience shortcut for `Line::alignment(Alignment::Left)`.
@@ -448,6 +487,86 @@ impl<'a> Line<'a> {
         self.patch_style(Style::reset())
     }
 
+    /// Highlights all the occurrences of `pattern` in this line.
+    ///
+    /// The spans are split at the boundaries of the matches, and `style` is patched onto the style
+    /// of the parts of the spans that match. A match can span multiple spans, in which case each
+    /// part keeps its original style with `style` patched onto it. The parts that don't match keep
+    /// their original style. Matches don't overlap and an empty pattern doesn't match anything.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::prelude::*;
+    /// let line = Line::from(vec!["error: ".red(), "file not found".into()]);
+    /// let line = line.highlight("r: f", Style::new().on_yellow());
+    /// assert_eq!(
+    ///     line.spans,
+    ///     [
+    ///         "erro".red(),
+    ///         "r: ".red().on_yellow(),
+    ///         "f".on_yellow(),
+    ///         "ile not found".into(),
+    ///     ]
+    /// );
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn highlight<S: Into<Style>>(mut self, pattern: &str, style: S) -> Self {
+        if pattern.is_empty() {
+            return self;
+        }
+        let content: String = self
+            .spans
+            .iter()
+            .map(|span| span.content.as_ref())
+            .collect();
+        let mut matches = content
+            .match_indices(pattern)
+            .map(|(start, matched)| start..start.saturating_add(matched.len()))
+            .peekable();
+        if matches.peek().is_none() {
+            return self;
+        }
+        let style = style.into();
+        let mut spans = Vec::with_capacity(self.spans.len());
+        let mut span_start = 0_usize;
+        for span in self.spans {
+            let span_end = span_start.saturating_add(span.content.len());
+            let mut start = span_start;
+            while start < span_end {
+                while matches.next_if(|range| range.end <= start).is_some() {}
+                let (end, is_match) = match matches.peek() {
+                    Some(range) if range.start <= start => (range.end.min(span_end), true),
+                    Some(range) => (range.start.min(span_end), false),
+                    None => (span_end, false),
+                };
+                let range = start.saturating_sub(span_start)..end.saturating_sub(span_start);
+                let part_style = if is_match {
+                    span.style.patch(style)
+                } else {
+                    span.style
+                };
+                spans.push(Span {
+                    content: slice_cow(&span.content, range),
+                    style: part_style,
+                    hyperlink: span.hyperlink.clone(),
+                });
+                start = end;
+            }
+            if span.content.is_empty() {
+                spans.push(span);
+            }
+            span_start = span_end;
+        }
+        self.spans = spans;
+        self
+    }
+
     /// Returns an iterator over the spans of this line.
     pub fn iter(&self) -> std::slice::Iter<Span<'a>> {
         self.spans.iter()
@@ -592,6 +711,19 @@ impl WidgetRef for Line<'_> {
             return;
         }
         let area = Rect { height: 1, ..area };
+        let area = if self.size == LineSize::Normal {
+            area
+        } else {
+            buf.set_line_size(area.y, self.size);
+            // each cell is displayed over two columns, counted from the left of the screen
+            let left = area.left().div_ceil(2);
+            let right = area.right() / 2;
+            Rect {
+                x: left,
+                width: right.saturating_sub(left),
+                ..area
+            }
+        };
         let line_width = self.width();
         if line_width == 0 {
             return;
@@ -674,7 +806,11 @@ fn spans_after_width<'a>(
             let first_grapheme_offset = available_width.saturating_sub(actual_width);
             let first_grapheme_offset = u16::try_from(first_grapheme_offset).unwrap_or(u16::MAX);
             (
-                Span::styled(content, span.style),
+                Span {
+                    content: content.into(),
+                    style: span.style,
+                    hyperlink: span.hyperlink.clone(),
+                },
                 actual_width,
                 first_grapheme_offset,
             )
@@ -847,6 +983,83 @@ mod tests {
         assert_eq!(Style::reset(), line.style);
     }
 
+    #[rstest]
+    #[case::single_span(Line::from("a cat and a dog"), "a", Line::from(vec![
+        "a".yellow(),
+        " c".into(),
+        "a".yellow(),
+        "t ".into(),
+        "a".yellow(),
+        "nd ".into(),
+        "a".yellow(),
+        " dog".into(),
+    ]))]
+    #[case::whole_line(Line::from("cat"), "cat", Line::from("cat".yellow()))]
+    #[case::span_boundary(Line::from(vec!["cat".red(), "dog".bold()]), "dog", Line::from(vec![
+        "cat".red(),
+        "dog".bold().yellow(),
+    ]))]
+    #[case::across_spans(Line::from(vec!["ca".red(), "td".bold(), "og".italic()]), "tdo", Line::from(vec![
+        "ca".red(),
+        "td".bold().yellow(),
+        "o".italic().yellow(),
+        "g".italic(),
+    ]))]
+    #[case::non_overlapping(Line::from("aaa"), "aa", Line::from(vec!["aa".yellow(), "a".into()]))]
+    #[case::multi_byte(Line::from("héllo wörld"), "ö", Line::from(vec![
+        "héllo w".into(),
+        "ö".yellow(),
+        "rld".into(),
+    ]))]
+    #[case::no_match(Line::from(vec!["cat".red(), "dog".into()]), "cow", Line::from(vec!["cat".red(), "dog".into()]))]
+    #[case::empty_pattern(Line::from("cat"), "", Line::from("cat"))]
+    #[case::empty_span(Line::from(vec!["".red(), "cat".into()]), "a", Line::from(vec![
+        "".red(),
+        "c".into(),
+        "a".yellow(),
+        "t".into(),
+    ]))]
+    fn highlight(#[case] line: Line, #[case] pattern: &str, #[case] expected: Line) {
+        assert_eq!(line.highlight(pattern, Color::Yellow), expected);
+    }
+
+    #[test]
+    fn highlight_keeps_line_style_and_alignment() {
+        let line = Line::styled("cat", Color::Red)
+            .centered()
+            .highlight("a", Color::Yellow);
+        assert_eq!(line.style, Style::new().red());
+        assert_eq!(line.alignment, Some(Alignment::Center));
+    }
+
+    #[test]
+    fn highlight_keeps_hyperlinks() {
+        let line = Line::from(vec![
+            Span::raw("a ").hyperlink("https://a.example"),
+            Span::raw("cat").hyperlink("https://cat.example"),
+        ])
+        .highlight("a", Color::Yellow);
+        assert_eq!(
+            line,
+            Line::from(vec![
+                Span::styled("a", Color::Yellow).hyperlink("https://a.example"),
+                Span::raw(" ").hyperlink("https://a.example"),
+                Span::raw("c").hyperlink("https://cat.example"),
+                Span::styled("a", Color::Yellow).hyperlink("https://cat.example"),
+                Span::raw("t").hyperlink("https://cat.example"),
+            ])
+        );
+    }
+
+    #[test]
+    fn highlight_borrows_content() {
+        let line = Line::from("a cat").highlight("cat", Color::Yellow);
+        assert!(line
+            .spans
+            .iter()
+            .all(|span| matches!(span.content, Cow::Borrowed(_))));
+    }
+
     #[test]
     fn stylize() {
         assert_eq!(Line::default().green().style, Color::Green.into());
@@ -936,6 +1149,7 @@ mod tests {
                 spans: vec![Span::raw("Red"), Span::raw("blue").blue()],
                 style: Style::new().red(),
                 alignment: None,
+                size: LineSize::Normal,
             },
         );
     }
@@ -962,6 +1176,7 @@ mod tests {
                 spans: vec![Span::raw("Red"), Span::raw("Blue").blue()],
                 style: Style::new().red(),
                 alignment: None,
+                size: LineSize::Normal,
             },
         );
     }
@@ -1166,6 +1381,26 @@ mod tests {
             assert_eq!(buf, expected);
         }
 
+        #[test]
+        fn render_double_width() {
+            let line = Line::from("abc").size(LineSize::DoubleWidth).centered();
+            let mut buf = Buffer::empty(Rect::new(0, 0, 10, 2));
+            line.render(buf.area, &mut buf);
+            let mut expected = Buffer::with_lines([" abc      ", "          "]);
+            expected.set_line_size(0, LineSize::DoubleWidth);
+            assert_eq!(buf, expected);
+        }
+
+        #[test]
+        fn render_double_width_in_offset_area() {
+            let line = Line::from("abc").size(LineSize::DoubleHeightTop);
+            let mut buf = Buffer::empty(Rect::new(0, 0, 10, 1));
+            line.render(Rect::new(3, 0, 7, 1), &mut buf);
+            let mut expected = Buffer::with_lines(["  abc     "]);
+            expected.set_line_size(0, LineSize::DoubleHeightTop);
+            assert_eq!(buf, expected);
+        }
+
         #[test]
         fn render_truncates_left() {
             let mut buf = Buffer::empty(Rect::new(0, 0, 5, 1));
@@ -0,0 +1,82 @@
+use strum::{Display, EnumString};
+
+/// The size at which the terminal displays a row of text.
+///
+/// Most terminals support the DEC line attributes (DECDWL / DECDHL) which display every character
+/// of a row at twice its normal width, and optionally twice its normal height. These are useful
+/// to make titles and headers stand out in dashboards.
+///
+/// The size applies to the whole row of the terminal, not only to the cells of a [`Line`]. As
+/// each character takes up two columns, only the left half of the row is visible.
+///
+/// Double height text is displayed by rendering the same content on two consecutive rows, the
+/// first one using [`LineSize::DoubleHeightTop`] and the second one using
+/// [`LineSize::DoubleHeightBottom`].
+///
+/// Backends that do not support line attributes display the row at its normal size.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::{prelude::*, text::LineSize};
+///
+/// let title = Line::from("Dashboard").size(LineSize::DoubleWidth);
+/// ```
+///
+/// [`Line`]: crate::text::Line
+#[derive(Debug, Default, Display, EnumString, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum LineSize {
+    /// Single width, single height (DECSWL)
+    #[default]
+    Normal,
+    /// Double width, single height (DECDWL)
+    DoubleWidth,
+    /// Top half of a double width, double height row (DECDHL)
+    DoubleHeightTop,
+    /// Bottom half of a double width, double height row (DECDHL)
+    DoubleHeightBottom,
+}
+
+impl LineSize {
+    /// Returns the number of columns each character of the row takes up on the screen.
+    pub const fn column_width(self) -> u16 {
+        match self {
+            Self::Normal => 1,
+            Self::DoubleWidth | Self::DoubleHeightTop | Self::DoubleHeightBottom => 2,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use strum::ParseError;
+
+    use super::*;
+
+    #[test]
+    fn line_size_to_string() {
+        assert_eq!(LineSize::Normal.to_string(), "Normal");
+        assert_eq!(LineSize::DoubleWidth.to_string(), "DoubleWidth");
+        assert_eq!(LineSize::DoubleHeightTop.to_string(), "DoubleHeightTop");
+        assert_eq!(
+            LineSize::DoubleHeightBottom.to_string(),
+            "DoubleHeightBottom"
+        );
+    }
+
+    #[test]
+    fn line_size_from_str() {
+        assert_eq!("Normal".parse::<LineSize>(), Ok(LineSize::Normal));
+        assert_eq!("DoubleWidth".parse::<LineSize>(), Ok(LineSize::DoubleWidth));
+        assert_eq!("".parse::<LineSize>(), Err(ParseError::VariantNotFound));
+    }
+
+    #[test]
+    fn column_width() {
+        assert_eq!(LineSize::Normal.column_width(), 1);
+        assert_eq!(LineSize::DoubleWidth.column_width(), 2);
+        assert_eq!(LineSize::DoubleHeightTop.column_width(), 2);
+        assert_eq!(LineSize::DoubleHeightBottom.column_width(), 2);
+    }
+}
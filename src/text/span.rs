@@ -94,6 +94,8 @@ pub struct Span<'a> {
     pub content: Cow<'a, str>,
     /// The style of the span.
     pub style: Style,
+    /// The URL the span links to, if any. See [`Span::hyperlink`].
+    pub hyperlink: Option<Cow<'a, str>>,
 }
 
 impl<'a> Span<'a> {
@@ -113,6 +115,7 @@ impl<'a> Span<'a> {
         Self {
             content: content.into(),
             style: Style::default(),
+            hyperlink: None,
         }
     }
 
@@ -140,6 +143,7 @@ impl<'a> Span<'a> {
         Self {
             content: content.into(),
             style: style.into(),
+            hyperlink: None,
         }
     }
 
@@ -187,6 +191,39 @@ impl<'a> Span<'a> {
         self
     }
 
+    /// Sets the URL the span links to.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// The cells of the span are rendered as an OSC 8 hyperlink by backends that support it, which
+    /// makes the span clickable in terminals such as Kitty. Terminals that
+    /// don't support hyperlinks ignore the link and only display the content.
+    ///
+    /// The link is kept when the span is rendered directly or as part of a [`Line`] (e.g. in a
+    /// [`List`]). [`Paragraph`] never renders the links of its spans, whether its text is wrapped
+    /// or not.
+    ///
+    /// `url` accepts any type that can be converted to [`Cow<str>`] (e.g. `&str`, `String`,
+    /// `&String`, etc.).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::prelude::*;
+    /// let span = Span::raw("ratatui").hyperlink("https://ratatui.rs");
+    /// ```
+    ///
+    /// [`List`]: crate::widgets::List
+    /// [`Paragraph`]: crate::widgets::Paragraph
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn hyperlink<T>(mut self, url: T) -> Self
+    where
+        T: Into<Cow<'a, str>>,
+    {
+        self.hyperlink = Some(url.into());
+        self
+    }
+
     /// Patches the style of the Span, adding modifiers from the given style.
     ///
     /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
@@ -415,6 +452,12 @@ impl WidgetRef for Span<'_> {
             }
             x = next_x;
         }
+        let rendered = Rect {
+            width: x - area.x,
+            height: 1,
+            ..area
+        };
+        buf.set_hyperlink(rendered, self.hyperlink.as_deref());
     }
 }
 
@@ -605,6 +648,12 @@ mod tests {
         assert_eq!(format!("{stylized_span:.8}"), "stylized");
     }
 
+    #[test]
+    fn hyperlink() {
+        let span = Span::raw("test").hyperlink("https://ratatui.rs");
+        assert_eq!(span.hyperlink, Some(Cow::Borrowed("https://ratatui.rs")));
+    }
+
     #[test]
     fn left_aligned() {
         let span = Span::styled("Test Content", Style::new().green().italic());
@@ -644,6 +693,19 @@ mod tests {
             assert_eq!(buf, expected);
         }
 
+        #[test]
+        fn render_hyperlink() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 4, 1));
+            buf.set_hyperlink(buf.area, Some("https://old.example"));
+            Span::raw("ab")
+                .hyperlink("https://ratatui.rs")
+                .render(Rect::new(1, 0, 3, 1), &mut buf);
+            Span::raw("c").render(Rect::new(3, 0, 1, 1), &mut buf);
+            let links = buf.content.iter().map(Cell::hyperlink).collect::<Vec<_>>();
+            let link = Some("https://ratatui.rs");
+            assert_eq!(links, [Some("https://old.example"), link, link, None]);
+        }
+
         #[rstest]
         #[case::x(20, 0)]
         #[case::y(0, 20)]
@@ -1,7 +1,14 @@
 #![warn(missing_docs)]
 use std::{borrow::Cow, fmt};
 
-use crate::{prelude::*, style::Styled};
+use crate::{
+    prelude::*,
+    style::Styled,
+    widgets::{
+        reflow::{self, LineComposer, WordWrapper},
+        BreakSet, Wrap,
+    },
+};
 
 /// A string split over one or more lines.
 ///
@@ -245,6 +252,63 @@ impl<'a> Text<'a> {
         self.lines.len()
     }
 
+    /// Returns the number of rows needed to render the text at the given width.
+    ///
+    /// The text is reflowed the same way as in a [`Paragraph`] with the given [`Wrap`] setting, so
+    /// the result can be used to reserve the exact height of the text in a [`Layout`], e.g. with
+    /// [`Constraint::Length`]. Without wrapping, each line takes a single row. Tabs are expanded
+    /// with the default [tab size](crate::widgets::Paragraph::tab_size) of a paragraph.
+    ///
+    /// Returns `0` if the width is `0`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::Wrap};
+    /// let text = Text::from("The first line\nThe second line");
+    /// assert_eq!(text.height_at_width(10, None), 2);
+    /// assert_eq!(text.height_at_width(10, Some(Wrap { trim: true })), 4);
+    /// ```
+    ///
+    /// [`Paragraph`]: crate::widgets::Paragraph
+    pub fn height_at_width(&self, width: u16, wrap: Option<Wrap>) -> u16 {
+        let count =
+            self.line_count_at_width(width, wrap, reflow::DEFAULT_TAB_SIZE, &BreakSet::default());
+        u16::try_from(count).unwrap_or(u16::MAX)
+    }
+
+    /// Returns the number of lines of the text once reflowed at the given width, with tabs
+    /// expanded to the given tab size and words broken at the given characters
+    pub(crate) fn line_count_at_width(
+        &self,
+        width: u16,
+        wrap: Option<Wrap>,
+        tab_size: u16,
+        break_on: &BreakSet,
+    ) -> usize {
+        if width == 0 {
+            return 0;
+        }
+        let Some(Wrap { trim }) = wrap else {
+            return self.height();
+        };
+        let styled = self.iter().map(|line| {
+            let graphemes = line
+                .spans
+                .iter()
+                .flat_map(|span| span.styled_graphemes(Style::default()));
+            let graphemes = reflow::expand_tabs(graphemes, tab_size);
+            (graphemes, line.alignment.unwrap_or(Alignment::Left))
+        });
+        let mut line_composer =
+            WordWrapper::new(styled, width, trim).with_break_set(break_on.clone());
+        let mut count = 0;
+        while line_composer.next_line().is_some() {
+            count += 1;
+        }
+        count
+    }
+
     /// Sets the style of this text.
     ///
     /// Defaults to [`Style::default()`].
@@ -324,6 +388,35 @@ impl<'a> Text<'a> {
         self.patch_style(Style::reset())
     }
 
+    /// Highlights all the occurrences of `pattern` in the text.
+    ///
+    /// Each line is highlighted separately with [`Line::highlight`], so matches don't span
+    /// multiple lines. The parts of the spans that match have `style` patched onto their style,
+    /// even when a match spans multiple spans, and the other parts keep their original style.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::prelude::*;
+    /// let text = Text::from("warn: disk full\ninfo: disk cleaned");
+    /// let text = text.highlight("disk", Style::new().black().on_yellow());
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn highlight<S: Into<Style>>(mut self, pattern: &str, style: S) -> Self {
+        let style = style.into();
+        self.lines = self
+            .lines
+            .into_iter()
+            .map(|line| line.highlight(pattern, style))
+            .collect();
+        self
+    }
+
     /// Sets the alignment for this text.
     ///
     /// Defaults to: [`None`], meaning the alignment is determined by the rendering widget.
@@ -651,7 +744,7 @@ impl WidgetRef for Text<'_> {
         let area = area.intersection(buf.area);
         buf.set_style(area, self.style);
         for (line, row) in self.iter().zip(area.rows()) {
-            let line_width = line.width() as u16;
+            let line_width = (line.width() as u16).saturating_mul(line.size.column_width());
 
             let x_offset = match (self.alignment, line.alignment) {
                 (Some(Alignment::Center), None) => area.width.saturating_sub(line_width) / 2,
@@ -690,6 +783,7 @@ mod tests {
     use rstest::{fixture, rstest};
 
     use super::*;
+    use crate::{text::LineSize, widgets::Paragraph};
 
     #[fixture]
     fn small_buf() -> Buffer {
@@ -728,6 +822,36 @@ mod tests {
         assert_eq!(2, text.height());
     }
 
+    #[rstest]
+    #[case::zero_width("hello", 0, Some(Wrap { trim: true }), 0)]
+    #[case::no_wrap("hello world\n\nfoo", 5, None, 3)]
+    #[case::wrap("hello world\n\nfoo", 5, Some(Wrap { trim: true }), 4)]
+    #[case::trim_leading_whitespace("  hello  world  ", 7, Some(Wrap { trim: true }), 2)]
+    #[case::keep_leading_whitespace("  hello  world  ", 7, Some(Wrap { trim: false }), 2)]
+    #[case::trailing_empty_line("foo\n\n", 5, Some(Wrap { trim: true }), 2)]
+    fn height_at_width(
+        #[case] text: &str,
+        #[case] width: u16,
+        #[case] wrap: Option<Wrap>,
+        #[case] expected: u16,
+    ) {
+        let text = Text::from(text);
+        assert_eq!(text.height_at_width(width, wrap), expected);
+    }
+
+    #[test]
+    fn height_at_width_matches_paragraph() {
+        let text = Text::from("  a long line that wraps\n\nlast  ");
+        let wrap = Wrap { trim: true };
+        let height = text.height_at_width(8, Some(wrap));
+        let mut buf = Buffer::empty(Rect::new(0, 0, 8, height));
+        Paragraph::new(text).wrap(wrap).render(buf.area, &mut buf);
+        assert_eq!(
+            buf,
+            Buffer::with_lines(["a long  ", "line", "that", "wraps", "", "last"])
+        );
+    }
+
     #[test]
     fn patch_style() {
         let style = Style::new().yellow().italic();
@@ -748,6 +872,30 @@ mod tests {
         assert_eq!(text.style, Style::reset());
     }
 
+    #[test]
+    fn highlight() {
+        let text = Text::from(vec![
+            Line::from(vec!["warn: ".yellow(), "disk full".into()]),
+            Line::from("info: ok"),
+            Line::from("disk cleaned"),
+        ])
+        .highlight("disk", Modifier::REVERSED);
+        assert_eq!(
+            text.lines,
+            [
+                Line::from(vec!["warn: ".yellow(), "disk".reversed(), " full".into()]),
+                Line::from("info: ok"),
+                Line::from(vec!["disk".reversed(), " cleaned".into()]),
+            ]
+        );
+    }
+
+    #[test]
+    fn highlight_does_not_match_across_lines() {
+        let text = Text::from("ab\ncd").highlight("bc", Color::Yellow);
+        assert_eq!(text, Text::from("ab\ncd"));
+    }
+
     #[test]
     fn from_string() {
         let text = Text::from(String::from("The first line\nThe second line"));
@@ -1092,6 +1240,21 @@ mod tests {
             assert_eq!(buf, Buffer::with_lines(["  foo"]));
         }
 
+        #[test]
+        fn render_right_aligned_double_width() {
+            let text = Text::from(vec![
+                Line::from("ab").size(LineSize::DoubleWidth),
+                Line::from("cd"),
+            ])
+            .alignment(Alignment::Right);
+            let area = Rect::new(0, 0, 8, 2);
+            let mut buf = Buffer::empty(area);
+            text.render(area, &mut buf);
+            let mut expected = Buffer::with_lines(["  ab    ", "      cd"]);
+            expected.set_line_size(0, LineSize::DoubleWidth);
+            assert_eq!(buf, expected);
+        }
+
         #[test]
         fn render_centered_odd() {
             let text = Text::from("foo").alignment(Alignment::Center);
@@ -11,14 +11,21 @@
 //! - [`Canvas`]: draws arbitrary shapes using drawing characters.
 //! - [`Chart`]: displays multiple datasets as a lines or scatter graph.
 //! - [`Clear`]: clears the area it occupies. Useful to render over previously drawn widgets.
+//! - [`Fill`]: fills the area it occupies with a symbol and a style, e.g. to shade a background.
 //! - [`Gauge`]: displays progress percentage using block characters.
+//! - [`HalfBlockCanvas`]: displays colored content at twice the vertical resolution.
+//! - [`Heatmap`]: displays a matrix of values as a grid of colored cells.
 //! - [`LineGauge`]: display progress as a line.
 //! - [`List`]: displays a list of items and allows selection.
 //! - [`Paragraph`]: displays a paragraph of optionally styled and wrapped text.
+//! - [`Popup`]: displays a widget in a centered area over previously drawn widgets.
+//! - [`ScrollView`]: displays a scrollable window of content larger than its area.
 //! - [`Scrollbar`]: displays a scrollbar.
 //! - [`Sparkline`]: display a single data set as a sparkline.
 //! - [`Table`]: displays multiple rows and columns in a grid and allows selection.
+//! - [`VirtualTable`]: displays a [`Table`] whose rows are built only when they are visible.
 //! - [`Tabs`]: displays a tab bar and allows selection.
+//! - [`Tree`]: displays hierarchical data with expandable nodes and allows selection.
 //!
 //! [`Canvas`]: crate::widgets::canvas::Canvas
 mod barchart;
@@ -29,30 +36,47 @@ pub mod calendar;
 pub mod canvas;
 mod chart;
 mod clear;
+mod fill;
 mod gauge;
+mod half_block_canvas;
+mod heatmap;
 mod list;
 mod paragraph;
-mod reflow;
+mod popup;
+pub(crate) mod reflow;
+mod scroll_offset;
+mod scroll_view;
 mod scrollbar;
 mod sparkline;
 mod table;
 mod tabs;
+mod tree;
 
 pub use self::{
-    barchart::{Bar, BarChart, BarGroup},
+    barchart::{Bar, BarChart, BarGroup, GroupLabelPosition},
     block::{Block, BorderType, Padding},
     borders::*,
-    chart::{Axis, Chart, Dataset, GraphType, LegendPosition},
+    chart::{
+        Axis, AxisScale, Chart, ChartData, ChartState, Dataset, GraphType, GridLines, GridStyle,
+        LegendPosition, YAxis,
+    },
     clear::Clear,
-    gauge::{Gauge, LineGauge},
+    fill::Fill,
+    gauge::{Gauge, GaugeState, LineGauge},
+    half_block_canvas::HalfBlockCanvas,
+    heatmap::Heatmap,
     list::{List, ListDirection, ListItem, ListState},
-    paragraph::{Paragraph, Wrap},
+    paragraph::{BreakSet, Paragraph, ParagraphState, TextDirection, Truncate, WordBoundary, Wrap},
+    popup::Popup,
+    scroll_offset::ScrollOffset,
+    scroll_view::{ScrollView, ScrollViewState},
     scrollbar::{ScrollDirection, Scrollbar, ScrollbarOrientation, ScrollbarState},
     sparkline::{RenderDirection, Sparkline},
-    table::{Cell, HighlightSpacing, Row, Table, TableState},
-    tabs::Tabs,
+    table::{Cell, HighlightSpacing, Row, Table, TableState, VirtualTable},
+    tabs::{Tabs, TabsState},
+    tree::{Tree, TreeItem, TreeState},
 };
-use crate::{buffer::Buffer, layout::Rect, style::Style};
+use crate::{accessibility::AccessNode, buffer::Buffer, layout::Rect, style::Style};
 
 /// A `Widget` is a type that can be drawn on a [`Buffer`] in a given [`Rect`].
 ///
@@ -78,7 +102,10 @@ use crate::{buffer::Buffer, layout::Rect, style::Style};
 /// can be quite useful.
 ///
 /// A blanket implementation of `Widget` for `&W` where `W` implements `WidgetRef` is provided.
-/// Widget is also implemented for `&str` and `String` types.
+/// Widget is also implemented for `&str` and `String` types, and for `Option<W>` where `W`
+/// implements `Widget`, which renders nothing when the option is `None`. Similarly, the internal
+/// stateful widgets implement [`StatefulWidgetRef`] and `StatefulWidget` for a reference to
+/// themselves.
 ///
 /// # Examples
 ///
@@ -111,6 +138,15 @@ pub trait Widget {
     fn render(self, area: Rect, buf: &mut Buffer)
     where
         Self: Sized;
+
+    /// Describes the widget as it would be rendered in the given area for assistive technologies.
+    ///
+    /// The node is collected by [`Frame::render_widget`](crate::Frame::render_widget) before the
+    /// widget is rendered, see the [`accessibility`](crate::accessibility) module. The default
+    /// implementation returns `None`.
+    fn accessibility_node(&self, _area: Rect) -> Option<AccessNode> {
+        None
+    }
 }
 
 /// A `StatefulWidget` is a widget that can take advantage of some local state to remember things
@@ -232,6 +268,17 @@ pub trait StatefulWidget {
     /// Draws the current state of the widget in the given buffer. That is the only method required
     /// to implement a custom stateful widget.
     fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State);
+
+    /// Describes the widget as it would be rendered in the given area with the given state for
+    /// assistive technologies.
+    ///
+    /// The node is collected by
+    /// [`Frame::render_stateful_widget`](crate::Frame::render_stateful_widget) before the widget
+    /// is rendered, see the [`accessibility`](crate::accessibility) module. The default
+    /// implementation returns `None`.
+    fn accessibility_node(&self, _area: Rect, _state: &Self::State) -> Option<AccessNode> {
+        None
+    }
 }
 
 /// A `WidgetRef` is a trait that allows rendering a widget by reference.
@@ -310,6 +357,13 @@ pub trait WidgetRef {
     /// Draws the current state of the widget in the given buffer. That is the only method required
     /// to implement a custom widget.
     fn render_ref(&self, area: Rect, buf: &mut Buffer);
+
+    /// Describes the widget as it would be rendered in the given area for assistive technologies.
+    ///
+    /// See [`Widget::accessibility_node`]. The default implementation returns `None`.
+    fn accessibility_node(&self, _area: Rect) -> Option<AccessNode> {
+        None
+    }
 }
 
 /// This allows you to render a widget by reference.
@@ -317,6 +371,10 @@ impl<W: WidgetRef> Widget for &W {
     fn render(self, area: Rect, buf: &mut Buffer) {
         self.render_ref(area, buf);
     }
+
+    fn accessibility_node(&self, area: Rect) -> Option<AccessNode> {
+        WidgetRef::accessibility_node(*self, area)
+    }
 }
 
 /// A blanket implementation of `WidgetExt` for `Option<W>` where `W` implements `WidgetRef`.
@@ -359,6 +417,36 @@ impl<W: WidgetRef> WidgetRef for Option<W> {
             widget.render_ref(area, buf);
         }
     }
+
+    fn accessibility_node(&self, area: Rect) -> Option<AccessNode> {
+        self.as_ref()?.accessibility_node(area)
+    }
+}
+
+/// Renders the widget if it is `Some` and nothing if it is `None`.
+///
+/// This makes it possible to render optional widgets without checking for `None` first.
+///
+/// # Examples
+///
+/// ```rust
+/// use ratatui::{prelude::*, widgets::*};
+///
+/// # fn ui(frame: &mut Frame, error: Option<&str>) {
+/// let popup = error.map(|message| Paragraph::new(message).block(Block::bordered()));
+/// frame.render_widget(popup, frame.area());
+/// # }
+/// ```
+impl<W: Widget> Widget for Option<W> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        if let Some(widget) = self {
+            widget.render(area, buf);
+        }
+    }
+
+    fn accessibility_node(&self, area: Rect) -> Option<AccessNode> {
+        self.as_ref()?.accessibility_node(area)
+    }
 }
 
 /// A `StatefulWidgetRef` is a trait that allows rendering a stateful widget by reference.
@@ -415,6 +503,14 @@ pub trait StatefulWidgetRef {
     /// Draws the current state of the widget in the given buffer. That is the only method required
     /// to implement a custom stateful widget.
     fn render_ref(&self, area: Rect, buf: &mut Buffer, state: &mut Self::State);
+
+    /// Describes the widget as it would be rendered in the given area with the given state for
+    /// assistive technologies.
+    ///
+    /// See [`StatefulWidget::accessibility_node`]. The default implementation returns `None`.
+    fn accessibility_node(&self, _area: Rect, _state: &Self::State) -> Option<AccessNode> {
+        None
+    }
 }
 
 // Note: while StatefulWidgetRef is marked as unstable, the blanket implementation of StatefulWidget
@@ -623,6 +719,32 @@ mod tests {
         }
     }
 
+    mod option_widget {
+        use super::*;
+
+        struct Greeting;
+
+        impl Widget for Greeting {
+            fn render(self, area: Rect, buf: &mut Buffer) {
+                Line::from("Hello").render(area, buf);
+            }
+        }
+
+        #[rstest]
+        fn render_some(mut buf: Buffer) {
+            let widget = Some(Greeting);
+            widget.render(buf.area, &mut buf);
+            assert_eq!(buf, Buffer::with_lines(["Hello               "]));
+        }
+
+        #[rstest]
+        fn render_none(mut buf: Buffer) {
+            let widget: Option<Greeting> = None;
+            widget.render(buf.area, &mut buf);
+            assert_eq!(buf, Buffer::with_lines(["                    "]));
+        }
+    }
+
     mod option_widget_ref {
         use super::*;
 
@@ -717,4 +839,130 @@ mod tests {
             assert_eq!(buf, Buffer::with_lines(["hello world         "]));
         }
     }
+
+    /// Checks that each built-in widget renders the same by reference as it does by value.
+    mod built_in {
+        use std::fmt;
+
+        use super::*;
+        use crate::{
+            layout::{Constraint, Position, Size},
+            style::Color,
+            text::{Span, Text},
+            widgets::canvas::{Canvas, Line as CanvasLine},
+        };
+
+        #[track_caller]
+        fn assert_renders_by_reference<W: Widget + WidgetRef + Clone>(widget: &W) {
+            let area = Rect::new(0, 0, 20, 6);
+            let mut by_value = Buffer::empty(area);
+            widget.clone().render(area, &mut by_value);
+            let mut by_reference = Buffer::empty(area);
+            widget.render(area, &mut by_reference);
+            assert_eq!(by_reference, by_value);
+        }
+
+        #[track_caller]
+        fn assert_renders_stateful_by_reference<W>(widget: &W, state: &W::State)
+        where
+            W: StatefulWidget + Clone,
+            W::State: Clone + fmt::Debug + PartialEq,
+            for<'a> &'a W: StatefulWidget<State = W::State>,
+        {
+            let area = Rect::new(0, 0, 20, 6);
+            let mut by_value = Buffer::empty(area);
+            let mut value_state = state.clone();
+            widget.clone().render(area, &mut by_value, &mut value_state);
+            let mut by_reference = Buffer::empty(area);
+            let mut reference_state = state.clone();
+            widget.render(area, &mut by_reference, &mut reference_state);
+            assert_eq!(by_reference, by_value);
+            assert_eq!(reference_state, value_state);
+        }
+
+        #[test]
+        fn widgets() {
+            let block = Block::bordered().title("Block");
+            assert_renders_by_reference(&block);
+            assert_renders_by_reference(&Paragraph::new("Hello\nworld").block(block.clone()));
+            assert_renders_by_reference(&List::new(["a", "b", "c"]).block(block.clone()));
+            assert_renders_by_reference(&Table::new(
+                [Row::new(["a", "b"]), Row::new(["c", "d"])],
+                [Constraint::Length(3); 2],
+            ));
+            assert_renders_by_reference(&Tabs::new(["one", "two"]).select(1));
+            assert_renders_by_reference(&Gauge::default().percent(40).block(block.clone()));
+            assert_renders_by_reference(&LineGauge::default().ratio(0.4));
+            assert_renders_by_reference(&Sparkline::default().data(&[1, 4, 2, 8]));
+            assert_renders_by_reference(&BarChart::default().data(&[("a", 1), ("b", 4)]));
+            assert_renders_by_reference(
+                &Chart::new(vec![Dataset::default().data(&[(0.0, 0.0), (1.0, 1.0)])])
+                    .x_axis(Axis::default().bounds([0.0, 1.0]))
+                    .y_axis(Axis::default().bounds([0.0, 1.0])),
+            );
+            assert_renders_by_reference(
+                &Canvas::default()
+                    .x_bounds([0.0, 1.0])
+                    .y_bounds([0.0, 1.0])
+                    .paint(|ctx| {
+                        ctx.draw(&CanvasLine::new(0.0, 0.0, 1.0, 1.0, Color::Red));
+                    }),
+            );
+            assert_renders_by_reference(&Clear);
+            assert_renders_by_reference(&Fill::new("░").style(Color::Red));
+            assert_renders_by_reference(&Line::from("line"));
+            assert_renders_by_reference(&Text::from("text"));
+            assert_renders_by_reference(&Span::from("span"));
+        }
+
+        #[cfg(feature = "widget-calendar")]
+        #[test]
+        fn calendar() {
+            use time::{Date, Month};
+
+            let date = Date::from_calendar_date(2023, Month::January, 1).unwrap();
+            assert_renders_by_reference(&calendar::Monthly::new(
+                date,
+                calendar::CalendarEventStore::today(Color::Red),
+            ));
+        }
+
+        #[test]
+        fn stateful_widgets() {
+            assert_renders_stateful_by_reference(
+                &List::new(["a", "b", "c"]).highlight_symbol(">"),
+                &ListState::default().with_selected(Some(1)),
+            );
+            assert_renders_stateful_by_reference(
+                &Table::new([Row::new(["a"]), Row::new(["b"])], [Constraint::Length(3)])
+                    .highlight_symbol(">"),
+                &TableState::default().with_selected(Some(1)),
+            );
+            assert_renders_stateful_by_reference(
+                &Scrollbar::new(ScrollbarOrientation::VerticalRight),
+                &ScrollbarState::new(10).position(4),
+            );
+            assert_renders_stateful_by_reference(
+                &Chart::new(vec![Dataset::default().data(&[(0.0, 0.0), (1.0, 1.0)])])
+                    .x_axis(Axis::default().bounds([0.0, 1.0]))
+                    .y_axis(Axis::default().bounds([0.0, 1.0])),
+                &ChartState::default(),
+            );
+            assert_renders_stateful_by_reference(
+                &Tree::new([TreeItem::new(1, "a").children([TreeItem::new(2, "b")])])
+                    .highlight_symbol(">"),
+                &TreeState::default().with_selected(Some(2)),
+            );
+            assert_renders_stateful_by_reference(
+                &Tabs::new(["one", "two", "three", "four", "five"]),
+                &TabsState::default().with_selected(Some(4)),
+            );
+            let mut scroll_view = ScrollView::new(Size::new(30, 10));
+            scroll_view.render_widget(Paragraph::new("scrolled"), scroll_view.area());
+            assert_renders_stateful_by_reference(
+                &scroll_view,
+                &ScrollViewState::new(Position::new(2, 1)),
+            );
+        }
+    }
 }
@@ -1,3 +1,11 @@
+use std::{
+    fmt,
+    hash::{Hash, Hasher},
+    sync::Arc,
+};
+
+use strum::{Display, EnumString};
+
 use crate::{prelude::*, style::Styled, widgets::Block};
 
 mod bar;
@@ -83,6 +91,50 @@ pub struct BarChart<'a> {
     max: Option<u64>,
     /// direction of the bars
     direction: Direction,
+    /// Formats the values printed on the bars
+    value_formatter: Option<ValueFormatter<'a>>,
+    /// Where the group labels are printed
+    group_label_position: GroupLabelPosition,
+}
+
+/// Where the labels of the [`BarGroup`]s of a vertical [`BarChart`] are printed
+///
+/// See [`BarChart::group_label_position`].
+#[derive(Debug, Default, Display, EnumString, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum GroupLabelPosition {
+    /// The group labels are printed below the bars and their labels. This is the default.
+    #[default]
+    Bottom,
+    /// The group labels are printed on a row above the bars, centered over each group unless
+    /// aligned otherwise, and truncated with an ellipsis when wider than the group.
+    Top,
+}
+
+/// A function formatting the values of a [`BarChart`]
+///
+/// Functions can't be compared or hashed, so two formatters are equal only if they are the same
+/// instance.
+#[derive(Clone)]
+struct ValueFormatter<'a>(Arc<dyn Fn(u64) -> String + Send + Sync + 'a>);
+
+impl fmt::Debug for ValueFormatter<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("ValueFormatter")
+    }
+}
+
+impl PartialEq for ValueFormatter<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl Eq for ValueFormatter<'_> {}
+
+impl Hash for ValueFormatter<'_> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        Arc::as_ptr(&self.0).cast::<()>().hash(state);
+    }
 }
 
 impl<'a> Default for BarChart<'a> {
@@ -100,6 +152,8 @@ impl<'a> Default for BarChart<'a> {
             bar_set: symbols::bar::NINE_LEVELS,
             style: Style::default(),
             direction: Direction::Vertical,
+            value_formatter: None,
+            group_label_position: GroupLabelPosition::Bottom,
         }
     }
 }
@@ -305,6 +359,66 @@ impl<'a> BarChart<'a> {
         self.direction = direction;
         self
     }
+
+    /// Set the function used to format the values printed on the bars
+    ///
+    /// By default, the values are printed as is. The formatted value is only used for display, so
+    /// the length of the bars still depends on the numeric value. Whether the value fits on a bar
+    /// is decided from the width of the formatted value.
+    ///
+    /// A [`Bar::text_value`] takes precedence over the formatter.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// BarChart::default()
+    ///     .data(&[("B0", 1_200), ("B1", 3_400_000)])
+    ///     .value_formatter(|value| match value {
+    ///         1_000_000.. => format!("{:.1}M", value as f64 / 1_000_000.0),
+    ///         1_000.. => format!("{:.1}k", value as f64 / 1_000.0),
+    ///         _ => value.to_string(),
+    ///     });
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn value_formatter<F>(mut self, formatter: F) -> Self
+    where
+        F: Fn(u64) -> String + Send + Sync + 'a,
+    {
+        self.value_formatter = Some(ValueFormatter(Arc::new(formatter)));
+        self
+    }
+
+    /// Set where the labels of the [`BarGroup`]s are printed
+    ///
+    /// By default, the group labels are printed below the bars, on the row after the bar labels.
+    /// With [`GroupLabelPosition::Top`], the group labels are printed on their own row above the
+    /// bars, centered over each group, while the values and labels of the bars stay below. A label
+    /// wider than its group is truncated with an ellipsis.
+    ///
+    /// This only applies to [`Vertical`](Direction::Vertical) bars. Horizontal bar charts print the
+    /// group labels after each group.
+    ///
+    /// # Example
+    ///
+    /// ```plain
+    ///  Group1      Group2
+    ///     ▄▄▄▄   ████ ████
+    /// ▆10▆ █20█   █50█ █40█
+    ///  B1   B2     B1   B2
+    /// ```
+    ///
+    /// ```
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// BarChart::default()
+    ///     .data(BarGroup::default().label("Group1".into()).bars(&[Bar::default().value(10)]))
+    ///     .group_label_position(GroupLabelPosition::Top);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn group_label_position(mut self, position: GroupLabelPosition) -> Self {
+        self.group_label_position = position;
+        self
+    }
 }
 
 #[derive(Clone, Copy)]
@@ -315,6 +429,13 @@ struct LabelInfo {
 }
 
 impl BarChart<'_> {
+    /// Formats the value of a bar with the value formatter if set
+    fn format_value(&self, value: u64) -> String {
+        self.value_formatter
+            .as_ref()
+            .map_or_else(|| value.to_string(), |formatter| (formatter.0)(value))
+    }
+
     /// Returns the visible bars length in ticks. A cell contains 8 ticks.
     /// `available_space` used to calculate how many bars can fit in the space
     /// `bar_max_length` is the maximal length a bar can take.
@@ -403,10 +524,12 @@ impl BarChart<'_> {
             .map(Line::width)
             .max()
             .unwrap_or(0) as u16;
+        // the labels can't take more than the whole area
+        let label_size = label_size.min(area.width);
 
         let label_x = area.x;
         let bars_area = {
-            let margin = u16::from(label_size != 0);
+            let margin = u16::from(label_size != 0 && label_size < area.width);
             Rect {
                 x: area.x + label_size + margin,
                 width: area.width - label_size - margin,
@@ -453,6 +576,7 @@ impl BarChart<'_> {
                     bar_length as usize,
                     self.value_style,
                     self.bar_style,
+                    &self.format_value(bar.value),
                 );
 
                 bar_y += self.bar_gap + self.bar_width;
@@ -475,14 +599,19 @@ impl BarChart<'_> {
     fn render_vertical(&self, buf: &mut Buffer, area: Rect) {
         let label_info = self.label_info(area.height - 1);
 
+        // the row of the group labels is reserved above the bars when they are printed on top
+        let top = u16::from(
+            label_info.group_label_visible && self.group_label_position == GroupLabelPosition::Top,
+        );
         let bars_area = Rect {
+            y: area.y + top,
             height: area.height - label_info.height,
             ..area
         };
 
         let group_ticks = self.group_ticks(bars_area.width, bars_area.height);
         self.render_vertical_bars(bars_area, buf, &group_ticks);
-        self.render_labels_and_values(area, buf, label_info, &group_ticks);
+        self.render_labels_and_values(area, bars_area, buf, label_info, &group_ticks);
     }
 
     fn render_vertical_bars(&self, area: Rect, buf: &mut Buffer, group_ticks: &[Vec<u64>]) {
@@ -536,18 +665,19 @@ impl BarChart<'_> {
     fn render_labels_and_values(
         &self,
         area: Rect,
+        bars_area: Rect,
         buf: &mut Buffer,
         label_info: LabelInfo,
         group_ticks: &[Vec<u64>],
     ) {
         // print labels and values in one go
         let mut bar_x = area.left();
-        let bar_y = area.bottom() - label_info.height - 1;
+        let bar_y = bars_area.bottom() - 1;
         for (group, ticks_vec) in self.data.iter().zip(group_ticks) {
             if group.bars.is_empty() {
                 continue;
             }
-            // print group labels under the bars or the previous labels
+            // print group labels above the bars, or under the bars or the previous labels
             if label_info.group_label_visible {
                 let label_max_width =
                     ticks_vec.len() as u16 * (self.bar_width + self.bar_gap) - self.bar_gap;
@@ -557,7 +687,19 @@ impl BarChart<'_> {
                     width: label_max_width,
                     height: 1,
                 };
-                group.render_label(buf, group_area, self.label_style);
+                match self.group_label_position {
+                    GroupLabelPosition::Bottom => {
+                        group.render_label(buf, group_area, self.label_style);
+                    }
+                    GroupLabelPosition::Top => group.render_top_label(
+                        buf,
+                        Rect {
+                            y: area.top(),
+                            ..group_area
+                        },
+                        self.label_style,
+                    ),
+                }
             }
 
             // print the bar values and numbers
@@ -566,7 +708,13 @@ impl BarChart<'_> {
                     bar.render_label(buf, self.bar_width, bar_x, bar_y + 1, self.label_style);
                 }
 
-                bar.render_value(buf, self.bar_width, bar_x, bar_y, self.value_style, *ticks);
+                bar.render_value(
+                    buf,
+                    Rect::new(bar_x, bar_y, self.bar_width, 1),
+                    self.value_style,
+                    *ticks,
+                    &self.format_value(bar.value),
+                );
 
                 bar_x += self.bar_gap + self.bar_width;
             }
@@ -918,6 +1066,26 @@ mod tests {
         assert_eq!(buffer, expected);
     }
 
+    #[test]
+    fn test_horizontal_bars_label_wider_than_area() {
+        let chart = BarChart::default()
+            .data(BarGroup::default().bars(&[
+                Bar::default().label("a very long label".into()).value(2),
+                Bar::default().label("short".into()).value(4),
+            ]))
+            .direction(Direction::Horizontal)
+            .bar_gap(0);
+
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 5, 2));
+        chart.render(buffer.area, &mut buffer);
+        #[rustfmt::skip]
+        let expected = Buffer::with_lines([
+            "a ver",
+            "short",
+        ]);
+        assert_eq!(buffer, expected);
+    }
+
     #[test]
     fn test_horizontal_bars_no_space_for_all_bars() {
         let chart: BarChart<'_> = build_test_barchart();
@@ -1078,6 +1246,125 @@ mod tests {
         assert_eq!(buffer, expected);
     }
 
+    #[test]
+    fn group_label_top() {
+        let group = BarGroup::from(&[("a", 1), ("b", 2), ("c", 3)]);
+        let chart = BarChart::default()
+            .data(group.clone().label("G1".into()))
+            .data(group.label(Line::from("G2").right_aligned()))
+            .group_gap(1)
+            .group_label_position(GroupLabelPosition::Top);
+
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 12, 5));
+        chart.render(buffer.area, &mut buffer);
+        let expected = Buffer::with_lines([
+            " G1       G2",
+            "    █      █",
+            "  █ █    █ █",
+            "1 2 3  1 2 3",
+            "a b c  a b c",
+        ]);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn group_label_top_without_bar_labels() {
+        let chart = BarChart::default()
+            .data(
+                BarGroup::default()
+                    .label("G".into())
+                    .bars(&[Bar::default().value(1), Bar::default().value(2)]),
+            )
+            .group_label_position(GroupLabelPosition::Top);
+
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 3, 3));
+        chart.render(buffer.area, &mut buffer);
+        #[rustfmt::skip]
+        let expected = Buffer::with_lines([
+            " G ",
+            "  █",
+            "1 2",
+        ]);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn group_label_top_is_truncated() {
+        let chart = BarChart::default()
+            .data(
+                BarGroup::default()
+                    .label("Group 1".into())
+                    .bars(&[Bar::default().value(1), Bar::default().value(1)]),
+            )
+            .label_style(Style::new().red())
+            .group_label_position(GroupLabelPosition::Top);
+
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 3, 2));
+        chart.render(buffer.area, &mut buffer);
+        let mut expected = Buffer::with_lines(["Gr…", "1 1"]);
+        expected.set_style(Rect::new(0, 0, 3, 1), Style::new().red());
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn value_formatter() {
+        let chart = BarChart::default()
+            .data(&[("a", 1_500), ("b", 3_000)])
+            .bar_width(4)
+            .value_formatter(|value| format!("{}k", value / 1_000));
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 9, 3));
+        chart.render(buffer.area, &mut buffer);
+        let expected = Buffer::with_lines(["     ████", "█1k█ █3k█", " a    b  "]);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn value_formatter_hides_values_wider_than_the_bar() {
+        let chart = BarChart::default()
+            .data(&[("a", 2), ("b", 3)])
+            .bar_width(3)
+            .value_formatter(|value| format!("${value}.00"));
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 7, 3));
+        chart.render(buffer.area, &mut buffer);
+        assert_eq!(
+            buffer,
+            Buffer::with_lines(["▂▂▂ ███", "███ ███", " a   b "])
+        );
+    }
+
+    #[test]
+    fn text_value_takes_precedence_over_value_formatter() {
+        let chart = BarChart::default()
+            .data(BarGroup::default().bars(&[Bar::default().value(1).text_value("x".into())]))
+            .bar_width(3)
+            .value_formatter(|value| format!("{value}!"));
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 3, 1));
+        chart.render(buffer.area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines(["█x█"]));
+    }
+
+    #[test]
+    fn value_formatter_horizontal() {
+        let chart = BarChart::default()
+            .data(&[("a", 1), ("b", 4)])
+            .direction(Direction::Horizontal)
+            .value_formatter(|value| format!("{value}€"));
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 6, 3));
+        chart.render(buffer.area, &mut buffer);
+        let expected = Buffer::with_lines(["a 1€  ", "      ", "b 4€██"]);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn value_formatter_equality() {
+        let chart = BarChart::default().value_formatter(|value| value.to_string());
+        assert_eq!(chart.clone(), chart);
+        assert_ne!(
+            chart,
+            BarChart::default().value_formatter(|value| value.to_string())
+        );
+    }
+
     #[test]
     fn test_unicode_as_value() {
         let group = BarGroup::default().bars(&[
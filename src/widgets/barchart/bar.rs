@@ -1,4 +1,4 @@
-use unicode_width::UnicodeWidthStr;
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
 
 use crate::prelude::*;
 
@@ -96,8 +96,9 @@ impl<'a> Bar<'a> {
 
     /// Set the text value printed in the bar.
     ///
-    /// If `text_value` is not set, then the [`ToString`] representation of `value` will be shown on
-    /// the bar.
+    /// If `text_value` is not set, then `value` will be shown on the bar, formatted with the
+    /// [value formatter](crate::widgets::BarChart::value_formatter) of the chart or its
+    /// [`ToString`] representation.
     ///
     /// # See also
     ///
@@ -110,7 +111,7 @@ impl<'a> Bar<'a> {
 
     /// Render the value of the bar.
     ///
-    /// [`text_value`](Bar::text_value) is used if set, otherwise the value is converted to string.
+    /// [`text_value`](Bar::text_value) is used if set, otherwise the formatted value.
     /// The value is rendered using `value_style`. If the value width is greater than the
     /// bar width, then the value is split into 2 parts. the first part is rendered in the bar
     /// using `value_style`. The second part is rendered outside the bar using `bar_style`
@@ -121,9 +122,9 @@ impl<'a> Bar<'a> {
         bar_length: usize,
         default_value_style: Style,
         bar_style: Style,
+        formatted_value: &str,
     ) {
-        let value = self.value.to_string();
-        let text = self.text_value.as_ref().unwrap_or(&value);
+        let text = self.text_value.as_deref().unwrap_or(formatted_value);
 
         if !text.is_empty() {
             let style = default_value_style.patch(self.value_style);
@@ -131,15 +132,15 @@ impl<'a> Bar<'a> {
             // while rendering. Render the first part with the default value style
             buf.set_stringn(area.x, area.y, text, bar_length, style);
             // render the second part with the bar_style
-            if text.len() > bar_length {
-                let (first, second) = text.split_at(bar_length);
+            if text.width() > bar_length {
+                let (first, second) = split_at_width(text, bar_length);
 
                 let style = bar_style.patch(self.style);
                 buf.set_stringn(
-                    area.x + first.len() as u16,
+                    area.x + first.width() as u16,
                     area.y,
                     second,
-                    area.width as usize - first.len(),
+                    (area.width as usize).saturating_sub(first.width()),
                     style,
                 );
             }
@@ -149,23 +150,22 @@ impl<'a> Bar<'a> {
     pub(super) fn render_value(
         &self,
         buf: &mut Buffer,
-        max_width: u16,
-        x: u16,
-        y: u16,
+        area: Rect,
         default_value_style: Style,
         ticks: u64,
+        formatted_value: &str,
     ) {
         if self.value != 0 {
             const TICKS_PER_LINE: u64 = 8;
-            let value = self.value.to_string();
-            let value_label = self.text_value.as_ref().unwrap_or(&value);
+            let value_label = self.text_value.as_deref().unwrap_or(formatted_value);
             let width = value_label.width() as u16;
+            let max_width = area.width;
             // if we have enough space or the ticks are greater equal than 1 cell (8)
             // then print the value
             if width < max_width || (width == max_width && ticks >= TICKS_PER_LINE) {
                 buf.set_string(
-                    x + (max_width.saturating_sub(value_label.len() as u16) >> 1),
-                    y,
+                    area.x + (max_width.saturating_sub(width) >> 1),
+                    area.y,
                     value_label,
                     default_value_style.patch(self.value_style),
                 );
@@ -200,3 +200,15 @@ impl<'a> Bar<'a> {
         }
     }
 }
+
+/// Splits the text at the last character boundary which fits in the given width
+fn split_at_width(text: &str, width: usize) -> (&str, &str) {
+    let mut used = 0;
+    for (index, ch) in text.char_indices() {
+        used += ch.width().unwrap_or(0);
+        if used > width {
+            return text.split_at(index);
+        }
+    }
+    (text, "")
+}
@@ -62,6 +62,34 @@ impl<'a> BarGroup<'a> {
             label.render(area, buf);
         }
     }
+
+    /// Renders the label above the group. The label is centered unless aligned otherwise, and is
+    /// truncated with an ellipsis when it is wider than the area.
+    pub(super) fn render_top_label(
+        &self,
+        buf: &mut Buffer,
+        area: Rect,
+        default_label_style: Style,
+    ) {
+        let Some(label) = self.label.as_ref().filter(|_| area.width > 0) else {
+            return;
+        };
+        let width = label.width() as u16;
+        if width <= area.width {
+            let x = match label.alignment {
+                Some(Alignment::Left) => area.x,
+                Some(Alignment::Right) => area.x + area.width - width,
+                _ => area.x + (area.width - width) / 2,
+            };
+            let area = Rect { x, width, ..area };
+            buf.set_style(area, default_label_style);
+            label.render(area, buf);
+        } else {
+            buf.set_style(area, default_label_style);
+            let (x, y) = buf.set_line(area.x, area.y, label, area.width - 1);
+            buf[(x, y)].set_symbol("…").set_style(label.style);
+        }
+    }
 }
 
 impl<'a> From<&[(&'a str, u64)]> for BarGroup<'a> {
@@ -5,10 +5,18 @@
 //! In its simplest form, a `Block` is a [border](Borders) around another widget. It can have a
 //! [title](Block::title) and [padding](Block::padding).
 
+use std::iter;
+
 use itertools::Itertools;
 use strum::{Display, EnumString};
+use unicode_width::UnicodeWidthStr;
 
-use crate::{prelude::*, style::Styled, symbols::border, widgets::Borders};
+use crate::{
+    prelude::*,
+    style::Styled,
+    symbols::{border, line},
+    widgets::Borders,
+};
 
 mod padding;
 pub mod title;
@@ -28,7 +36,9 @@ pub use title::{Position, Title};
 /// the full width of the block, rather than the leftover width.
 ///
 /// Titles are not rendered in the corners of the block unless there is no border on that edge. If
-/// the block is too small and multiple titles overlap, the border may get cut off at a corner.
+/// the block is too small to fit all titles, left aligned titles take precedence over centered
+/// titles, which take precedence over right aligned titles. Titles that do not fit are truncated
+/// rather than drawn over each other.
 ///
 /// ```plain
 /// ┌With at least a left border───
@@ -126,6 +136,8 @@ pub struct Block<'a> {
     style: Style,
     /// Block padding
     padding: Padding,
+    /// Whether the borders are joined with the borders already drawn under them
+    connect_borders: bool,
 }
 
 /// The type of border of a [`Block`].
@@ -178,6 +190,26 @@ pub enum BorderType {
     /// ┗━━━━━━━┛
     /// ```
     Thick,
+    /// A dashed border with plain corners.
+    ///
+    /// # Example
+    ///
+    /// ```plain
+    /// ┌┄┄┄┄┄┄┄┐
+    /// ┆       ┆
+    /// └┄┄┄┄┄┄┄┘
+    /// ```
+    Dashed,
+    /// A dotted border with plain corners.
+    ///
+    /// # Example
+    ///
+    /// ```plain
+    /// ┌┈┈┈┈┈┈┈┐
+    /// ┊       ┊
+    /// └┈┈┈┈┈┈┈┘
+    /// ```
+    Dotted,
     /// A border with a single line on the inside of a half block.
     ///
     /// # Example
@@ -214,6 +246,7 @@ impl<'a> Block<'a> {
             border_set: BorderType::Plain.to_border_set(),
             style: Style::new(),
             padding: Padding::ZERO,
+            connect_borders: false,
         }
     }
 
@@ -253,8 +286,8 @@ impl<'a> Block<'a> {
     /// Without left border───
     /// ```
     ///
-    /// Note: If the block is too small and multiple titles overlap, the border might get cut off at
-    /// a corner.
+    /// Note: If the block is too small to fit all titles, left aligned titles take precedence over
+    /// centered titles, which take precedence over right aligned titles. See [`Block::titles`].
     ///
     /// # Examples
     ///
@@ -298,6 +331,52 @@ impl<'a> Block<'a> {
         self
     }
 
+    /// Adds multiple titles to the block.
+    ///
+    /// This is equivalent to calling [`Block::title`] for each item, in order. Each item can be
+    /// anything that converts into a [`Title`], so the position and alignment of each title can be
+    /// set individually.
+    ///
+    /// Titles that share a position and alignment are rendered next to each other, in the order
+    /// they were added, separated by a single space. When titles with different alignments do not
+    /// fit, left aligned titles take precedence, then centered titles, then right aligned titles.
+    /// Each group keeps a one cell gap from the previous one and is truncated instead of drawn
+    /// over the titles that take precedence.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui::{
+    ///     prelude::*,
+    ///     widgets::{
+    ///         block::{Position, Title},
+    ///         Block,
+    ///     },
+    /// };
+    ///
+    /// Block::bordered().titles([
+    ///     Title::from("Left").alignment(Alignment::Left),
+    ///     Title::from("Center").alignment(Alignment::Center),
+    ///     Title::from("Right")
+    ///         .alignment(Alignment::Right)
+    ///         .position(Position::Bottom),
+    /// ]);
+    ///
+    /// // Renders
+    /// // ┌Left──────────Center──────────────┐
+    /// // │                                  │
+    /// // └─────────────────────────────Right┘
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn titles<T>(mut self, titles: T) -> Self
+    where
+        T: IntoIterator,
+        T::Item: Into<Title<'a>>,
+    {
+        self.titles.extend(titles.into_iter().map(Into::into));
+        self
+    }
+
     /// Adds a title to the top of the block.
     ///
     /// You can provide any type that can be converted into [`Line`] including: strings, string
@@ -584,6 +663,37 @@ impl<'a> Block<'a> {
         self
     }
 
+    /// Joins the borders of the block with the borders already drawn under them.
+    ///
+    /// When enabled, a border symbol drawn over another line symbol is replaced by the junction
+    /// of both (e.g. `┬` where the top right corner of a block meets the top left corner of the
+    /// next one). The junction uses the line weight of this block, so when blocks with different
+    /// [`BorderType`]s meet, the block rendered last wins. Symbols which are not lines, such as
+    /// the [`BorderType::QuadrantInside`] ones or text, are never joined.
+    ///
+    /// Only borders drawn in the same cell can be joined, so the areas of the blocks must overlap
+    /// by one cell (e.g. by widening each area but the last one by one cell).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// # fn render(frame: &mut Frame) {
+    /// let block = Block::bordered().connect_borders(true);
+    /// frame.render_widget(&block, Rect::new(0, 0, 5, 3));
+    /// frame.render_widget(&block, Rect::new(4, 0, 5, 3));
+    /// # }
+    /// // Renders
+    /// // ┌───┬───┐
+    /// // │   │   │
+    /// // └───┴───┘
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn connect_borders(mut self, connect_borders: bool) -> Self {
+        self.connect_borders = connect_borders;
+        self
+    }
+
     /// Compute the inner area of a block based on its border visibility rules.
     ///
     /// # Examples
@@ -646,6 +756,23 @@ impl<'a> Block<'a> {
     }
 }
 
+/// Returns the positions of the cells on the edges of a non-empty area, each of them once
+fn perimeter(area: Rect) -> impl Iterator<Item = layout::Position> {
+    let bottom = (area.height > 1).then(|| area.bottom() - 1);
+    let right = (area.width > 1).then(|| area.right() - 1);
+    let rows = (area.left()..area.right()).flat_map(move |x| {
+        iter::once(area.top())
+            .chain(bottom)
+            .map(move |y| layout::Position::new(x, y))
+    });
+    let columns = (area.top() + 1..area.bottom().saturating_sub(1)).flat_map(move |y| {
+        iter::once(area.left())
+            .chain(right)
+            .map(move |x| layout::Position::new(x, y))
+    });
+    rows.chain(columns)
+}
+
 impl BorderType {
     /// Convert this `BorderType` into the corresponding [`Set`](border::Set) of border symbols.
     pub const fn border_symbols(border_type: Self) -> border::Set {
@@ -654,6 +781,8 @@ impl BorderType {
             Self::Rounded => border::ROUNDED,
             Self::Double => border::DOUBLE,
             Self::Thick => border::THICK,
+            Self::Dashed => border::DASHED,
+            Self::Dotted => border::DOTTED,
             Self::QuadrantInside => border::QUADRANT_INSIDE,
             Self::QuadrantOutside => border::QUADRANT_OUTSIDE,
         }
@@ -685,6 +814,24 @@ impl WidgetRef for Block<'_> {
 
 impl Block<'_> {
     fn render_borders(&self, area: Rect, buf: &mut Buffer) {
+        if !self.connect_borders {
+            self.render_sides_and_corners(area, buf);
+            return;
+        }
+        // only the line symbols can be connected, and they are all static
+        let lines: Vec<_> = perimeter(area)
+            .filter_map(|position| Some((position, line::find(buf[position].symbol())?)))
+            .collect();
+        self.render_sides_and_corners(area, buf);
+        for (position, existing) in lines {
+            let cell = &mut buf[position];
+            if let Some(junction) = line::merge(existing, cell.symbol()) {
+                cell.set_symbol(junction);
+            }
+        }
+    }
+
+    fn render_sides_and_corners(&self, area: Rect, buf: &mut Buffer) {
         self.render_left_side(area, buf);
         self.render_top_side(area, buf);
         self.render_right_side(area, buf);
@@ -701,11 +848,17 @@ impl Block<'_> {
         self.render_title_position(Position::Bottom, area, buf);
     }
 
+    /// Render the titles for one position.
+    ///
+    /// Titles are laid out by alignment in priority order: left titles first, then centered titles
+    /// in the space that is left over, and finally right titles in whatever remains. Each group
+    /// reserves the space it uses plus a single cell gap, so titles are truncated rather than drawn
+    /// over each other when the block is too narrow.
     fn render_title_position(&self, position: Position, area: Rect, buf: &mut Buffer) {
-        // NOTE: the order in which these functions are called defines the overlapping behavior
-        self.render_right_titles(position, area, buf);
-        self.render_center_titles(position, area, buf);
-        self.render_left_titles(position, area, buf);
+        let titles_area = self.titles_area(area, position);
+        let remaining = self.render_left_titles(position, titles_area, buf);
+        let remaining = self.render_center_titles(position, titles_area, remaining, buf);
+        self.render_right_titles(position, remaining, buf);
     }
 
     fn render_left_side(&self, area: Rect, buf: &mut Buffer) {
@@ -782,16 +935,14 @@ impl Block<'_> {
         }
     }
 
-    /// Render titles aligned to the right of the block
+    /// Render titles aligned to the right of the given area
     ///
-    /// Currently (due to the way lines are truncated), the right side of the leftmost title will
-    /// be cut off if the block is too small to fit all titles. This is not ideal and should be
-    /// the left side of that leftmost that is cut off. This is due to the line being truncated
-    /// incorrectly. See <https://github.com/ratatui/ratatui/issues/932>
+    /// The area is what is left by the left and centered titles, so the right titles are the first
+    /// to be truncated or hidden when the block is too small to fit all titles.
     #[allow(clippy::similar_names)]
     fn render_right_titles(&self, position: Position, area: Rect, buf: &mut Buffer) {
         let titles = self.filtered_titles(position, Alignment::Right);
-        let mut titles_area = self.titles_area(area, position);
+        let mut titles_area = area;
 
         // render titles in reverse order to align them to the right
         for title in titles.rev() {
@@ -808,7 +959,7 @@ impl Block<'_> {
                 ..titles_area
             };
             buf.set_style(title_area, self.titles_style);
-            title.content.render_ref(title_area, buf);
+            render_title(title, title_area, buf);
 
             // bump the width of the titles area to the left
             titles_area.width = titles_area
@@ -820,48 +971,63 @@ impl Block<'_> {
 
     /// Render titles in the center of the block
     ///
-    /// Currently this method aligns the titles to the left inside a centered area. This is not
-    /// ideal and should be fixed in the future to align the titles to the center of the block and
-    /// truncate both sides of the titles if the block is too small to fit all titles.
+    /// The titles are centered on the full width of the block (`titles_area`), but are moved and
+    /// truncated as needed to stay within the `available` area. Returns the area to the right of
+    /// the rendered titles that is still free.
     #[allow(clippy::similar_names)]
-    fn render_center_titles(&self, position: Position, area: Rect, buf: &mut Buffer) {
+    fn render_center_titles(
+        &self,
+        position: Position,
+        titles_area: Rect,
+        available: Rect,
+        buf: &mut Buffer,
+    ) -> Rect {
         let titles = self
             .filtered_titles(position, Alignment::Center)
             .collect_vec();
+        if titles.is_empty() {
+            return available;
+        }
         let total_width = titles
             .iter()
             .map(|title| title.content.width() as u16 + 1) // space between titles
             .sum::<u16>()
             .saturating_sub(1); // no space for the last title
 
-        let titles_area = self.titles_area(area, position);
-        let mut titles_area = Rect {
-            x: titles_area.left() + (titles_area.width.saturating_sub(total_width) / 2),
-            ..titles_area
+        let centered_x = titles_area.left() + (titles_area.width.saturating_sub(total_width) / 2);
+        let x = centered_x
+            .min(available.right().saturating_sub(total_width))
+            .max(available.left());
+        let mut area = Rect {
+            x,
+            width: available.right().saturating_sub(x),
+            ..available
         };
         for title in titles {
-            if titles_area.is_empty() {
+            if area.is_empty() {
                 break;
             }
             let title_width = title.content.width() as u16;
             let title_area = Rect {
-                width: title_width.min(titles_area.width),
-                ..titles_area
+                width: title_width.min(area.width),
+                ..area
             };
             buf.set_style(title_area, self.titles_style);
-            title.content.render_ref(title_area, buf);
+            render_title(title, title_area, buf);
 
             // bump the titles area to the right and reduce its width
-            titles_area.x = titles_area.x.saturating_add(title_width + 1);
-            titles_area.width = titles_area.width.saturating_sub(title_width + 1);
+            area = shrink_left(area, title_width + 1);
         }
+        area
     }
 
     /// Render titles aligned to the left of the block
+    ///
+    /// Returns the area to the right of the rendered titles that is still free.
     #[allow(clippy::similar_names)]
-    fn render_left_titles(&self, position: Position, area: Rect, buf: &mut Buffer) {
+    fn render_left_titles(&self, position: Position, area: Rect, buf: &mut Buffer) -> Rect {
         let titles = self.filtered_titles(position, Alignment::Left);
-        let mut titles_area = self.titles_area(area, position);
+        let mut titles_area = area;
         for title in titles {
             if titles_area.is_empty() {
                 break;
@@ -872,12 +1038,12 @@ impl Block<'_> {
                 ..titles_area
             };
             buf.set_style(title_area, self.titles_style);
-            title.content.render_ref(title_area, buf);
+            render_title(title, title_area, buf);
 
             // bump the titles area to the right and reduce its width
-            titles_area.x = titles_area.x.saturating_add(title_width + 1);
-            titles_area.width = titles_area.width.saturating_sub(title_width + 1);
+            titles_area = shrink_left(titles_area, title_width + 1);
         }
+        titles_area
     }
 
     /// An iterator over the titles that match the position and alignment
@@ -941,6 +1107,59 @@ impl Block<'_> {
     }
 }
 
+/// The number of blank cells between the end and the start of a scrolling title
+const TITLE_SCROLL_GAP: u16 = 3;
+
+/// Renders the content of a title in the given area
+///
+/// A title which is wider than the area is displayed from its [scroll offset](Title::scroll),
+/// wrapping around to its start after a few blank cells, like a marquee.
+fn render_title(title: &Title, area: Rect, buf: &mut Buffer) {
+    let title_width = title.content.width();
+    if title_width <= usize::from(area.width) || area.is_empty() {
+        title.content.render_ref(area, buf);
+        return;
+    }
+    // the columns of the title followed by the gap, with `None` for the columns hidden by wide
+    // characters
+    let mut columns = Vec::with_capacity(title_width + usize::from(TITLE_SCROLL_GAP));
+    for grapheme in title.content.styled_graphemes(Style::default()) {
+        let width = grapheme.symbol.width();
+        if width > 0 {
+            columns.push(Some((grapheme.symbol, grapheme.style, width)));
+            columns.extend((1..width).map(|_| None));
+        }
+    }
+    columns.extend((0..TITLE_SCROLL_GAP).map(|_| Some((" ", Style::default(), 1))));
+    let window = columns.iter().cycle().skip(title.scroll % columns.len());
+    for (x, column) in (area.left()..area.right()).zip(window) {
+        let cell = &mut buf[(x, area.y)];
+        match *column {
+            // a wide character cut by the right edge would be drawn over the border
+            Some((symbol, style, width)) if usize::from(area.right() - x) >= width => {
+                cell.set_symbol(symbol).set_style(style);
+            }
+            _ => {
+                cell.set_symbol(" ");
+            }
+        }
+    }
+}
+
+/// Removes `width` columns from the left of `area`, never moving past its right edge.
+const fn shrink_left(area: Rect, width: u16) -> Rect {
+    let width = if width < area.width {
+        width
+    } else {
+        area.width
+    };
+    Rect {
+        x: area.x + width,
+        width: area.width - width,
+        ..area
+    }
+}
+
 /// An extension trait for [`Block`] that provides some convenience methods.
 ///
 /// This is implemented for [`Option<Block>`](Option) to simplify the common case of having a
@@ -1230,6 +1449,7 @@ mod tests {
                 border_set: BorderType::Plain.to_border_set(),
                 style: Style::new(),
                 padding: Padding::ZERO,
+                connect_borders: false,
             }
         );
     }
@@ -1347,6 +1567,53 @@ mod tests {
         assert_eq!(buffer, expected);
     }
 
+    #[test]
+    fn titles() {
+        use Alignment::*;
+        use Position::*;
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 36, 3));
+        Block::bordered()
+            .titles([
+                Title::from("Left").alignment(Left),
+                Title::from("Center").alignment(Center),
+                Title::from("Right").alignment(Right).position(Bottom),
+            ])
+            .render(buffer.area, &mut buffer);
+        #[rustfmt::skip]
+        let expected = Buffer::with_lines([
+            "┌Left──────────Center──────────────┐",
+            "│                                  │",
+            "└─────────────────────────────Right┘",
+        ]);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn titles_accepts_strings() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 9, 1));
+        Block::new()
+            .titles(["foo", "bar"])
+            .render(buffer.area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines(["foo bar  "]));
+    }
+
+    #[rstest]
+    #[case::left_and_right(&["left", "", "right"], "left righ")]
+    #[case::left_and_center(&["left", "center", ""], "left cent")]
+    #[case::center_and_right(&["", "center", "right"], " center r")]
+    #[case::all(&["ab", "cd", "ef"], "ab cd  ef")]
+    fn titles_do_not_overlap(#[case] titles: &[&str], #[case] expected: &str) {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 9, 1));
+        let alignments = [Alignment::Left, Alignment::Center, Alignment::Right];
+        let titles = titles
+            .iter()
+            .zip(alignments)
+            .filter(|(title, _)| !title.is_empty())
+            .map(|(title, alignment)| Title::from(*title).alignment(alignment));
+        Block::new().titles(titles).render(buffer.area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines([expected]));
+    }
+
     #[test]
     fn title_alignment() {
         let tests = vec![
@@ -1440,6 +1707,61 @@ mod tests {
         }
     }
 
+    #[rstest]
+    #[case::start(0, "┌abcdef┐")]
+    #[case::end(4, "┌efghij┐")]
+    #[case::gap(7, "┌hij   ┐")]
+    #[case::wrap_around(11, "┌  abcd┐")]
+    #[case::period(13, "┌abcdef┐")]
+    #[case::multiple_periods(30, "┌efghij┐")]
+    fn scrolling_title(#[case] offset: usize, #[case] expected: &str) {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 8, 1));
+        Block::new()
+            .borders(Borders::TOP | Borders::LEFT | Borders::RIGHT)
+            .title(Title::from("abcdefghij").scroll(offset))
+            .render(buffer.area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines([expected]));
+    }
+
+    #[rstest]
+    #[case::left(Alignment::Left, "┌test────┐")]
+    #[case::center(Alignment::Center, "┌──test──┐")]
+    #[case::right(Alignment::Right, "┌────test┐")]
+    fn scrolling_title_that_fits_is_aligned(#[case] alignment: Alignment, #[case] expected: &str) {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 1));
+        Block::new()
+            .borders(Borders::TOP | Borders::LEFT | Borders::RIGHT)
+            .title(Title::from("test").alignment(alignment).scroll(2))
+            .render(buffer.area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines([expected]));
+    }
+
+    #[test]
+    fn scrolling_title_style() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 6, 1));
+        Block::new()
+            .title_style(Style::new().on_red())
+            .title(Title::from("abcdefgh".yellow()).scroll(6))
+            .render(buffer.area, &mut buffer);
+        let mut expected = Buffer::with_lines(["gh   a"]);
+        expected.set_style(Rect::new(0, 0, 6, 1), Style::new().on_red());
+        expected.set_style(Rect::new(0, 0, 2, 1), Style::new().yellow());
+        expected.set_style(Rect::new(5, 0, 1, 1), Style::new().yellow());
+        assert_eq!(buffer, expected);
+    }
+
+    #[rstest]
+    #[case::cut_at_the_end(0, "┌ab中 ┐")]
+    #[case::cut_at_the_start(3, "┌ 文字┐")]
+    fn scrolling_title_with_wide_characters(#[case] offset: usize, #[case] expected: &str) {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 7, 1));
+        Block::new()
+            .borders(Borders::TOP | Borders::LEFT | Borders::RIGHT)
+            .title(Title::from("ab中文字cd").scroll(offset))
+            .render(buffer.area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines([expected]));
+    }
+
     #[test]
     fn title_border_style() {
         let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 3));
@@ -1464,6 +1786,8 @@ mod tests {
         assert_eq!(format!("{}", BorderType::Rounded), "Rounded");
         assert_eq!(format!("{}", BorderType::Double), "Double");
         assert_eq!(format!("{}", BorderType::Thick), "Thick");
+        assert_eq!(format!("{}", BorderType::Dashed), "Dashed");
+        assert_eq!(format!("{}", BorderType::Dotted), "Dotted");
     }
 
     #[test]
@@ -1472,6 +1796,8 @@ mod tests {
         assert_eq!("Rounded".parse(), Ok(BorderType::Rounded));
         assert_eq!("Double".parse(), Ok(BorderType::Double));
         assert_eq!("Thick".parse(), Ok(BorderType::Thick));
+        assert_eq!("Dashed".parse(), Ok(BorderType::Dashed));
+        assert_eq!("Dotted".parse(), Ok(BorderType::Dotted));
         assert_eq!("".parse::<BorderType>(), Err(ParseError::VariantNotFound));
     }
 
@@ -1565,6 +1891,53 @@ mod tests {
         assert_eq!(buffer, expected);
     }
 
+    #[test]
+    fn render_dashed_border() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 3));
+        Block::bordered()
+            .border_type(BorderType::Dashed)
+            .render(buffer.area, &mut buffer);
+        #[rustfmt::skip]
+        let expected = Buffer::with_lines([
+            "┌┄┄┄┄┄┄┄┄┐",
+            "┆        ┆",
+            "└┄┄┄┄┄┄┄┄┘",
+        ]);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn render_dotted_border() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 3));
+        Block::bordered()
+            .border_type(BorderType::Dotted)
+            .render(buffer.area, &mut buffer);
+        #[rustfmt::skip]
+        let expected = Buffer::with_lines([
+            "┌┈┈┈┈┈┈┈┈┐",
+            "┊        ┊",
+            "└┈┈┈┈┈┈┈┈┘",
+        ]);
+        assert_eq!(buffer, expected);
+    }
+
+    #[rstest]
+    #[case::single_row(Rect::new(0, 0, 4, 1), ["┌┄┄┐"])]
+    #[case::single_column(Rect::new(0, 0, 1, 3), ["┌", "┆", "└"])]
+    fn render_dashed_border_in_single_row_or_column<'line, Lines>(
+        #[case] area: Rect,
+        #[case] expected: Lines,
+    ) where
+        Lines: IntoIterator,
+        Lines::Item: Into<Line<'line>>,
+    {
+        let mut buffer = Buffer::empty(area);
+        Block::bordered()
+            .border_type(BorderType::Dashed)
+            .render(buffer.area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines(expected));
+    }
+
     #[test]
     fn render_custom_border_set() {
         let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 3));
@@ -1588,4 +1961,103 @@ mod tests {
         ]);
         assert_eq!(buffer, expected);
     }
+
+    #[test]
+    fn connect_borders_side_by_side() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 9, 3));
+        let block = Block::bordered().connect_borders(true);
+        block.clone().render(Rect::new(0, 0, 5, 3), &mut buffer);
+        block.render(Rect::new(4, 0, 5, 3), &mut buffer);
+        #[rustfmt::skip]
+        let expected = Buffer::with_lines([
+            "┌───┬───┐",
+            "│   │   │",
+            "└───┴───┘",
+        ]);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn connect_borders_grid() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 7, 5));
+        let block = Block::bordered().connect_borders(true);
+        for (x, y) in [(0, 0), (3, 0), (0, 2), (3, 2)] {
+            block.clone().render(Rect::new(x, y, 4, 3), &mut buffer);
+        }
+        #[rustfmt::skip]
+        let expected = Buffer::with_lines([
+            "┌──┬──┐",
+            "│  │  │",
+            "├──┼──┤",
+            "│  │  │",
+            "└──┴──┘",
+        ]);
+        assert_eq!(buffer, expected);
+    }
+
+    #[rstest]
+    #[case::area(Rect::new(1, 1, 4, 3), 10)]
+    #[case::single_row(Rect::new(1, 1, 4, 1), 4)]
+    #[case::single_column(Rect::new(1, 1, 1, 3), 3)]
+    #[case::single_cell(Rect::new(1, 1, 1, 1), 1)]
+    fn perimeter_visits_each_edge_once(#[case] area: Rect, #[case] count: usize) {
+        let positions: Vec<_> = perimeter(area).collect();
+        assert_eq!(positions.len(), count);
+        assert!(positions.iter().all_unique());
+        assert!(positions.iter().all(|&position| area.contains(position)
+            && (position.x == area.left()
+                || position.x == area.right() - 1
+                || position.y == area.top()
+                || position.y == area.bottom() - 1)));
+    }
+
+    #[test]
+    fn connect_borders_keeps_the_inside_lines() {
+        let mut buffer = Buffer::with_lines(["     ", "  ─  ", "     "]);
+        Block::bordered()
+            .connect_borders(true)
+            .render(buffer.area, &mut buffer);
+        #[rustfmt::skip]
+        let expected = Buffer::with_lines([
+            "┌───┐",
+            "│ ─ │",
+            "└───┘",
+        ]);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn connect_borders_uses_last_border_type() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 9, 3));
+        Block::bordered()
+            .connect_borders(true)
+            .render(Rect::new(0, 0, 5, 3), &mut buffer);
+        Block::bordered()
+            .border_type(BorderType::Thick)
+            .connect_borders(true)
+            .render(Rect::new(4, 0, 5, 3), &mut buffer);
+        #[rustfmt::skip]
+        let expected = Buffer::with_lines([
+            "┌───┳━━━┓",
+            "│   ┃   ┃",
+            "└───┻━━━┛",
+        ]);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn connect_borders_ignores_other_symbols() {
+        let mut buffer = Buffer::with_lines(["x────", "     ", "     "]);
+        Block::bordered()
+            .connect_borders(true)
+            .render(Rect::new(0, 0, 3, 3), &mut buffer);
+        Block::bordered().render(Rect::new(2, 0, 3, 3), &mut buffer);
+        #[rustfmt::skip]
+        let expected = Buffer::with_lines([
+            "┌─┌─┐",
+            "│ │ │",
+            "└─└─┘",
+        ]);
+        assert_eq!(buffer, expected);
+    }
 }
@@ -63,6 +63,12 @@ pub struct Title<'a> {
     /// [`Block::title_position`](crate::widgets::Block::title_position) in the associated
     /// [`Block`](crate::widgets::Block).
     pub position: Option<Position>,
+
+    /// Title scroll offset
+    ///
+    /// When the title is too long to fit in the block, it scrolls horizontally by this number of
+    /// cells, wrapping around to its start. See [`Title::scroll`].
+    pub scroll: usize,
 }
 
 /// Defines the [title](crate::widgets::block::Title) position.
@@ -112,6 +118,29 @@ impl<'a> Title<'a> {
         self.position = Some(position);
         self
     }
+
+    /// Set the title scroll offset, to display a title which is too long to fit in the block as a
+    /// marquee.
+    ///
+    /// When the title is wider than the space available in the border of the block, the title is
+    /// displayed from the given offset and wraps around to its start, after a few blank cells.
+    /// Increasing the offset on each frame makes the title scroll. A title which fits is displayed
+    /// as usual and the offset is ignored.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui::{prelude::*, widgets::{block::Title, *}};
+    ///
+    /// # let tick = 0;
+    /// let title = Title::from("A very long status message").scroll(tick);
+    /// let block = Block::bordered().title(title);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn scroll(mut self, offset: usize) -> Self {
+        self.scroll = offset;
+        self
+    }
 }
 
 impl<'a, T> From<T> for Title<'a>
@@ -125,6 +154,7 @@ where
             content,
             alignment,
             position: None,
+            scroll: 0,
         }
     }
 }
@@ -155,6 +185,13 @@ mod tests {
         assert_eq!(title.content, Line::from("Title"));
         assert_eq!(title.alignment, None);
         assert_eq!(title.position, None);
+        assert_eq!(title.scroll, 0);
+    }
+
+    #[test]
+    fn title_scroll() {
+        let title = Title::from("Title").scroll(3);
+        assert_eq!(title.scroll, 3);
     }
 
     #[rstest]
@@ -5,15 +5,27 @@
 //! The [`Monthly`] widget will display a calendar for the month provided in `display_date`. Days
 //! are styled using the default style unless:
 //! * `show_surrounding` is set, then days not in the `display_date` month will use that style.
+//! * `events_by_count` is set, then days with events are shaded using the `color_ramp`.
 //! * a style is returned by the [`DateStyler`] for the day
 //!
 //! [`Monthly`] has several controls for what should be displayed
-use std::collections::HashMap;
+use std::{
+    borrow::Cow,
+    collections::{BTreeMap, HashMap},
+};
 
 use time::{Date, Duration, OffsetDateTime};
 
 use crate::{prelude::*, widgets::Block};
 
+/// The color ramp used to shade days by their event count, from the least to the most busy day.
+const DEFAULT_COLOR_RAMP: [Color; 4] = [
+    Color::Rgb(14, 68, 41),
+    Color::Rgb(0, 109, 50),
+    Color::Rgb(38, 166, 65),
+    Color::Rgb(57, 211, 83),
+];
+
 /// Display a month calendar for the month containing `display_date`
 #[derive(Debug, Clone, Eq, PartialEq, Hash)]
 pub struct Monthly<'a, DS: DateStyler> {
@@ -23,6 +35,8 @@ pub struct Monthly<'a, DS: DateStyler> {
     show_weekday: Option<Style>,
     show_month: Option<Style>,
     default_style: Style,
+    event_counts: BTreeMap<Date, u32>,
+    color_ramp: Cow<'a, [Color]>,
     block: Option<Block<'a>>,
 }
 
@@ -36,6 +50,8 @@ impl<'a, DS: DateStyler> Monthly<'a, DS> {
             show_weekday: None,
             show_month: None,
             default_style: Style::new(),
+            event_counts: BTreeMap::new(),
+            color_ramp: Cow::Borrowed(&DEFAULT_COLOR_RAMP),
             block: None,
         }
     }
@@ -82,6 +98,56 @@ impl<'a, DS: DateStyler> Monthly<'a, DS> {
         self
     }
 
+    /// Shade each day by its number of events, like a contribution graph
+    ///
+    /// The background of each day with events is set to a color of the
+    /// [`color_ramp`](Self::color_ramp), the day with the most events in the displayed month using
+    /// the last (brightest) color. Days without events use the default style. Styles returned by
+    /// the [`DateStyler`] are applied on top of the shading, so dates with an explicit background
+    /// keep it.
+    ///
+    /// `counts` accepts any iterator of `(Date, u32)` pairs, such as a `HashMap<Date, u32>`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::collections::HashMap;
+    ///
+    /// use ratatui::widgets::calendar::{CalendarEventStore, Monthly};
+    /// use time::{Date, Month};
+    ///
+    /// let date = Date::from_calendar_date(2023, Month::January, 1).unwrap();
+    /// let counts = HashMap::from([(date, 3), (date.next_day().unwrap(), 1)]);
+    /// let calendar = Monthly::new(date, CalendarEventStore::default()).events_by_count(counts);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn events_by_count<I>(mut self, counts: I) -> Self
+    where
+        I: IntoIterator<Item = (Date, u32)>,
+    {
+        self.event_counts = counts.into_iter().collect();
+        self
+    }
+
+    /// Set the colors used to shade days by their number of events
+    ///
+    /// The colors are ordered from the least to the most busy day. Event counts are scaled so that
+    /// the day with the most events in the displayed month uses the last color. Defaults to a ramp
+    /// of four shades of green.
+    ///
+    /// `colors` accepts any iterator of items that are convertible to [`Color`].
+    ///
+    /// See [`Monthly::events_by_count`].
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn color_ramp<I>(mut self, colors: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<Color>,
+    {
+        self.color_ramp = Cow::Owned(colors.into_iter().map(Into::into).collect());
+        self
+    }
+
     /// Render the calendar within a [Block]
     #[must_use = "method moves the value of self and returns the modified value"]
     pub fn block(mut self, block: Block<'a>) -> Self {
@@ -97,12 +163,38 @@ impl<'a, DS: DateStyler> Monthly<'a, DS> {
         }
     }
 
+    /// The highest event count of the days in the displayed month
+    fn max_event_count(&self) -> u32 {
+        let month = self.display_date.month();
+        self.event_counts
+            .iter()
+            .filter(|(date, _)| date.year() == self.display_date.year() && date.month() == month)
+            .map(|(_, count)| *count)
+            .max()
+            .unwrap_or_default()
+    }
+
+    /// The style used to shade a date by its event count, given the highest count of the month
+    fn event_count_style(&self, date: Date, max_count: u32) -> Style {
+        let count = self.event_counts.get(&date).copied().unwrap_or_default();
+        if count == 0 || self.color_ramp.is_empty() {
+            return Style::new();
+        }
+        let len = self.color_ramp.len() as u64;
+        let max_count = u64::from(max_count.max(1));
+        let index = (u64::from(count) * len).div_ceil(max_count).clamp(1, len) - 1;
+        Style::new().bg(self.color_ramp[index as usize])
+    }
+
     /// All logic to style a date goes here.
-    fn format_date(&self, date: Date) -> Span {
+    fn format_date(&self, date: Date, max_count: u32) -> Span {
+        let count_style = self.event_count_style(date, max_count);
         if date.month() == self.display_date.month() {
             Span::styled(
                 format!("{:2?}", date.day()),
-                self.default_style.patch(self.events.get_style(date)),
+                self.default_style
+                    .patch(count_style)
+                    .patch(self.events.get_style(date)),
             )
         } else {
             match self.show_surrounding {
@@ -111,6 +203,7 @@ impl<'a, DS: DateStyler> Monthly<'a, DS> {
                     let style = self
                         .default_style
                         .patch(s)
+                        .patch(count_style)
                         .patch(self.events.get_style(date));
                     Span::styled(format!("{:2?}", date.day()), style)
                 }
@@ -161,6 +254,7 @@ impl<DS: DateStyler> Monthly<'_, DS> {
         let first_of_month = self.display_date.replace_day(1).unwrap();
         let offset = Duration::days(first_of_month.weekday().number_days_from_sunday().into());
         let mut curr_day = first_of_month - offset;
+        let max_count = self.max_event_count();
 
         let mut y = days_area.y;
         // go through all the weeks containing a day in the target month.
@@ -174,7 +268,7 @@ impl<DS: DateStyler> Monthly<'_, DS> {
                 } else {
                     spans.push(Span::styled(" ", self.default_bg()));
                 }
-                spans.push(self.format_date(curr_day));
+                spans.push(self.format_date(curr_day, max_count));
                 curr_day += Duration::DAY;
             }
             if buf.area.height > y {
@@ -276,6 +370,49 @@ mod tests {
         );
     }
 
+    #[test]
+    fn event_count_style() {
+        let date = |day| Date::from_calendar_date(2023, Month::January, day).unwrap();
+        let calendar = Monthly::new(date(1), CalendarEventStore::default())
+            .events_by_count([(date(1), 1), (date(2), 2), (date(3), 4), (date(4), 6)])
+            .color_ramp([Color::Red, Color::Green, Color::Blue]);
+        let max_count = calendar.max_event_count();
+        assert_eq!(max_count, 6);
+        let styles = (1..=5)
+            .map(|day| calendar.event_count_style(date(day), max_count))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            styles,
+            [
+                Style::new().bg(Color::Red),
+                Style::new().bg(Color::Red),
+                Style::new().bg(Color::Green),
+                Style::new().bg(Color::Blue),
+                Style::new(),
+            ]
+        );
+    }
+
+    #[test]
+    fn max_event_count_ignores_other_months() {
+        let date = Date::from_calendar_date(2023, Month::January, 31).unwrap();
+        let calendar = Monthly::new(date, CalendarEventStore::default()).events_by_count([
+            (date, 2),
+            (date.next_day().unwrap(), 5),
+            (date.replace_year(2022).unwrap(), 7),
+        ]);
+        assert_eq!(calendar.max_event_count(), 2);
+    }
+
+    #[test]
+    fn event_count_style_with_empty_ramp() {
+        let date = Date::from_calendar_date(2023, Month::January, 1).unwrap();
+        let calendar = Monthly::new(date, CalendarEventStore::default())
+            .events_by_count([(date, 1)])
+            .color_ramp(Vec::<Color>::new());
+        assert_eq!(calendar.event_count_style(date, 1), Style::new());
+    }
+
     #[test]
     fn test_today() {
         CalendarEventStore::today(Style::default());
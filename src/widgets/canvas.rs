@@ -19,9 +19,10 @@ mod points;
 mod rectangle;
 mod world;
 
-use std::{fmt, iter::zip};
+use std::{fmt, iter::zip, sync::Arc};
 
 use itertools::Itertools;
+use unicode_segmentation::UnicodeSegmentation;
 
 pub use self::{
     circle::Circle,
@@ -30,7 +31,12 @@ pub use self::{
     points::Points,
     rectangle::Rectangle,
 };
-use crate::{prelude::*, symbols::Marker, text::Line as TextLine, widgets::Block};
+use crate::{
+    prelude::*,
+    symbols::{Marker, PointSymbol},
+    text::Line as TextLine,
+    widgets::Block,
+};
 
 /// Something that can be drawn on a [`Canvas`].
 ///
@@ -51,6 +57,59 @@ pub struct Label<'a> {
     line: TextLine<'a>,
 }
 
+/// The symbols used to draw the points of a [`Canvas`]: either one of the built-in [`Marker`]s
+/// or a custom [`PointSymbol`], whichever was set last.
+///
+/// The built-in marker is kept apart from the custom symbol so that it can be set in a const
+/// context, which can't drop the custom symbol.
+#[derive(Debug, Default, Clone)]
+pub(crate) struct PointMarker {
+    /// The built-in marker
+    pub(crate) builtin: Marker,
+    /// The custom symbol, if any
+    pub(crate) custom: Option<Arc<dyn PointSymbol>>,
+    /// Whether the custom symbol is used rather than the built-in marker
+    pub(crate) use_custom: bool,
+}
+
+impl PointMarker {
+    /// Creates a `PointMarker` which uses the given built-in marker
+    pub(crate) const fn new(marker: Marker) -> Self {
+        Self {
+            builtin: marker,
+            custom: None,
+            use_custom: false,
+        }
+    }
+
+    /// Returns the custom symbol, if it is used rather than the built-in marker
+    fn custom(&self) -> Option<&Arc<dyn PointSymbol>> {
+        self.custom.as_ref().filter(|_| self.use_custom)
+    }
+}
+
+impl From<Marker> for PointMarker {
+    fn from(marker: Marker) -> Self {
+        Self::new(marker)
+    }
+}
+
+impl PartialEq for PointMarker {
+    fn eq(&self, other: &Self) -> bool {
+        match (self.custom(), other.custom()) {
+            (None, None) => self.builtin == other.builtin,
+            (Some(a), Some(b)) => Arc::ptr_eq(a, b),
+            _ => false,
+        }
+    }
+}
+
+impl PartialEq<Marker> for PointMarker {
+    fn eq(&self, other: &Marker) -> bool {
+        self.custom().is_none() && self.builtin == *other
+    }
+}
+
 /// A single layer of the canvas.
 ///
 /// This allows the canvas to be drawn in multiple layers. This is useful if you want to draw
@@ -156,6 +215,75 @@ impl Grid for BrailleGrid {
     }
 }
 
+/// The `QuadrantGrid` is a grid made up of cells each containing a quadrant block character.
+///
+/// This makes it possible to draw shapes with a resolution of 2x2 pixels per cell. Like the
+/// `BrailleGrid`, this grid type only supports a single foreground color for each cell.
+#[derive(Debug)]
+struct QuadrantGrid {
+    /// Width of the grid in number of terminal columns
+    width: u16,
+    /// Height of the grid in number of terminal rows
+    height: u16,
+    /// The pixels painted in each cell, as a bit mask indexing [`QuadrantGrid::SYMBOLS`]
+    pixels: Vec<u8>,
+    /// The color of each cell
+    colors: Vec<Color>,
+}
+
+impl QuadrantGrid {
+    /// The bit of each pixel of a cell, arranged in row, column order
+    const PIXELS: [[u8; 2]; 2] = [[0b0001, 0b0010], [0b0100, 0b1000]];
+
+    /// The symbol for each combination of pixels
+    const SYMBOLS: [char; 16] = [
+        ' ', '▘', '▝', '▀', '▖', '▌', '▞', '▛', '▗', '▚', '▐', '▜', '▄', '▙', '▟', '█',
+    ];
+
+    /// Create a new `QuadrantGrid` with the given width and height measured in terminal columns
+    /// and rows respectively.
+    fn new(width: u16, height: u16) -> Self {
+        let length = usize::from(width * height);
+        Self {
+            width,
+            height,
+            pixels: vec![0; length],
+            colors: vec![Color::Reset; length],
+        }
+    }
+}
+
+impl Grid for QuadrantGrid {
+    fn resolution(&self) -> (f64, f64) {
+        (f64::from(self.width) * 2.0, f64::from(self.height) * 2.0)
+    }
+
+    fn save(&self) -> Layer {
+        let string = self
+            .pixels
+            .iter()
+            .map(|&pixels| Self::SYMBOLS[usize::from(pixels)])
+            .collect();
+        let colors = self.colors.iter().map(|c| (*c, Color::Reset)).collect();
+        Layer { string, colors }
+    }
+
+    fn reset(&mut self) {
+        self.pixels.fill(0);
+        self.colors.fill(Color::Reset);
+    }
+
+    fn paint(&mut self, x: usize, y: usize, color: Color) {
+        let index = y / 2 * self.width as usize + x / 2;
+        if let Some(pixels) = self.pixels.get_mut(index) {
+            *pixels |= Self::PIXELS[y % 2][x % 2];
+        }
+        if let Some(c) = self.colors.get_mut(index) {
+            *c = color;
+        }
+    }
+}
+
 /// The `CharGrid` is a grid made up of cells each containing a single character.
 ///
 /// This makes it possible to draw shapes with a resolution of 1x1 dots per cell. This is useful
@@ -219,6 +347,91 @@ impl Grid for CharGrid {
     }
 }
 
+/// The `SymbolGrid` is a grid made up of cells each containing a glyph of a custom
+/// [`PointSymbol`].
+///
+/// The resolution of the grid is given by the [`PointSymbol`], and each cell shows the glyph of
+/// the last point painted in it.
+#[derive(Debug)]
+struct SymbolGrid {
+    /// Width of the grid in number of terminal columns
+    width: u16,
+    /// Height of the grid in number of terminal rows
+    height: u16,
+    /// The symbols to use for the points
+    symbol: Arc<dyn PointSymbol>,
+    /// The position of the last point painted within each cell
+    cells: Vec<Option<(u16, u16)>>,
+    /// The color of each cell
+    colors: Vec<Color>,
+}
+
+impl SymbolGrid {
+    /// Create a new `SymbolGrid` with the given width and height measured in terminal columns and
+    /// rows respectively.
+    fn new(width: u16, height: u16, symbol: Arc<dyn PointSymbol>) -> Self {
+        let length = usize::from(width * height);
+        Self {
+            width,
+            height,
+            symbol,
+            cells: vec![None; length],
+            colors: vec![Color::Reset; length],
+        }
+    }
+
+    /// Returns the number of points per cell, horizontally and vertically, which is at least one.
+    fn cell_resolution(&self) -> (u16, u16) {
+        let (x, y) = self.symbol.resolution();
+        (x.max(1), y.max(1))
+    }
+}
+
+impl Grid for SymbolGrid {
+    fn resolution(&self) -> (f64, f64) {
+        let (x, y) = self.cell_resolution();
+        (
+            f64::from(self.width) * f64::from(x),
+            f64::from(self.height) * f64::from(y),
+        )
+    }
+
+    fn save(&self) -> Layer {
+        let string = self
+            .cells
+            .iter()
+            .map(|cell| {
+                cell.and_then(|(x, y)| self.symbol.symbol(x, y).graphemes(true).next())
+                    .unwrap_or(" ")
+            })
+            .collect();
+        Layer {
+            string,
+            colors: self.colors.iter().map(|c| (*c, Color::Reset)).collect(),
+        }
+    }
+
+    fn reset(&mut self) {
+        self.cells.fill(None);
+        self.colors.fill(Color::Reset);
+    }
+
+    fn paint(&mut self, x: usize, y: usize, color: Color) {
+        let (resolution_x, resolution_y) = self.cell_resolution();
+        let (resolution_x, resolution_y) = (usize::from(resolution_x), usize::from(resolution_y));
+        let index = y / resolution_y * self.width as usize + x / resolution_x;
+        let position = ((x % resolution_x) as u16, (y % resolution_y) as u16);
+        // using get_mut here because we are indexing the vector with usize values
+        // and we want to make sure we don't panic if the index is out of bounds
+        if let Some(c) = self.cells.get_mut(index) {
+            *c = Some(position);
+        }
+        if let Some(c) = self.colors.get_mut(index) {
+            *c = color;
+        }
+    }
+}
+
 /// The `HalfBlockGrid` is a grid made up of cells each containing a half block character.
 ///
 /// In terminals, each character is usually twice as tall as it is wide. Unicode has a couple of
@@ -432,7 +645,8 @@ pub struct Context<'a> {
     y_bounds: [f64; 2],
     grid: Box<dyn Grid>,
     dirty: bool,
-    layers: Vec<Layer>,
+    z_index: i16,
+    layers: Vec<(i16, Layer)>,
     labels: Vec<Label<'a>>,
 }
 
@@ -465,27 +679,58 @@ impl<'a> Context<'a> {
         x_bounds: [f64; 2],
         y_bounds: [f64; 2],
         marker: Marker,
+    ) -> Self {
+        Self::with_point_marker(width, height, x_bounds, y_bounds, &PointMarker::new(marker))
+    }
+
+    /// Create a new Context which draws the points with the given [`PointMarker`]
+    fn with_point_marker(
+        width: u16,
+        height: u16,
+        x_bounds: [f64; 2],
+        y_bounds: [f64; 2],
+        marker: &PointMarker,
     ) -> Self {
         let dot = symbols::DOT.chars().next().unwrap();
         let block = symbols::block::FULL.chars().next().unwrap();
         let bar = symbols::bar::HALF.chars().next().unwrap();
-        let grid: Box<dyn Grid> = match marker {
-            Marker::Dot => Box::new(CharGrid::new(width, height, dot)),
-            Marker::Block => Box::new(CharGrid::new(width, height, block)),
-            Marker::Bar => Box::new(CharGrid::new(width, height, bar)),
-            Marker::Braille => Box::new(BrailleGrid::new(width, height)),
-            Marker::HalfBlock => Box::new(HalfBlockGrid::new(width, height)),
+        let grid: Box<dyn Grid> = match (marker.custom(), marker.builtin) {
+            (Some(symbol), _) => Box::new(SymbolGrid::new(width, height, Arc::clone(symbol))),
+            (None, Marker::Dot) => Box::new(CharGrid::new(width, height, dot)),
+            (None, Marker::Block) => Box::new(CharGrid::new(width, height, block)),
+            (None, Marker::Bar) => Box::new(CharGrid::new(width, height, bar)),
+            (None, Marker::Braille) => Box::new(BrailleGrid::new(width, height)),
+            (None, Marker::HalfBlock) => Box::new(HalfBlockGrid::new(width, height)),
+            (None, Marker::Quadrant) => Box::new(QuadrantGrid::new(width, height)),
         };
         Self {
             x_bounds,
             y_bounds,
             grid,
             dirty: false,
+            z_index: 0,
             layers: Vec::new(),
             labels: Vec::new(),
         }
     }
 
+    /// Returns the resolution of the grid the shapes are drawn on, in number of points
+    ///
+    /// This is the size of the area in cells multiplied by the number of points per cell of the
+    /// marker, e.g. 2x4 points per cell for [`Marker::Braille`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui::{symbols::Marker, widgets::canvas::Context};
+    ///
+    /// let ctx = Context::new(10, 5, [0.0, 1.0], [0.0, 1.0], Marker::Quadrant);
+    /// assert_eq!(ctx.resolution(), (20.0, 10.0));
+    /// ```
+    pub fn resolution(&self) -> (f64, f64) {
+        self.grid.resolution()
+    }
+
     /// Draw the given [`Shape`] in this context
     pub fn draw<S>(&mut self, shape: &S)
     where
@@ -502,13 +747,55 @@ impl<'a> Context<'a> {
     /// state for the next layer.
     ///
     /// This allows the canvas to be drawn in multiple layers. This is useful if you want to
-    /// draw multiple shapes on the [`Canvas`] in specific order.
+    /// draw multiple shapes on the [`Canvas`] in specific order. Layers are rendered in order of
+    /// their [z-index](Self::set_z_index), and layers with the same z-index are rendered in the
+    /// order they were saved.
     pub fn layer(&mut self) {
-        self.layers.push(self.grid.save());
+        self.layers.push((self.z_index, self.grid.save()));
         self.grid.reset();
         self.dirty = false;
     }
 
+    /// Set the z-index of the shapes drawn from now on.
+    ///
+    /// The shapes drawn so far are saved as a [layer](Self::layer) first, so that they keep their
+    /// own z-index. Layers with a higher z-index are rendered on top of layers with a lower one,
+    /// whatever the order in which they were drawn. The z-index defaults to `0`.
+    ///
+    /// With the [`Braille`](Marker::Braille) marker, the dots of a layer are added to the dots of
+    /// the layers below it in the same cell, and the cell takes the color of the topmost layer.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui::{style::Color, widgets::canvas::*};
+    ///
+    /// Canvas::default()
+    ///     .x_bounds([0.0, 10.0])
+    ///     .y_bounds([0.0, 10.0])
+    ///     .paint(|ctx| {
+    ///         ctx.draw(&Points {
+    ///             coords: &[(5.0, 5.0)],
+    ///             color: Color::Red,
+    ///         });
+    ///         // the grid is drawn after the points but rendered below them
+    ///         ctx.set_z_index(-1);
+    ///         ctx.draw(&Line {
+    ///             x1: 0.0,
+    ///             y1: 5.0,
+    ///             x2: 10.0,
+    ///             y2: 5.0,
+    ///             color: Color::DarkGray,
+    ///         });
+    ///     });
+    /// ```
+    pub fn set_z_index(&mut self, z_index: i16) {
+        if self.dirty {
+            self.layer();
+        }
+        self.z_index = z_index;
+    }
+
     /// Print a [`Text`] on the [`Canvas`] at the given position.
     ///
     /// Note that the text is always printed on top of the canvas and is **not** affected by the
@@ -554,7 +841,8 @@ impl<'a> Context<'a> {
 /// The [`Context`] object provides a [`Context::draw`] method that can be used to draw shapes on
 /// the canvas. The [`Context::layer`] method can be used to save the current state of the canvas
 /// and start a new layer. This is useful if you want to draw multiple shapes on the canvas in
-/// specific order. The [`Context`] object also provides a [`Context::print`] method that can be
+/// specific order. The [`Context::set_z_index`] method orders layers independently of the order in
+/// which they are drawn. The [`Context`] object also provides a [`Context::print`] method that can be
 /// used to print text on the canvas. Note that the text is always printed on top of the canvas and
 /// is not affected by the layers.
 ///
@@ -604,7 +892,8 @@ where
     y_bounds: [f64; 2],
     paint_func: Option<F>,
     background_color: Color,
-    marker: Marker,
+    marker: PointMarker,
+    aspect_ratio: Option<f64>,
 }
 
 impl<'a, F> Default for Canvas<'a, F>
@@ -618,7 +907,8 @@ where
             y_bounds: [0.0, 0.0],
             paint_func: None,
             background_color: Color::Reset,
-            marker: Marker::Braille,
+            marker: PointMarker::new(Marker::Braille),
+            aspect_ratio: None,
         }
     }
 }
@@ -671,6 +961,8 @@ where
 
     /// Change the background [`Color`] of the entire canvas
     ///
+    /// The background is filled before any layer is rendered.
+    ///
     /// This is a fluent setter method which must be chained or used as it consumes self
     #[must_use = "method moves the value of self and returns the modified value"]
     pub const fn background_color(mut self, color: Color) -> Self {
@@ -684,16 +976,30 @@ where
     /// but you might want to use the simple [`Dot`] or [`Block`] instead if the targeted terminal
     /// does not support those symbols.
     ///
+    /// The markers trade resolution for color fidelity:
+    ///
+    /// | Marker                          | Points per cell | Colors per cell                    |
+    /// |---------------------------------|-----------------|------------------------------------|
+    /// | [`Dot`], [`Block`], [`Bar`]     | 1x1             | one                                |
+    /// | [`HalfBlock`]                   | 1x2             | one per point (foreground and bg)  |
+    /// | [`Quadrant`]                    | 2x2             | one, shared by the points          |
+    /// | [`Braille`]                     | 2x4             | one, shared by the points          |
+    ///
     /// The [`HalfBlock`] marker is useful when you want to draw shapes with a higher resolution
     /// than with a grid of characters (e.g. with [`Block`] or [`Dot`]) but lower than with
     /// [`Braille`]. This grid type supports a foreground and background color for each terminal
     /// cell. This allows for more flexibility than the `BrailleGrid` which only supports a single
-    /// foreground color for each 2x4 dots cell.
+    /// foreground color for each 2x4 dots cell. The [`Quadrant`] marker doubles the horizontal
+    /// resolution of [`HalfBlock`] with solid blocks, at the cost of a single color per cell.
+    ///
+    /// Use [`Canvas::point_symbol`] to draw the points with custom glyphs instead.
     ///
     /// [`Braille`]: crate::symbols::Marker::Braille
     /// [`HalfBlock`]: crate::symbols::Marker::HalfBlock
+    /// [`Quadrant`]: crate::symbols::Marker::Quadrant
     /// [`Dot`]: crate::symbols::Marker::Dot
     /// [`Block`]: crate::symbols::Marker::Block
+    /// [`Bar`]: crate::symbols::Marker::Bar
     ///
     /// # Examples
     ///
@@ -709,6 +1015,10 @@ where
     ///     .paint(|ctx| {});
     ///
     /// Canvas::default()
+    ///     .marker(symbols::Marker::Quadrant)
+    ///     .paint(|ctx| {});
+    ///
+    /// Canvas::default()
     ///     .marker(symbols::Marker::Dot)
     ///     .paint(|ctx| {});
     ///
@@ -718,6 +1028,103 @@ where
     /// ```
     #[must_use = "method moves the value of self and returns the modified value"]
     pub const fn marker(mut self, marker: Marker) -> Self {
+        self.marker.builtin = marker;
+        self.marker.use_custom = false;
+        self
+    }
+
+    /// Change the symbols used to draw the points to a custom [`PointSymbol`]
+    ///
+    /// The points are drawn with the glyphs of the [`PointSymbol`], using the sub-cell resolution
+    /// it defines. This replaces the [marker](Canvas::marker) of the canvas.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::{prelude::*, symbols::PointSymbol, widgets::canvas::*};
+    ///
+    /// #[derive(Debug)]
+    /// struct Cross;
+    ///
+    /// impl PointSymbol for Cross {
+    ///     fn symbol(&self, _sub_x: u16, _sub_y: u16) -> &str {
+    ///         "✗"
+    ///     }
+    /// }
+    ///
+    /// Canvas::default().point_symbol(Cross).paint(|ctx| {});
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn point_symbol<S: PointSymbol + 'static>(mut self, symbol: S) -> Self {
+        self.marker.custom = Some(Arc::new(symbol));
+        self.marker.use_custom = true;
+        self
+    }
+
+    /// Correct the bounds so that shapes keep their proportions on screen.
+    ///
+    /// Terminal cells are taller than they are wide, so by default a [`Circle`] is displayed as
+    /// an ellipse and a square as a rectangle unless the bounds are chosen to match the shape of
+    /// the area. With an aspect ratio, which is the height of a cell divided by its width (usually
+    /// about `2.0`), the [x bounds](Canvas::x_bounds) or [y bounds](Canvas::y_bounds) are widened
+    /// around their center when the canvas is rendered, so that one unit is displayed with the
+    /// same length horizontally and vertically. All the shapes and labels are drawn with the
+    /// corrected bounds. The bounds are never narrowed, so everything within them stays visible.
+    ///
+    /// By default, the bounds are mapped as is to the area of the canvas.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::{prelude::*, widgets::canvas::*};
+    ///
+    /// Canvas::default()
+    ///     .x_bounds([-1.0, 1.0])
+    ///     .y_bounds([-1.0, 1.0])
+    ///     .aspect_ratio(2.0)
+    ///     .paint(|ctx| {
+    ///         ctx.draw(&Circle {
+    ///             x: 0.0,
+    ///             y: 0.0,
+    ///             radius: 1.0,
+    ///             color: Color::Yellow,
+    ///         });
+    ///     });
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn aspect_ratio(mut self, ratio: f64) -> Self {
+        self.aspect_ratio = Some(ratio);
+        self
+    }
+
+    /// Returns the x and y bounds corrected for the aspect ratio of the cells of the given area
+    fn corrected_bounds(&self, area: Rect) -> ([f64; 2], [f64; 2]) {
+        let (x_bounds, y_bounds) = (self.x_bounds, self.y_bounds);
+        let Some(ratio) = self.aspect_ratio else {
+            return (x_bounds, y_bounds);
+        };
+        let width = f64::from(area.width);
+        let height = f64::from(area.height) * ratio;
+        let x_range = x_bounds[1] - x_bounds[0];
+        let y_range = y_bounds[1] - y_bounds[0];
+        if !(ratio > 0.0 && x_range > 0.0 && y_range > 0.0) {
+            return (x_bounds, y_bounds);
+        }
+        // the length on screen of one unit, so that the narrowest direction fits its bounds
+        let scale = (width / x_range).min(height / y_range);
+        let widen = |[start, end]: [f64; 2], length: f64| {
+            let center = (start + end) / 2.0;
+            let half_range = length / scale / 2.0;
+            [center - half_range, center + half_range]
+        };
+        (widen(x_bounds, width), widen(y_bounds, height))
+    }
+
+    /// Change the type of points used to draw the shapes to an already wrapped [`PointMarker`]
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub(crate) fn point_marker(mut self, marker: PointMarker) -> Self {
         self.marker = marker;
         self
     }
@@ -752,26 +1159,47 @@ where
         };
 
         // Create a blank context that match the size of the canvas
-        let mut ctx = Context::new(
+        let (x_bounds, y_bounds) = self.corrected_bounds(canvas_area);
+        let mut ctx = Context::with_point_marker(
             canvas_area.width,
             canvas_area.height,
-            self.x_bounds,
-            self.y_bounds,
-            self.marker,
+            x_bounds,
+            y_bounds,
+            &self.marker,
         );
         // Paint to this context
         painter(&mut ctx);
         ctx.finish();
 
-        // Retrieve painted points for each layer
-        for layer in ctx.layers {
-            for (index, (ch, colors)) in layer.string.chars().zip(layer.colors).enumerate() {
-                if ch != ' ' && ch != '\u{2800}' {
+        // Retrieve painted points for each layer, from the lowest z-index to the highest
+        ctx.layers.sort_by_key(|(z_index, _)| *z_index);
+        // braille dots painted so far in each cell, so that layers are composited per dot
+        let mut braille = vec![symbols::braille::BLANK; canvas_area.area() as usize];
+        for (_, layer) in ctx.layers {
+            let symbols = layer.string.graphemes(true);
+            for (index, (symbol, colors)) in symbols.zip(layer.colors).enumerate() {
+                if symbol != " " && symbol != "\u{2800}" {
                     let (x, y) = (
                         (index % width) as u16 + canvas_area.left(),
                         (index / width) as u16 + canvas_area.top(),
                     );
-                    let cell = buf[(x, y)].set_char(ch);
+                    let mut chars = symbol.chars();
+                    let braille_char = match (chars.next(), chars.next()) {
+                        (Some(ch), None) => u16::try_from(u32::from(ch))
+                            .ok()
+                            .filter(|&dots| is_braille(dots))
+                            .map(|dots| (ch, dots)),
+                        _ => None,
+                    };
+                    let cell = if let Some((ch, dots)) = braille_char {
+                        braille[index] |= dots;
+                        let ch = char::from_u32(u32::from(braille[index])).unwrap_or(ch);
+                        buf[(x, y)].set_char(ch)
+                    } else {
+                        // the symbol covers the dots painted by the layers below
+                        braille[index] = symbols::braille::BLANK;
+                        buf[(x, y)].set_symbol(symbol)
+                    };
                     if colors.0 != Color::Reset {
                         cell.set_fg(colors.0);
                     }
@@ -806,9 +1234,15 @@ where
     }
 }
 
+/// Returns whether the given code point is a braille pattern
+const fn is_braille(code_point: u16) -> bool {
+    code_point & 0xFF00 == symbols::braille::BLANK
+}
+
 #[cfg(test)]
 mod tests {
     use indoc::indoc;
+    use rstest::rstest;
 
     use super::*;
     use crate::buffer::Cell;
@@ -889,6 +1323,45 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_quadrant_marker() {
+        test_marker(
+            Marker::Quadrant,
+            indoc!(
+                "
+                ▌xxxx
+                ▌xxxx
+                ▌xxxx
+                ▌xxxx
+                ▙▄▄▄▄"
+            ),
+        );
+    }
+
+    #[test]
+    fn quadrant_marker_uses_the_last_color_of_the_cell() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 1, 1));
+        Canvas::default()
+            .marker(Marker::Quadrant)
+            .x_bounds([0.0, 1.0])
+            .y_bounds([0.0, 1.0])
+            .paint(|ctx| {
+                assert_eq!(ctx.resolution(), (2.0, 2.0));
+                ctx.draw(&Points {
+                    coords: &[(0.0, 1.0)],
+                    color: Color::Red,
+                });
+                ctx.draw(&Points {
+                    coords: &[(1.0, 0.0)],
+                    color: Color::Blue,
+                });
+            })
+            .render(buf.area, &mut buf);
+        let mut expected = Buffer::with_lines(["▚"]);
+        expected.set_style(buf.area, Style::new().blue());
+        assert_eq!(buf, expected);
+    }
+
     #[test]
     fn test_dot_marker() {
         test_marker(
@@ -903,4 +1376,196 @@ mod tests {
             ),
         );
     }
+
+    #[test]
+    fn layers_are_rendered_by_z_index() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 1, 1));
+        Canvas::default()
+            .marker(Marker::Block)
+            .x_bounds([0.0, 1.0])
+            .y_bounds([0.0, 1.0])
+            .paint(|ctx| {
+                ctx.set_z_index(1);
+                ctx.draw(&Points {
+                    coords: &[(0.0, 0.0)],
+                    color: Color::Red,
+                });
+                ctx.set_z_index(0);
+                ctx.draw(&Points {
+                    coords: &[(0.0, 0.0)],
+                    color: Color::Blue,
+                });
+            })
+            .render(buf.area, &mut buf);
+        let mut expected = Buffer::with_lines(["█"]);
+        expected.set_style(buf.area, Style::new().red());
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn braille_layers_are_composited_per_dot() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 1, 1));
+        Canvas::default()
+            .x_bounds([0.0, 1.0])
+            .y_bounds([0.0, 1.0])
+            .paint(|ctx| {
+                ctx.draw(&Points {
+                    coords: &[(0.0, 1.0)],
+                    color: Color::Red,
+                });
+                ctx.layer();
+                ctx.draw(&Points {
+                    coords: &[(1.0, 1.0)],
+                    color: Color::Blue,
+                });
+            })
+            .render(buf.area, &mut buf);
+        let mut expected = Buffer::with_lines(["⠉"]);
+        expected.set_style(buf.area, Style::new().blue());
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn braille_dots_covered_by_other_symbols_are_not_composited() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 1, 1));
+        Canvas::default()
+            .x_bounds([0.0, 1.0])
+            .y_bounds([0.0, 1.0])
+            .paint(|ctx| {
+                ctx.draw(&Points {
+                    coords: &[(0.0, 1.0)],
+                    color: Color::Red,
+                });
+                ctx.layer();
+                ctx.layers.push((
+                    0,
+                    Layer {
+                        string: "█".into(),
+                        colors: vec![(Color::Green, Color::Reset)],
+                    },
+                ));
+                ctx.draw(&Points {
+                    coords: &[(1.0, 1.0)],
+                    color: Color::Blue,
+                });
+            })
+            .render(buf.area, &mut buf);
+        let mut expected = Buffer::with_lines(["⠈"]);
+        expected.set_style(buf.area, Style::new().blue());
+        assert_eq!(buf, expected);
+    }
+
+    /// Uses the left or right half of a circle depending on the position of the point
+    #[derive(Debug)]
+    struct HalfCircle;
+
+    impl PointSymbol for HalfCircle {
+        fn resolution(&self) -> (u16, u16) {
+            (2, 1)
+        }
+
+        fn symbol(&self, sub_x: u16, _sub_y: u16) -> &str {
+            if sub_x == 0 {
+                "◖"
+            } else {
+                "◗"
+            }
+        }
+    }
+
+    #[test]
+    fn point_marker_eq() {
+        assert_eq!(PointMarker::from(Marker::Block), Marker::Block);
+        let custom = |canvas: Canvas<fn(&mut Context)>| canvas.point_symbol(HalfCircle).marker;
+        let marker = custom(Canvas::default());
+        assert_eq!(marker, marker.clone());
+        assert_ne!(marker, Marker::Braille);
+        assert_ne!(marker, custom(Canvas::default()));
+        let canvas: Canvas<fn(&mut Context)> = Canvas::default().point_symbol(HalfCircle);
+        assert_eq!(canvas.marker(Marker::Block).marker, Marker::Block);
+    }
+
+    #[test]
+    fn custom_point_symbol() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 3, 2));
+        Canvas::default()
+            .point_symbol(HalfCircle)
+            .x_bounds([0.0, 5.0])
+            .y_bounds([0.0, 1.0])
+            .paint(|ctx| {
+                ctx.draw(&Points {
+                    coords: &[(0.0, 1.0), (3.0, 1.0), (5.0, 0.0)],
+                    color: Color::Red,
+                });
+            })
+            .render(buf.area, &mut buf);
+        let mut expected = Buffer::with_lines(["◖◗ ", "  ◗"]);
+        expected.set_style(Rect::new(0, 0, 2, 1), Style::new().red());
+        expected.set_style(Rect::new(2, 1, 1, 1), Style::new().red());
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn custom_point_symbol_uses_the_last_point_of_a_cell() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 1, 1));
+        Canvas::default()
+            .point_symbol(HalfCircle)
+            .x_bounds([0.0, 1.0])
+            .y_bounds([0.0, 1.0])
+            .paint(|ctx| {
+                ctx.draw(&Points {
+                    coords: &[(1.0, 0.0), (0.0, 0.0)],
+                    color: Color::Reset,
+                });
+            })
+            .render(buf.area, &mut buf);
+        assert_eq!(buf, Buffer::with_lines(["◖"]));
+    }
+
+    #[rstest]
+    #[case::raw_mapping(None, Rect::new(0, 0, 20, 5), [0.0, 10.0], [0.0, 10.0])]
+    #[case::square_area(Some(2.0), Rect::new(0, 0, 10, 5), [0.0, 10.0], [0.0, 10.0])]
+    #[case::wide_area(Some(2.0), Rect::new(0, 0, 20, 5), [-5.0, 15.0], [0.0, 10.0])]
+    #[case::tall_area(Some(2.0), Rect::new(0, 0, 10, 10), [0.0, 10.0], [-5.0, 15.0])]
+    #[case::square_cells(Some(1.0), Rect::new(0, 0, 10, 5), [-5.0, 15.0], [0.0, 10.0])]
+    #[case::invalid_ratio(Some(0.0), Rect::new(0, 0, 20, 5), [0.0, 10.0], [0.0, 10.0])]
+    fn corrected_bounds(
+        #[case] aspect_ratio: Option<f64>,
+        #[case] area: Rect,
+        #[case] x_bounds: [f64; 2],
+        #[case] y_bounds: [f64; 2],
+    ) {
+        let mut canvas = Canvas::default()
+            .x_bounds([0.0, 10.0])
+            .y_bounds([0.0, 10.0])
+            .paint(|_| {});
+        if let Some(ratio) = aspect_ratio {
+            canvas = canvas.aspect_ratio(ratio);
+        }
+        assert_eq!(canvas.corrected_bounds(area), (x_bounds, y_bounds));
+    }
+
+    #[test]
+    fn aspect_ratio_widens_bounds_of_wide_area() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 12, 3));
+        Canvas::default()
+            .marker(Marker::Block)
+            .x_bounds([0.0, 2.0])
+            .y_bounds([0.0, 2.0])
+            .aspect_ratio(2.0)
+            .paint(|ctx| {
+                ctx.draw(&Rectangle {
+                    x: 0.0,
+                    y: 0.0,
+                    width: 2.0,
+                    height: 2.0,
+                    color: Color::Reset,
+                });
+            })
+            .render(buf.area, &mut buf);
+        assert_eq!(
+            buf,
+            Buffer::with_lines(["  ███████   ", "  █     █   ", "  ███████   "])
+        );
+    }
 }
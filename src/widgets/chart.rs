@@ -1,24 +1,37 @@
-use std::{cmp::max, ops::Not};
+use std::{
+    borrow::Cow,
+    cmp::max,
+    collections::HashMap,
+    ops::Not,
+    sync::Arc,
+    time::{Duration, Instant},
+};
 
+use itertools::Itertools;
 use strum::{Display, EnumString};
+use unicode_width::UnicodeWidthStr;
 
 use crate::{
     layout::Flex,
     prelude::*,
     style::Styled,
     widgets::{
-        canvas::{Canvas, Line as CanvasLine, Points},
-        Block,
+        canvas::{Canvas, Line as CanvasLine, PointMarker, Points},
+        Block, StatefulWidgetRef,
     },
 };
 
+mod chart_data;
+pub use chart_data::ChartData;
+
 /// An X or Y axis for the [`Chart`] widget
 ///
 /// An axis can have a [title](Axis::title) which will be displayed at the end of the axis. For an
 /// X axis this is the right, for a Y axis, this is the top.
 ///
 /// You can also set the bounds and labels on this axis using respectively [`Axis::bounds`] and
-/// [`Axis::labels`].
+/// [`Axis::labels`]. When no bounds are set, they are computed from the data of the chart (see
+/// [`Axis::bounds_auto`]).
 ///
 /// See [`Chart::x_axis`] and [`Chart::y_axis`] to set an axis on a chart.
 ///
@@ -36,14 +49,21 @@ use crate::{
 pub struct Axis<'a> {
     /// Title displayed next to axis end
     title: Option<Line<'a>>,
-    /// Bounds for the axis (all data points outside these limits will not be represented)
-    bounds: [f64; 2],
+    /// Bounds for the axis (all data points outside these limits will not be represented), or
+    /// `None` to compute them from the data
+    bounds: Option<[f64; 2]>,
+    /// The padding added to both ends of automatic bounds, as a percentage of their range
+    bounds_padding: f64,
     /// A list of labels to put to the left or below the axis
     labels: Vec<Line<'a>>,
+    /// The data coordinates of the labels when they are placed with [`Axis::labels_at`]
+    label_positions: Option<Vec<f64>>,
     /// The style used to draw the axis itself
     style: Style,
     /// The alignment of the labels of the Axis
     labels_alignment: Alignment,
+    /// The scale used to map data coordinates onto the axis
+    scale: AxisScale,
 }
 
 impl<'a> Axis<'a> {
@@ -64,12 +84,60 @@ impl<'a> Axis<'a> {
 
     /// Sets the bounds of this axis
     ///
-    /// In other words, sets the min and max value on this axis.
+    /// In other words, sets the values at the start and at the end of this axis. The first bound
+    /// is at the left of the x axis and at the bottom of the y axis. When the first bound is
+    /// greater than the second one, the direction of the axis is reversed, e.g. `[100.0, 0.0]`
+    /// makes a y axis grow downward, which is useful for depth plots. The labels are drawn from
+    /// the start to the end of the axis in both cases.
     ///
     /// This is a fluent setter method which must be chained or used as it consumes self
     #[must_use = "method moves the value of self and returns the modified value"]
     pub const fn bounds(mut self, bounds: [f64; 2]) -> Self {
-        self.bounds = bounds;
+        self.bounds = Some(bounds);
+        self
+    }
+
+    /// Computes the bounds of this axis from the data of the chart
+    ///
+    /// This is the default when no [bounds](Axis::bounds) are set. When the chart is rendered, the
+    /// bounds are set to the minimum and maximum values of all the data points on this axis (the
+    /// stacked totals and the `0.0` baseline for [`GraphType::StackedBar`] datasets), widened by
+    /// the [padding](Axis::bounds_padding). Each axis of a chart is computed independently, so one
+    /// axis can use automatic bounds while the other one has explicit bounds.
+    ///
+    /// When there is no data the bounds are `[0.0, 1.0]`, and when all the values are equal the
+    /// bounds are centered on this value with a range of `1.0`.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let data = [(0.0, 1.5), (1.0, 4.2), (2.0, 3.1)];
+    /// let chart = Chart::new(vec![Dataset::default().data(&data)])
+    ///     .x_axis(Axis::default().bounds([0.0, 2.0]))
+    ///     .y_axis(Axis::default().bounds_auto().bounds_padding(10.0));
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn bounds_auto(mut self) -> Self {
+        self.bounds = None;
+        self
+    }
+
+    /// Sets the padding added to both ends of the automatic bounds of this axis
+    ///
+    /// The padding is a percentage of the range of the data, e.g. a padding of `10.0` on data
+    /// ranging from `0.0` to `50.0` gives bounds of `[-5.0, 55.0]`. On a
+    /// [logarithmic](AxisScale::Logarithmic) axis, the padding is a percentage of the range in log
+    /// space. Defaults to `0.0`.
+    ///
+    /// This has no effect when the bounds are set with [`Axis::bounds`].
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn bounds_padding(mut self, percent: f64) -> Self {
+        self.bounds_padding = percent;
         self
     }
 
@@ -82,8 +150,14 @@ impl<'a> Axis<'a> {
     /// more than 3 labels is currently broken and the middle labels won't be in the correct
     /// position, see [issue 334].
     ///
+    /// Labels are spaced evenly along the [scaled](Axis::scale) axis. For a
+    /// [logarithmic](AxisScale::Logarithmic) axis this means that each label should represent the
+    /// same multiple of the previous one (e.g. `1`, `10`, `100`).
+    ///
     /// [issue 334]: https://github.com/ratatui/ratatui/issues/334
     ///
+    /// To place labels at specific values instead, use [`Axis::labels_at`].
+    ///
     /// `labels` is a vector of any type that can be converted into a [`Line`] (e.g. `&str`,
     /// `String`, `&Line`, `Span`, ...). This allows you to style the labels using the methods
     /// provided by [`Line`]. Any alignment set on the labels will be ignored as the alignment is
@@ -106,6 +180,46 @@ impl<'a> Axis<'a> {
         Labels::Item: Into<Line<'a>>,
     {
         self.labels = labels.into_iter().map(Into::into).collect();
+        self.label_positions = None;
+        self
+    }
+
+    /// Sets the axis labels at the given data coordinates
+    ///
+    /// Unlike [`Axis::labels`], which spaces the labels evenly, each label is displayed at the
+    /// position of its value on the axis, using the same mapping as the data points. This is useful
+    /// for irregular ticks, e.g. month boundaries on a time axis. Labels whose value is outside of
+    /// the [bounds](Axis::bounds) are not displayed.
+    ///
+    /// - For the X axis, each label is centered below its value.
+    /// - For the Y axis, each label is displayed on the row of its value, aligned according to the
+    ///   [labels alignment](Axis::labels_alignment).
+    ///
+    /// `labels` is a vector of pairs of a value and any type that can be converted into a [`Line`]
+    /// (e.g. `&str`, `String`, `Span`, ...).
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let axis = Axis::default()
+    ///     .bounds([0.0, 90.0])
+    ///     .labels_at([(0.0, "Jan".bold()), (31.0, "Feb".into()), (59.0, "Mar".into())]);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn labels_at<Labels, T>(mut self, labels: Labels) -> Self
+    where
+        Labels: IntoIterator<Item = (f64, T)>,
+        T: Into<Line<'a>>,
+    {
+        let (positions, labels) = labels
+            .into_iter()
+            .map(|(position, label)| (position, label.into()))
+            .unzip();
+        self.labels = labels;
+        self.label_positions = Some(positions);
         self
     }
 
@@ -143,6 +257,257 @@ impl<'a> Axis<'a> {
         self.labels_alignment = alignment;
         self
     }
+
+    /// Sets the scale of the axis
+    ///
+    /// The default is [`AxisScale::Linear`]. With [`AxisScale::Logarithmic`], data points and
+    /// [bounds](Axis::bounds) are mapped to log space before being drawn, so that each order of
+    /// magnitude takes the same amount of space. Lines between points are also drawn in log space.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let axis = Axis::default()
+    ///     .bounds([1.0, 1000.0])
+    ///     .labels(["1", "10", "100", "1000"])
+    ///     .scale(AxisScale::Logarithmic);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn scale(mut self, scale: AxisScale) -> Self {
+        self.scale = scale;
+        self
+    }
+
+    /// Returns whether the first bound of the axis is greater than the second one
+    fn is_reversed(&self) -> bool {
+        matches!(self.bounds, Some([start, end]) if start > end)
+    }
+
+    /// Maps a data coordinate to its position along the axis
+    ///
+    /// The value is mapped by the scale of the axis, and negated when the axis is reversed so that
+    /// the positions always increase from the start to the end of the axis.
+    fn map(&self, value: f64) -> f64 {
+        let value = self.scale.apply(value);
+        if self.is_reversed() {
+            -value
+        } else {
+            value
+        }
+    }
+
+    /// Maps a position along the axis back to a data coordinate, see [`Axis::map`]
+    fn unmap(&self, position: f64) -> f64 {
+        let position = if self.is_reversed() {
+            -position
+        } else {
+            position
+        };
+        self.scale.invert(position)
+    }
+
+    /// Returns the positions of the bounds of the axis, see [`Axis::map`]
+    ///
+    /// Automatic bounds are resolved by the chart before rendering, see [`Chart::resolve_bounds`].
+    fn scaled_bounds(&self) -> [f64; 2] {
+        self.bounds
+            .unwrap_or(Self::DEFAULT_AUTO_BOUNDS)
+            .map(|bound| self.map(bound))
+    }
+
+    /// Maps the start of a bar segment to its position along the axis
+    ///
+    /// Bars usually start at zero, which can't be represented on a logarithmic axis, so they are
+    /// clamped to the edge of the axis instead.
+    fn scale_bar_start(&self, y: f64) -> f64 {
+        match self.scale {
+            AxisScale::Linear => self.map(y),
+            AxisScale::Logarithmic => {
+                let [min, max] = self.scaled_bounds();
+                self.map(y).max(min).min(max)
+            }
+        }
+    }
+
+    /// The bounds used when there is no data to compute automatic bounds from
+    const DEFAULT_AUTO_BOUNDS: [f64; 2] = [0.0, 1.0];
+
+    /// Computes the bounds of the axis from the given values
+    ///
+    /// The range is computed and padded in the space of the scale of the axis, so that the padding
+    /// looks the same at both ends of the axis. Non finite values are ignored.
+    fn auto_bounds(&self, values: impl Iterator<Item = f64>) -> [f64; 2] {
+        let Some((min, max)) = values
+            .map(|value| self.scale.apply(value))
+            .filter(|value| value.is_finite())
+            .fold(None, |range: Option<(f64, f64)>, value| {
+                Some(range.map_or((value, value), |(min, max)| {
+                    (min.min(value), max.max(value))
+                }))
+            })
+        else {
+            return Self::DEFAULT_AUTO_BOUNDS;
+        };
+        let padding = (max - min) * self.bounds_padding / 100.0;
+        let (min, max) = if (max - min).abs() < f64::EPSILON {
+            (min - 0.5, max + 0.5)
+        } else {
+            (min - padding, max + padding)
+        };
+        [self.scale.invert(min), self.scale.invert(max)]
+    }
+
+    /// Returns the labels placed with [`Axis::labels_at`] which are within the bounds, along with
+    /// the offset of their cell from the start of an axis of `length` cells
+    fn positioned_labels(&self, length: u16) -> impl Iterator<Item = (&Line<'a>, u16)> + '_ {
+        let [min, max] = self.scaled_bounds();
+        self.labels
+            .iter()
+            .zip(self.label_positions.iter().flatten())
+            .filter_map(move |(label, &position)| {
+                let position = self.map(position);
+                if max <= min || !(min..=max).contains(&position) {
+                    return None;
+                }
+                let offset = (position - min) * f64::from(length.saturating_sub(1)) / (max - min);
+                Some((label, offset as u16))
+            })
+    }
+
+    /// Returns the offsets of the cells of the labels from the start of an axis of `length` cells
+    fn label_offsets(&self, length: u16) -> Vec<u16> {
+        if self.label_positions.is_some() {
+            return self
+                .positioned_labels(length)
+                .map(|(_, offset)| offset)
+                .collect();
+        }
+        let labels_len = self.labels.len() as u16;
+        if labels_len < 2 {
+            return vec![];
+        }
+        (0..labels_len)
+            .map(|i| i * length.saturating_sub(1) / (labels_len - 1))
+            .collect()
+    }
+}
+
+/// Used to determine how data coordinates are mapped onto an [`Axis`]
+///
+/// See [`Axis::scale`]
+#[derive(Debug, Default, Display, EnumString, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum AxisScale {
+    /// Values are spaced evenly along the axis. This is the default.
+    #[default]
+    Linear,
+
+    /// Values are mapped using their base 10 logarithm, so that each order of magnitude takes the
+    /// same amount of space along the axis.
+    ///
+    /// Values (including bounds) that are less than or equal to zero are clamped to a small
+    /// positive epsilon instead of producing NaN.
+    Logarithmic,
+}
+
+impl AxisScale {
+    /// The smallest value that can be represented on a logarithmic axis
+    const LOG_EPSILON: f64 = f64::EPSILON;
+
+    /// Maps a data coordinate to its position along an axis with this scale
+    fn apply(self, value: f64) -> f64 {
+        match self {
+            Self::Linear => value,
+            Self::Logarithmic => value.max(Self::LOG_EPSILON).log10(),
+        }
+    }
+
+    /// Maps a position along an axis with this scale back to a data coordinate
+    fn invert(self, value: f64) -> f64 {
+        match self {
+            Self::Linear => value,
+            Self::Logarithmic => 10f64.powf(value),
+        }
+    }
+}
+
+/// The gridlines drawn in the graph area of a [`Chart`]
+///
+/// Vertical lines are drawn at the position of each label of the X axis and horizontal lines at the
+/// position of each label of the Y axis. The gridlines are drawn beneath the datasets, so the
+/// points and lines remain visible.
+///
+/// See [`Chart::grid`]
+///
+/// # Example
+///
+/// ```rust
+/// # use ratatui::{prelude::*, widgets::*};
+/// let grid = GridStyle::new()
+///     .style(Style::new().dark_gray())
+///     .lines(GridLines::Horizontal);
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct GridStyle {
+    /// The style of the gridlines
+    style: Style,
+    /// Which gridlines are drawn
+    lines: GridLines,
+}
+
+impl GridStyle {
+    /// Creates a new grid with both horizontal and vertical lines, styled dim gray
+    pub const fn new() -> Self {
+        Self {
+            style: Style::new().fg(Color::Gray).add_modifier(Modifier::DIM),
+            lines: GridLines::Both,
+        }
+    }
+
+    /// Sets the style of the gridlines
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets which gridlines are drawn
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn lines(mut self, lines: GridLines) -> Self {
+        self.lines = lines;
+        self
+    }
+}
+
+impl Default for GridStyle {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Used to determine which gridlines of a [`Chart`] are drawn
+///
+/// See [`GridStyle::lines`]
+#[derive(Debug, Default, Display, EnumString, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum GridLines {
+    /// Draw both horizontal and vertical lines. This is the default.
+    #[default]
+    Both,
+
+    /// Only draw horizontal lines, at the labels of the Y axis
+    Horizontal,
+
+    /// Only draw vertical lines, at the labels of the X axis
+    Vertical,
 }
 
 /// Used to determine which style of graphing to use
@@ -160,6 +525,92 @@ pub enum GraphType {
 
     /// Draw a bar chart. This will draw a bar for each point in the dataset.
     Bar,
+
+    /// Draw a stacked bar chart.
+    ///
+    /// The points of all the `StackedBar` datasets that share the same x coordinate are summed and
+    /// drawn as segments of a single bar, in the order the datasets were given to the [`Chart`].
+    /// Each segment uses the style of its dataset. Datasets that have no point at a given x
+    /// coordinate don't contribute to that bar. Positive values are stacked upwards from zero and
+    /// negative values are stacked downwards from zero.
+    ///
+    /// The bounds of the y [`Axis`] should be large enough to contain the stacked totals, which is
+    /// the case for [automatic bounds](Axis::bounds_auto).
+    StackedBar,
+
+    /// Draw a line between each following point and fill the area between the line and zero.
+    ///
+    /// The area is filled with the background color of the cells, using the color given to
+    /// [`Dataset::fill`], or the background of the dataset style, or else its foreground. The fills
+    /// of all the datasets are drawn under the lines and points of the chart, and overlapping fills
+    /// are composited in the order the datasets were given to the [`Chart`].
+    Area,
+}
+
+/// The vertical axis a [`Dataset`] is plotted against
+///
+/// See [`Dataset::y_axis`] and [`Chart::y_axis_secondary`].
+#[derive(Debug, Default, Display, EnumString, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum YAxis {
+    /// The y axis on the left of the chart. This is the default.
+    #[default]
+    Primary,
+
+    /// The y axis on the right of the chart, set with [`Chart::y_axis_secondary`].
+    ///
+    /// When the chart has no secondary axis, the dataset is plotted against the primary axis.
+    Secondary,
+}
+
+/// Keeps track of the positive and negative totals of the bars drawn by
+/// [`GraphType::StackedBar`] datasets
+#[derive(Debug, Default)]
+struct BarStacks {
+    /// The positive and negative totals, keyed by the bits of the x coordinate
+    totals: HashMap<u64, (f64, f64)>,
+}
+
+impl BarStacks {
+    /// Stacks `y` on the bar at `x` and returns the start and end of the new segment
+    fn push(&mut self, x: f64, y: f64) -> (f64, f64) {
+        // adding 0.0 normalizes -0.0 to 0.0 so that both end up in the same bar
+        let (positive, negative) = self.totals.entry((x + 0.0).to_bits()).or_default();
+        let total = if y < 0.0 { negative } else { positive };
+        let start = *total;
+        *total += y;
+        (start, *total)
+    }
+}
+
+/// Returns the y coordinate of the line joining the points at the given x coordinate
+///
+/// The first segment of the line that spans `x` is used. Returns `None` if no segment spans `x`.
+fn interpolate(data: &[(f64, f64)], x: f64) -> Option<f64> {
+    if let [(x1, y1)] = data {
+        return ((x - x1).abs() < f64::EPSILON).then_some(*y1);
+    }
+    data.windows(2).find_map(|segment| {
+        let [(x1, y1), (x2, y2)] = [segment[0], segment[1]];
+        // the line is broken at the non-finite points
+        if !is_finite_point(segment[0]) || !is_finite_point(segment[1]) {
+            return None;
+        }
+        if x < x1.min(x2) || x > x1.max(x2) {
+            return None;
+        }
+        if (x2 - x1).abs() < f64::EPSILON {
+            return Some(y1.max(y2));
+        }
+        Some(y1 + (y2 - y1) * (x - x1) / (x2 - x1))
+    })
+}
+
+/// Returns whether both coordinates of a data point are finite
+///
+/// Points with a NaN or infinite coordinate are gaps in the data: they are not drawn and the lines
+/// joining the points are broken at them.
+fn is_finite_point((x, y): (f64, f64)) -> bool {
+    x.is_finite() && y.is_finite()
 }
 
 /// Allow users to specify the position of a legend in a [`Chart`]
@@ -184,6 +635,11 @@ pub enum LegendPosition {
     BottomRight,
     /// Legend is in the bottom-left corner
     BottomLeft,
+    /// Legend is in the top-right corner, outside of the graph
+    ///
+    /// Unlike the other positions, the legend doesn't overlay the graph: its width is taken from
+    /// the right of the chart, and the axes and the graph are laid out in the remaining space.
+    Outside,
 }
 
 impl LegendPosition {
@@ -207,6 +663,8 @@ impl LegendPosition {
         };
 
         let (x, y) = match self {
+            // the area is the whole chart, which the graph is laid out next to
+            Self::Outside => (area.right() - legend_width, area.top()),
             Self::TopRight => {
                 if legend_width + y_title_width > area.width {
                     (area.right() - legend_width, area.top() + 1)
@@ -313,11 +771,17 @@ pub struct Dataset<'a> {
     /// A reference to the actual data
     data: &'a [(f64, f64)],
     /// Symbol used for each points of this dataset
-    marker: symbols::Marker,
+    marker: PointMarker,
     /// Determines graph type used for drawing points
     graph_type: GraphType,
     /// Style used to plot this dataset
     style: Style,
+    /// Color used to fill the area of a [`GraphType::Area`] dataset
+    fill: Option<Color>,
+    /// Symbols used for the individual points of this dataset, by index of the point
+    point_markers: Vec<symbols::Marker>,
+    /// The vertical axis this dataset is plotted against
+    y_axis: YAxis,
 }
 
 impl<'a> Dataset<'a> {
@@ -349,6 +813,13 @@ impl<'a> Dataset<'a> {
     /// second Y. It's also worth noting that, unlike the [`Rect`], here the Y axis is bottom to
     /// top, as in math.
     ///
+    /// A point with a NaN or infinite coordinate is a gap in the data (e.g. a missing sample of a
+    /// time series): it is not drawn, and the line joining the points is broken at it and resumes
+    /// at the next finite point.
+    ///
+    /// For data which is streamed, a [`ChartData`] keeps the latest points without shifting the
+    /// older ones and can be passed as is.
+    ///
     /// This is a fluent setter method which must be chained or used as it consumes self
     #[must_use = "method moves the value of self and returns the modified value"]
     pub const fn data(mut self, data: &'a [(f64, f64)]) -> Self {
@@ -364,19 +835,67 @@ impl<'a> Dataset<'a> {
     /// Note [`Marker::Braille`](symbols::Marker::Braille) requires a font that supports Unicode
     /// Braille Patterns.
     ///
+    /// Use [`Dataset::point_symbol`] to draw the points with custom glyphs instead.
+    ///
     /// This is a fluent setter method which must be chained or used as it consumes self
     #[must_use = "method moves the value of self and returns the modified value"]
     pub const fn marker(mut self, marker: symbols::Marker) -> Self {
-        self.marker = marker;
+        self.marker.builtin = marker;
+        self.marker.use_custom = false;
+        self
+    }
+
+    /// Sets a custom [`PointSymbol`](symbols::PointSymbol) to display this dataset
+    ///
+    /// This replaces the [marker](Dataset::marker) of the dataset.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn point_symbol<S: symbols::PointSymbol + 'static>(mut self, symbol: S) -> Self {
+        self.marker.custom = Some(Arc::new(symbol));
+        self.marker.use_custom = true;
+        self
+    }
+
+    /// Sets the marker of each point of the dataset
+    ///
+    /// The markers are given in the same order as the [data](Dataset::data), which makes it
+    /// possible to show a category of each point, e.g. in a classification scatter plot, without
+    /// splitting the points in several datasets. The points beyond the end of `markers` use the
+    /// [marker](Dataset::marker) of the dataset, as do the lines drawn between the points.
+    ///
+    /// The points are drawn by marker, so a point with its own marker is drawn on top of the
+    /// points and lines which use the marker of the dataset.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let data = [(0.0, 1.0), (1.0, 2.0), (2.0, 0.5)];
+    /// let dataset = Dataset::default()
+    ///     .data(&data)
+    ///     .marker(symbols::Marker::Dot)
+    ///     .point_markers([symbols::Marker::Block, symbols::Marker::Dot]);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn point_markers<T>(mut self, markers: T) -> Self
+    where
+        T: IntoIterator<Item = symbols::Marker>,
+    {
+        self.point_markers = markers.into_iter().collect();
         self
     }
 
     /// Sets how the dataset should be drawn
     ///
-    /// [`Chart`] can draw [scatter](GraphType::Scatter), [line](GraphType::Line) or
-    /// [bar](GraphType::Bar) charts. A scatter chart draws only the points in the dataset, a line
-    /// char draws a line between each point, and a bar chart draws a line from the x axis to the
-    /// point.  See [`GraphType`] for more details
+    /// [`Chart`] can draw [scatter](GraphType::Scatter), [line](GraphType::Line),
+    /// [bar](GraphType::Bar), [stacked bar](GraphType::StackedBar) or [area](GraphType::Area)
+    /// charts. A scatter chart draws only the points in the dataset, a line char draws a line
+    /// between each point, a bar chart draws a line from the x axis to the point, a stacked bar
+    /// chart draws the points of several datasets on top of each other, and an area chart fills
+    /// the area under the line.  See [`GraphType`] for more details
     ///
     /// This is a fluent setter method which must be chained or used as it consumes self
     #[must_use = "method moves the value of self and returns the modified value"]
@@ -409,8 +928,108 @@ impl<'a> Dataset<'a> {
         self.style = style.into();
         self
     }
+
+    /// Sets the color used to fill the area under the line of a [`GraphType::Area`] dataset
+    ///
+    /// When no fill color is set, the background of the dataset style is used, or else its
+    /// foreground.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let dataset = Dataset::default()
+    ///     .graph_type(GraphType::Area)
+    ///     .fill(Color::Blue)
+    ///     .light_blue();
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn fill(mut self, color: Color) -> Self {
+        self.fill = Some(color);
+        self
+    }
+
+    /// Sets the vertical axis this dataset is plotted against
+    ///
+    /// Datasets are plotted against the [primary](YAxis::Primary) y axis by default. Datasets of
+    /// the [secondary](YAxis::Secondary) axis are mapped using the bounds and scale of
+    /// [`Chart::y_axis_secondary`], which makes it possible to overlay series of different
+    /// magnitudes in the same chart.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let dataset = Dataset::default().y_axis(YAxis::Secondary);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn y_axis(mut self, axis: YAxis) -> Self {
+        self.y_axis = axis;
+        self
+    }
+
+    /// Returns the markers of the points that differ from the marker of the dataset, without
+    /// duplicates
+    fn point_marker_kinds(&self) -> Vec<symbols::Marker> {
+        self.point_markers
+            .iter()
+            .copied()
+            .filter(|&marker| self.marker != marker)
+            .unique()
+            .collect()
+    }
+
+    /// Returns the (scaled) points of the dataset which use the given marker, or the marker of the
+    /// dataset if `marker` is `None`
+    fn points_with_marker<'d>(
+        &self,
+        data: &'d [(f64, f64)],
+        marker: Option<symbols::Marker>,
+    ) -> Cow<'d, [(f64, f64)]> {
+        let own_marker = |index: usize| {
+            self.point_markers
+                .get(index)
+                .copied()
+                .filter(|&m| self.marker != m)
+        };
+        if self.point_markers.iter().all(|&m| self.marker == m) {
+            return if marker.is_some() {
+                Cow::Owned(vec![])
+            } else if data.iter().all(|&point| is_finite_point(point)) {
+                Cow::Borrowed(data)
+            } else {
+                data.iter()
+                    .copied()
+                    .filter(|&point| is_finite_point(point))
+                    .collect()
+            };
+        }
+        data.iter()
+            .enumerate()
+            .filter(|&(i, &point)| own_marker(i) == marker && is_finite_point(point))
+            .map(|(_, point)| *point)
+            .collect()
+    }
+
+    /// Returns the color used to fill the area of a [`GraphType::Area`] dataset
+    fn fill_color(&self) -> Color {
+        self.fill
+            .or(self.style.bg)
+            .or(self.style.fg)
+            .unwrap_or(Color::Reset)
+    }
 }
 
+/// The mark which follows the names of the datasets of the secondary y axis in the legend
+const SECONDARY_AXIS_LEGEND_MARKER: &str = " →";
+
+/// The number of empty cells between the columns of the legend
+const LEGEND_COLUMN_SPACING: u16 = 1;
+
 /// A container that holds all the infos about where to display each elements of the chart (axis,
 /// labels, legend, ...).
 struct ChartLayout {
@@ -418,37 +1037,185 @@ struct ChartLayout {
     title_x: Option<Position>,
     /// Location of the title of the y axis
     title_y: Option<Position>,
+    /// Location of the title of the secondary y axis
+    title_y_secondary: Option<Position>,
     /// Location of the first label of the x axis
     label_x: Option<u16>,
     /// Location of the first label of the y axis
     label_y: Option<u16>,
+    /// X coordinate of the labels of the secondary y axis
+    label_y_secondary: Option<u16>,
     /// Y coordinate of the horizontal axis
     axis_x: Option<u16>,
     /// X coordinate of the vertical axis
     axis_y: Option<u16>,
+    /// X coordinate of the secondary vertical axis
+    axis_y_secondary: Option<u16>,
     /// Area of the legend
     legend_area: Option<Rect>,
+    /// Whether the names of the datasets of the secondary y axis are marked in the legend
+    legend_axis_markers: bool,
     /// Area of the graph
     graph_area: Rect,
 }
 
-/// A widget to plot one or more [`Dataset`] in a cartesian coordinate system
+/// State of a [`Chart`] which animates the changes of its datasets and holds the selected point
 ///
-/// To use this widget, start by creating one or more [`Dataset`]. With it, you can set the
-/// [data points](Dataset::data), the [name](Dataset::name) or the
-/// [chart type](Dataset::graph_type). See [`Dataset`] for a complete documentation of what is
-/// possible.
+/// The state remembers the data of the datasets as they were last drawn, so that the chart can
+/// move the points from their previous position to the new one. See [`Chart::transition`].
 ///
-/// Then, you'll usually want to configure the [`Axis`]. Axis [titles](Axis::title),
-/// [bounds](Axis::bounds) and [labels](Axis::labels) can be configured on both axis. See [`Axis`]
-/// for a complete documentation of what is possible.
+/// The [selected](ChartState::select) data point is highlighted with a crosshair and a label
+/// showing its value. Use [`Chart::point_at`] to select the point under the mouse cursor.
 ///
-/// Finally, you can pass all of that to the `Chart` via [`Chart::new`], [`Chart::x_axis`] and
-/// [`Chart::y_axis`].
+/// # Example
 ///
-/// Additionally, `Chart` allows configuring the legend [position](Chart::legend_position) and
+/// ```
+/// use std::time::Duration;
+///
+/// use ratatui::{prelude::*, widgets::*};
+///
+/// let mut state = ChartState::default();
+/// let data = [(0.0, 1.0), (1.0, 2.0)];
+/// let chart = Chart::new(vec![Dataset::default().data(&data)])
+///     .transition(Duration::from_millis(200));
+///
+/// # let area = Rect::new(0, 0, 10, 5);
+/// # let mut buf = Buffer::empty(area);
+/// StatefulWidget::render(chart, area, &mut buf, &mut state);
+///
+/// // highlight the second point of the first dataset
+/// state.select(Some((0, 1)));
+/// ```
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct ChartState {
+    /// The index of the dataset and the index of the point in this dataset of the selected point
+    selected: Option<(usize, usize)>,
+    /// The data of each dataset at the start of the current transition
+    from: Vec<Vec<(f64, f64)>>,
+    /// The data of each dataset at the end of the current transition
+    to: Vec<Vec<(f64, f64)>>,
+    /// The data of each dataset as it was last displayed
+    current: Vec<Vec<(f64, f64)>>,
+    /// The instant at which the current transition started
+    started: Option<Instant>,
+}
+
+impl ChartState {
+    /// Sets the selected point, as the index of the dataset and the index of the point in this
+    /// dataset
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn with_selected(mut self, selected: Option<(usize, usize)>) -> Self {
+        self.selected = selected;
+        self
+    }
+
+    /// Returns the selected point, as the index of the dataset and the index of the point in this
+    /// dataset
+    pub const fn selected(&self) -> Option<(usize, usize)> {
+        self.selected
+    }
+
+    /// Selects a point, given as the index of the dataset and the index of the point in this
+    /// dataset, or clears the selection with `None`
+    ///
+    /// A selection which doesn't match any point, or a point outside of the bounds of the axes,
+    /// isn't displayed.
+    pub fn select(&mut self, selected: Option<(usize, usize)>) {
+        self.selected = selected;
+    }
+
+    /// Returns whether a transition is still running at the instant `now`
+    ///
+    /// This can be used to keep drawing frames while the chart is being animated.
+    pub fn is_transitioning(&self, duration: Duration, now: Instant) -> bool {
+        self.started
+            .is_some_and(|started| now.saturating_duration_since(started) < duration)
+    }
+
+    /// Starts a new transition if the data of the datasets changed, and returns the data to
+    /// display at the instant `now`
+    fn update(
+        &mut self,
+        datasets: &[Dataset],
+        duration: Option<Duration>,
+        now: Instant,
+    ) -> &[Vec<(f64, f64)>] {
+        let changed = datasets.len() != self.to.len()
+            || datasets
+                .iter()
+                .zip(&self.to)
+                .any(|(d, to)| !same_points(d.data, to));
+        if changed {
+            self.interpolate(duration, now);
+            self.from = std::mem::take(&mut self.current);
+            self.to = datasets.iter().map(|d| d.data.to_vec()).collect();
+            self.started = duration.map(|_| now);
+        }
+        self.interpolate(duration, now);
+        &self.current
+    }
+
+    /// Computes the data of each dataset at the instant `now` of the current transition
+    ///
+    /// The points which exist both at the start and at the end of the transition are
+    /// interpolated, the other points are at their final position.
+    fn interpolate(&mut self, duration: Option<Duration>, now: Instant) {
+        let progress = match (duration, self.started) {
+            (Some(duration), Some(started)) if !duration.is_zero() => {
+                let elapsed = now.saturating_duration_since(started);
+                (elapsed.as_secs_f64() / duration.as_secs_f64()).min(1.0)
+            }
+            _ => 1.0,
+        };
+        self.current.resize_with(self.to.len(), Vec::new);
+        for (index, (to, current)) in self.to.iter().zip(&mut self.current).enumerate() {
+            let from = self.from.get(index).map_or(&[][..], Vec::as_slice);
+            current.clear();
+            current.extend(to.iter().enumerate().map(|(i, &(x, y))| match from.get(i) {
+                Some(&(from_x, from_y)) => (
+                    (x - from_x).mul_add(progress, from_x),
+                    (y - from_y).mul_add(progress, from_y),
+                ),
+                None => (x, y),
+            }));
+        }
+    }
+}
+
+/// Returns whether two lists of points are identical
+///
+/// The coordinates are compared by their bits so that the gaps in the data, which are `NaN`, are
+/// equal to themselves.
+fn same_points(a: &[(f64, f64)], b: &[(f64, f64)]) -> bool {
+    a.len() == b.len()
+        && a.iter().zip(b).all(|(&(ax, ay), &(bx, by))| {
+            ax.to_bits() == bx.to_bits() && ay.to_bits() == by.to_bits()
+        })
+}
+
+/// A widget to plot one or more [`Dataset`] in a cartesian coordinate system
+///
+/// To use this widget, start by creating one or more [`Dataset`]. With it, you can set the
+/// [data points](Dataset::data), the [name](Dataset::name) or the
+/// [chart type](Dataset::graph_type). See [`Dataset`] for a complete documentation of what is
+/// possible.
+///
+/// Then, you'll usually want to configure the [`Axis`]. Axis [titles](Axis::title),
+/// [bounds](Axis::bounds) and [labels](Axis::labels) can be configured on both axis. See [`Axis`]
+/// for a complete documentation of what is possible.
+///
+/// Finally, you can pass all of that to the `Chart` via [`Chart::new`], [`Chart::x_axis`] and
+/// [`Chart::y_axis`].
+///
+/// Additionally, `Chart` allows configuring the legend [position](Chart::legend_position) and
 /// [hiding constraints](Chart::hidden_legend_constraints).
 ///
+/// When rendered as a [`StatefulWidget`] with a [`ChartState`], the chart can animate the changes
+/// of its datasets, see [`Chart::transition`], and highlight a [selected](ChartState::select) data
+/// point.
+///
 /// # Examples
 ///
 /// ```
@@ -500,6 +1267,8 @@ pub struct Chart<'a> {
     x_axis: Axis<'a>,
     /// The vertical axis
     y_axis: Axis<'a>,
+    /// The vertical axis on the right, used by the datasets of the [`YAxis::Secondary`] axis
+    y_axis_secondary: Option<Axis<'a>>,
     /// A reference to the datasets
     datasets: Vec<Dataset<'a>>,
     /// The widget base style
@@ -509,6 +1278,14 @@ pub struct Chart<'a> {
     /// The position determine where the length is shown or hide regardless of
     /// `hidden_legend_constraints`
     legend_position: Option<LegendPosition>,
+    /// The number of columns of the legend
+    legend_columns: u16,
+    /// The duration of the animation between two versions of the datasets
+    transition: Option<Duration>,
+    /// The gridlines drawn in the graph area
+    grid: Option<GridStyle>,
+    /// Style used for the crosshair and the label of the selected data point
+    selection_style: Style,
 }
 
 impl<'a> Chart<'a> {
@@ -542,10 +1319,15 @@ impl<'a> Chart<'a> {
             block: None,
             x_axis: Axis::default(),
             y_axis: Axis::default(),
+            y_axis_secondary: None,
             style: Style::default(),
             datasets,
             hidden_legend_constraints: (Constraint::Ratio(1, 4), Constraint::Ratio(1, 4)),
             legend_position: Some(LegendPosition::default()),
+            legend_columns: 1,
+            transition: None,
+            grid: None,
+            selection_style: Style::new(),
         }
     }
 
@@ -618,6 +1400,47 @@ impl<'a> Chart<'a> {
         self
     }
 
+    /// Sets the secondary Y [`Axis`], drawn on the right of the chart
+    ///
+    /// The datasets which are plotted against the [secondary](YAxis::Secondary) axis (see
+    /// [`Dataset::y_axis`]) are mapped using the bounds and scale of this axis instead of the
+    /// primary [`Chart::y_axis`]. This makes it possible to overlay two series with different
+    /// y scales, e.g. a temperature and a pressure over time.
+    ///
+    /// The labels of the axis are drawn on the right of the graph area and its title in the top
+    /// right corner. When the legend is shown and there is enough space, the names of the
+    /// datasets of the secondary axis are followed by an arrow pointing to the right.
+    ///
+    /// There is no secondary axis by default.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// # let temperature = vec![];
+    /// # let pressure = vec![];
+    /// let chart = Chart::new(vec![
+    ///     Dataset::default().name("temperature").data(&temperature),
+    ///     Dataset::default()
+    ///         .name("pressure")
+    ///         .data(&pressure)
+    ///         .y_axis(YAxis::Secondary),
+    /// ])
+    /// .y_axis(Axis::default().bounds([0.0, 40.0]).labels(["0", "40"]))
+    /// .y_axis_secondary(
+    ///     Axis::default()
+    ///         .bounds([950.0, 1050.0])
+    ///         .labels(["950", "1050"]),
+    /// );
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn y_axis_secondary(mut self, axis: Axis<'a>) -> Self {
+        self.y_axis_secondary = Some(axis);
+        self
+    }
+
     /// Sets the constraints used to determine whether the legend should be shown or not.
     ///
     /// The tuple's first constraint is used for the width and the second for the height. If the
@@ -627,6 +1450,9 @@ impl<'a> Chart<'a> {
     /// If this is not set, the default behavior is to hide the legend if it is greater than 25% of
     /// the chart, either horizontally or vertically.
     ///
+    /// The size of the legend includes all of its [columns](Chart::legend_columns). The legend is
+    /// compared with the graph area, or with the whole chart for [`LegendPosition::Outside`].
+    ///
     /// This is a fluent setter method which must be chained or used as it consumes self
     ///
     /// # Examples
@@ -701,12 +1527,308 @@ impl<'a> Chart<'a> {
         self
     }
 
+    /// Sets the number of columns of the legend
+    ///
+    /// The default is a single column, with a row for each named dataset. With more columns, the
+    /// names of the datasets are laid out from left to right then from top to bottom, which keeps
+    /// the legend of charts with many datasets short enough to fit. A value of `0` is treated as
+    /// `1`.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ratatui::widgets::{Chart, LegendPosition};
+    /// let chart: Chart = Chart::new(vec![])
+    ///     .legend_position(Some(LegendPosition::Outside))
+    ///     .legend_columns(2);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn legend_columns(mut self, columns: u16) -> Self {
+        self.legend_columns = columns;
+        self
+    }
+
+    /// Animates the changes of the datasets over the given duration
+    ///
+    /// When the chart is rendered as a [`StatefulWidget`], the [`ChartState`] remembers the data
+    /// of the previous frames. Whenever the data of the datasets changes, the points move from
+    /// their previous position to the new one over the given duration, instead of jumping
+    /// directly to it. The application has to keep drawing frames for the animation to be
+    /// visible.
+    ///
+    /// If the number of points of a dataset changes, the points which exist in both versions of
+    /// the data are animated and the others are drawn at their new position right away.
+    ///
+    /// Transitions are disabled by default, and have no effect when the chart is rendered without
+    /// a state.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use ratatui::{prelude::*, widgets::*};
+    ///
+    /// # fn ui(frame: &mut Frame, data: &[(f64, f64)], state: &mut ChartState) {
+    /// let chart = Chart::new(vec![Dataset::default().data(data)])
+    ///     .transition(Duration::from_millis(200));
+    /// frame.render_stateful_widget(chart, frame.size(), state);
+    /// # }
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn transition(mut self, duration: Duration) -> Self {
+        self.transition = Some(duration);
+        self
+    }
+
+    /// Draws gridlines in the graph area at the positions of the axis labels
+    ///
+    /// The lines are drawn beneath the datasets. Use [`GridStyle::lines`] to only draw the
+    /// horizontal or the vertical lines. A line which would be drawn next to an axis is skipped.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// # let data_points = vec![];
+    /// let chart = Chart::new(vec![Dataset::default().data(&data_points)])
+    ///     .x_axis(Axis::default().bounds([0.0, 10.0]).labels(["0", "5", "10"]))
+    ///     .y_axis(Axis::default().bounds([0.0, 10.0]).labels(["0", "5", "10"]))
+    ///     .grid(GridStyle::new());
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn grid(mut self, grid: GridStyle) -> Self {
+        self.grid = Some(grid);
+        self
+    }
+
+    /// Sets the style of the crosshair and of the label of the selected data point
+    ///
+    /// The selected point is set with [`ChartState::select`]. The crosshair is only drawn on the
+    /// cells of the graph area which are empty, so that it doesn't hide the datasets.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// # let data_points = vec![];
+    /// let chart = Chart::new(vec![Dataset::default().data(&data_points)])
+    ///     .selection_style(Style::new().yellow());
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn selection_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.selection_style = style.into();
+        self
+    }
+
+    /// Returns the data point which is drawn nearest to the given position, as the index of the
+    /// dataset and the index of the point in this dataset
+    ///
+    /// `area` is the area the chart is rendered in. Returns `None` when the position is outside of
+    /// the graph area or when no point is displayed. This makes it possible to select the point
+    /// under the mouse cursor, e.g. to show its value as a tooltip with [`ChartState::select`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let data = [(0.0, 0.0), (10.0, 10.0)];
+    /// let chart = Chart::new(vec![Dataset::default().data(&data)])
+    ///     .x_axis(Axis::default().bounds([0.0, 10.0]))
+    ///     .y_axis(Axis::default().bounds([0.0, 10.0]));
+    /// let area = Rect::new(0, 0, 11, 11);
+    /// assert_eq!(chart.point_at(area, Position::new(9, 2)), Some((0, 1)));
+    /// ```
+    #[must_use]
+    pub fn point_at(&self, area: Rect, position: Position) -> Option<(usize, usize)> {
+        let points = self.dataset_points();
+        let chart = self.resolve_bounds(&points);
+        let graph_area = chart.layout(chart.block.inner_if_some(area))?.graph_area;
+        if !graph_area.contains(position) {
+            return None;
+        }
+        let distance = |point: Position| {
+            let dx = u32::from(point.x.abs_diff(position.x));
+            let dy = u32::from(point.y.abs_diff(position.y));
+            dx * dx + dy * dy
+        };
+        chart
+            .plotted_data(&points)
+            .iter()
+            .zip(&chart.datasets)
+            .enumerate()
+            .flat_map(|(dataset_index, (data, dataset))| {
+                let chart = &*chart;
+                data.iter().enumerate().filter_map(move |(index, &point)| {
+                    let y_axis = chart.axis(chart.dataset_y_axis(dataset));
+                    let point = chart.point_position(graph_area, y_axis, point)?;
+                    Some(((dataset_index, index), point))
+                })
+            })
+            .min_by_key(|&(_, point)| distance(point))
+            .map(|(selected, _)| selected)
+    }
+
+    /// Returns the position of the cell where the given data coordinates are drawn
+    ///
+    /// `area` is the area the chart is rendered in, and the coordinates are mapped using the bounds
+    /// and scales of the x axis and of the primary y axis. Returns `None` when the coordinates are
+    /// outside of the bounds of the axes. See [`Chart::screen_to_data`] for the inverse mapping.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let chart = Chart::new(vec![])
+    ///     .x_axis(Axis::default().bounds([0.0, 10.0]))
+    ///     .y_axis(Axis::default().bounds([0.0, 10.0]));
+    /// let area = Rect::new(0, 0, 11, 11);
+    /// assert_eq!(chart.data_to_screen(area, (2.0, 3.0)), Some(Position::new(2, 7)));
+    /// assert_eq!(chart.data_to_screen(area, (20.0, 3.0)), None);
+    /// ```
+    #[must_use]
+    pub fn data_to_screen(&self, area: Rect, (x, y): (f64, f64)) -> Option<Position> {
+        let chart = self.resolve_bounds(&self.dataset_points());
+        let graph_area = chart.layout(chart.block.inner_if_some(area))?.graph_area;
+        let point = (chart.x_axis.map(x), chart.y_axis.map(y));
+        chart.point_position(graph_area, &chart.y_axis, point)
+    }
+
+    /// Returns the data coordinates drawn at the given position
+    ///
+    /// `area` is the area the chart is rendered in, and the coordinates are mapped using the bounds
+    /// and scales of the x axis and of the primary y axis. As a cell covers a range of coordinates,
+    /// the coordinates in the middle of this range are returned, so that they are drawn in the
+    /// same cell. Returns `None` when the position is outside of the graph area, i.e. on the axes,
+    /// the labels or the block. See [`Chart::data_to_screen`] for the inverse mapping.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let chart = Chart::new(vec![])
+    ///     .x_axis(Axis::default().bounds([0.0, 10.0]))
+    ///     .y_axis(Axis::default().bounds([0.0, 10.0]));
+    /// let area = Rect::new(0, 0, 11, 11);
+    /// assert_eq!(chart.screen_to_data(area, Position::new(2, 7)), Some((2.5, 2.5)));
+    /// assert_eq!(chart.screen_to_data(area, Position::new(20, 7)), None);
+    /// ```
+    #[must_use]
+    pub fn screen_to_data(&self, area: Rect, position: Position) -> Option<(f64, f64)> {
+        let chart = self.resolve_bounds(&self.dataset_points());
+        let graph_area = chart.layout(chart.block.inner_if_some(area))?.graph_area;
+        if !graph_area.contains(position) {
+            return None;
+        }
+        // each cell covers the range of coordinates up to the next cell, except for the last
+        // column and row which only contain the end of the axis
+        let fraction = |offset: u16, length: u16| {
+            if offset + 1 < length {
+                (f64::from(offset) + 0.5) / f64::from(length - 1)
+            } else {
+                1.0
+            }
+        };
+        let [left, right] = chart.x_axis.scaled_bounds();
+        let [bottom, top] = chart.y_axis.scaled_bounds();
+        let x = (right - left).mul_add(fraction(position.x - graph_area.x, graph_area.width), left);
+        // rows are numbered from the top of the graph area and end with the bottom of the axis
+        let y = (bottom - top).mul_add(fraction(position.y - graph_area.y, graph_area.height), top);
+        Some((chart.x_axis.unmap(x), chart.y_axis.unmap(y)))
+    }
+
+    /// Returns the data points of each dataset
+    fn dataset_points(&self) -> Vec<&[(f64, f64)]> {
+        self.datasets.iter().map(|dataset| dataset.data).collect()
+    }
+
+    /// Returns the chart where the automatic bounds of the axes are computed from the given data
+    /// points of each dataset
+    ///
+    /// The chart is only copied when some bounds are automatic. The non-finite points, which are
+    /// gaps in the data, are ignored.
+    fn resolve_bounds(&self, points: &[&[(f64, f64)]]) -> Cow<'_, Self> {
+        let secondary_bounds = self.y_axis_secondary.as_ref().map(|axis| axis.bounds);
+        if self.x_axis.bounds.is_some()
+            && self.y_axis.bounds.is_some()
+            && secondary_bounds != Some(None)
+        {
+            return Cow::Borrowed(self);
+        }
+        let mut chart = self.clone();
+        if self.x_axis.bounds.is_none() {
+            let values = points.iter().flat_map(|points| {
+                points
+                    .iter()
+                    .filter(|&&point| is_finite_point(point))
+                    .map(|&(x, _)| x)
+            });
+            chart.x_axis.bounds = Some(self.x_axis.auto_bounds(values));
+        }
+        if self.y_axis.bounds.is_none() {
+            chart.y_axis.bounds = Some(self.auto_y_bounds(YAxis::Primary, points));
+        }
+        if let Some(axis) = chart.y_axis_secondary.as_mut() {
+            if axis.bounds.is_none() {
+                axis.bounds = Some(self.auto_y_bounds(YAxis::Secondary, points));
+            }
+        }
+        Cow::Owned(chart)
+    }
+
+    /// Computes the automatic bounds of the given y axis from the data points of the datasets
+    /// plotted against it
+    fn auto_y_bounds(&self, y_axis: YAxis, points: &[&[(f64, f64)]]) -> [f64; 2] {
+        // stacked bars extend from the baseline up to the stacked totals rather than their own
+        // values
+        let mut bar_stacks = BarStacks::default();
+        let mut values = vec![];
+        for (dataset, points) in self
+            .datasets
+            .iter()
+            .zip(points)
+            .filter(|(dataset, _)| self.dataset_y_axis(dataset) == y_axis)
+        {
+            if dataset.graph_type == GraphType::StackedBar {
+                values.push(0.0);
+            }
+            for &(x, y) in points.iter().filter(|&&point| is_finite_point(point)) {
+                if dataset.graph_type == GraphType::StackedBar {
+                    values.push(bar_stacks.push(x, y).1);
+                } else {
+                    values.push(y);
+                }
+            }
+        }
+        self.axis(y_axis).auto_bounds(values.into_iter())
+    }
+
     /// Compute the internal layout of the chart given the area. If the area is too small some
     /// elements may be automatically hidden
+    #[allow(clippy::too_many_lines)]
     fn layout(&self, area: Rect) -> Option<ChartLayout> {
         if area.height == 0 || area.width == 0 {
             return None;
         }
+        let outside_legend = self.outside_legend_area(area);
+        let area = match outside_legend {
+            Some((legend_area, _)) => Rect {
+                width: area.width - legend_area.width,
+                ..area
+            },
+            None => area,
+        };
         let mut x = area.left();
         let mut y = area.bottom() - 1;
 
@@ -731,7 +1853,26 @@ impl<'a> Chart<'a> {
             x += 1;
         }
 
-        let graph_width = area.right().saturating_sub(x);
+        let mut right = area.right();
+        let mut label_y_secondary = None;
+        let mut axis_y_secondary = None;
+        if let Some(axis) = self.y_axis_secondary.as_ref() {
+            let width = axis
+                .labels
+                .iter()
+                .map(Line::width)
+                .max()
+                .unwrap_or_default() as u16;
+            let width = width.min(area.width / 3);
+            if !axis.labels.is_empty() && x + width + 1 < right {
+                right -= width;
+                label_y_secondary = Some(right);
+                right -= 1;
+                axis_y_secondary = Some(right);
+            }
+        }
+
+        let graph_width = right.saturating_sub(x);
         let graph_height = y.saturating_sub(area.top()).saturating_add(1);
         debug_assert_ne!(
             graph_width, 0,
@@ -759,57 +1900,185 @@ impl<'a> Chart<'a> {
             }
         }
 
-        let mut legend_area = None;
-        if let Some(legend_position) = self.legend_position {
-            let legends = self
-                .datasets
-                .iter()
-                .filter_map(|d| Some(d.name.as_ref()?.width() as u16));
-
-            if let Some(inner_width) = legends.clone().max() {
-                let legend_width = inner_width + 2;
-                let legend_height = legends.count() as u16 + 2;
-
-                let [max_legend_width] = Layout::horizontal([self.hidden_legend_constraints.0])
-                    .flex(Flex::Start)
-                    .areas(graph_area);
-
-                let [max_legend_height] = Layout::vertical([self.hidden_legend_constraints.1])
-                    .flex(Flex::Start)
-                    .areas(graph_area);
-
-                if inner_width > 0
-                    && legend_width <= max_legend_width.width
-                    && legend_height <= max_legend_height.height
-                {
-                    legend_area = legend_position.layout(
-                        graph_area,
-                        legend_width,
-                        legend_height,
-                        title_x
-                            .and(self.x_axis.title.as_ref())
-                            .map(|t| t.width() as u16)
-                            .unwrap_or_default(),
-                        title_y
-                            .and(self.y_axis.title.as_ref())
-                            .map(|t| t.width() as u16)
-                            .unwrap_or_default(),
-                    );
-                }
+        let mut title_y_secondary = None;
+        if let Some(title) = self
+            .y_axis_secondary
+            .as_ref()
+            .and_then(|a| a.title.as_ref())
+        {
+            let w = title.width() as u16;
+            let primary_width = title_y
+                .and(self.y_axis.title.as_ref())
+                .map_or(0, |t| t.width() as u16 + 1);
+            if w + primary_width + 1 < graph_area.width && graph_area.height > 2 {
+                title_y_secondary = Some(Position::new(graph_area.right() - w, area.top()));
+            }
+        }
+
+        let mut legend_area = outside_legend.map(|(legend_area, _)| legend_area);
+        let mut legend_axis_markers = outside_legend.is_some_and(|(_, markers)| markers);
+        if let Some(legend_position) = self
+            .legend_position
+            .filter(|&position| position != LegendPosition::Outside)
+        {
+            let legend_area_with = |axis_markers| {
+                self.legend_area(
+                    legend_position,
+                    graph_area,
+                    axis_markers,
+                    title_x.and(self.x_axis.title.as_ref()),
+                    title_y.and(self.y_axis.title.as_ref()),
+                    title_y_secondary,
+                )
+            };
+            // the datasets of the secondary axis are marked if the legend still fits with the marks
+            if self.y_axis_secondary.is_some() {
+                legend_area = legend_area_with(true);
+                legend_axis_markers = legend_area.is_some();
+            }
+            if legend_area.is_none() {
+                legend_area = legend_area_with(false);
             }
         }
         Some(ChartLayout {
             title_x,
             title_y,
+            title_y_secondary,
             label_x,
             label_y,
+            label_y_secondary,
             axis_x,
             axis_y,
+            axis_y_secondary,
             legend_area,
+            legend_axis_markers,
             graph_area,
         })
     }
 
+    /// Returns the names of the datasets shown in the legend, with the marks of the datasets of the
+    /// secondary y axis if `axis_markers` is set
+    fn legend_entries(&self, axis_markers: bool) -> Vec<Line<'a>> {
+        self.datasets
+            .iter()
+            .filter_map(|dataset| {
+                let mut name = dataset.name.clone()?;
+                if axis_markers && self.dataset_y_axis(dataset) == YAxis::Secondary {
+                    name.push_span(SECONDARY_AXIS_LEGEND_MARKER);
+                }
+                Some(name.patch_style(dataset.style()))
+            })
+            .collect()
+    }
+
+    /// Returns the width of each column of the legend, given the width of its entries
+    ///
+    /// The entries are laid out from left to right then from top to bottom.
+    fn legend_column_widths(&self, widths: &[u16]) -> Vec<u16> {
+        let columns = usize::from(self.legend_columns.max(1)).min(widths.len());
+        (0..columns)
+            .map(|column| {
+                widths
+                    .iter()
+                    .skip(column)
+                    .step_by(columns)
+                    .copied()
+                    .max()
+                    .unwrap_or_default()
+            })
+            .collect()
+    }
+
+    /// Returns the width and the height of the legend, borders included, if it fits in the area
+    /// according to the [hidden legend constraints](Chart::hidden_legend_constraints)
+    fn legend_size(&self, area: Rect, axis_markers: bool) -> Option<(u16, u16)> {
+        let widths: Vec<_> = self
+            .legend_entries(axis_markers)
+            .iter()
+            .map(|name| name.width() as u16)
+            .collect();
+        let column_widths = self.legend_column_widths(&widths);
+        if column_widths.is_empty() {
+            return None;
+        }
+        let inner_width = column_widths.iter().sum::<u16>()
+            + (column_widths.len() as u16 - 1) * LEGEND_COLUMN_SPACING;
+        let legend_width = inner_width + 2;
+        let legend_height = widths.len().div_ceil(column_widths.len()) as u16 + 2;
+
+        let [max_legend_width] = Layout::horizontal([self.hidden_legend_constraints.0])
+            .flex(Flex::Start)
+            .areas(area);
+
+        let [max_legend_height] = Layout::vertical([self.hidden_legend_constraints.1])
+            .flex(Flex::Start)
+            .areas(area);
+
+        if column_widths.iter().all(|&width| width == 0)
+            || legend_width > max_legend_width.width
+            || legend_height > max_legend_height.height
+        {
+            return None;
+        }
+        Some((legend_width, legend_height))
+    }
+
+    /// Computes the area of a [`LegendPosition::Outside`] legend in the chart area, if it fits
+    /// next to the graph, and whether the datasets of the secondary y axis are marked in it
+    fn outside_legend_area(&self, area: Rect) -> Option<(Rect, bool)> {
+        if self.legend_position != Some(LegendPosition::Outside) {
+            return None;
+        }
+        // the datasets of the secondary axis are marked if the legend still fits with the marks
+        [true, false]
+            .into_iter()
+            .filter(|&markers| !markers || self.y_axis_secondary.is_some())
+            .find_map(|markers| {
+                let (width, height) = self.legend_size(area, markers)?;
+                // leave some space for the graph
+                if width >= area.width {
+                    return None;
+                }
+                let legend_area = LegendPosition::Outside.layout(area, width, height, 0, 0)?;
+                Some((legend_area, markers))
+            })
+    }
+
+    /// Computes the area of the legend in the graph area, if it fits
+    fn legend_area(
+        &self,
+        legend_position: LegendPosition,
+        graph_area: Rect,
+        axis_markers: bool,
+        title_x: Option<&Line>,
+        title_y: Option<&Line>,
+        title_y_secondary: Option<Position>,
+    ) -> Option<Rect> {
+        let (legend_width, legend_height) = self.legend_size(graph_area, axis_markers)?;
+        let title_secondary_width = title_y_secondary
+            .and(self.y_axis_secondary.as_ref())
+            .and_then(|axis| axis.title.as_ref())
+            .map_or(0, |t| t.width() as u16);
+        let mut legend_area = legend_position.layout(
+            graph_area,
+            legend_width,
+            legend_height,
+            title_x.map(|t| t.width() as u16).unwrap_or_default(),
+            // the titles of both y axes are on the top row of the graph area
+            title_y.map_or(title_secondary_width, |t| t.width() as u16),
+        )?;
+        if let Some(title) = title_y_secondary {
+            let title_area = Rect::new(title.x, title.y, title_secondary_width, 1);
+            if legend_area.intersects(title_area) {
+                if legend_area.bottom() >= graph_area.bottom() {
+                    return None;
+                }
+                legend_area.y += 1;
+            }
+        }
+        Some(legend_area)
+    }
+
     fn max_width_of_labels_left_of_y_axis(&self, area: Rect, has_y_axis: bool) -> u16 {
         let mut max_width = self
             .y_axis
@@ -845,6 +2114,16 @@ impl<'a> Chart<'a> {
         graph_area: Rect,
     ) {
         let Some(y) = layout.label_x else { return };
+        if self.x_axis.label_positions.is_some() {
+            for (label, offset) in self.x_axis.positioned_labels(graph_area.width) {
+                let width = (label.width() as u16).min(chart_area.width);
+                let x = (graph_area.left() + offset)
+                    .saturating_sub(width / 2)
+                    .clamp(chart_area.left(), chart_area.right() - width);
+                Self::render_label(buf, label, Rect::new(x, y, width, 1), Alignment::Left);
+            }
+            return;
+        }
         let labels = &self.x_axis.labels;
         let labels_len = labels.len() as u16;
         if labels_len < 2 {
@@ -907,6 +2186,38 @@ impl<'a> Chart<'a> {
         Rect::new(min_x, y, max_x - min_x, 1)
     }
 
+    /// Draws the gridlines in the cells of the graph area which were left blank by the datasets
+    fn render_grid(&self, buf: &mut Buffer, layout: &ChartLayout) {
+        let Some(grid) = self.grid else { return };
+        let area = layout.graph_area;
+        // a line at the first label would be drawn right next to the axis
+        let mut columns = vec![];
+        if grid.lines != GridLines::Horizontal {
+            columns = self.x_axis.label_offsets(area.width);
+            columns.retain(|&offset| offset > 0 || layout.axis_y.is_none());
+        }
+        let mut rows = vec![];
+        if grid.lines != GridLines::Vertical {
+            rows = self.y_axis.label_offsets(area.height);
+            rows.retain(|&offset| offset > 0 || layout.axis_x.is_none());
+        }
+        for y in area.top()..area.bottom() {
+            let is_row = rows.contains(&(area.bottom() - 1 - y));
+            for x in area.left()..area.right() {
+                let symbol = match (columns.contains(&(x - area.left())), is_row) {
+                    (true, true) => symbols::line::CROSS,
+                    (true, false) => symbols::line::VERTICAL,
+                    (false, true) => symbols::line::HORIZONTAL,
+                    (false, false) => continue,
+                };
+                let cell = &mut buf[(x, y)];
+                if cell.symbol() == " " {
+                    cell.set_symbol(symbol).set_style(grid.style);
+                }
+            }
+        }
+    }
+
     fn render_label(buf: &mut Buffer, label: &Line, label_area: Rect, alignment: Alignment) {
         let label = match alignment {
             Alignment::Left => label.clone().left_aligned(),
@@ -916,40 +2227,301 @@ impl<'a> Chart<'a> {
         label.render(label_area, buf);
     }
 
-    fn render_y_labels(
+    /// Fills the cells of the graph area between the line joining the (scaled) data points and zero
+    /// with the given background color
+    ///
+    /// A cell is filled when its center lies in the area, so the fill is clipped to the bounds of
+    /// the axes. Columns outside of the range of the data are not filled.
+    fn render_area_fill(
         &self,
         buf: &mut Buffer,
-        layout: &ChartLayout,
-        chart_area: Rect,
         graph_area: Rect,
+        y_axis: &Axis,
+        data: &[(f64, f64)],
+        color: Color,
     ) {
-        let Some(x) = layout.label_y else { return };
-        let labels = &self.y_axis.labels;
-        let labels_len = labels.len() as u16;
-        for (i, label) in labels.iter().enumerate() {
-            let dy = i as u16 * (graph_area.height - 1) / (labels_len - 1);
-            if dy < graph_area.bottom() {
-                let label_area = Rect::new(
-                    x,
-                    graph_area.bottom().saturating_sub(1) - dy,
-                    (graph_area.left() - chart_area.left()).saturating_sub(1),
-                    1,
-                );
-                Self::render_label(buf, label, label_area, self.y_axis.labels_alignment);
-            }
+        let [left, right] = self.x_axis.scaled_bounds();
+        let [bottom, top] = y_axis.scaled_bounds();
+        if right <= left || top <= bottom {
+            return;
         }
-    }
+        let baseline = y_axis.scale_bar_start(0.0);
+        let width = f64::from(graph_area.width);
+        let height = f64::from(graph_area.height);
+        for (column, x) in (graph_area.left()..graph_area.right()).enumerate() {
+            let center_x = left + (column as f64 + 0.5) * (right - left) / width;
+            let Some(y) = interpolate(data, center_x) else {
+                continue;
+            };
+            let (low, high) = if y < baseline {
+                (y, baseline)
+            } else {
+                (baseline, y)
+            };
+            for (row, y) in (graph_area.top()..graph_area.bottom()).enumerate() {
+                let center_y = top - (row as f64 + 0.5) * (top - bottom) / height;
+                if low <= center_y && center_y <= high {
+                    buf[(x, y)].set_bg(color);
+                }
+            }
+        }
+    }
+
+    /// Maps the data points of a dataset to their positions along the x axis and the given y axis
+    ///
+    /// The points with a non-finite coordinate are mapped to NaN, so that they remain gaps
+    /// whatever the scale of the axes. The data is only copied when one of the axes is not linear
+    /// or is reversed, or when the data contains infinite points.
+    fn scale_data<'d>(&self, y_axis: &Axis, data: &'d [(f64, f64)]) -> Cow<'d, [(f64, f64)]> {
+        let is_identity = |axis: &Axis| axis.scale == AxisScale::Linear && !axis.is_reversed();
+        let is_gap = |&(x, y): &(f64, f64)| x.is_nan() || y.is_nan();
+        if is_identity(&self.x_axis)
+            && is_identity(y_axis)
+            && data
+                .iter()
+                .all(|&point| is_finite_point(point) || is_gap(&point))
+        {
+            return Cow::Borrowed(data);
+        }
+        data.iter()
+            .map(|&point| {
+                if is_finite_point(point) {
+                    (self.x_axis.map(point.0), y_axis.map(point.1))
+                } else {
+                    (f64::NAN, f64::NAN)
+                }
+            })
+            .collect()
+    }
+
+    /// Renders the labels of a y axis in the column of the given width starting at `x`
+    fn render_y_labels(buf: &mut Buffer, axis: &Axis, x: u16, width: u16, graph_area: Rect) {
+        if axis.label_positions.is_some() {
+            for (label, offset) in axis.positioned_labels(graph_area.height) {
+                let label_area = Rect::new(x, graph_area.bottom() - 1 - offset, width, 1);
+                Self::render_label(buf, label, label_area, axis.labels_alignment);
+            }
+            return;
+        }
+        let labels = &axis.labels;
+        let labels_len = labels.len() as u16;
+        for (i, label) in labels.iter().enumerate() {
+            let dy = i as u16 * (graph_area.height - 1) / (labels_len - 1);
+            if dy < graph_area.bottom() {
+                let label_area = Rect::new(x, graph_area.bottom().saturating_sub(1) - dy, width, 1);
+                Self::render_label(buf, label, label_area, axis.labels_alignment);
+            }
+        }
+    }
+
+    /// Returns the y axis the given dataset is plotted against
+    ///
+    /// Datasets of the secondary axis use the primary axis when the chart has no secondary axis.
+    const fn dataset_y_axis(&self, dataset: &Dataset) -> YAxis {
+        match (dataset.y_axis, &self.y_axis_secondary) {
+            (YAxis::Secondary, Some(_)) => YAxis::Secondary,
+            _ => YAxis::Primary,
+        }
+    }
+
+    /// Returns the positions of the given data points of each dataset along the axes, as they are
+    /// plotted
+    ///
+    /// Stacked bars are positioned at their stacked totals.
+    fn plotted_data(&self, points: &[&[(f64, f64)]]) -> Vec<Vec<(f64, f64)>> {
+        let mut bar_stacks = [BarStacks::default(), BarStacks::default()];
+        self.datasets
+            .iter()
+            .zip(points)
+            .map(|(dataset, points)| {
+                let which_y_axis = self.dataset_y_axis(dataset);
+                let y_axis = self.axis(which_y_axis);
+                if dataset.graph_type == GraphType::StackedBar {
+                    let bar_stacks = &mut bar_stacks[usize::from(which_y_axis == YAxis::Secondary)];
+                    let totals: Vec<_> = points
+                        .iter()
+                        .map(|&(x, y)| {
+                            if is_finite_point((x, y)) {
+                                (x, bar_stacks.push(x, y).1)
+                            } else {
+                                (f64::NAN, f64::NAN)
+                            }
+                        })
+                        .collect();
+                    self.scale_data(y_axis, &totals).into_owned()
+                } else {
+                    self.scale_data(y_axis, points).into_owned()
+                }
+            })
+            .collect()
+    }
+
+    /// Returns the cell of the graph area where a point plotted against the given y axis is drawn,
+    /// or `None` when the point is outside of the bounds of the axes
+    ///
+    /// This matches the position of the points drawn with a marker of one dot per cell.
+    fn point_position(
+        &self,
+        graph_area: Rect,
+        y_axis: &Axis,
+        (x, y): (f64, f64),
+    ) -> Option<Position> {
+        let [left, right] = self.x_axis.scaled_bounds();
+        let [bottom, top] = y_axis.scaled_bounds();
+        let (width, height) = (right - left, top - bottom);
+        if graph_area.is_empty()
+            || width == 0.0
+            || height == 0.0
+            || !(left..=right).contains(&x)
+            || !(bottom..=top).contains(&y)
+        {
+            return None;
+        }
+        let column = ((x - left) * f64::from(graph_area.width - 1) / width) as u16;
+        let row = ((top - y) * f64::from(graph_area.height - 1) / height) as u16;
+        Some(Position::new(graph_area.x + column, graph_area.y + row))
+    }
+
+    /// Returns the given y axis, or the primary axis when the chart has no secondary axis
+    const fn axis(&self, y_axis: YAxis) -> &Axis<'a> {
+        match (y_axis, &self.y_axis_secondary) {
+            (YAxis::Secondary, Some(axis)) => axis,
+            _ => &self.y_axis,
+        }
+    }
 }
 
 impl Widget for Chart<'_> {
     fn render(self, area: Rect, buf: &mut Buffer) {
-        self.render_ref(area, buf);
+        WidgetRef::render_ref(&self, area, buf);
+    }
+}
+
+impl StatefulWidget for Chart<'_> {
+    type State = ChartState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        StatefulWidgetRef::render_ref(&self, area, buf, state);
+    }
+}
+
+// Note: remove this when StatefulWidgetRef is stabilized and replace with the blanket impl
+impl StatefulWidget for &Chart<'_> {
+    type State = ChartState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        StatefulWidgetRef::render_ref(self, area, buf, state);
+    }
+}
+
+impl StatefulWidgetRef for Chart<'_> {
+    type State = ChartState;
+
+    fn render_ref(&self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        self.render_at(area, buf, state, Instant::now());
+    }
+}
+
+impl Chart<'_> {
+    /// Renders the chart as it is displayed at the instant `now` of its transition
+    fn render_at(&self, area: Rect, buf: &mut Buffer, state: &mut ChartState, now: Instant) {
+        let selected = state.selected;
+        let points: Vec<_> = state
+            .update(&self.datasets, self.transition, now)
+            .iter()
+            .map(Vec::as_slice)
+            .collect();
+        let chart = self.resolve_bounds(&points);
+        chart.render_points(area, buf, &points);
+        if let Some(selected) = selected {
+            chart.render_selection(area, buf, &points, selected);
+        }
+    }
+
+    /// Draws a crosshair through the selected data point and a label with its value
+    ///
+    /// The label is drawn above and to the right of the point, and moved to the other side of the
+    /// point when it would go past the edge of the graph area.
+    fn render_selection(
+        &self,
+        area: Rect,
+        buf: &mut Buffer,
+        points: &[&[(f64, f64)]],
+        (dataset_index, index): (usize, usize),
+    ) {
+        let Some(layout) = self.layout(self.block.inner_if_some(area)) else {
+            return;
+        };
+        let graph_area = layout.graph_area;
+        let (Some(dataset), Some(&(x, y)), Some(&point)) = (
+            self.datasets.get(dataset_index),
+            points
+                .get(dataset_index)
+                .and_then(|points| points.get(index)),
+            self.plotted_data(points)
+                .get(dataset_index)
+                .and_then(|data| data.get(index)),
+        ) else {
+            return;
+        };
+        let y_axis = self.axis(self.dataset_y_axis(dataset));
+        let Some(position) = self.point_position(graph_area, y_axis, point) else {
+            return;
+        };
+
+        let crosshair = graph_area
+            .columns()
+            .map(|column| (column.x, position.y, symbols::line::HORIZONTAL))
+            .chain(
+                graph_area
+                    .rows()
+                    .map(|row| (position.x, row.y, symbols::line::VERTICAL)),
+            );
+        for (x, y, symbol) in crosshair {
+            let cell = &mut buf[(x, y)];
+            if (x, y) != (position.x, position.y) && cell.symbol() == " " {
+                cell.set_symbol(symbol).set_style(self.selection_style);
+            }
+        }
+
+        let label = format!("({x}, {y})");
+        let width = (label.width() as u16).min(graph_area.width);
+        let label_x = if position.x + 1 + width <= graph_area.right() {
+            position.x + 1
+        } else {
+            position.x.saturating_sub(width).max(graph_area.left())
+        };
+        let label_y = if position.y > graph_area.top() {
+            position.y - 1
+        } else if position.y + 1 < graph_area.bottom() {
+            position.y + 1
+        } else {
+            position.y
+        };
+        buf.set_stringn(
+            label_x,
+            label_y,
+            label,
+            usize::from(width),
+            self.selection_style,
+        );
     }
 }
 
 impl WidgetRef for Chart<'_> {
-    #[allow(clippy::too_many_lines)]
     fn render_ref(&self, area: Rect, buf: &mut Buffer) {
+        let points = self.dataset_points();
+        self.resolve_bounds(&points)
+            .render_points(area, buf, &points);
+    }
+}
+
+impl Chart<'_> {
+    /// Renders the chart with the given data points of each dataset
+    ///
+    /// The bounds of the axes must have been resolved with [`Chart::resolve_bounds`].
+    #[allow(clippy::too_many_lines)]
+    fn render_points(&self, area: Rect, buf: &mut Buffer, points: &[&[(f64, f64)]]) {
         buf.set_style(area, self.style);
 
         self.block.render_ref(area, buf);
@@ -965,7 +2537,14 @@ impl WidgetRef for Chart<'_> {
         let original_style = buf[(area.left(), area.top())].style();
 
         self.render_x_labels(buf, &layout, chart_area, graph_area);
-        self.render_y_labels(buf, &layout, chart_area, graph_area);
+        if let Some(x) = layout.label_y {
+            let width = (graph_area.left() - chart_area.left()).saturating_sub(1);
+            Self::render_y_labels(buf, &self.y_axis, x, width, graph_area);
+        }
+        if let (Some(x), Some(axis)) = (layout.label_y_secondary, &self.y_axis_secondary) {
+            let width = chart_area.right() - x;
+            Self::render_y_labels(buf, axis, x, width, graph_area);
+        }
 
         if let Some(y) = layout.axis_x {
             for x in graph_area.left()..graph_area.right() {
@@ -983,28 +2562,72 @@ impl WidgetRef for Chart<'_> {
             }
         }
 
+        if let (Some(x), Some(axis)) = (layout.axis_y_secondary, &self.y_axis_secondary) {
+            for y in graph_area.top()..graph_area.bottom() {
+                buf[(x, y)]
+                    .set_symbol(symbols::line::VERTICAL)
+                    .set_style(axis.style);
+            }
+        }
+
         if let Some(y) = layout.axis_x {
             if let Some(x) = layout.axis_y {
                 buf[(x, y)]
                     .set_symbol(symbols::line::BOTTOM_LEFT)
                     .set_style(self.x_axis.style);
             }
+            if let Some(x) = layout.axis_y_secondary {
+                buf[(x, y)]
+                    .set_symbol(symbols::line::BOTTOM_RIGHT)
+                    .set_style(self.x_axis.style);
+            }
         }
 
-        for dataset in &self.datasets {
+        let x_bounds = self.x_axis.scaled_bounds();
+        // the bars of each y axis are stacked separately
+        let mut bar_stacks = [BarStacks::default(), BarStacks::default()];
+        for (dataset, points) in self.datasets.iter().zip(points) {
+            let which_y_axis = self.dataset_y_axis(dataset);
+            let y_axis = self.axis(which_y_axis);
+            let y_bounds = y_axis.scaled_bounds();
+            // stacked bars are drawn from the end of the previous segment to the stacked total
+            let (data, bar_starts) = if dataset.graph_type == GraphType::StackedBar {
+                let bar_stacks = &mut bar_stacks[usize::from(which_y_axis == YAxis::Secondary)];
+                let (totals, starts): (Vec<_>, Vec<_>) = points
+                    .iter()
+                    .map(|&(x, y)| {
+                        // the gaps are not stacked
+                        if !is_finite_point((x, y)) {
+                            return ((f64::NAN, f64::NAN), f64::NAN);
+                        }
+                        let (start, end) = bar_stacks.push(x, y);
+                        ((x, end), y_axis.scale_bar_start(start))
+                    })
+                    .unzip();
+                (
+                    Cow::Owned(self.scale_data(y_axis, &totals).into_owned()),
+                    starts,
+                )
+            } else {
+                (self.scale_data(y_axis, points), vec![])
+            };
             Canvas::default()
                 .background_color(self.style.bg.unwrap_or(Color::Reset))
-                .x_bounds(self.x_axis.bounds)
-                .y_bounds(self.y_axis.bounds)
-                .marker(dataset.marker)
+                .x_bounds(x_bounds)
+                .y_bounds(y_bounds)
+                .point_marker(dataset.marker.clone())
                 .paint(|ctx| {
                     ctx.draw(&Points {
-                        coords: dataset.data,
+                        coords: &dataset.points_with_marker(&data, None),
                         color: dataset.style.fg.unwrap_or(Color::Reset),
                     });
                     match dataset.graph_type {
-                        GraphType::Line => {
-                            for data in dataset.data.windows(2) {
+                        GraphType::Line | GraphType::Area => {
+                            // the line is broken at the non-finite points
+                            for data in data
+                                .windows(2)
+                                .filter(|data| is_finite_point(data[0]) && is_finite_point(data[1]))
+                            {
                                 ctx.draw(&CanvasLine {
                                     x1: data[0].0,
                                     y1: data[0].1,
@@ -1015,10 +2638,26 @@ impl WidgetRef for Chart<'_> {
                             }
                         }
                         GraphType::Bar => {
-                            for (x, y) in dataset.data {
+                            let y1 = y_axis.scale_bar_start(0.0);
+                            for (x, y) in data.iter().filter(|&&point| is_finite_point(point)) {
+                                ctx.draw(&CanvasLine {
+                                    x1: *x,
+                                    y1,
+                                    x2: *x,
+                                    y2: *y,
+                                    color: dataset.style.fg.unwrap_or(Color::Reset),
+                                });
+                            }
+                        }
+                        GraphType::StackedBar => {
+                            for ((x, y), y1) in data
+                                .iter()
+                                .zip(&bar_starts)
+                                .filter(|(&point, _)| is_finite_point(point))
+                            {
                                 ctx.draw(&CanvasLine {
                                     x1: *x,
-                                    y1: 0.0,
+                                    y1: *y1,
                                     x2: *x,
                                     y2: *y,
                                     color: dataset.style.fg.unwrap_or(Color::Reset),
@@ -1029,8 +2668,37 @@ impl WidgetRef for Chart<'_> {
                     }
                 })
                 .render(graph_area, buf);
+
+            // the points with their own marker are drawn on top, with a canvas for each marker
+            for marker in dataset.point_marker_kinds() {
+                Canvas::default()
+                    .background_color(self.style.bg.unwrap_or(Color::Reset))
+                    .x_bounds(x_bounds)
+                    .y_bounds(y_bounds)
+                    .marker(marker)
+                    .paint(|ctx| {
+                        ctx.draw(&Points {
+                            coords: &dataset.points_with_marker(&data, Some(marker)),
+                            color: dataset.style.fg.unwrap_or(Color::Reset),
+                        });
+                    })
+                    .render(graph_area, buf);
+            }
+        }
+
+        // the background of every canvas is reset when it is rendered, so the areas are filled
+        // once all the datasets are drawn
+        for (dataset, points) in self.datasets.iter().zip(points) {
+            if dataset.graph_type == GraphType::Area {
+                let y_axis = self.axis(self.dataset_y_axis(dataset));
+                let data = self.scale_data(y_axis, points);
+                self.render_area_fill(buf, graph_area, y_axis, &data, dataset.fill_color());
+            }
         }
 
+        // the gridlines only fill the blank cells so that they appear beneath the datasets
+        self.render_grid(buf, &layout);
+
         if let Some(Position { x, y }) = layout.title_x {
             let title = self.x_axis.title.as_ref().unwrap();
             let width = graph_area
@@ -1067,22 +2735,39 @@ impl WidgetRef for Chart<'_> {
             buf.set_line(x, y, title, width);
         }
 
+        if let Some(Position { x, y }) = layout.title_y_secondary {
+            let axis = self.y_axis_secondary.as_ref().unwrap();
+            let title = axis.title.as_ref().unwrap();
+            let width = graph_area
+                .right()
+                .saturating_sub(x)
+                .min(title.width() as u16);
+            buf.set_style(Rect::new(x, y, width, 1), original_style);
+            buf.set_line(x, y, title, width);
+        }
+
         if let Some(legend_area) = layout.legend_area {
             buf.set_style(legend_area, original_style);
             Block::bordered().render(legend_area, buf);
 
-            for (i, (dataset_name, dataset_style)) in self
-                .datasets
+            let names = self.legend_entries(layout.legend_axis_markers);
+            let widths: Vec<_> = names.iter().map(|name| name.width() as u16).collect();
+            let column_widths = self.legend_column_widths(&widths);
+            let column_xs: Vec<_> = column_widths
                 .iter()
-                .filter_map(|ds| Some((ds.name.as_ref()?, ds.style())))
-                .enumerate()
-            {
-                let name = dataset_name.clone().patch_style(dataset_style);
+                .scan(legend_area.x + 1, |x, width| {
+                    let column_x = *x;
+                    *x += width + LEGEND_COLUMN_SPACING;
+                    Some(column_x)
+                })
+                .collect();
+            for (i, name) in names.iter().enumerate() {
+                let column = i % column_widths.len();
                 name.render(
                     Rect {
-                        x: legend_area.x + 1,
-                        y: legend_area.y + 1 + i as u16,
-                        width: legend_area.width - 2,
+                        x: column_xs[column],
+                        y: legend_area.y + 1 + (i / column_widths.len()) as u16,
+                        width: column_widths[column],
                         height: 1,
                     },
                     buf,
@@ -1135,6 +2820,11 @@ mod tests {
 
     use super::*;
 
+    /// Returns the chart with the automatic bounds of its axes computed from its datasets
+    fn resolved<'a>(chart: &Chart<'a>) -> Chart<'a> {
+        chart.resolve_bounds(&chart.dataset_points()).into_owned()
+    }
+
     struct LegendTestCase {
         chart_area: Rect,
         hidden_legend_constraints: (Constraint, Constraint),
@@ -1213,6 +2903,8 @@ mod tests {
         assert_eq!(GraphType::Scatter.to_string(), "Scatter");
         assert_eq!(GraphType::Line.to_string(), "Line");
         assert_eq!(GraphType::Bar.to_string(), "Bar");
+        assert_eq!(GraphType::StackedBar.to_string(), "StackedBar");
+        assert_eq!(GraphType::Area.to_string(), "Area");
     }
 
     #[test]
@@ -1220,16 +2912,43 @@ mod tests {
         assert_eq!("Scatter".parse::<GraphType>(), Ok(GraphType::Scatter));
         assert_eq!("Line".parse::<GraphType>(), Ok(GraphType::Line));
         assert_eq!("Bar".parse::<GraphType>(), Ok(GraphType::Bar));
+        assert_eq!("StackedBar".parse::<GraphType>(), Ok(GraphType::StackedBar));
+        assert_eq!("Area".parse::<GraphType>(), Ok(GraphType::Area));
         assert_eq!("".parse::<GraphType>(), Err(ParseError::VariantNotFound));
     }
 
+    #[test]
+    fn axis_scale_to_string() {
+        assert_eq!(AxisScale::Linear.to_string(), "Linear");
+        assert_eq!(AxisScale::Logarithmic.to_string(), "Logarithmic");
+    }
+
+    #[test]
+    fn axis_scale_from_str() {
+        assert_eq!("Linear".parse::<AxisScale>(), Ok(AxisScale::Linear));
+        assert_eq!(
+            "Logarithmic".parse::<AxisScale>(),
+            Ok(AxisScale::Logarithmic)
+        );
+        assert_eq!("".parse::<AxisScale>(), Err(ParseError::VariantNotFound));
+    }
+
+    #[test]
+    fn logarithmic_scale_clamps_non_positive_values() {
+        let scale = AxisScale::Logarithmic;
+        assert!((scale.apply(100.0) - 2.0).abs() < f64::EPSILON);
+        assert!(scale.apply(0.0).is_finite());
+        assert!(scale.apply(-10.0).is_finite());
+        assert!(scale.apply(f64::NAN).is_finite());
+    }
+
     #[test]
     fn it_does_not_panic_if_title_is_wider_than_buffer() {
         let widget = Chart::default()
             .y_axis(Axis::default().title("xxxxxxxxxxxxxxxx"))
             .x_axis(Axis::default().title("xxxxxxxxxxxxxxxx"));
         let mut buffer = Buffer::empty(Rect::new(0, 0, 8, 4));
-        widget.render(buffer.area, &mut buffer);
+        Widget::render(widget, buffer.area, &mut buffer);
         assert_eq!(buffer, Buffer::with_lines(vec![" ".repeat(8); 4]));
     }
 
@@ -1264,7 +2983,7 @@ mod tests {
         let widget = Chart::new(vec![long_dataset_name, short_dataset])
             .hidden_legend_constraints((100.into(), 100.into()));
         let mut buffer = Buffer::empty(Rect::new(0, 0, 20, 5));
-        widget.render(buffer.area, &mut buffer);
+        Widget::render(widget, buffer.area, &mut buffer);
         let expected = Buffer::with_lines([
             "    ┌──────────────┐",
             "    │Very long name│",
@@ -1281,7 +3000,7 @@ mod tests {
             .legend_position(Some(LegendPosition::TopLeft));
         let area = Rect::new(0, 0, 30, 20);
         let mut buffer = Buffer::empty(area);
-        chart.render(buffer.area, &mut buffer);
+        Widget::render(chart, buffer.area, &mut buffer);
         let expected = Buffer::with_lines([
             "┌───┐                         ",
             "│Ds1│                         ",
@@ -1313,7 +3032,7 @@ mod tests {
             .y_axis(Axis::default().title("The title overlap a legend."));
         let area = Rect::new(0, 0, 30, 20);
         let mut buffer = Buffer::empty(area);
-        chart.render(buffer.area, &mut buffer);
+        Widget::render(chart, buffer.area, &mut buffer);
         let expected = Buffer::with_lines([
             "The title overlap a legend.   ",
             "                         ┌───┐",
@@ -1345,7 +3064,7 @@ mod tests {
             .y_axis(Axis::default().title("The title overlap a legend."));
         let area = Rect::new(0, 0, 10, 10);
         let mut buffer = Buffer::empty(area);
-        chart.render(buffer.area, &mut buffer);
+        Widget::render(chart, buffer.area, &mut buffer);
         let expected = Buffer::with_lines([
             "          ",
             "          ",
@@ -1380,7 +3099,7 @@ mod tests {
         ] {
             let chart = chart.clone().legend_position(Some(position));
             buffer.reset();
-            chart.render(buffer.area, &mut buffer);
+            Widget::render(chart, buffer.area, &mut buffer);
             #[rustfmt::skip]
             let expected = Buffer::with_lines([
                 "┌────┐",
@@ -1477,7 +3196,7 @@ mod tests {
         let chart = Chart::new(vec![Dataset::default().name(name)])
             .legend_position(legend_position)
             .hidden_legend_constraints((Constraint::Percentage(100), Constraint::Percentage(100)));
-        chart.render(buffer.area, &mut buffer);
+        Widget::render(chart, buffer.area, &mut buffer);
         assert_eq!(buffer, Buffer::with_lines(expected));
     }
 
@@ -1499,7 +3218,7 @@ mod tests {
         .y_axis(Axis::default().bounds([0.0, 10.0]));
         let area = Rect::new(0, 0, 11, 11);
         let mut buffer = Buffer::empty(area);
-        chart.render(buffer.area, &mut buffer);
+        Widget::render(chart, buffer.area, &mut buffer);
         let expected = Buffer::with_lines([
             "          •",
             "        • •",
@@ -1515,4 +3234,1039 @@ mod tests {
         ]);
         assert_eq!(buffer, expected);
     }
+
+    #[test]
+    fn logarithmic_y_axis() {
+        let data = [(0.0, 1.0), (1.0, 10.0), (2.0, 100.0), (3.0, 1000.0)];
+        let chart = Chart::new(vec![Dataset::default()
+            .data(&data)
+            .marker(symbols::Marker::Dot)
+            .graph_type(GraphType::Scatter)])
+        .x_axis(Axis::default().bounds([0.0, 3.0]))
+        .y_axis(
+            Axis::default()
+                .bounds([1.0, 1000.0])
+                .scale(AxisScale::Logarithmic),
+        );
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 4, 4));
+        Widget::render(chart, buffer.area, &mut buffer);
+        let expected = Buffer::with_lines(["   •", "  • ", " •  ", "•   "]);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn logarithmic_axis_line_is_drawn_in_log_space() {
+        let data = [(1.0, 0.0), (1000.0, 3.0)];
+        let chart = Chart::new(vec![Dataset::default()
+            .data(&data)
+            .marker(symbols::Marker::Dot)
+            .graph_type(GraphType::Line)])
+        .x_axis(
+            Axis::default()
+                .bounds([1.0, 1000.0])
+                .scale(AxisScale::Logarithmic),
+        )
+        .y_axis(Axis::default().bounds([0.0, 3.0]));
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 4, 4));
+        Widget::render(chart, buffer.area, &mut buffer);
+        let expected = Buffer::with_lines(["   •", "  • ", " •  ", "•   "]);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn logarithmic_axis_with_zero_bound_does_not_panic() {
+        let data = [(0.0, 0.0), (1.0, 10.0), (2.0, 100.0)];
+        let chart = Chart::new(vec![Dataset::default()
+            .data(&data)
+            .graph_type(GraphType::Bar)])
+        .x_axis(Axis::default().bounds([0.0, 2.0]))
+        .y_axis(
+            Axis::default()
+                .bounds([0.0, 100.0])
+                .scale(AxisScale::Logarithmic),
+        );
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 3, 3));
+        Widget::render(chart, buffer.area, &mut buffer);
+        assert_eq!(buffer[(0, 2)].symbol(), "•");
+        assert_eq!(buffer[(2, 0)].symbol(), "•");
+    }
+
+    #[rstest]
+    #[case::nan_y((2.0, f64::NAN), AxisScale::Linear)]
+    #[case::nan_x((f64::NAN, 0.0), AxisScale::Linear)]
+    #[case::infinite_y((2.0, f64::INFINITY), AxisScale::Linear)]
+    #[case::nan_y_logarithmic((2.0, f64::NAN), AxisScale::Logarithmic)]
+    fn line_is_broken_at_non_finite_points(#[case] gap: (f64, f64), #[case] scale: AxisScale) {
+        let data = [(0.0, 1.0), (1.0, 1.0), gap, (3.0, 1.0), (5.0, 1.0)];
+        let chart = Chart::new(vec![Dataset::default()
+            .data(&data)
+            .marker(symbols::Marker::Dot)
+            .graph_type(GraphType::Line)])
+        .x_axis(Axis::default().bounds([0.0, 5.0]))
+        .y_axis(Axis::default().bounds([1.0, 10.0]).scale(scale));
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 6, 1));
+        Widget::render(chart, buffer.area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines(["•• •••"]));
+    }
+
+    #[test]
+    fn scatter_skips_non_finite_points() {
+        let data = [
+            (1.0, 0.0),
+            (f64::NAN, 0.0),
+            (2.0, f64::NAN),
+            (f64::NEG_INFINITY, 0.0),
+            (3.0, 0.0),
+        ];
+        let chart = Chart::new(vec![Dataset::default()
+            .data(&data)
+            .marker(symbols::Marker::Dot)
+            .graph_type(GraphType::Scatter)])
+        .x_axis(Axis::default().bounds([0.0, 4.0]))
+        .y_axis(Axis::default().bounds([0.0, 1.0]));
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 5, 1));
+        Widget::render(chart, buffer.area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines([" • • "]));
+    }
+
+    #[rstest]
+    fn all_non_finite_points_are_not_drawn(
+        #[values(
+            GraphType::Scatter,
+            GraphType::Line,
+            GraphType::Bar,
+            GraphType::StackedBar,
+            GraphType::Area
+        )]
+        graph_type: GraphType,
+    ) {
+        let data = [(f64::NAN, f64::NAN), (1.0, f64::NAN), (f64::INFINITY, 1.0)];
+        let chart = Chart::new(vec![Dataset::default()
+            .data(&data)
+            .marker(symbols::Marker::Dot)
+            .graph_type(graph_type)]);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 3, 3));
+        Widget::render(chart, buffer.area, &mut buffer);
+        assert_eq!(buffer, Buffer::empty(buffer.area));
+    }
+
+    #[test]
+    fn x_labels_at() {
+        let chart = Chart::new(vec![]).x_axis(Axis::default().bounds([0.0, 10.0]).labels_at([
+            (0.0, "a"),
+            (5.0, "b"),
+            (10.0, "end"),
+            (20.0, "hidden"),
+        ]));
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 12, 3));
+        Widget::render(chart, buffer.area, &mut buffer);
+        let expected = Buffer::with_lines(["            ", " ───────────", " a    b  end"]);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn y_labels_at() {
+        let chart = Chart::new(vec![]).y_axis(Axis::default().bounds([0.0, 4.0]).labels_at([
+            (0.0, "0"),
+            (1.0, "1"),
+            (4.0, "4"),
+            (-1.0, "x"),
+        ]));
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 5, 5));
+        Widget::render(chart, buffer.area, &mut buffer);
+        let expected = Buffer::with_lines(["4│   ", " │   ", " │   ", "1│   ", "0│   "]);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn labels_replaces_labels_at() {
+        let axis = Axis::default().labels_at([(1.0, "a")]).labels(["b", "c"]);
+        assert_eq!(axis.labels, [Line::from("b"), Line::from("c")]);
+        assert_eq!(axis.label_positions, None);
+    }
+
+    #[rstest]
+    #[case::empty(&[], 0.0, [0.0, 1.0])]
+    #[case::range(&[3.0, -2.0, 8.0], 0.0, [-2.0, 8.0])]
+    #[case::padding(&[0.0, 50.0], 10.0, [-5.0, 55.0])]
+    #[case::single_value(&[4.0], 10.0, [3.5, 4.5])]
+    #[case::non_finite_values(&[f64::NAN, 1.0, f64::INFINITY, 2.0], 0.0, [1.0, 2.0])]
+    #[case::only_non_finite_values(&[f64::NAN], 0.0, [0.0, 1.0])]
+    fn axis_auto_bounds(#[case] values: &[f64], #[case] padding: f64, #[case] expected: [f64; 2]) {
+        let axis = Axis::default().bounds_padding(padding);
+        let [min, max] = axis.auto_bounds(values.iter().copied());
+        assert!((min - expected[0]).abs() < f64::EPSILON, "min: {min}");
+        assert!((max - expected[1]).abs() < f64::EPSILON, "max: {max}");
+    }
+
+    #[test]
+    fn axis_auto_bounds_logarithmic() {
+        let axis = Axis::default()
+            .scale(AxisScale::Logarithmic)
+            .bounds_padding(50.0);
+        let [min, max] = axis.auto_bounds([10.0, 1000.0].into_iter());
+        assert!((min - 1.0).abs() < 1e-9);
+        assert!((max - 10_000.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn bounds_auto_is_default() {
+        assert_eq!(Axis::default().bounds, None);
+        assert_eq!(
+            Axis::default().bounds([0.0, 1.0]).bounds_auto().bounds,
+            None
+        );
+    }
+
+    #[test]
+    fn auto_and_explicit_bounds_are_independent() {
+        let data = [(1.0, -3.0), (2.0, 5.0)];
+        let other = [(7.0, 2.0)];
+        let chart = Chart::new(vec![
+            Dataset::default().data(&data),
+            Dataset::default().data(&other),
+        ])
+        .x_axis(Axis::default().bounds([0.0, 10.0]))
+        .y_axis(Axis::default().bounds_auto());
+        let chart = resolved(&chart);
+        assert_eq!(chart.x_axis.bounds, Some([0.0, 10.0]));
+        assert_eq!(chart.y_axis.bounds, Some([-3.0, 5.0]));
+
+        let chart = Chart::new(vec![Dataset::default().data(&data)])
+            .y_axis(Axis::default().bounds([-10.0, 10.0]));
+        let chart = resolved(&chart);
+        assert_eq!(chart.x_axis.bounds, Some([1.0, 2.0]));
+        assert_eq!(chart.y_axis.bounds, Some([-10.0, 10.0]));
+    }
+
+    #[test]
+    fn auto_bounds_of_empty_datasets() {
+        let chart = resolved(&Chart::new(vec![Dataset::default()]));
+        assert_eq!(chart.x_axis.bounds, Some([0.0, 1.0]));
+        assert_eq!(chart.y_axis.bounds, Some([0.0, 1.0]));
+    }
+
+    #[test]
+    fn auto_bounds_contain_stacked_totals() {
+        let first = [(0.0, 1.0), (1.0, -2.0)];
+        let second = [(0.0, 3.0), (1.0, -1.0)];
+        let chart = resolved(&Chart::new(vec![
+            Dataset::default()
+                .data(&first)
+                .graph_type(GraphType::StackedBar),
+            Dataset::default()
+                .data(&second)
+                .graph_type(GraphType::StackedBar),
+        ]));
+        assert_eq!(chart.y_axis.bounds, Some([-3.0, 4.0]));
+    }
+
+    #[test]
+    fn auto_bounds_contain_the_baseline_of_stacked_bars() {
+        let first = [(0.0, 2.0), (1.0, 3.0)];
+        let second = [(0.0, 1.0)];
+        let chart = resolved(&Chart::new(vec![
+            Dataset::default()
+                .data(&first)
+                .graph_type(GraphType::StackedBar),
+            Dataset::default()
+                .data(&second)
+                .graph_type(GraphType::StackedBar),
+        ]));
+        assert_eq!(chart.y_axis.bounds, Some([0.0, 3.0]));
+    }
+
+    #[test]
+    fn render_with_auto_bounds() {
+        let data = [(0.0, 0.0), (5.0, 10.0), (10.0, 5.0)];
+        let dataset = Dataset::default()
+            .data(&data)
+            .marker(symbols::Marker::Dot)
+            .graph_type(GraphType::Scatter);
+        let auto = Chart::new(vec![dataset.clone()]);
+        let explicit = Chart::new(vec![dataset])
+            .x_axis(Axis::default().bounds([0.0, 10.0]))
+            .y_axis(Axis::default().bounds([0.0, 10.0]));
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 11, 11));
+        Widget::render(auto, buffer.area, &mut buffer);
+        let mut expected = Buffer::empty(buffer.area);
+        Widget::render(explicit, expected.area, &mut expected);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn labels_at_with_auto_bounds() {
+        let data = [(0.0, 0.0), (10.0, 0.0)];
+        let chart = Chart::new(vec![Dataset::default().data(&data)])
+            .x_axis(Axis::default().labels_at([(0.0, "a"), (5.0, "b"), (10.0, "end")]));
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 12, 3));
+        Widget::render(chart, buffer.area, &mut buffer);
+        let expected = Buffer::with_lines([" •         •", " ───────────", " a    b  end"]);
+        assert_eq!(buffer, expected);
+    }
+
+    fn grid_chart(datasets: Vec<Dataset>, grid: GridStyle) -> Chart {
+        Chart::new(datasets)
+            .x_axis(Axis::default().bounds([0.0, 4.0]).labels(["a", "b", "c"]))
+            .y_axis(Axis::default().bounds([0.0, 4.0]).labels(["a", "b", "c"]))
+            .grid(grid)
+    }
+
+    #[test]
+    fn grid() {
+        let chart = grid_chart(vec![], GridStyle::new().style(Style::new()));
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 7, 6));
+        Widget::render(chart, buffer.area, &mut buffer);
+        let expected = Buffer::with_lines([
+            "c│──┼─┼",
+            " │  │ │",
+            "b│──┼─┼",
+            "a│  │ │",
+            " └─────",
+            " a    c",
+        ]);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn grid_default_style() {
+        let chart = grid_chart(vec![], GridStyle::default());
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 7, 6));
+        Widget::render(chart, buffer.area, &mut buffer);
+        assert_eq!(buffer[(4, 1)].symbol(), "│");
+        assert_eq!(buffer[(4, 1)].fg, Color::Gray);
+        assert_eq!(buffer[(4, 1)].modifier, Modifier::DIM);
+    }
+
+    #[rstest]
+    #[case::horizontal(GridLines::Horizontal, ["c│─•───", " │     ", "b│─────", "a│     "])]
+    #[case::vertical(GridLines::Vertical, ["c│ •│ │", " │  │ │", "b│  │ │", "a│  │ │"])]
+    fn grid_lines_beneath_datasets(#[case] lines: GridLines, #[case] expected: [&str; 4]) {
+        let data = [(1.0, 4.0)];
+        let dataset = Dataset::default().data(&data).marker(symbols::Marker::Dot);
+        let chart = grid_chart(
+            vec![dataset],
+            GridStyle::new().style(Style::new()).lines(lines),
+        );
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 7, 6));
+        Widget::render(chart, buffer.area, &mut buffer);
+        let mut expected = Buffer::with_lines(expected.into_iter().chain([" └─────", " a    c"]));
+        expected.set_style(Rect::new(3, 0, 1, 1), Style::new().reset());
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn stacked_bar_chart() {
+        let heavy = [(0.0, 1.0), (2.0, 2.0), (4.0, 1.0)];
+        let light = [(0.0, 2.0), (4.0, 3.0)];
+        let chart = Chart::new(vec![
+            Dataset::default()
+                .data(&heavy)
+                .graph_type(GraphType::StackedBar)
+                .red(),
+            Dataset::default()
+                .data(&light)
+                .graph_type(GraphType::StackedBar)
+                .blue(),
+        ])
+        .x_axis(Axis::default().bounds([0.0, 4.0]))
+        .y_axis(Axis::default().bounds([0.0, 4.0]));
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 5, 5));
+        Widget::render(chart, buffer.area, &mut buffer);
+        let mut expected = Buffer::with_lines(["    •", "•   •", "• • •", "• • •", "• • •"]);
+        for (x, y) in [(0, 4), (2, 4), (2, 3), (2, 2), (4, 4)] {
+            expected[(x, y)].set_fg(Color::Red);
+        }
+        for (x, y) in [(0, 3), (0, 2), (0, 1), (4, 3), (4, 2), (4, 1), (4, 0)] {
+            expected[(x, y)].set_fg(Color::Blue);
+        }
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn stacked_bar_chart_negative_values() {
+        let first = [(0.0, 1.0), (1.0, -1.0)];
+        let second = [(0.0, -1.0), (1.0, -1.0)];
+        let chart = Chart::new(vec![
+            Dataset::default()
+                .data(&first)
+                .graph_type(GraphType::StackedBar),
+            Dataset::default()
+                .data(&second)
+                .graph_type(GraphType::StackedBar),
+        ])
+        .x_axis(Axis::default().bounds([0.0, 1.0]))
+        .y_axis(Axis::default().bounds([-2.0, 2.0]));
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 2, 5));
+        Widget::render(chart, buffer.area, &mut buffer);
+        let expected = Buffer::with_lines(["  ", "• ", "••", "••", " •"]);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn point_markers() {
+        let data = [(0.0, 0.0), (1.0, 1.0), (2.0, 2.0), (3.0, 3.0)];
+        let chart = Chart::new(vec![Dataset::default()
+            .data(&data)
+            .marker(symbols::Marker::Dot)
+            .point_markers([
+                symbols::Marker::Block,
+                symbols::Marker::Dot,
+                symbols::Marker::Bar,
+            ])])
+        .x_axis(Axis::default().bounds([0.0, 3.0]))
+        .y_axis(Axis::default().bounds([0.0, 3.0]));
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 4, 4));
+        Widget::render(chart, buffer.area, &mut buffer);
+        let expected = Buffer::with_lines(["   •", "  ▄ ", " •  ", "█   "]);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn point_markers_are_drawn_over_lines() {
+        let data = [(0.0, 0.0), (1.0, 0.0), (2.0, 0.0)];
+        let chart = Chart::new(vec![Dataset::default()
+            .data(&data)
+            .marker(symbols::Marker::Dot)
+            .graph_type(GraphType::Line)
+            .point_markers([symbols::Marker::Dot, symbols::Marker::Block])])
+        .x_axis(Axis::default().bounds([0.0, 2.0]))
+        .y_axis(Axis::default().bounds([0.0, 1.0]));
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 3, 1));
+        Widget::render(chart, buffer.area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines(["•█•"]));
+    }
+
+    #[test]
+    fn custom_marker() {
+        #[derive(Debug)]
+        struct Cross;
+
+        impl symbols::PointSymbol for Cross {
+            fn symbol(&self, _sub_x: u16, _sub_y: u16) -> &'static str {
+                "✗"
+            }
+        }
+
+        let data = [(0.0, 0.0), (1.0, 0.0), (2.0, 0.0)];
+        let chart = Chart::new(vec![Dataset::default()
+            .data(&data)
+            .point_symbol(Cross)
+            .point_markers([symbols::Marker::Dot, symbols::Marker::Block])])
+        .x_axis(Axis::default().bounds([0.0, 2.0]))
+        .y_axis(Axis::default().bounds([0.0, 1.0]));
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 3, 1));
+        Widget::render(chart, buffer.area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines(["•█✗"]));
+    }
+
+    #[test]
+    fn transition_interpolates_the_points() {
+        let start = Instant::now();
+        let mut state = ChartState::default();
+        let render = |data: &[(f64, f64)], state: &mut ChartState, elapsed: u64| {
+            let chart = Chart::new(vec![Dataset::default().data(data)])
+                .x_axis(Axis::default().bounds([0.0, 1.0]))
+                .y_axis(Axis::default().bounds([0.0, 4.0]))
+                .transition(Duration::from_millis(100));
+            let mut buffer = Buffer::empty(Rect::new(0, 0, 2, 5));
+            chart.render_at(
+                buffer.area,
+                &mut buffer,
+                state,
+                start + Duration::from_millis(elapsed),
+            );
+            buffer
+        };
+        let data = [(0.0, 0.0), (1.0, 0.0)];
+        assert_eq!(
+            render(&data, &mut state, 0),
+            Buffer::with_lines(["  ", "  ", "  ", "  ", "••"])
+        );
+        let data = [(0.0, 4.0), (1.0, 0.0)];
+        assert_eq!(
+            render(&data, &mut state, 100),
+            Buffer::with_lines(["  ", "  ", "  ", "  ", "••"])
+        );
+        assert_eq!(
+            render(&data, &mut state, 150),
+            Buffer::with_lines(["  ", "  ", "• ", "  ", " •"])
+        );
+        assert_eq!(
+            render(&data, &mut state, 200),
+            Buffer::with_lines(["• ", "  ", "  ", "  ", " •"])
+        );
+        assert!(!state.is_transitioning(
+            Duration::from_millis(100),
+            start + Duration::from_millis(200)
+        ));
+    }
+
+    #[test]
+    fn transition_snaps_the_new_points() {
+        let start = Instant::now();
+        let duration = Some(Duration::from_secs(1));
+        let mut state = ChartState::default();
+        let data = [(0.0, 0.0)];
+        state.update(&[Dataset::default().data(&data)], duration, start);
+        let data = [(0.0, 2.0), (1.0, 2.0)];
+        let datasets = [Dataset::default().data(&data)];
+        assert_eq!(
+            state.update(&datasets, duration, start),
+            [[(0.0, 0.0), (1.0, 2.0)]]
+        );
+        let now = start + Duration::from_millis(500);
+        assert_eq!(
+            state.update(&datasets, duration, now),
+            [[(0.0, 1.0), (1.0, 2.0)]]
+        );
+        assert!(state.is_transitioning(Duration::from_secs(1), now));
+    }
+
+    #[test]
+    fn transition_ends_with_gaps_in_the_data() {
+        let start = Instant::now();
+        let duration = Duration::from_millis(100);
+        let mut state = ChartState::default();
+        let data = [(0.0, 1.0), (f64::NAN, f64::NAN), (1.0, 2.0)];
+        let datasets = [Dataset::default().data(&data)];
+        state.update(&datasets, Some(duration), start);
+        let now = start + Duration::from_millis(200);
+        state.update(&datasets, Some(duration), now);
+        assert!(!state.is_transitioning(duration, now));
+    }
+
+    #[test]
+    fn without_transition_the_points_move_right_away() {
+        let start = Instant::now();
+        let mut state = ChartState::default();
+        let data = [(0.0, 0.0)];
+        state.update(&[Dataset::default().data(&data)], None, start);
+        let data = [(0.0, 2.0)];
+        assert_eq!(
+            state.update(&[Dataset::default().data(&data)], None, start),
+            [[(0.0, 2.0)]]
+        );
+    }
+
+    #[test]
+    fn selected_point_has_a_crosshair_and_a_label() {
+        let data = [(0.0, 0.0), (2.0, 2.0), (8.0, 4.0)];
+        let chart = Chart::new(vec![Dataset::default().data(&data)])
+            .x_axis(Axis::default().bounds([0.0, 8.0]))
+            .y_axis(Axis::default().bounds([0.0, 4.0]));
+        let mut state = ChartState::default().with_selected(Some((0, 1)));
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 9, 5));
+        StatefulWidget::render(chart, buffer.area, &mut buffer, &mut state);
+        assert_eq!(
+            buffer,
+            Buffer::with_lines([
+                "  │     •",
+                "  │(2, 2)",
+                "──•──────",
+                "  │      ",
+                "• │      ",
+            ])
+        );
+    }
+
+    #[rstest]
+    #[case::top_right(
+        (0, 2),
+        ["────────•", "  (8, 4)│", "        │", "        │", "• •     │"],
+    )]
+    #[case::bottom_left(
+        (0, 0),
+        ["│       •", "│        ", "│        ", "│(0, 0)  ", "•─•──────"],
+    )]
+    fn selected_point_label_stays_in_the_graph_area(
+        #[case] selected: (usize, usize),
+        #[case] expected: [&str; 5],
+    ) {
+        let data = [(0.0, 0.0), (2.0, 0.0), (8.0, 4.0)];
+        let chart = Chart::new(vec![Dataset::default().data(&data)])
+            .x_axis(Axis::default().bounds([0.0, 8.0]))
+            .y_axis(Axis::default().bounds([0.0, 4.0]));
+        let mut state = ChartState::default().with_selected(Some(selected));
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 9, 5));
+        StatefulWidget::render(chart, buffer.area, &mut buffer, &mut state);
+        assert_eq!(buffer, Buffer::with_lines(expected));
+    }
+
+    #[rstest]
+    #[case::no_dataset((1, 0))]
+    #[case::no_point((0, 5))]
+    #[case::out_of_bounds((0, 1))]
+    fn invalid_selection_is_ignored(#[case] selected: (usize, usize)) {
+        let data = [(0.0, 0.0), (20.0, 0.0)];
+        let chart = Chart::new(vec![Dataset::default().data(&data)])
+            .x_axis(Axis::default().bounds([0.0, 2.0]))
+            .y_axis(Axis::default().bounds([0.0, 2.0]));
+        let mut state = ChartState::default().with_selected(Some(selected));
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 3, 3));
+        StatefulWidget::render(chart, buffer.area, &mut buffer, &mut state);
+        assert_eq!(buffer, Buffer::with_lines(["   ", "   ", "•  "]));
+    }
+
+    #[test]
+    fn selection_style() {
+        let data = [(1.0, 1.0)];
+        let chart = Chart::new(vec![Dataset::default().data(&data)])
+            .x_axis(Axis::default().bounds([0.0, 2.0]))
+            .y_axis(Axis::default().bounds([0.0, 2.0]))
+            .selection_style(Color::Yellow);
+        let mut state = ChartState::default().with_selected(Some((0, 0)));
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 3, 3));
+        StatefulWidget::render(chart, buffer.area, &mut buffer, &mut state);
+        let mut expected = Buffer::with_lines(["(1,", "─•─", " │ "]);
+        expected.set_style(Rect::new(0, 0, 3, 1), Color::Yellow);
+        expected.set_style(Rect::new(0, 1, 1, 1), Color::Yellow);
+        expected.set_style(Rect::new(2, 1, 1, 1), Color::Yellow);
+        expected.set_style(Rect::new(1, 2, 1, 1), Color::Yellow);
+        assert_eq!(buffer, expected);
+    }
+
+    #[rstest]
+    #[case::on_a_point(Position::new(0, 4), Some((0, 0)))]
+    #[case::nearest(Position::new(5, 1), Some((1, 0)))]
+    #[case::second_dataset(Position::new(7, 0), Some((1, 0)))]
+    #[case::stacked_on_the_first_dataset(Position::new(2, 3), Some((0, 1)))]
+    #[case::outside(Position::new(9, 0), None)]
+    fn point_at(#[case] position: Position, #[case] expected: Option<(usize, usize)>) {
+        let first = [(0.0, 0.0), (2.0, 1.0)];
+        let second = [(8.0, 4.0)];
+        let chart = Chart::new(vec![
+            Dataset::default().data(&first),
+            Dataset::default().data(&second),
+        ])
+        .x_axis(Axis::default().bounds([0.0, 8.0]))
+        .y_axis(Axis::default().bounds([0.0, 4.0]));
+        assert_eq!(chart.point_at(Rect::new(0, 0, 9, 5), position), expected);
+    }
+
+    #[test]
+    fn point_at_with_stacked_bars() {
+        let first = [(4.0, 1.0)];
+        let second = [(4.0, 2.0)];
+        let chart = Chart::new(vec![
+            Dataset::default()
+                .data(&first)
+                .graph_type(GraphType::StackedBar),
+            Dataset::default()
+                .data(&second)
+                .graph_type(GraphType::StackedBar),
+        ])
+        .x_axis(Axis::default().bounds([0.0, 8.0]))
+        .y_axis(Axis::default().bounds([0.0, 4.0]));
+        let area = Rect::new(0, 0, 9, 5);
+        assert_eq!(chart.point_at(area, Position::new(4, 1)), Some((1, 0)));
+        assert_eq!(chart.point_at(area, Position::new(4, 3)), Some((0, 0)));
+    }
+
+    #[rstest]
+    #[case::origin((0.0, 0.0), Some(Position::new(1, 5)))]
+    #[case::end((8.0, 4.0), Some(Position::new(9, 1)))]
+    #[case::middle((4.0, 2.0), Some(Position::new(5, 3)))]
+    #[case::within_a_cell((4.9, 1.1), Some(Position::new(5, 3)))]
+    #[case::outside_x((9.0, 2.0), None)]
+    #[case::outside_y((4.0, -1.0), None)]
+    fn data_to_screen(#[case] data: (f64, f64), #[case] expected: Option<Position>) {
+        let chart = Chart::new(vec![])
+            .block(Block::bordered())
+            .x_axis(Axis::default().bounds([0.0, 8.0]))
+            .y_axis(Axis::default().bounds([0.0, 4.0]));
+        assert_eq!(chart.data_to_screen(Rect::new(0, 0, 11, 7), data), expected);
+    }
+
+    #[rstest]
+    #[case::origin(Position::new(1, 5), Some((0.5, 0.0)))]
+    #[case::end(Position::new(9, 1), Some((8.0, 3.5)))]
+    #[case::middle(Position::new(5, 3), Some((4.5, 1.5)))]
+    #[case::on_the_block(Position::new(0, 3), None)]
+    #[case::outside(Position::new(20, 3), None)]
+    fn screen_to_data(#[case] position: Position, #[case] expected: Option<(f64, f64)>) {
+        let chart = Chart::new(vec![])
+            .block(Block::bordered())
+            .x_axis(Axis::default().bounds([0.0, 8.0]))
+            .y_axis(Axis::default().bounds([0.0, 4.0]));
+        assert_eq!(
+            chart.screen_to_data(Rect::new(0, 0, 11, 7), position),
+            expected
+        );
+    }
+
+    #[test]
+    fn screen_to_data_excludes_the_axes() {
+        let chart = Chart::new(vec![])
+            .x_axis(Axis::default().bounds([0.0, 8.0]).labels(["0", "8"]))
+            .y_axis(Axis::default().bounds([0.0, 4.0]).labels(["0", "4"]));
+        let area = Rect::new(0, 0, 11, 7);
+        // the y labels and axis take the first two columns, the x axis and labels the last two rows
+        assert_eq!(chart.screen_to_data(area, Position::new(1, 2)), None);
+        assert_eq!(chart.screen_to_data(area, Position::new(4, 5)), None);
+        assert_eq!(
+            chart.screen_to_data(area, Position::new(2, 4)),
+            Some((0.5, 0.0))
+        );
+        assert_eq!(
+            chart.data_to_screen(area, (0.0, 0.0)),
+            Some(Position::new(2, 4))
+        );
+    }
+
+    #[test]
+    fn screen_to_data_with_scaled_axes() {
+        let chart = Chart::new(vec![])
+            .x_axis(Axis::default().bounds([8.0, 0.0]))
+            .y_axis(
+                Axis::default()
+                    .bounds([1.0, 10_000.0])
+                    .scale(AxisScale::Logarithmic),
+            );
+        let area = Rect::new(0, 0, 9, 5);
+        let (x, y) = chart.screen_to_data(area, Position::new(2, 1)).unwrap();
+        assert!((x - 5.5).abs() < 1e-9, "x: {x}");
+        assert!((y - 10f64.powf(2.5)).abs() < 1e-9, "y: {y}");
+        assert_eq!(
+            chart.data_to_screen(area, (x, y)),
+            Some(Position::new(2, 1))
+        );
+    }
+
+    #[test]
+    fn screen_to_data_round_trips() {
+        let chart = Chart::new(vec![])
+            .x_axis(Axis::default().bounds([-0.3, 0.7]))
+            .y_axis(Axis::default().bounds([0.1, 1.3]));
+        let area = Rect::new(0, 0, 37, 23);
+        for position in area.positions() {
+            let data = chart.screen_to_data(area, position).unwrap();
+            assert_eq!(chart.data_to_screen(area, data), Some(position), "{data:?}");
+        }
+    }
+
+    /// Returns the background colors of the buffer, using `.` for cells without a background
+    fn backgrounds(buffer: &Buffer) -> Vec<String> {
+        buffer
+            .content
+            .chunks(buffer.area.width as usize)
+            .map(|row| {
+                row.iter()
+                    .map(|cell| match cell.bg {
+                        Color::Reset => '.',
+                        Color::Red => 'r',
+                        Color::Blue => 'b',
+                        _ => '?',
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn area_chart() {
+        let data = [(0.0, 0.0), (4.0, 4.0)];
+        let chart = Chart::new(vec![Dataset::default()
+            .data(&data)
+            .graph_type(GraphType::Area)
+            .fill(Color::Red)
+            .blue()])
+        .x_axis(Axis::default().bounds([0.0, 4.0]))
+        .y_axis(Axis::default().bounds([0.0, 4.0]));
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 4, 4));
+        Widget::render(chart, buffer.area, &mut buffer);
+        assert_eq!(backgrounds(&buffer), ["...r", "..rr", ".rrr", "rrrr"]);
+        // the line is still drawn over the fill
+        assert_eq!(buffer[(3, 0)].fg, Color::Blue);
+    }
+
+    #[test]
+    fn area_chart_composites_datasets_in_order() {
+        let rising = [(0.0, 0.0), (4.0, 4.0)];
+        let flat = [(0.0, 2.0), (4.0, 2.0)];
+        let chart = Chart::new(vec![
+            Dataset::default()
+                .data(&rising)
+                .graph_type(GraphType::Area)
+                .on_red(),
+            Dataset::default()
+                .data(&flat)
+                .graph_type(GraphType::Area)
+                .blue(),
+        ])
+        .x_axis(Axis::default().bounds([0.0, 4.0]))
+        .y_axis(Axis::default().bounds([0.0, 4.0]));
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 4, 4));
+        Widget::render(chart, buffer.area, &mut buffer);
+        assert_eq!(backgrounds(&buffer), ["...r", "..rr", "bbbb", "bbbb"]);
+    }
+
+    #[test]
+    fn area_chart_is_clipped_to_axis_bounds() {
+        let data = [(-2.0, -4.0), (2.0, 8.0)];
+        let chart = Chart::new(vec![Dataset::default()
+            .data(&data)
+            .graph_type(GraphType::Area)
+            .fill(Color::Red)])
+        .x_axis(Axis::default().bounds([0.0, 4.0]))
+        .y_axis(Axis::default().bounds([-2.0, 2.0]));
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 4, 4));
+        Widget::render(chart, buffer.area, &mut buffer);
+        assert_eq!(backgrounds(&buffer), ["rr..", "rr..", "....", "...."]);
+    }
+
+    #[rstest]
+    #[case::start(0.0, Some(0.0))]
+    #[case::middle(1.0, Some(2.0))]
+    #[case::second_segment(3.0, Some(3.0))]
+    #[case::outside(5.0, None)]
+    fn interpolate_line(#[case] x: f64, #[case] expected: Option<f64>) {
+        let data = [(0.0, 0.0), (2.0, 4.0), (4.0, 2.0)];
+        assert_eq!(interpolate(&data, x), expected);
+    }
+
+    #[test]
+    fn bar_stacks() {
+        let mut stacks = BarStacks::default();
+        assert_eq!(stacks.push(0.0, 1.0), (0.0, 1.0));
+        assert_eq!(stacks.push(-0.0, 2.0), (1.0, 3.0));
+        assert_eq!(stacks.push(0.0, -1.0), (0.0, -1.0));
+        assert_eq!(stacks.push(1.0, 2.0), (0.0, 2.0));
+        assert_eq!(stacks.push(0.0, -2.0), (-1.0, -3.0));
+    }
+
+    #[test]
+    fn auto_bounds_of_secondary_axis() {
+        let primary = [(0.0, 0.0), (1.0, 10.0)];
+        let secondary = [(0.0, 100.0), (2.0, 200.0)];
+        let datasets = vec![
+            Dataset::default().data(&primary),
+            Dataset::default().data(&secondary).y_axis(YAxis::Secondary),
+        ];
+        let chart = resolved(&Chart::new(datasets.clone()).y_axis_secondary(Axis::default()));
+        assert_eq!(chart.x_axis.bounds, Some([0.0, 2.0]));
+        assert_eq!(chart.y_axis.bounds, Some([0.0, 10.0]));
+        assert_eq!(
+            chart.y_axis_secondary.and_then(|axis| axis.bounds),
+            Some([100.0, 200.0])
+        );
+
+        // without a secondary axis, all the datasets are plotted against the primary axis
+        let chart = resolved(&Chart::new(datasets));
+        assert_eq!(chart.y_axis.bounds, Some([0.0, 200.0]));
+    }
+
+    #[test]
+    fn render_secondary_axis() {
+        let primary = [(0.0, 0.0), (4.0, 10.0)];
+        let secondary = [(0.0, 100.0), (4.0, 0.0)];
+        let chart = Chart::new(vec![
+            Dataset::default()
+                .data(&primary)
+                .marker(symbols::Marker::Dot),
+            Dataset::default()
+                .data(&secondary)
+                .marker(symbols::Marker::Dot)
+                .y_axis(YAxis::Secondary),
+        ])
+        .x_axis(Axis::default().bounds([0.0, 4.0]))
+        .y_axis(Axis::default().bounds([0.0, 10.0]).labels(["0", "10"]))
+        .y_axis_secondary(Axis::default().bounds([0.0, 100.0]).labels(["0", "100"]));
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 14, 4));
+        Widget::render(chart, buffer.area, &mut buffer);
+        let expected = Buffer::with_lines([
+            "10│•     •│100",
+            "  │       │   ",
+            "  │       │   ",
+            "0 │•     •│0  ",
+        ]);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn render_secondary_axis_title_and_legend() {
+        let primary = [(0.0, 0.0)];
+        let secondary = [(0.0, 0.0)];
+        let chart = Chart::new(vec![
+            Dataset::default().name("a").data(&primary),
+            Dataset::default()
+                .name("b")
+                .data(&secondary)
+                .y_axis(YAxis::Secondary),
+        ])
+        .hidden_legend_constraints((Constraint::Min(0), Constraint::Min(0)))
+        .x_axis(Axis::default().bounds([0.0, 1.0]))
+        .y_axis(Axis::default().bounds([0.0, 1.0]))
+        .y_axis_secondary(Axis::default().bounds([0.0, 1.0]).title("R"));
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 5));
+        Widget::render(chart, buffer.area, &mut buffer);
+        let expected = Buffer::with_lines([
+            "         R",
+            "     ┌───┐",
+            "     │a  │",
+            "     │b →│",
+            "•    └───┘",
+        ]);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn render_legend_without_axis_markers_when_too_narrow() {
+        let data = [(0.0, 0.0)];
+        let chart = Chart::new(vec![Dataset::default()
+            .name("b")
+            .data(&data)
+            .y_axis(YAxis::Secondary)])
+        .hidden_legend_constraints((Constraint::Length(3), Constraint::Min(0)))
+        .x_axis(Axis::default().bounds([0.0, 1.0]))
+        .y_axis(Axis::default().bounds([0.0, 1.0]))
+        .y_axis_secondary(Axis::default().bounds([0.0, 1.0]));
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 6, 3));
+        Widget::render(chart, buffer.area, &mut buffer);
+        let expected = Buffer::with_lines(["   ┌─┐", "   │b│", "•  └─┘"]);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn render_legend_in_columns() {
+        let names = ["one", "two", "three", "four", "five"];
+        let chart = Chart::new(names.map(|name| Dataset::default().name(name)).to_vec())
+            .hidden_legend_constraints((Constraint::Min(0), Constraint::Min(0)))
+            .legend_columns(2);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 14, 6));
+        Widget::render(chart, buffer.area, &mut buffer);
+        let expected = Buffer::with_lines([
+            "  ┌──────────┐",
+            "  │one   two │",
+            "  │three four│",
+            "  │five      │",
+            "  └──────────┘",
+            "              ",
+        ]);
+        assert_eq!(buffer, expected);
+    }
+
+    #[rstest]
+    #[case::single_column(1, None)]
+    #[case::zero_is_one_column(0, None)]
+    #[case::multiple_columns(4, Some(Rect::new(31, 0, 9, 3)))]
+    #[case::more_columns_than_datasets(10, Some(Rect::new(31, 0, 9, 3)))]
+    fn legend_columns_are_accounted_for_by_hidden_legend_constraints(
+        #[case] columns: u16,
+        #[case] expected: Option<Rect>,
+    ) {
+        let chart = Chart::new(
+            ["a", "b", "c", "d"]
+                .map(|name| Dataset::default().name(name))
+                .to_vec(),
+        )
+        .legend_columns(columns);
+        let layout = chart.layout(Rect::new(0, 0, 40, 12)).unwrap();
+        assert_eq!(layout.legend_area, expected);
+    }
+
+    #[test]
+    fn render_legend_outside() {
+        let data = [(0.0, 0.0), (1.0, 1.0)];
+        let chart = Chart::new(vec![
+            Dataset::default().name("a").data(&data),
+            Dataset::default().name("b"),
+        ])
+        .hidden_legend_constraints((Constraint::Min(0), Constraint::Min(0)))
+        .legend_position(Some(LegendPosition::Outside))
+        .x_axis(Axis::default().bounds([0.0, 1.0]))
+        .y_axis(Axis::default().bounds([0.0, 1.0]));
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 4));
+        Widget::render(chart, buffer.area, &mut buffer);
+        let expected = Buffer::with_lines(["      •┌─┐", "       │a│", "       │b│", "•      └─┘"]);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn legend_outside_leaves_space_for_the_graph() {
+        let chart = Chart::new(vec![Dataset::default().name("a")])
+            .hidden_legend_constraints((Constraint::Min(0), Constraint::Min(0)))
+            .legend_position(Some(LegendPosition::Outside));
+        let layout = chart.layout(Rect::new(0, 0, 3, 3)).unwrap();
+        assert_eq!(layout.legend_area, None);
+        assert_eq!(layout.graph_area, Rect::new(0, 0, 3, 3));
+
+        let layout = chart.layout(Rect::new(0, 0, 4, 3)).unwrap();
+        assert_eq!(layout.legend_area, Some(Rect::new(1, 0, 3, 3)));
+        assert_eq!(layout.graph_area, Rect::new(0, 0, 1, 3));
+    }
+
+    #[test]
+    fn legend_outside_with_secondary_axis_markers() {
+        let chart = Chart::new(vec![
+            Dataset::default().name("a"),
+            Dataset::default().name("b").y_axis(YAxis::Secondary),
+        ])
+        .hidden_legend_constraints((Constraint::Length(4), Constraint::Min(0)))
+        .legend_position(Some(LegendPosition::Outside))
+        .y_axis_secondary(Axis::default());
+        let layout = chart.layout(Rect::new(0, 0, 10, 4)).unwrap();
+        assert_eq!(layout.legend_area, Some(Rect::new(7, 0, 3, 4)));
+        assert!(!layout.legend_axis_markers);
+
+        let chart = chart.hidden_legend_constraints((Constraint::Min(0), Constraint::Min(0)));
+        let layout = chart.layout(Rect::new(0, 0, 10, 4)).unwrap();
+        assert_eq!(layout.legend_area, Some(Rect::new(5, 0, 5, 4)));
+        assert!(layout.legend_axis_markers);
+    }
+
+    #[rstest]
+    #[case::normal([0.0, 10.0], [0.0, 10.0], ["    •", "     ", "•    "])]
+    #[case::reversed_y([0.0, 10.0], [10.0, 0.0], ["•    ", "     ", "    •"])]
+    #[case::reversed_x([10.0, 0.0], [0.0, 10.0], ["•    ", "     ", "    •"])]
+    #[case::reversed_both([10.0, 0.0], [10.0, 0.0], ["    •", "     ", "•    "])]
+    #[case::negative_reversed([0.0, 10.0], [10.0, -10.0], ["     ", "•    ", "    •"])]
+    fn render_reversed_bounds(
+        #[case] x_bounds: [f64; 2],
+        #[case] y_bounds: [f64; 2],
+        #[case] expected: [&str; 3],
+    ) {
+        let data = [(0.0, 0.0), (10.0, 10.0)];
+        let chart = Chart::new(vec![Dataset::default()
+            .data(&data)
+            .marker(symbols::Marker::Dot)])
+        .x_axis(Axis::default().bounds(x_bounds))
+        .y_axis(Axis::default().bounds(y_bounds));
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 5, 3));
+        Widget::render(chart, buffer.area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines(expected));
+    }
+
+    #[test]
+    fn first_bound_is_at_the_start_of_a_reversed_axis() {
+        let data = [(0.0, 100.0)];
+        let chart = Chart::new(vec![Dataset::default()
+            .data(&data)
+            .marker(symbols::Marker::Dot)])
+        .x_axis(Axis::default().bounds([0.0, 1.0]))
+        .y_axis(
+            Axis::default()
+                .bounds([100.0, 0.0])
+                .labels_at([(100.0, "100"), (0.0, "0")]),
+        );
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 9, 3));
+        Widget::render(chart, buffer.area, &mut buffer);
+        let expected = Buffer::with_lines(["0  │     ", "   │     ", "100│•    "]);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn render_bars_on_reversed_axis() {
+        let data = [(0.0, 2.0)];
+        let chart = Chart::new(vec![Dataset::default()
+            .data(&data)
+            .marker(symbols::Marker::Dot)
+            .graph_type(GraphType::Bar)])
+        .x_axis(Axis::default().bounds([0.0, 1.0]))
+        .y_axis(Axis::default().bounds([4.0, 0.0]));
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 2, 5));
+        Widget::render(chart, buffer.area, &mut buffer);
+        let expected = Buffer::with_lines(["• ", "• ", "• ", "  ", "  "]);
+        assert_eq!(buffer, expected);
+    }
 }
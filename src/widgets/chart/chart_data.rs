@@ -0,0 +1,195 @@
+use std::ops::Deref;
+
+/// A fixed capacity buffer of data points for streaming [`Chart`](super::Chart)s
+///
+/// `ChartData` keeps the latest points pushed to it, up to its capacity. Pushing a point when the
+/// buffer is full drops the oldest point. Pushing is O(1), unlike removing the first points of a
+/// `Vec`, which shifts all the others.
+///
+/// The points are always available as a contiguous slice, from the oldest to the newest, so that
+/// `ChartData` can be given directly to [`Dataset::data`](super::Dataset::data). This is achieved
+/// by storing each point twice, at a distance of the capacity, so the buffer uses twice as much
+/// memory as its capacity.
+///
+/// # Example
+///
+/// ```
+/// use ratatui::widgets::{Chart, ChartData, Dataset};
+///
+/// let mut data = ChartData::new(100);
+/// for tick in 0..1000 {
+///     data.push((f64::from(tick), f64::from(tick).sin()));
+/// }
+/// assert_eq!(data.len(), 100);
+/// assert_eq!(data.first(), Some(&(900.0, 900f64.sin())));
+///
+/// let chart = Chart::new(vec![Dataset::default().data(&data)]);
+/// ```
+///
+/// Two `ChartData` are equal when they hold the same points, whatever their capacity.
+#[derive(Debug, Default, Clone)]
+pub struct ChartData {
+    /// The points, stored twice: at `index` and at `index + capacity`
+    points: Vec<(f64, f64)>,
+    /// The maximum number of points
+    capacity: usize,
+    /// The index of the oldest point
+    start: usize,
+    /// The number of points
+    len: usize,
+}
+
+impl ChartData {
+    /// Creates an empty `ChartData` which keeps at most `capacity` points
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            points: vec![(0.0, 0.0); capacity * 2],
+            capacity,
+            start: 0,
+            len: 0,
+        }
+    }
+
+    /// Appends a point, dropping the oldest point if the buffer is full
+    ///
+    /// A buffer with a capacity of 0 stays empty.
+    pub fn push(&mut self, point: (f64, f64)) {
+        if self.capacity == 0 {
+            return;
+        }
+        let index = (self.start + self.len) % self.capacity;
+        self.points[index] = point;
+        self.points[index + self.capacity] = point;
+        if self.len < self.capacity {
+            self.len += 1;
+        } else {
+            self.start = (self.start + 1) % self.capacity;
+        }
+    }
+
+    /// Removes all the points
+    pub fn clear(&mut self) {
+        self.start = 0;
+        self.len = 0;
+    }
+
+    /// Returns the maximum number of points
+    pub const fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns the number of points
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if there are no points
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns `true` if the number of points is the capacity, so that pushing a point drops the
+    /// oldest one
+    pub const fn is_full(&self) -> bool {
+        self.len == self.capacity
+    }
+
+    /// Returns the points, from the oldest to the newest
+    pub fn as_slice(&self) -> &[(f64, f64)] {
+        &self.points[self.start..self.start + self.len]
+    }
+}
+
+impl Deref for ChartData {
+    type Target = [(f64, f64)];
+
+    fn deref(&self) -> &Self::Target {
+        self.as_slice()
+    }
+}
+
+impl PartialEq for ChartData {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl AsRef<[(f64, f64)]> for ChartData {
+    fn as_ref(&self) -> &[(f64, f64)] {
+        self.as_slice()
+    }
+}
+
+impl Extend<(f64, f64)> for ChartData {
+    fn extend<I: IntoIterator<Item = (f64, f64)>>(&mut self, points: I) {
+        for point in points {
+            self.push(point);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    fn points(range: std::ops::Range<u8>) -> Vec<(f64, f64)> {
+        range.map(|i| (f64::from(i), f64::from(i) * 2.0)).collect()
+    }
+
+    #[rstest]
+    #[case::empty(0, points(0..0))]
+    #[case::partially_filled(2, points(0..2))]
+    #[case::full(4, points(0..4))]
+    #[case::wrapped(5, points(1..5))]
+    #[case::wrapped_twice(9, points(5..9))]
+    #[case::wrapped_exactly(8, points(4..8))]
+    fn push(#[case] count: u8, #[case] expected: Vec<(f64, f64)>) {
+        let mut data = ChartData::new(4);
+        data.extend(points(0..count));
+        assert_eq!(data.as_slice(), expected);
+        assert_eq!(data.len(), expected.len());
+        assert_eq!(data.is_full(), expected.len() == 4);
+    }
+
+    #[test]
+    fn zero_capacity() {
+        let mut data = ChartData::new(0);
+        data.push((1.0, 1.0));
+        assert!(data.is_empty());
+        assert_eq!(data.as_slice(), []);
+        assert_eq!(ChartData::default(), data);
+    }
+
+    #[test]
+    fn clear() {
+        let mut data = ChartData::new(3);
+        data.extend(points(0..5));
+        data.clear();
+        assert!(data.is_empty());
+        data.push((7.0, 7.0));
+        assert_eq!(data.as_slice(), [(7.0, 7.0)]);
+    }
+
+    #[test]
+    fn eq_compares_the_points() {
+        let mut wrapped = ChartData::new(3);
+        wrapped.extend(points(0..5));
+        let mut other = ChartData::new(4);
+        other.extend(points(2..5));
+        assert_eq!(wrapped, other);
+        other.push((9.0, 9.0));
+        assert_ne!(wrapped, other);
+    }
+
+    #[test]
+    fn deref_and_as_ref() {
+        let mut data = ChartData::new(3);
+        data.extend(points(0..4));
+        assert_eq!(data.first(), Some(&(1.0, 2.0)));
+        assert_eq!(data.iter().count(), 3);
+        assert_eq!(data.as_ref(), points(1..4));
+        assert_eq!(data.capacity(), 3);
+    }
+}
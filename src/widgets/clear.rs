@@ -19,8 +19,10 @@ use crate::prelude::*;
 ///
 /// # Popup Example
 ///
-/// For a more complete example how to utilize `Clear` to realize popups see
-/// the example `examples/popup.rs`
+/// The [`Popup`] widget centers, clears and renders a widget in one go. For a more complete example
+/// how to realize popups see the example `examples/popup.rs`
+///
+/// [`Popup`]: crate::widgets::Popup
 #[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
 pub struct Clear;
 
@@ -0,0 +1,183 @@
+use unicode_width::UnicodeWidthStr;
+
+use crate::{prelude::*, style::Styled};
+
+/// A widget to fill a certain area with a symbol and a style (e.g. to shade the background
+/// behind a popup).
+///
+/// Like [`Clear`](crate::widgets::Clear), this widget overwrites every cell of its area: the cells
+/// are reset, then filled with the [symbol](Fill::symbol) and the [style](Fill::style). The default
+/// `Fill` is a blank symbol without style, which renders like `Clear`.
+///
+/// The symbol should be a single grapheme. A wide symbol (e.g. `Ｘ`) is repeated every two
+/// columns, and the column left at the right edge of the area, if any, is filled with a blank
+/// symbol of the same style. A symbol without width is replaced by a blank symbol.
+///
+/// # Examples
+///
+/// ```
+/// use ratatui::{prelude::*, widgets::*};
+///
+/// fn draw_popup(f: &mut Frame, popup_area: Rect) {
+///     // shade the background behind the popup
+///     f.render_widget(Fill::new("░").dark_gray(), f.area());
+///     f.render_widget(Clear, popup_area);
+///     f.render_widget(Block::bordered().title("Popup"), popup_area);
+/// }
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Fill<'a> {
+    /// The symbol written in each cell
+    symbol: &'a str,
+    /// The style of the cells
+    style: Style,
+}
+
+impl Default for Fill<'_> {
+    fn default() -> Self {
+        Self::new(" ")
+    }
+}
+
+impl<'a> Fill<'a> {
+    /// Creates a widget filling its area with the given symbol, without style.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui::{prelude::*, widgets::*};
+    ///
+    /// let fill = Fill::new("░").style(Style::new().dark_gray());
+    /// ```
+    pub const fn new(symbol: &'a str) -> Self {
+        Self {
+            symbol,
+            style: Style::new(),
+        }
+    }
+
+    /// Sets the symbol written in each cell.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn symbol(mut self, symbol: &'a str) -> Self {
+        self.symbol = symbol;
+        self
+    }
+
+    /// Sets the style of the cells.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+}
+
+impl Widget for Fill<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        self.render_ref(area, buf);
+    }
+}
+
+impl WidgetRef for Fill<'_> {
+    fn render_ref(&self, area: Rect, buf: &mut Buffer) {
+        let area = area.intersection(buf.area);
+        let (symbol, width) = match self.symbol.width() as u16 {
+            0 => (" ", 1),
+            width => (self.symbol, width),
+        };
+        for y in area.top()..area.bottom() {
+            for x in area.left()..area.right() {
+                buf[(x, y)].reset();
+                buf[(x, y)].set_style(self.style);
+            }
+            // the cells hidden by a wide symbol are left blank
+            for x in (area.left()..area.right()).step_by(usize::from(width)) {
+                let symbol = if x + width <= area.right() {
+                    symbol
+                } else {
+                    " "
+                };
+                buf[(x, y)].set_symbol(symbol);
+            }
+        }
+    }
+}
+
+impl Styled for Fill<'_> {
+    type Item = Self;
+
+    fn style(&self) -> Style {
+        self.style
+    }
+
+    fn set_style<S: Into<Style>>(self, style: S) -> Self::Item {
+        self.style(style)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[test]
+    fn render() {
+        let mut buffer = Buffer::with_lines(["xxxxx"; 4]);
+        Fill::new("░").render(Rect::new(1, 1, 3, 2), &mut buffer);
+        let expected = Buffer::with_lines(["xxxxx", "x░░░x", "x░░░x", "xxxxx"]);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn default_renders_like_clear() {
+        let mut buffer = Buffer::with_lines(["xxx".red(), "xxx".red()]);
+        Fill::default().render(buffer.area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines(["   ", "   "]));
+    }
+
+    #[test]
+    fn style_overwrites_the_cells() {
+        let mut buffer = Buffer::with_lines(["xxx".red().bold()]);
+        Fill::new("·").on_blue().render(buffer.area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines(["···".on_blue()]));
+    }
+
+    #[rstest]
+    #[case::even_width(4, "ＸＸ")]
+    #[case::odd_width(5, "ＸＸ ")]
+    #[case::narrower_than_the_symbol(1, " ")]
+    fn wide_symbol(#[case] width: u16, #[case] expected: &str) {
+        let mut buffer = Buffer::with_lines(["x".repeat(width as usize)]);
+        Fill::new("Ｘ").render(buffer.area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines([expected]));
+    }
+
+    #[test]
+    fn zero_width_symbol_is_blank() {
+        let mut buffer = Buffer::with_lines(["xx"]);
+        Fill::new("\u{200B}").render(buffer.area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines(["  "]));
+    }
+
+    #[test]
+    fn render_outside_of_the_buffer() {
+        let mut buffer = Buffer::with_lines(["xx", "xx"]);
+        Fill::new("o").render(Rect::new(1, 1, 5, 5), &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines(["xx", "xo"]));
+    }
+
+    #[test]
+    fn stylize() {
+        assert_eq!(
+            Fill::new("░").dark_gray().on_black(),
+            Fill::new("░").style(Style::new().dark_gray().on_black())
+        );
+    }
+}
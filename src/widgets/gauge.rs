@@ -1,4 +1,8 @@
-use crate::{prelude::*, style::Styled, widgets::Block};
+use crate::{
+    prelude::*,
+    style::Styled,
+    widgets::{block, Block, StatefulWidgetRef},
+};
 
 /// A widget to display a progress bar.
 ///
@@ -6,11 +10,17 @@ use crate::{prelude::*, style::Styled, widgets::Block};
 /// [`Gauge::ratio`]. The bar width and height are defined by the [`Rect`] it is
 /// [rendered](Widget::render) in.
 ///
-/// The associated label is always centered horizontally and vertically. If not set with
-/// [`Gauge::label`], the label is the percentage of the bar filled.
+/// The associated label is centered vertically and, by default, horizontally. If not set with
+/// [`Gauge::label`], the label is the percentage of the bar filled. Use [`Gauge::label_alignment`]
+/// to align the label horizontally and [`Gauge::percent_suffix`] to display the percentage after a
+/// custom label.
 ///
 /// You might want to have a higher precision bar using [`Gauge::use_unicode`].
 ///
+/// When the progress is unknown, an [indeterminate](Gauge::indeterminate) gauge displays a segment
+/// moving across the bar instead. A reference to the gauge is then rendered as a
+/// [`StatefulWidget`] with a [`GaugeState`] which holds the position of the segment.
+///
 /// This can be useful to indicate the progression of a task, like a download.
 ///
 /// # Example
@@ -33,14 +43,70 @@ use crate::{prelude::*, style::Styled, widgets::Block};
 ///
 /// - [`LineGauge`] for a thin progress bar
 #[allow(clippy::struct_field_names)] // gauge_style needs to be differentiated to style
-#[derive(Debug, Default, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Gauge<'a> {
     block: Option<Block<'a>>,
     ratio: f64,
     label: Option<Span<'a>>,
+    label_alignment: Alignment,
+    percent_suffix: bool,
     use_unicode: bool,
     style: Style,
     gauge_style: Style,
+    gradient: Vec<(f64, Color)>,
+    indeterminate: bool,
+}
+
+/// State of an [indeterminate](Gauge::indeterminate) [`Gauge`]
+///
+/// The state holds the number of ticks elapsed since the start of the animation. Each tick moves
+/// the segment of the gauge one cell to the right, wrapping around to the left of the bar. The
+/// ticks are usually advanced once per frame or on a timer.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::{prelude::*, widgets::*};
+///
+/// # fn ui(frame: &mut Frame) {
+/// # let area = Rect::default();
+/// // This should be stored outside of the function in your application state.
+/// let mut state = GaugeState::default();
+///
+/// let gauge = Gauge::default().indeterminate(true).label("Connecting");
+/// frame.render_stateful_widget(&gauge, area, &mut state);
+/// state.tick += 1;
+/// # }
+/// ```
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GaugeState {
+    /// The number of ticks elapsed since the start of the animation
+    pub tick: u64,
+}
+
+impl GaugeState {
+    /// Creates a new `GaugeState` at the given tick
+    pub const fn new(tick: u64) -> Self {
+        Self { tick }
+    }
+}
+
+impl Default for Gauge<'_> {
+    fn default() -> Self {
+        Self {
+            block: None,
+            ratio: 0.0,
+            label: None,
+            label_alignment: Alignment::Center,
+            percent_suffix: false,
+            use_unicode: false,
+            style: Style::new(),
+            gauge_style: Style::new(),
+            gradient: Vec::new(),
+            indeterminate: false,
+        }
+    }
 }
 
 impl<'a> Gauge<'a> {
@@ -97,8 +163,23 @@ impl<'a> Gauge<'a> {
 
     /// Sets the label to display in the center of the bar.
     ///
-    /// For a left-aligned label, see [`LineGauge`].
+    /// For a left-aligned label, see [`Gauge::label_alignment`] or [`LineGauge`].
     /// If the label is not defined, it is the percentage filled.
+    ///
+    /// # Example
+    ///
+    /// Use [`format_duration`](crate::text::format_duration) to display the remaining time.
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use ratatui::{text::format_duration, widgets::Gauge};
+    ///
+    /// let remaining = Duration::from_secs(133);
+    /// let gauge = Gauge::default()
+    ///     .ratio(0.4)
+    ///     .label(format!("{} left", format_duration(remaining)));
+    /// ```
     #[must_use = "method moves the value of self and returns the modified value"]
     pub fn label<T>(mut self, label: T) -> Self
     where
@@ -108,6 +189,45 @@ impl<'a> Gauge<'a> {
         self
     }
 
+    /// Sets the horizontal alignment of the label within the bar.
+    ///
+    /// Defaults to [`Alignment::Center`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let gauge = Gauge::default()
+    ///     .label("3.2 MB/s")
+    ///     .label_alignment(Alignment::Left)
+    ///     .percent(45);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn label_alignment(mut self, alignment: Alignment) -> Self {
+        self.label_alignment = alignment;
+        self
+    }
+
+    /// Sets whether to display the percentage filled after a custom [label](Gauge::label).
+    ///
+    /// The percentage is separated from the label by a space, e.g. `3.2 MB/s 45%`. This has no
+    /// effect if no label is set, as the label is then the percentage.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let gauge = Gauge::default()
+    ///     .label("3.2 MB/s •")
+    ///     .percent_suffix(true)
+    ///     .percent(45);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn percent_suffix(mut self, percent_suffix: bool) -> Self {
+        self.percent_suffix = percent_suffix;
+        self
+    }
+
     /// Sets the widget style.
     ///
     /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
@@ -131,6 +251,40 @@ impl<'a> Gauge<'a> {
         self
     }
 
+    /// Sets a gradient to color the filled part of the bar.
+    ///
+    /// `stops` are pairs of a position along the bar, from `0.0` (left) to `1.0` (right), and the
+    /// [`Color`] at that position. Each filled cell is colored according to its position across
+    /// the full width of the bar, not the filled ratio, so a cell keeps its color as the bar grows.
+    /// This overrides the foreground color of the [gauge style](Gauge::gauge_style).
+    ///
+    /// Colors between two stops are interpolated in RGB space. When either stop is not a
+    /// [`Color::Rgb`], the interpolated color is mapped to the nearest ANSI color (see
+    /// [`Color::to_ansi`]), e.g. to fall back to ANSI colors on terminals that don't support RGB
+    /// colors. [`Color::Reset`] can't be interpolated, so the color of the nearest stop is used.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let gauge = Gauge::default()
+    ///     .gradient([
+    ///         (0.0, Color::Rgb(0, 255, 0)),
+    ///         (0.5, Color::Rgb(255, 255, 0)),
+    ///         (1.0, Color::Rgb(255, 0, 0)),
+    ///     ])
+    ///     .percent(75);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn gradient<I>(mut self, stops: I) -> Self
+    where
+        I: IntoIterator<Item = (f64, Color)>,
+    {
+        self.gradient = stops.into_iter().collect();
+        self.gradient.sort_by(|a, b| a.0.total_cmp(&b.0));
+        self
+    }
+
     /// Sets whether to use unicode characters to display the progress bar.
     ///
     /// This enables the use of
@@ -141,25 +295,73 @@ impl<'a> Gauge<'a> {
         self.use_unicode = unicode;
         self
     }
+
+    /// Sets whether the gauge displays an indeterminate progress.
+    ///
+    /// An indeterminate gauge is used when the progress of a task is unknown. Instead of being
+    /// filled according to the [ratio](Gauge::ratio), a segment a quarter of the width of the bar
+    /// moves across it, one cell per tick of the [`GaugeState`] the gauge is rendered with,
+    /// wrapping around. The segment is drawn with the [gauge style](Gauge::gauge_style) or
+    /// [gradient](Gauge::gradient) like the filled part of a determinate gauge. Only a custom
+    /// [label](Gauge::label) is displayed, without the percentage.
+    ///
+    /// Render a reference to the gauge as a [`StatefulWidget`] to animate it. When rendered as a
+    /// [`Widget`], the segment stays at the left of the bar.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let gauge = Gauge::default()
+    ///     .gauge_style(Style::new().light_blue().on_black())
+    ///     .indeterminate(true);
+    ///
+    /// let mut state = GaugeState::new(3);
+    /// # let area = Rect::new(0, 0, 8, 1);
+    /// # let mut buf = Buffer::empty(area);
+    /// StatefulWidget::render(&gauge, area, &mut buf, &mut state);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn indeterminate(mut self, indeterminate: bool) -> Self {
+        self.indeterminate = indeterminate;
+        self
+    }
 }
 
 impl Widget for Gauge<'_> {
     fn render(self, area: Rect, buf: &mut Buffer) {
-        self.render_ref(area, buf);
+        WidgetRef::render_ref(&self, area, buf);
     }
 }
 
 impl WidgetRef for Gauge<'_> {
     fn render_ref(&self, area: Rect, buf: &mut Buffer) {
+        StatefulWidgetRef::render_ref(self, area, buf, &mut GaugeState::default());
+    }
+}
+
+// Note: remove this when StatefulWidgetRef is stabilized and replace with the blanket impl
+impl StatefulWidget for &Gauge<'_> {
+    type State = GaugeState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        StatefulWidgetRef::render_ref(self, area, buf, state);
+    }
+}
+
+impl StatefulWidgetRef for Gauge<'_> {
+    type State = GaugeState;
+
+    fn render_ref(&self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
         buf.set_style(area, self.style);
         self.block.render_ref(area, buf);
         let inner = self.block.inner_if_some(area);
-        self.render_gauge(inner, buf);
+        self.render_gauge(inner, buf, state.tick);
     }
 }
 
 impl Gauge<'_> {
-    fn render_gauge(&self, gauge_area: Rect, buf: &mut Buffer) {
+    fn render_gauge(&self, gauge_area: Rect, buf: &mut Buffer, tick: u64) {
         if gauge_area.is_empty() {
             return;
         }
@@ -167,12 +369,51 @@ impl Gauge<'_> {
         buf.set_style(gauge_area, self.gauge_style);
 
         // compute label value and its position
-        // label is put at the center of the gauge_area
-        let default_label = Span::raw(format!("{}%", f64::round(self.ratio * 100.0)));
-        let label = self.label.as_ref().unwrap_or(&default_label);
+        // label is put at the vertical center of the gauge_area
+        let percent = format!("{}%", f64::round(self.ratio * 100.0));
+        let label = match &self.label {
+            _ if self.indeterminate => self.label.clone().map(Line::from).unwrap_or_default(),
+            Some(label) if self.percent_suffix => {
+                Line::from(vec![label.clone(), Span::raw(" "), Span::raw(percent)])
+            }
+            Some(label) => Line::from(label.clone()),
+            None => Line::from(percent),
+        };
         let clamped_label_width = gauge_area.width.min(label.width() as u16);
-        let label_col = gauge_area.left() + (gauge_area.width - clamped_label_width) / 2;
+        let label_col = gauge_area.left()
+            + match self.label_alignment {
+                Alignment::Left => 0,
+                Alignment::Center => (gauge_area.width - clamped_label_width) / 2,
+                Alignment::Right => gauge_area.width - clamped_label_width,
+            };
         let label_row = gauge_area.top() + gauge_area.height / 2;
+        let bg = self.gauge_style.bg.unwrap_or(Color::Reset);
+
+        if self.indeterminate {
+            // a segment moving one cell to the right at each tick, wrapping around
+            let segment_width = u64::from((gauge_area.width / 4).max(1));
+            let width = u64::from(gauge_area.width);
+            let start = tick % width;
+            for y in gauge_area.top()..gauge_area.bottom() {
+                for x in gauge_area.left()..gauge_area.right() {
+                    let offset = u64::from(x - gauge_area.left());
+                    if (offset + width - start) % width >= segment_width {
+                        continue;
+                    }
+                    let fg = self.fill_color(gauge_area, x);
+                    if x < label_col || x > label_col + clamped_label_width || y != label_row {
+                        buf[(x, y)]
+                            .set_symbol(symbols::block::FULL)
+                            .set_fg(fg)
+                            .set_bg(bg);
+                    } else {
+                        buf[(x, y)].set_symbol(" ").set_fg(bg).set_bg(fg);
+                    }
+                }
+            }
+            buf.set_line(label_col, label_row, &label, clamped_label_width);
+            return;
+        }
 
         // the gauge will be filled proportionally to the ratio
         let filled_width = f64::from(gauge_area.width) * self.ratio;
@@ -184,27 +425,64 @@ impl Gauge<'_> {
         for y in gauge_area.top()..gauge_area.bottom() {
             // render the filled area (left to end)
             for x in gauge_area.left()..end {
+                let fg = self.fill_color(gauge_area, x);
                 // Use full block for the filled part of the gauge and spaces for the part that is
                 // covered by the label. Note that the background and foreground colors are swapped
                 // for the label part, otherwise the gauge will be inverted
                 if x < label_col || x > label_col + clamped_label_width || y != label_row {
                     buf[(x, y)]
                         .set_symbol(symbols::block::FULL)
-                        .set_fg(self.gauge_style.fg.unwrap_or(Color::Reset))
-                        .set_bg(self.gauge_style.bg.unwrap_or(Color::Reset));
+                        .set_fg(fg)
+                        .set_bg(bg);
                 } else {
-                    buf[(x, y)]
-                        .set_symbol(" ")
-                        .set_fg(self.gauge_style.bg.unwrap_or(Color::Reset))
-                        .set_bg(self.gauge_style.fg.unwrap_or(Color::Reset));
+                    buf[(x, y)].set_symbol(" ").set_fg(bg).set_bg(fg);
                 }
             }
             if self.use_unicode && self.ratio < 1.0 {
-                buf[(end, y)].set_symbol(get_unicode_block(filled_width % 1.0));
+                let cell = buf[(end, y)].set_symbol(get_unicode_block(filled_width % 1.0));
+                if !self.gradient.is_empty() {
+                    cell.set_fg(self.fill_color(gauge_area, end));
+                }
             }
         }
         // render the label
-        buf.set_span(label_col, label_row, label, clamped_label_width);
+        buf.set_line(label_col, label_row, &label, clamped_label_width);
+    }
+}
+
+impl Gauge<'_> {
+    /// Returns the color of the filled cell in the given column
+    fn fill_color(&self, gauge_area: Rect, x: u16) -> Color {
+        if self.gradient.is_empty() {
+            return self.gauge_style.fg.unwrap_or(Color::Reset);
+        }
+        let position = if gauge_area.width > 1 {
+            f64::from(x - gauge_area.left()) / f64::from(gauge_area.width - 1)
+        } else {
+            0.0
+        };
+        gradient_color(&self.gradient, position)
+    }
+}
+
+/// Returns the color at the given position of a gradient made of stops sorted by position
+pub(crate) fn gradient_color(stops: &[(f64, Color)], position: f64) -> Color {
+    let next = stops.partition_point(|(stop, _)| *stop < position);
+    let (start, end) = match (next.checked_sub(1), stops.get(next)) {
+        (Some(previous), Some(&end)) => (stops[previous], end),
+        (Some(previous), None) => return stops[previous].1,
+        (None, Some(&end)) => return end.1,
+        (None, None) => return Color::Reset,
+    };
+    let t = (position - start.0) / (end.0 - start.0);
+    let (Some((r1, g1, b1)), Some((r2, g2, b2))) = (start.1.to_rgb(), end.1.to_rgb()) else {
+        return if t < 0.5 { start.1 } else { end.1 };
+    };
+    let lerp = |a: u8, b: u8| (f64::from(a) + (f64::from(b) - f64::from(a)) * t).round() as u8;
+    let color = Color::Rgb(lerp(r1, r2), lerp(g1, g2), lerp(b1, b2));
+    match (start.1, end.1) {
+        (Color::Rgb(..), Color::Rgb(..)) => color,
+        _ => color.to_ansi(),
     }
 }
 
@@ -228,12 +506,17 @@ fn get_unicode_block<'a>(frac: f64) -> &'a str {
 ///
 /// A `LineGauge` renders a thin line filled according to the value given to [`LineGauge::ratio`].
 /// Unlike [`Gauge`], only the width can be defined by the [rendering](Widget::render) [`Rect`]. The
-/// height is always 1.
+/// height of a horizontal line is always 1.
 ///
 /// The associated label is always left-aligned. If not set with [`LineGauge::label`], the label is
 /// the percentage of the bar filled.
 ///
-/// You can also set the symbols used to draw the bar with [`LineGauge::line_set`].
+/// With [`LineGauge::direction`] set to [`Direction::Vertical`], the line is drawn vertically in
+/// the middle column of the area and filled from bottom to top. The label is then rendered above
+/// or below the line depending on [`LineGauge::label_position`].
+///
+/// You can also set the symbols used to draw the bar with [`LineGauge::line_set`], and show the
+/// progress with a higher precision using [`LineGauge::use_unicode`].
 ///
 /// To style the gauge line use [`LineGauge::filled_style`] and [`LineGauge::unfilled_style`] which
 /// let you pick a color for foreground (i.e. line) and background of the filled and unfilled part
@@ -259,17 +542,37 @@ fn get_unicode_block<'a>(frac: f64) -> &'a str {
 /// # See also
 ///
 /// - [`Gauge`] for bigger, higher precision and more configurable progress bar
-#[derive(Debug, Default, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct LineGauge<'a> {
     block: Option<Block<'a>>,
     ratio: f64,
     label: Option<Line<'a>>,
+    label_position: block::Position,
     line_set: symbols::line::Set,
+    direction: Direction,
+    use_unicode: bool,
     style: Style,
     filled_style: Style,
     unfilled_style: Style,
 }
 
+impl Default for LineGauge<'_> {
+    fn default() -> Self {
+        Self {
+            block: None,
+            ratio: 0.0,
+            label: None,
+            label_position: block::Position::Top,
+            line_set: symbols::line::NORMAL,
+            direction: Direction::Horizontal,
+            use_unicode: false,
+            style: Style::new(),
+            filled_style: Style::new(),
+            unfilled_style: Style::new(),
+        }
+    }
+}
+
 impl<'a> LineGauge<'a> {
     /// Surrounds the `LineGauge` with a [`Block`].
     #[must_use = "method moves the value of self and returns the modified value"]
@@ -322,6 +625,65 @@ impl<'a> LineGauge<'a> {
         self
     }
 
+    /// Sets whether the label is rendered above or below a [vertical](LineGauge::direction) line.
+    ///
+    /// Defaults to [`Position::Top`](block::Position::Top). This has no effect on a horizontal
+    /// line, where the label is always on the left.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn label_position(mut self, position: block::Position) -> Self {
+        self.label_position = position;
+        self
+    }
+
+    /// Sets the direction of the line.
+    ///
+    /// Defaults to [`Direction::Horizontal`]. A [`Direction::Vertical`] line uses the vertical
+    /// symbol of the [line set](LineGauge::line_set) and is filled from bottom to top. If the area
+    /// is a single row high, only one cell is drawn, with the filled style if the ratio is not
+    /// zero, and no label.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ratatui::{prelude::*, widgets::{block::Position, *}};
+    /// let gauge = LineGauge::default()
+    ///     .direction(Direction::Vertical)
+    ///     .label_position(Position::Bottom)
+    ///     .ratio(0.5);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn direction(mut self, direction: Direction) -> Self {
+        self.direction = direction;
+        self
+    }
+
+    /// Sets whether to use unicode characters to display the boundary of the filled part.
+    ///
+    /// This enables the use of
+    /// [unicode block characters](https://en.wikipedia.org/wiki/Block_Elements) in the cell where
+    /// the filled part ends: left eighth blocks (`▏▎▍▌▋▊▉█`) for a horizontal line, and lower
+    /// eighth blocks (`▁▂▃▄▅▆▇█`) for a vertical one. This is useful to display a higher precision
+    /// line (8 extra fractional parts per cell).
+    ///
+    /// The block uses the foreground color of the [filled style](LineGauge::filled_style), on the
+    /// foreground color of the [unfilled style](LineGauge::unfilled_style) as background.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let gauge = LineGauge::default()
+    ///     .filled_style(Color::Green)
+    ///     .unfilled_style(Color::DarkGray)
+    ///     .use_unicode(true)
+    ///     .ratio(0.33);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn use_unicode(mut self, unicode: bool) -> Self {
+        self.use_unicode = unicode;
+        self
+    }
+
     /// Sets the widget style.
     ///
     /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
@@ -392,6 +754,11 @@ impl WidgetRef for LineGauge<'_> {
             return;
         }
 
+        if self.direction == Direction::Vertical {
+            self.render_vertical(gauge_area, buf);
+            return;
+        }
+
         let ratio = self.ratio;
         let default_label = Line::from(format!("{:.0}%", ratio * 100.0));
         let label = self.label.as_ref().unwrap_or(&default_label);
@@ -401,14 +768,23 @@ impl WidgetRef for LineGauge<'_> {
             return;
         }
 
-        let end = start
-            + (f64::from(gauge_area.right().saturating_sub(start)) * self.ratio).floor() as u16;
+        let filled_width = f64::from(gauge_area.right().saturating_sub(start)) * self.ratio;
+        let end = start + filled_width.floor() as u16;
         for col in start..end {
             buf[(col, row)]
                 .set_symbol(self.line_set.horizontal)
                 .set_style(self.filled_style);
         }
-        for col in end..gauge_area.right() {
+        let mut unfilled_start = end;
+        if let Some(symbol) = self.partial_block(filled_width % 1.0, &HORIZONTAL_EIGHTHS) {
+            if end < gauge_area.right() {
+                buf[(end, row)]
+                    .set_symbol(symbol)
+                    .set_style(self.partial_style());
+                unfilled_start += 1;
+            }
+        }
+        for col in unfilled_start..gauge_area.right() {
             buf[(col, row)]
                 .set_symbol(self.line_set.horizontal)
                 .set_style(self.unfilled_style);
@@ -416,6 +792,97 @@ impl WidgetRef for LineGauge<'_> {
     }
 }
 
+impl LineGauge<'_> {
+    fn render_vertical(&self, gauge_area: Rect, buf: &mut Buffer) {
+        let col = gauge_area.left() + gauge_area.width.saturating_sub(1) / 2;
+        if gauge_area.height == 1 {
+            let style = if self.ratio > 0.0 {
+                self.filled_style
+            } else {
+                self.unfilled_style
+            };
+            buf[(col, gauge_area.top())]
+                .set_symbol(self.line_set.vertical)
+                .set_style(style);
+            return;
+        }
+
+        let default_label = Line::from(format!("{:.0}%", self.ratio * 100.0));
+        let label = self.label.as_ref().unwrap_or(&default_label);
+        let (label_row, line_top) = match self.label_position {
+            block::Position::Top => (gauge_area.top(), gauge_area.top() + 1),
+            block::Position::Bottom => (gauge_area.bottom() - 1, gauge_area.top()),
+        };
+        buf.set_line(gauge_area.left(), label_row, label, gauge_area.width);
+
+        let line_height = gauge_area.height - 1;
+        let line_bottom = line_top + line_height;
+        let filled_height = f64::from(line_height) * self.ratio;
+        let filled = filled_height.floor() as u16;
+        for row in line_top..line_bottom {
+            let style = if row >= line_bottom - filled {
+                self.filled_style
+            } else {
+                self.unfilled_style
+            };
+            buf[(col, row)]
+                .set_symbol(self.line_set.vertical)
+                .set_style(style);
+        }
+        if let Some(symbol) = self.partial_block(filled_height % 1.0, &VERTICAL_EIGHTHS) {
+            if filled < line_height {
+                buf[(col, line_bottom - filled - 1)]
+                    .set_symbol(symbol)
+                    .set_style(self.partial_style());
+            }
+        }
+    }
+
+    /// Returns the eighth block of the cell where the filled part ends, if unicode characters are
+    /// enabled and the cell is at least an eighth filled
+    fn partial_block(&self, fraction: f64, eighths: &[&'static str; 8]) -> Option<&'static str> {
+        if !self.use_unicode {
+            return None;
+        }
+        match (fraction * 8.0).round() as usize {
+            0 => None,
+            n => eighths.get(n - 1).copied(),
+        }
+    }
+
+    /// Returns the style of the cell where the filled part ends, which blends the filled and
+    /// unfilled colors
+    const fn partial_style(&self) -> Style {
+        let mut style = self.filled_style;
+        style.bg = self.unfilled_style.fg;
+        style
+    }
+}
+
+/// The left eighth blocks, from one eighth to a full block
+const HORIZONTAL_EIGHTHS: [&str; 8] = [
+    symbols::block::ONE_EIGHTH,
+    symbols::block::ONE_QUARTER,
+    symbols::block::THREE_EIGHTHS,
+    symbols::block::HALF,
+    symbols::block::FIVE_EIGHTHS,
+    symbols::block::THREE_QUARTERS,
+    symbols::block::SEVEN_EIGHTHS,
+    symbols::block::FULL,
+];
+
+/// The lower eighth blocks, from one eighth to a full block
+const VERTICAL_EIGHTHS: [&str; 8] = [
+    symbols::bar::ONE_EIGHTH,
+    symbols::bar::ONE_QUARTER,
+    symbols::bar::THREE_EIGHTHS,
+    symbols::bar::HALF,
+    symbols::bar::FIVE_EIGHTHS,
+    symbols::bar::THREE_QUARTERS,
+    symbols::bar::SEVEN_EIGHTHS,
+    symbols::bar::FULL,
+];
+
 impl<'a> Styled for Gauge<'a> {
     type Item = Self;
 
@@ -442,6 +909,8 @@ impl<'a> Styled for LineGauge<'a> {
 
 #[cfg(test)]
 mod tests {
+    use rstest::rstest;
+
     use super::*;
 
     #[test]
@@ -462,6 +931,113 @@ mod tests {
         let _ = Gauge::default().ratio(-0.5);
     }
 
+    #[test]
+    fn gauge_label_alignment() {
+        let gauge = Gauge::default()
+            .label("ab")
+            .label_alignment(Alignment::Left)
+            .percent(50);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 10, 1));
+        gauge.render(buf.area, &mut buf);
+        assert_eq!(buf, Buffer::with_lines(["ab ██     "]));
+    }
+
+    #[test]
+    fn gauge_percent_suffix() {
+        let gauge = Gauge::default()
+            .label("x")
+            .label_alignment(Alignment::Right)
+            .percent_suffix(true)
+            .percent(50);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 10, 1));
+        gauge.render(buf.area, &mut buf);
+        assert_eq!(buf, Buffer::with_lines(["█████x 50%"]));
+    }
+
+    #[test]
+    fn gauge_gradient() {
+        let gauge = Gauge::default()
+            .gradient([(1.0, Color::Rgb(0, 0, 200)), (0.0, Color::Rgb(100, 0, 0))])
+            .label("")
+            .label_alignment(Alignment::Right)
+            .percent(60);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 5, 1));
+        gauge.render(buf.area, &mut buf);
+        let mut expected = Buffer::with_lines(["███  "]);
+        expected[(0, 0)].set_fg(Color::Rgb(100, 0, 0));
+        expected[(1, 0)].set_fg(Color::Rgb(75, 0, 50));
+        expected[(2, 0)].set_fg(Color::Rgb(50, 0, 100));
+        for x in 0..3 {
+            expected[(x, 0)].set_bg(Color::Reset);
+        }
+        assert_eq!(buf, expected);
+    }
+
+    #[rstest]
+    #[case::start(0, ["██        "])]
+    #[case::moved(3, ["   ██     "])]
+    #[case::end(8, ["        ██"])]
+    #[case::wraps_around(9, ["█        █"])]
+    #[case::wrapped(10, ["██        "])]
+    fn gauge_indeterminate(#[case] tick: u64, #[case] expected: [&str; 1]) {
+        let gauge = Gauge::default().indeterminate(true).percent(50);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 10, 1));
+        StatefulWidget::render(&gauge, buf.area, &mut buf, &mut GaugeState::new(tick));
+        assert_eq!(buf, Buffer::with_lines(expected));
+    }
+
+    #[test]
+    fn gauge_indeterminate_label() {
+        let gauge = Gauge::default()
+            .indeterminate(true)
+            .label("wait")
+            .gauge_style(Style::new().red().on_blue());
+        let mut buf = Buffer::empty(Rect::new(0, 0, 8, 1));
+        StatefulWidget::render(&gauge, buf.area, &mut buf, &mut GaugeState::new(2));
+        let mut expected = Buffer::with_lines(["  wait  "]);
+        expected.set_style(buf.area, Style::new().red().on_blue());
+        expected.set_style(Rect::new(2, 0, 2, 1), Style::new().blue().on_red());
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn gauge_stateful_without_indeterminate() {
+        let gauge = Gauge::default().label("ab").percent(50);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 10, 1));
+        StatefulWidget::render(&gauge, buf.area, &mut buf, &mut GaugeState::new(3));
+        let mut expected = Buffer::empty(buf.area);
+        gauge.render(expected.area, &mut expected);
+        assert_eq!(buf, expected);
+    }
+
+    #[rstest]
+    #[case::before_first_stop(0.0, Color::Red)]
+    #[case::start(0.2, Color::Red)]
+    #[case::nearest_start(0.3, Color::Red)]
+    #[case::nearest_end(0.7, Color::Green)]
+    #[case::after_last_stop(1.0, Color::Green)]
+    fn gradient_color_without_rgb(#[case] position: f64, #[case] expected: Color) {
+        let stops = [(0.2, Color::Red), (0.8, Color::Green)];
+        assert_eq!(gradient_color(&stops, position), expected);
+    }
+
+    #[rstest]
+    #[case::near_start(0.1, Color::Black)]
+    #[case::middle(0.5, Color::DarkGray)]
+    #[case::end(1.0, Color::White)]
+    fn gradient_color_between_rgb_and_ansi(#[case] position: f64, #[case] expected: Color) {
+        let stops = [(0.0, Color::Rgb(0, 0, 0)), (1.0, Color::White)];
+        assert_eq!(gradient_color(&stops, position), expected);
+    }
+
+    #[rstest]
+    #[case::nearest_start(0.4, Color::Reset)]
+    #[case::nearest_end(0.6, Color::Blue)]
+    fn gradient_color_with_reset(#[case] position: f64, #[case] expected: Color) {
+        let stops = [(0.0, Color::Reset), (1.0, Color::Blue)];
+        assert_eq!(gradient_color(&stops, position), expected);
+    }
+
     #[test]
     fn gauge_can_be_stylized() {
         assert_eq!(
@@ -516,11 +1092,107 @@ mod tests {
                 block: None,
                 ratio: 0.0,
                 label: None,
+                label_position: block::Position::Top,
                 style: Style::default(),
                 line_set: symbols::line::NORMAL,
+                direction: Direction::Horizontal,
+                use_unicode: false,
                 filled_style: Style::default(),
                 unfilled_style: Style::default()
             }
         );
     }
+
+    #[test]
+    fn line_gauge_unicode() {
+        let gauge = LineGauge::default()
+            .label("")
+            .filled_style(Style::new().green())
+            .unfilled_style(Style::new().red())
+            .use_unicode(true)
+            .ratio(0.33);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 11, 1));
+        gauge.render(buf.area, &mut buf);
+        let mut expected = Buffer::with_lines([" ───▎──────"]);
+        expected.set_style(Rect::new(1, 0, 3, 1), Style::new().green());
+        expected.set_style(Rect::new(4, 0, 1, 1), Style::new().green().on_red());
+        expected.set_style(Rect::new(5, 0, 6, 1), Style::new().red());
+        assert_eq!(buf, expected);
+    }
+
+    #[rstest]
+    #[case::empty(0.0, " ──────────")]
+    #[case::less_than_an_eighth_of_a_cell(0.005, " ──────────")]
+    #[case::an_eighth_of_a_cell(0.0125, " ▏─────────")]
+    #[case::almost_full_cell(0.098, " █─────────")]
+    #[case::full(1.0, " ──────────")]
+    fn line_gauge_unicode_boundary(#[case] ratio: f64, #[case] expected: &str) {
+        let gauge = LineGauge::default()
+            .label("")
+            .use_unicode(true)
+            .ratio(ratio);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 11, 1));
+        gauge.render(buf.area, &mut buf);
+        assert_eq!(buf, Buffer::with_lines([expected]));
+    }
+
+    #[test]
+    fn line_gauge_vertical_unicode() {
+        let gauge = LineGauge::default()
+            .direction(Direction::Vertical)
+            .filled_style(Style::new().green())
+            .unfilled_style(Style::new().red())
+            .use_unicode(true)
+            .ratio(0.6);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 3, 5));
+        gauge.render(buf.area, &mut buf);
+        let mut expected = Buffer::with_lines(["60%", " │ ", " ▃ ", " │ ", " │ "]);
+        expected.set_style(Rect::new(1, 1, 1, 1), Style::new().red());
+        expected.set_style(Rect::new(1, 2, 1, 1), Style::new().green().on_red());
+        expected.set_style(Rect::new(1, 3, 1, 2), Style::new().green());
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn line_gauge_vertical() {
+        let gauge = LineGauge::default()
+            .direction(Direction::Vertical)
+            .filled_style(Style::new().green())
+            .unfilled_style(Style::new().red())
+            .ratio(0.5);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 3, 5));
+        gauge.render(buf.area, &mut buf);
+        let mut expected = Buffer::with_lines(["50%", " │ ", " │ ", " │ ", " │ "]);
+        expected.set_style(Rect::new(1, 1, 1, 2), Style::new().red());
+        expected.set_style(Rect::new(1, 3, 1, 2), Style::new().green());
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn line_gauge_vertical_label_at_bottom() {
+        let gauge = LineGauge::default()
+            .direction(Direction::Vertical)
+            .label("a")
+            .label_position(block::Position::Bottom)
+            .filled_style(Style::new().green())
+            .ratio(1.0);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 1, 3));
+        gauge.render(buf.area, &mut buf);
+        let mut expected = Buffer::with_lines(["│", "│", "a"]);
+        expected.set_style(Rect::new(0, 0, 1, 2), Style::new().green());
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn line_gauge_vertical_single_row() {
+        let gauge = LineGauge::default()
+            .direction(Direction::Vertical)
+            .filled_style(Style::new().green())
+            .ratio(0.1);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 1, 1));
+        gauge.render(buf.area, &mut buf);
+        let mut expected = Buffer::with_lines(["│"]);
+        expected.set_style(buf.area, Style::new().green());
+        assert_eq!(buf, expected);
+    }
 }
@@ -0,0 +1,175 @@
+use crate::{layout::Size, prelude::*, widgets::WidgetRef};
+
+/// A widget that displays colored content at twice the vertical resolution of the terminal.
+///
+/// Widgets are rendered into the internal buffer of the `HalfBlockCanvas` with
+/// [`HalfBlockCanvas::render_widget`]. The internal buffer is twice as tall as the canvas, so each
+/// row of the canvas is made of two rows of the buffer. When the canvas is rendered, each pair of
+/// rows is collapsed into a single row of half block cells with [`Buffer::to_half_blocks`], using
+/// the upper row as the foreground color and the lower row as the background color of each cell.
+///
+/// Only colors are displayed: the background color of the cells, or the foreground color of cells
+/// containing a full block (`█`). This is useful for images, heatmaps and other dense colored
+/// content, but not for text.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::{
+///     layout::Size,
+///     prelude::*,
+///     widgets::{Block, HalfBlockCanvas},
+/// };
+///
+/// # fn ui(frame: &mut Frame) {
+/// let area = frame.area();
+/// let mut canvas = HalfBlockCanvas::new(area.as_size());
+/// // the canvas is 2 cells tall for each row of the area
+/// let [top, bottom] = Layout::vertical([Constraint::Fill(1); 2]).areas(canvas.area());
+/// canvas.render_widget(Block::new().on_red(), top);
+/// canvas.render_widget(Block::new().on_blue(), bottom);
+/// frame.render_widget(canvas, area);
+/// # }
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct HalfBlockCanvas {
+    buf: Buffer,
+}
+
+impl HalfBlockCanvas {
+    /// Creates a new `HalfBlockCanvas` which is displayed in an area of the given size.
+    ///
+    /// The internal buffer is twice as tall as the given size.
+    pub fn new(size: Size) -> Self {
+        Self {
+            buf: Buffer::empty(Rect::new(0, 0, size.width, size.height.saturating_mul(2))),
+        }
+    }
+
+    /// Returns the size of the area the canvas is displayed in.
+    pub const fn size(&self) -> Size {
+        Size::new(self.buf.area.width, self.buf.area.height.div_ceil(2))
+    }
+
+    /// Returns the area of the internal buffer, which starts at `(0, 0)` and is twice as tall as
+    /// the canvas.
+    pub const fn area(&self) -> Rect {
+        self.buf.area
+    }
+
+    /// Returns the internal buffer.
+    pub const fn buf(&self) -> &Buffer {
+        &self.buf
+    }
+
+    /// Returns a mutable reference to the internal buffer.
+    pub fn buf_mut(&mut self) -> &mut Buffer {
+        &mut self.buf
+    }
+
+    /// Renders a widget into the internal buffer at the given area.
+    ///
+    /// The area is relative to the top left corner of the internal buffer, where each row is half
+    /// a row of the canvas.
+    pub fn render_widget<W: Widget>(&mut self, widget: W, area: Rect) {
+        widget.render(area, &mut self.buf);
+    }
+
+    /// Renders a stateful widget into the internal buffer at the given area.
+    ///
+    /// The area is relative to the top left corner of the internal buffer, where each row is half
+    /// a row of the canvas.
+    pub fn render_stateful_widget<W: StatefulWidget>(
+        &mut self,
+        widget: W,
+        area: Rect,
+        state: &mut W::State,
+    ) {
+        widget.render(area, &mut self.buf, state);
+    }
+}
+
+impl Widget for HalfBlockCanvas {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        self.render_ref(area, buf);
+    }
+}
+
+impl WidgetRef for HalfBlockCanvas {
+    fn render_ref(&self, area: Rect, buf: &mut Buffer) {
+        let area = area.intersection(buf.area);
+        let half_blocks = self.buf.to_half_blocks();
+        let size = self.size();
+        for y in 0..size.height.min(area.height) {
+            for x in 0..size.width.min(area.width) {
+                buf[(area.x + x, area.y + y)] = half_blocks[(x, y)].clone();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::widgets::Block;
+
+    #[test]
+    fn new() {
+        let canvas = HalfBlockCanvas::new(Size::new(4, 3));
+        assert_eq!(canvas.size(), Size::new(4, 3));
+        assert_eq!(canvas.area(), Rect::new(0, 0, 4, 6));
+        assert_eq!(canvas.buf(), &Buffer::empty(Rect::new(0, 0, 4, 6)));
+    }
+
+    #[test]
+    fn render() {
+        let mut canvas = HalfBlockCanvas::new(Size::new(4, 2));
+        canvas.render_widget(Block::new().on_red(), Rect::new(0, 0, 4, 1));
+        canvas.render_widget(Block::new().on_blue(), Rect::new(0, 1, 2, 2));
+        canvas.render_widget(Block::new().on_green(), Rect::new(2, 3, 2, 1));
+        let mut buf = Buffer::empty(Rect::new(0, 0, 4, 2));
+        canvas.render(buf.area, &mut buf);
+
+        let mut expected = Buffer::with_lines(["▀▀▀▀", "▀▀▄▄"]);
+        expected.set_style(Rect::new(0, 0, 2, 1), Style::new().red().on_blue());
+        expected.set_style(Rect::new(2, 0, 2, 1), Style::new().red());
+        expected.set_style(Rect::new(0, 1, 2, 1), Style::new().blue());
+        expected.set_style(Rect::new(2, 1, 2, 1), Style::new().green());
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn render_full_blocks() {
+        let mut canvas = HalfBlockCanvas::new(Size::new(2, 1));
+        canvas.render_widget("██".yellow(), Rect::new(0, 0, 2, 1));
+        canvas.render_widget("█".yellow(), Rect::new(0, 1, 1, 1));
+        let mut buf = Buffer::empty(Rect::new(0, 0, 2, 1));
+        canvas.render(buf.area, &mut buf);
+
+        let mut expected = Buffer::with_lines(["█▀"]);
+        expected.set_style(Rect::new(0, 0, 1, 1), Style::new().yellow().on_yellow());
+        expected.set_style(Rect::new(1, 0, 1, 1), Style::new().yellow());
+        assert_eq!(buf, expected);
+    }
+
+    #[rstest]
+    #[case::smaller_area(Rect::new(1, 1, 2, 1), ["    ", " ▀▀ ", "    "])]
+    #[case::larger_area(Rect::new(1, 0, 3, 3), [" ▀▀ ", "    ", "    "])]
+    #[case::outside_buffer(Rect::new(3, 2, 2, 2), ["    ", "    ", "   ▀"])]
+    fn render_clipped(#[case] area: Rect, #[case] expected: [&str; 3]) {
+        let mut canvas = HalfBlockCanvas::new(Size::new(2, 1));
+        canvas.render_widget(Block::new().on_red(), Rect::new(0, 0, 2, 1));
+        let mut buf = Buffer::empty(Rect::new(0, 0, 4, 3));
+        canvas.render(area, &mut buf);
+
+        let mut expected = Buffer::with_lines(expected);
+        for cell in &mut expected.content {
+            if cell.symbol() == "▀" {
+                cell.set_fg(Color::Red);
+            }
+        }
+        assert_eq!(buf, expected);
+    }
+}
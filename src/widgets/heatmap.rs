@@ -0,0 +1,604 @@
+use unicode_width::UnicodeWidthStr;
+
+use super::gauge::gradient_color;
+use crate::{prelude::*, style::Styled, widgets::Block};
+
+/// The default color ramp of a [`Heatmap`], going from purple to teal to yellow
+const DEFAULT_GRADIENT: [(f64, Color); 3] = [
+    (0.0, Color::Rgb(68, 1, 84)),
+    (0.5, Color::Rgb(33, 145, 140)),
+    (1.0, Color::Rgb(253, 231, 37)),
+];
+
+/// A widget to display a matrix of values as a grid of colored cells.
+///
+/// Each value of the matrix is displayed as a rectangle of terminal cells whose background color is
+/// taken from a [color ramp](Heatmap::gradient) according to the value. This is useful to display
+/// e.g. a confusion matrix or a correlation matrix.
+///
+/// The values are scaled between the minimum and maximum values of the data, or between explicit
+/// [bounds](Heatmap::bounds). The cells are as large as possible so that the whole matrix fits in
+/// the area, and the space left over is left empty. Values which are `NaN` are not displayed.
+///
+/// # Setter methods
+///
+/// - [`Heatmap::block`] wraps the heatmap in a [`Block`]
+/// - [`Heatmap::gradient`] sets the color ramp
+/// - [`Heatmap::bounds`] sets the values mapped to the ends of the color ramp
+/// - [`Heatmap::row_labels`] and [`Heatmap::column_labels`] label the rows and columns
+/// - [`Heatmap::legend`] displays a color bar next to the matrix
+/// - [`Heatmap::show_values`] prints the values inside the cells
+/// - [`Heatmap::precision`] sets the number of decimals of the printed values
+///
+/// # Example
+///
+/// ```
+/// use ratatui::{prelude::*, widgets::*};
+///
+/// Heatmap::new([[1.0, 0.2, 0.0], [0.3, 0.9, 0.1], [0.0, 0.4, 0.8]])
+///     .block(Block::bordered().title("Confusion matrix"))
+///     .row_labels(["cat", "dog", "fox"])
+///     .column_labels(["cat", "dog", "fox"])
+///     .legend(true)
+///     .show_values(true);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Heatmap<'a> {
+    /// The values of the matrix, row by row
+    data: Vec<Vec<f64>>,
+    /// A block to wrap the widget in
+    block: Option<Block<'a>>,
+    /// Widget style
+    style: Style,
+    /// The color ramp, made of stops sorted by position
+    gradient: Vec<(f64, Color)>,
+    /// The values mapped to the ends of the color ramp, computed from the data when `None`
+    bounds: Option<[f64; 2]>,
+    /// The labels displayed on the left of the rows
+    row_labels: Vec<Line<'a>>,
+    /// The labels displayed above the columns
+    column_labels: Vec<Line<'a>>,
+    /// Whether to display a color bar on the right of the matrix
+    legend: bool,
+    /// Whether to print the values inside the cells
+    show_values: bool,
+    /// The number of decimals of the printed values
+    precision: usize,
+}
+
+impl Default for Heatmap<'_> {
+    fn default() -> Self {
+        Self {
+            data: Vec::new(),
+            block: None,
+            style: Style::new(),
+            gradient: DEFAULT_GRADIENT.to_vec(),
+            bounds: None,
+            row_labels: Vec::new(),
+            column_labels: Vec::new(),
+            legend: false,
+            show_values: false,
+            precision: 2,
+        }
+    }
+}
+
+impl<'a> Heatmap<'a> {
+    /// Creates a new `Heatmap` displaying the given matrix of values.
+    ///
+    /// `data` is an iterator of rows, each row being an iterator of values. Rows may have
+    /// different lengths, in which case the missing values are not displayed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ratatui::widgets::Heatmap;
+    /// let heatmap = Heatmap::new([[0.0, 1.0], [2.0, 3.0]]);
+    /// let heatmap = Heatmap::new(vec![vec![0.0, 1.0, 2.0], vec![3.0]]);
+    /// ```
+    pub fn new<R, V>(data: R) -> Self
+    where
+        R: IntoIterator<Item = V>,
+        V: IntoIterator<Item = f64>,
+    {
+        Self {
+            data: data
+                .into_iter()
+                .map(|row| row.into_iter().collect())
+                .collect(),
+            ..Self::default()
+        }
+    }
+
+    /// Wraps the heatmap with the given `block`.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn block(mut self, block: Block<'a>) -> Self {
+        self.block = Some(block);
+        self
+    }
+
+    /// Sets the style of the entire widget.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// The foreground color of this style is used for the labels.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets the color ramp used to color the cells.
+    ///
+    /// `stops` are pairs of a position along the ramp, from `0.0` (the minimum value) to `1.0`
+    /// (the maximum value), and the [`Color`] at that position. Colors between two stops are
+    /// interpolated in RGB space, and mapped to the nearest ANSI color when either stop is not a
+    /// [`Color::Rgb`].
+    ///
+    /// The default ramp goes from purple to teal to yellow.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let heatmap = Heatmap::new([[-1.0, 0.0, 1.0]]).gradient([
+    ///     (0.0, Color::Rgb(0, 0, 255)),
+    ///     (0.5, Color::Rgb(255, 255, 255)),
+    ///     (1.0, Color::Rgb(255, 0, 0)),
+    /// ]);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn gradient<I>(mut self, stops: I) -> Self
+    where
+        I: IntoIterator<Item = (f64, Color)>,
+    {
+        self.gradient = stops.into_iter().collect();
+        self.gradient.sort_by(|a, b| a.0.total_cmp(&b.0));
+        self
+    }
+
+    /// Sets the values mapped to the start and end of the color ramp, as `[min, max]`.
+    ///
+    /// Values outside of the bounds are colored like the nearest bound. By default, the bounds are
+    /// the minimum and maximum values of the data, so that the whole ramp is used. Explicit bounds
+    /// are useful to compare heatmaps, or when the values have a known range, e.g. `[-1.0, 1.0]`
+    /// for a correlation matrix.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn bounds(mut self, bounds: [f64; 2]) -> Self {
+        self.bounds = Some(bounds);
+        self
+    }
+
+    /// Sets the labels displayed on the left of the rows.
+    ///
+    /// Each label is displayed on the middle line of its row and the labels are left aligned.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn row_labels<I>(mut self, labels: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<Line<'a>>,
+    {
+        self.row_labels = labels.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Sets the labels displayed above the columns.
+    ///
+    /// Each label is centered above its column and truncated to the width of the column.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn column_labels<I>(mut self, labels: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<Line<'a>>,
+    {
+        self.column_labels = labels.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Sets whether to display a legend on the right of the matrix.
+    ///
+    /// The legend is a vertical color bar of the ramp, with the maximum value at the top and the
+    /// minimum value at the bottom.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn legend(mut self, legend: bool) -> Self {
+        self.legend = legend;
+        self
+    }
+
+    /// Sets whether to print the values inside the cells.
+    ///
+    /// A value is printed on the middle line of its cell when it fits in the width of the cell.
+    /// The text is black or white depending on the brightness of the cell, when the color of the
+    /// cell is a [`Color::Rgb`].
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn show_values(mut self, show_values: bool) -> Self {
+        self.show_values = show_values;
+        self
+    }
+
+    /// Sets the number of decimals of the values printed in the cells and the legend.
+    ///
+    /// The default is 2.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn precision(mut self, precision: usize) -> Self {
+        self.precision = precision;
+        self
+    }
+}
+
+impl Widget for Heatmap<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        self.render_ref(area, buf);
+    }
+}
+
+impl WidgetRef for Heatmap<'_> {
+    fn render_ref(&self, area: Rect, buf: &mut Buffer) {
+        buf.set_style(area, self.style);
+        self.block.render_ref(area, buf);
+        let inner = self.block.inner_if_some(area);
+        self.render_heatmap(inner, buf);
+    }
+}
+
+impl Heatmap<'_> {
+    fn render_heatmap(&self, area: Rect, buf: &mut Buffer) {
+        let column_count = self.data.iter().map(Vec::len).max().unwrap_or(0);
+        if area.is_empty() || column_count == 0 {
+            return;
+        }
+        let [min, max] = self.value_bounds();
+
+        // the row labels are followed by a space, and the legend is made of a space, a bar two
+        // cells wide, another space and the labels of the bounds
+        let row_labels_width = self.row_labels.iter().map(Line::width).max().unwrap_or(0) as u16;
+        let row_labels_width = if row_labels_width > 0 {
+            row_labels_width + 1
+        } else {
+            0
+        };
+        let legend_labels = [self.format_value(max), self.format_value(min)];
+        let legend_width = if self.legend {
+            legend_labels.iter().map(|l| l.width()).max().unwrap_or(0) as u16 + 4
+        } else {
+            0
+        };
+        let column_labels_height = u16::from(!self.column_labels.is_empty());
+        let [row_labels_area, matrix_area, legend_area] = Layout::horizontal([
+            Constraint::Length(row_labels_width),
+            Constraint::Fill(1),
+            Constraint::Length(legend_width),
+        ])
+        .areas(area);
+        let [column_labels_area, matrix_area] = Layout::vertical([
+            Constraint::Length(column_labels_height),
+            Constraint::Fill(1),
+        ])
+        .areas(matrix_area);
+        if matrix_area.is_empty() {
+            return;
+        }
+
+        let cell_width = (usize::from(matrix_area.width) / column_count).max(1) as u16;
+        let cell_height = (usize::from(matrix_area.height) / self.data.len()).max(1) as u16;
+        let cells = |index: usize, start: u16, end: u16, size: u16| {
+            let offset = u16::try_from(index).ok()?.checked_mul(size)?;
+            let position = start.checked_add(offset)?;
+            (position < end).then_some(position)
+        };
+
+        for (row, values) in self.data.iter().enumerate() {
+            let Some(y) = cells(row, matrix_area.top(), matrix_area.bottom(), cell_height) else {
+                break;
+            };
+            for (column, &value) in values.iter().enumerate() {
+                let Some(x) = cells(column, matrix_area.left(), matrix_area.right(), cell_width)
+                else {
+                    break;
+                };
+                if value.is_nan() {
+                    continue;
+                }
+                let cell_area = Rect::new(x, y, cell_width, cell_height).intersection(matrix_area);
+                self.render_cell(cell_area, buf, value, [min, max]);
+            }
+            if let Some(label) = self.row_labels.get(row) {
+                let label_y = y + cell_height / 2;
+                if label_y < matrix_area.bottom() {
+                    let width = row_labels_area.width.saturating_sub(1);
+                    buf.set_line(row_labels_area.x, label_y, label, width);
+                }
+            }
+        }
+
+        for (column, label) in self.column_labels.iter().enumerate() {
+            let Some(x) = cells(column, matrix_area.left(), matrix_area.right(), cell_width) else {
+                break;
+            };
+            let width = cell_width.min(matrix_area.right() - x);
+            let offset = width.saturating_sub(label.width() as u16) / 2;
+            buf.set_line(x + offset, column_labels_area.y, label, width - offset);
+        }
+
+        if self.legend {
+            let legend_area = Rect {
+                y: matrix_area.y,
+                height: matrix_area.height,
+                ..legend_area
+            };
+            self.render_legend(legend_area, buf, &legend_labels);
+        }
+    }
+
+    fn render_cell(&self, area: Rect, buf: &mut Buffer, value: f64, [min, max]: [f64; 2]) {
+        let position = if max > min {
+            ((value - min) / (max - min)).clamp(0.0, 1.0)
+        } else {
+            0.5
+        };
+        let color = gradient_color(&self.gradient, position);
+        buf.set_style(area, Style::new().bg(color));
+        if !self.show_values {
+            return;
+        }
+        let text = self.format_value(value);
+        let width = text.width() as u16;
+        if width > area.width {
+            return;
+        }
+        let style = contrasting_color(color).map_or_else(Style::new, |fg| Style::new().fg(fg));
+        let x = area.x + (area.width - width) / 2;
+        buf.set_stringn(x, area.y + area.height / 2, text, width.into(), style);
+    }
+
+    fn render_legend(&self, area: Rect, buf: &mut Buffer, labels: &[String; 2]) {
+        let bar = Rect {
+            x: area.x + 1,
+            width: 2,
+            ..area
+        }
+        .intersection(area);
+        for (i, y) in (bar.top()..bar.bottom()).enumerate() {
+            let position = if bar.height > 1 {
+                1.0 - i as f64 / f64::from(bar.height - 1)
+            } else {
+                1.0
+            };
+            let color = gradient_color(&self.gradient, position);
+            buf.set_style(
+                Rect {
+                    y,
+                    height: 1,
+                    ..bar
+                },
+                Style::new().bg(color),
+            );
+        }
+        let [max_label, min_label] = labels;
+        let x = area.x + 4;
+        let width = area.right().saturating_sub(x).into();
+        buf.set_stringn(x, area.top(), max_label, width, Style::new());
+        if area.height > 1 {
+            buf.set_stringn(x, area.bottom() - 1, min_label, width, Style::new());
+        }
+    }
+
+    /// Returns the values mapped to the ends of the color ramp
+    fn value_bounds(&self) -> [f64; 2] {
+        self.bounds.unwrap_or_else(|| {
+            let values = self.data.iter().flatten().copied().filter(|v| !v.is_nan());
+            let (min, max) = values.fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), v| {
+                (min.min(v), max.max(v))
+            });
+            if min > max {
+                [0.0, 0.0]
+            } else {
+                [min, max]
+            }
+        })
+    }
+
+    fn format_value(&self, value: f64) -> String {
+        format!("{value:.*}", self.precision)
+    }
+}
+
+/// Returns black or white, whichever is most readable on the given color, or `None` when the
+/// brightness of the color is unknown
+fn contrasting_color(color: Color) -> Option<Color> {
+    match color {
+        Color::Rgb(r, g, b) => {
+            let luma = 0.114f64.mul_add(
+                f64::from(b),
+                0.299f64.mul_add(f64::from(r), 0.587 * f64::from(g)),
+            );
+            Some(if luma > 127.5 {
+                Color::Black
+            } else {
+                Color::White
+            })
+        }
+        _ => None,
+    }
+}
+
+impl Styled for Heatmap<'_> {
+    type Item = Self;
+
+    fn style(&self) -> Style {
+        self.style
+    }
+
+    fn set_style<S: Into<Style>>(self, style: S) -> Self::Item {
+        self.style(style)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    const BLACK: Color = Color::Rgb(0, 0, 0);
+    const WHITE: Color = Color::Rgb(255, 255, 255);
+    const GRAY: Color = Color::Rgb(128, 128, 128);
+
+    /// A heatmap with a black to white ramp
+    fn heatmap<'a, const W: usize, const H: usize>(data: [[f64; W]; H]) -> Heatmap<'a> {
+        Heatmap::new(data).gradient([(0.0, BLACK), (1.0, WHITE)])
+    }
+
+    fn render(heatmap: &Heatmap, width: u16, height: u16) -> Buffer {
+        let mut buf = Buffer::empty(Rect::new(0, 0, width, height));
+        heatmap.render_ref(buf.area, &mut buf);
+        buf
+    }
+
+    #[test]
+    fn cells_fill_area() {
+        let buf = render(&heatmap([[0.0, 1.0], [2.0, 4.0]]), 4, 4);
+        let mut expected = Buffer::empty(buf.area);
+        expected.set_style(Rect::new(0, 0, 2, 2), Style::new().bg(BLACK));
+        expected.set_style(
+            Rect::new(2, 0, 2, 2),
+            Style::new().bg(Color::Rgb(64, 64, 64)),
+        );
+        expected.set_style(Rect::new(0, 2, 2, 2), Style::new().bg(GRAY));
+        expected.set_style(Rect::new(2, 2, 2, 2), Style::new().bg(WHITE));
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn leftover_space_is_empty() {
+        let buf = render(&heatmap([[0.0, 1.0]]), 5, 1);
+        let mut expected = Buffer::empty(buf.area);
+        expected.set_style(Rect::new(0, 0, 2, 1), Style::new().bg(BLACK));
+        expected.set_style(Rect::new(2, 0, 2, 1), Style::new().bg(WHITE));
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn too_many_columns_are_clipped() {
+        let buf = render(&heatmap([[0.0, 1.0, 2.0]]), 2, 1);
+        let mut expected = Buffer::empty(buf.area);
+        expected.set_style(Rect::new(0, 0, 1, 1), Style::new().bg(BLACK));
+        expected.set_style(Rect::new(1, 0, 1, 1), Style::new().bg(GRAY));
+        assert_eq!(buf, expected);
+    }
+
+    #[rstest]
+    #[case::explicit_bounds(Some([0.0, 10.0]), [BLACK, Color::Rgb(26, 26, 26)])]
+    #[case::clamped_bounds(Some([0.5, 0.75]), [BLACK, WHITE])]
+    #[case::auto_bounds(None, [BLACK, WHITE])]
+    fn scaling(#[case] bounds: Option<[f64; 2]>, #[case] expected: [Color; 2]) {
+        let mut heatmap = heatmap([[0.0, 1.0]]);
+        if let Some(bounds) = bounds {
+            heatmap = heatmap.bounds(bounds);
+        }
+        let buf = render(&heatmap, 2, 1);
+        assert_eq!(buf[(0, 0)].bg, expected[0]);
+        assert_eq!(buf[(1, 0)].bg, expected[1]);
+    }
+
+    #[test]
+    fn equal_values_use_middle_of_ramp() {
+        let buf = render(&heatmap([[3.0, 3.0]]), 2, 1);
+        assert_eq!(buf[(0, 0)].bg, GRAY);
+        assert_eq!(buf[(1, 0)].bg, GRAY);
+    }
+
+    #[test]
+    fn nan_and_missing_values_are_not_displayed() {
+        let heatmap = Heatmap::new(vec![vec![0.0, f64::NAN], vec![1.0]])
+            .gradient([(0.0, BLACK), (1.0, WHITE)]);
+        let buf = render(&heatmap, 2, 2);
+        let mut expected = Buffer::empty(buf.area);
+        expected.set_style(Rect::new(0, 0, 1, 1), Style::new().bg(BLACK));
+        expected.set_style(Rect::new(0, 1, 1, 1), Style::new().bg(WHITE));
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn labels() {
+        let heatmap = heatmap([[0.0, 1.0], [1.0, 0.0]])
+            .row_labels(["a", "bb"])
+            .column_labels(["x", "yyyyy"]);
+        let buf = render(&heatmap, 9, 5);
+        let mut expected = Buffer::with_lines([
+            "    x yyy",
+            "         ",
+            "a        ",
+            "         ",
+            "bb       ",
+        ]);
+        expected.set_style(Rect::new(3, 1, 3, 2), Style::new().bg(BLACK));
+        expected.set_style(Rect::new(6, 1, 3, 2), Style::new().bg(WHITE));
+        expected.set_style(Rect::new(3, 3, 3, 2), Style::new().bg(WHITE));
+        expected.set_style(Rect::new(6, 3, 3, 2), Style::new().bg(BLACK));
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn show_values() {
+        let heatmap = heatmap([[0.0, 1.0, 0.5]]).show_values(true).precision(1);
+        let buf = render(&heatmap, 9, 3);
+        let mut expected = Buffer::with_lines(["         ", "0.01.00.5", "         "]);
+        expected.set_style(Rect::new(0, 0, 3, 3), Style::new().bg(BLACK));
+        expected.set_style(Rect::new(3, 0, 3, 3), Style::new().bg(WHITE));
+        expected.set_style(Rect::new(6, 0, 3, 3), Style::new().bg(GRAY));
+        expected.set_style(Rect::new(0, 1, 3, 1), Style::new().fg(Color::White));
+        expected.set_style(Rect::new(3, 1, 6, 1), Style::new().fg(Color::Black));
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn values_are_hidden_when_too_wide() {
+        let heatmap = heatmap([[0.0, 1.0]]).show_values(true);
+        let buf = render(&heatmap, 6, 1);
+        assert_eq!(buf[(0, 0)].symbol(), " ");
+        assert_eq!(buf[(1, 0)].symbol(), " ");
+        assert_eq!(buf[(2, 0)].symbol(), " ");
+    }
+
+    #[test]
+    fn legend() {
+        let heatmap = heatmap([[0.0], [2.0], [4.0]]).legend(true).precision(0);
+        let buf = render(&heatmap, 6, 3);
+        let mut expected = Buffer::with_lines(["     4", "      ", "     0"]);
+        expected.set_style(Rect::new(0, 0, 1, 1), Style::new().bg(BLACK));
+        expected.set_style(Rect::new(0, 1, 1, 1), Style::new().bg(GRAY));
+        expected.set_style(Rect::new(0, 2, 1, 1), Style::new().bg(WHITE));
+        expected.set_style(Rect::new(2, 0, 2, 1), Style::new().bg(WHITE));
+        expected.set_style(Rect::new(2, 1, 2, 1), Style::new().bg(GRAY));
+        expected.set_style(Rect::new(2, 2, 2, 1), Style::new().bg(BLACK));
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn block() {
+        let heatmap = heatmap([[0.0, 1.0]]).block(Block::bordered());
+        let buf = render(&heatmap, 4, 3);
+        let mut expected = Buffer::with_lines(["┌──┐", "│  │", "└──┘"]);
+        expected.set_style(Rect::new(1, 1, 1, 1), Style::new().bg(BLACK));
+        expected.set_style(Rect::new(2, 1, 1, 1), Style::new().bg(WHITE));
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn empty() {
+        let buf = render(&Heatmap::default(), 4, 2);
+        assert_eq!(buf, Buffer::empty(buf.area));
+        let buf = render(&heatmap([[1.0]]), 0, 0);
+        assert_eq!(buf, Buffer::empty(buf.area));
+    }
+
+    #[test]
+    fn can_be_stylized() {
+        assert_eq!(
+            Heatmap::default().red().on_white().style,
+            Style::new().red().on_white()
+        );
+    }
+}
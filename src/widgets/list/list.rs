@@ -1,3 +1,9 @@
+use std::{
+    fmt,
+    hash::{Hash, Hasher},
+    sync::Arc,
+};
+
 use strum::{Display, EnumString};
 
 use super::ListItem;
@@ -13,7 +19,8 @@ use crate::{
 ///
 /// This is different from a [`Table`] because it does not handle columns, headers or footers and
 /// the item's height is automatically determined. A `List` can also be put in reverse order (i.e.
-/// *bottom to top*) whereas a [`Table`] cannot.
+/// *bottom to top*) whereas a [`Table`] cannot. An item that only partially fits at the edge of
+/// the list is clipped rather than hidden.
 ///
 /// [`Table`]: crate::widgets::Table
 ///
@@ -108,6 +115,40 @@ pub struct List<'a> {
     pub(crate) highlight_spacing: HighlightSpacing,
     /// How many items to try to keep visible before and after the selected item
     pub(crate) scroll_padding: usize,
+    /// The line drawn between the items
+    pub(crate) separator: Option<Line<'a>>,
+    /// Style used to render the separator
+    pub(crate) separator_style: Style,
+    /// Function computing an additional style for each item
+    pub(crate) item_style_fn: Option<ItemStyleFn<'a>>,
+}
+
+/// A function computing the style of the items of a [`List`] from their index and whether they
+/// are selected
+///
+/// Functions can't be compared or hashed, so two functions are equal only if they are the same
+/// instance.
+#[derive(Clone)]
+pub(crate) struct ItemStyleFn<'a>(pub(crate) Arc<dyn Fn(usize, bool) -> Style + Send + Sync + 'a>);
+
+impl fmt::Debug for ItemStyleFn<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("ItemStyleFn")
+    }
+}
+
+impl PartialEq for ItemStyleFn<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl Eq for ItemStyleFn<'_> {}
+
+impl Hash for ItemStyleFn<'_> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        Arc::as_ptr(&self.0).cast::<()>().hash(state);
+    }
 }
 
 /// Defines the direction in which the list will be rendered.
@@ -252,6 +293,11 @@ impl<'a> List<'a> {
     ///
     /// By default there are no highlight symbol.
     ///
+    /// The symbol can span several cells (e.g. `"▶ "` or an emoji). When the
+    /// [highlight spacing](List::highlight_spacing) is allocated, the content of every item is
+    /// shifted by the display width of the symbol, and the unselected items are filled with
+    /// blanks instead, so that the content of the items stays aligned.
+    ///
     /// This is a fluent setter method which must be chained or used as it consumes self
     ///
     /// # Examples
@@ -374,6 +420,91 @@ impl<'a> List<'a> {
         self
     }
 
+    /// Sets the line drawn between the items
+    ///
+    /// The separator takes one row between each pair of items, but not before the first item or
+    /// after the last one. It is drawn across the whole width of the list (the highlight symbol
+    /// column included) and truncated when it's wider than the list. Use an empty [`Line`] to
+    /// separate the items with blank rows. Separators are taken into account when the list is
+    /// scrolled to keep the selected item visible.
+    ///
+    /// There is no separator by default.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// # let items = ["Item 1", "Item 2"];
+    /// // a horizontal line, truncated to the width of the list
+    /// let list = List::new(items).separator(Some(Line::from("─".repeat(200))));
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn separator(mut self, separator: Option<Line<'a>>) -> Self {
+        self.separator = separator;
+        self
+    }
+
+    /// Sets the style of the separator drawn between the items
+    ///
+    /// The style is applied to the whole separator row, on top of the style of the list. The
+    /// style of the separator [`Line`] itself has priority over this style.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// # let items = ["Item 1", "Item 2"];
+    /// let list = List::new(items)
+    ///     .separator(Some(Line::from("----------")))
+    ///     .separator_style(Style::new().dark_gray());
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn separator_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.separator_style = style.into();
+        self
+    }
+
+    /// Sets a function computing a style for each item when the list is rendered
+    ///
+    /// The function is called with the index of the item in the list and whether the item is
+    /// selected, and the style it returns is applied on top of the style of the item. The
+    /// [highlight style](List::highlight_style) is still applied on top of it for the selected
+    /// item. This makes it possible to style the items depending on the selection, without
+    /// rebuilding the [`ListItem`]s on each frame.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Example
+    ///
+    /// Dim all the items except the selected one.
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// # let items = ["Item 1", "Item 2"];
+    /// let list = List::new(items).item_style_fn(|_index, selected| {
+    ///     if selected {
+    ///         Style::new()
+    ///     } else {
+    ///         Style::new().dim()
+    ///     }
+    /// });
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn item_style_fn<F>(mut self, style_fn: F) -> Self
+    where
+        F: Fn(usize, bool) -> Style + Send + Sync + 'a,
+    {
+        self.item_style_fn = Some(ItemStyleFn(Arc::new(style_fn)));
+        self
+    }
+
     /// Returns the number of [`ListItem`]s in the list
     pub fn len(&self) -> usize {
         self.items.len()
@@ -1,14 +1,22 @@
+use std::borrow::Cow;
+
 use unicode_width::UnicodeWidthStr;
 
+use super::list::ItemStyleFn;
 use crate::{
+    accessibility::{AccessNode, Role},
     prelude::{Buffer, Rect, StatefulWidget, StatefulWidgetRef, Widget, WidgetRef},
-    widgets::{block::BlockExt, List, ListDirection, ListState},
+    widgets::{block::BlockExt, List, ListDirection, ListItem, ListState},
 };
 
 impl Widget for List<'_> {
     fn render(self, area: Rect, buf: &mut Buffer) {
         WidgetRef::render_ref(&self, area, buf);
     }
+
+    fn accessibility_node(&self, area: Rect) -> Option<AccessNode> {
+        Some(self.access_node(area, &ListState::default()))
+    }
 }
 
 impl WidgetRef for List<'_> {
@@ -16,6 +24,10 @@ impl WidgetRef for List<'_> {
         let mut state = ListState::default();
         StatefulWidgetRef::render_ref(self, area, buf, &mut state);
     }
+
+    fn accessibility_node(&self, area: Rect) -> Option<AccessNode> {
+        Some(self.access_node(area, &ListState::default()))
+    }
 }
 
 impl StatefulWidget for List<'_> {
@@ -24,6 +36,10 @@ impl StatefulWidget for List<'_> {
     fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
         StatefulWidgetRef::render_ref(&self, area, buf, state);
     }
+
+    fn accessibility_node(&self, area: Rect, state: &Self::State) -> Option<AccessNode> {
+        Some(self.access_node(area, state))
+    }
 }
 
 // Note: remove this when StatefulWidgetRef is stabilized and replace with the blanket impl
@@ -32,6 +48,10 @@ impl StatefulWidget for &List<'_> {
     fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
         StatefulWidgetRef::render_ref(self, area, buf, state);
     }
+
+    fn accessibility_node(&self, area: Rect, state: &Self::State) -> Option<AccessNode> {
+        Some(self.access_node(area, state))
+    }
 }
 
 impl StatefulWidgetRef for List<'_> {
@@ -51,70 +71,74 @@ impl StatefulWidgetRef for List<'_> {
             return;
         }
 
-        // If the selected index is out of bounds, set it to the last item
-        if state.selected.is_some_and(|s| s >= self.items.len()) {
-            state.select(Some(self.items.len().saturating_sub(1)));
-        }
-
-        let list_height = list_area.height as usize;
-
-        let (first_visible_index, last_visible_index) =
-            self.get_items_bounds(state.selected, state.offset, list_height);
-
         // Important: this changes the state's offset to be the beginning of the now viewable items
-        state.offset = first_visible_index;
+        self.scroll_to_selected(state, list_area.height);
 
-        // Get our set highlighted symbol (if one was set)
+        // Get our set highlighted symbol (if one was set). The items are shifted by the display
+        // width of the symbol, and the unselected items are filled with blanks of the same width,
+        // so that their content stays aligned.
         let highlight_symbol = self.highlight_symbol.unwrap_or("");
-        let blank_symbol = " ".repeat(highlight_symbol.width());
+        let highlight_symbol_width = highlight_symbol.width();
+        let blank_symbol = " ".repeat(highlight_symbol_width);
 
-        let mut current_height = 0;
         let selection_spacing = self.highlight_spacing.should_add(state.selected.is_some());
-        for (i, item) in self
-            .items
-            .iter()
-            .enumerate()
-            .skip(state.offset)
-            .take(last_visible_index - first_visible_index)
-        {
-            let (x, y) = if self.direction == ListDirection::BottomToTop {
-                current_height += item.height() as u16;
-                (list_area.left(), list_area.bottom() - current_height)
-            } else {
-                let pos = (list_area.left(), list_area.top() + current_height);
-                current_height += item.height() as u16;
-                pos
+        for row in self.rows(list_area, state.offset) {
+            let (i, item, row_area) = match row {
+                ListRow::Separator(separator_area) => {
+                    if let Some(separator) = &self.separator {
+                        buf.set_style(separator_area, self.style.patch(self.separator_style));
+                        separator.render_ref(separator_area, buf);
+                    }
+                    continue;
+                }
+                ListRow::Item(i, item, row_area) => (i, item, row_area),
             };
-
-            let row_area = Rect {
+            let Rect {
                 x,
                 y,
-                width: list_area.width,
-                height: item.height() as u16,
-            };
+                height: item_height,
+                ..
+            } = row_area;
 
-            let item_style = self.style.patch(item.style);
-            buf.set_style(row_area, item_style);
+            // when the list is drawn from the bottom, the clipped lines are the first ones
+            let skipped_lines = if self.direction == ListDirection::BottomToTop {
+                item.height() - item_height as usize
+            } else {
+                0
+            };
+            let content = if skipped_lines > 0 {
+                let mut content = item.content.clone();
+                content.lines.drain(..skipped_lines);
+                Cow::Owned(content)
+            } else {
+                Cow::Borrowed(&item.content)
+            };
 
             let is_selected = state.selected.map_or(false, |s| s == i);
+            let mut item_style = self.style.patch(item.style);
+            if let Some(ItemStyleFn(style_fn)) = &self.item_style_fn {
+                item_style = item_style.patch(style_fn(i, is_selected));
+            }
+            buf.set_style(row_area, item_style);
 
             let item_area = if selection_spacing {
-                let highlight_symbol_width = self.highlight_symbol.unwrap_or("").width() as u16;
+                let gutter_width = highlight_symbol_width.min(usize::from(row_area.width)) as u16;
                 Rect {
-                    x: row_area.x + highlight_symbol_width,
-                    width: row_area.width.saturating_sub(highlight_symbol_width),
+                    x: row_area.x + gutter_width,
+                    width: row_area.width - gutter_width,
                     ..row_area
                 }
             } else {
                 row_area
             };
-            item.content.render_ref(item_area, buf);
+            content.render_ref(item_area, buf);
 
-            for j in 0..item.content.height() {
+            for j in 0..item_height as usize {
                 // if the item is selected, we need to display the highlight symbol:
                 // - either for the first line of the item only,
                 // - or for each line of the item if the appropriate option is set
-                let symbol = if is_selected && (j == 0 || self.repeat_highlight_symbol) {
+                let line = skipped_lines + j;
+                let symbol = if is_selected && (line == 0 || self.repeat_highlight_symbol) {
                     highlight_symbol
                 } else {
                     &blank_symbol
@@ -135,9 +159,125 @@ impl StatefulWidgetRef for List<'_> {
             }
         }
     }
+
+    fn accessibility_node(&self, area: Rect, state: &Self::State) -> Option<AccessNode> {
+        Some(self.access_node(area, state))
+    }
+}
+
+/// A row of a list, as laid out by `List::rows`
+enum ListRow<'l, 'a> {
+    /// The separator between two items
+    Separator(Rect),
+    /// An item, with its index and its area
+    Item(usize, &'l ListItem<'a>, Rect),
 }
 
 impl List<'_> {
+    /// Describes the list as a [`Role::List`] node with a [`Role::ListItem`] child for each item
+    /// which is displayed when the list is rendered with the given state.
+    fn access_node(&self, area: Rect, state: &ListState) -> AccessNode {
+        let node = AccessNode::new(Role::List, "", area);
+        let list_area = self.block.inner_if_some(area);
+        if list_area.is_empty() || self.items.is_empty() {
+            return node;
+        }
+        let mut state = state.clone();
+        self.scroll_to_selected(&mut state, list_area.height);
+
+        let children: Vec<_> = self
+            .rows(list_area, state.offset)
+            .filter_map(|row| match row {
+                ListRow::Separator(_) => None,
+                ListRow::Item(i, item, item_area) => Some(
+                    AccessNode::new(Role::ListItem, item.content.to_string(), item_area)
+                        .selected(state.selected == Some(i)),
+                ),
+            })
+            .collect();
+        node.children(children)
+    }
+
+    /// Lays out the rows displayed in the list area, starting with the item at `offset`.
+    ///
+    /// A separator row is placed between consecutive items when the list has a separator. The
+    /// rows stop at the edge of the list area, and an item that doesn't fit in the remaining
+    /// space is clipped to it.
+    fn rows(&self, list_area: Rect, offset: usize) -> impl Iterator<Item = ListRow<'_, '_>> {
+        let mut items = self.items.iter().enumerate().skip(offset).peekable();
+        let mut current_height = 0;
+        let mut separated = false;
+        std::iter::from_fn(move || {
+            if current_height >= list_area.height {
+                return None;
+            }
+            let &(i, item) = items.peek()?;
+            let bottom_to_top = self.direction == ListDirection::BottomToTop;
+            if self.separator.is_some() && i > offset && !separated {
+                let y = if bottom_to_top {
+                    list_area.bottom() - current_height - 1
+                } else {
+                    list_area.top() + current_height
+                };
+                current_height += 1;
+                separated = true;
+                return Some(ListRow::Separator(Rect::new(
+                    list_area.x,
+                    y,
+                    list_area.width,
+                    1,
+                )));
+            }
+            items.next();
+            separated = false;
+            let item_height = (item.height() as u16).min(list_area.height - current_height);
+            let y = if bottom_to_top {
+                list_area.bottom() - current_height - item_height
+            } else {
+                list_area.top() + current_height
+            };
+            current_height += item_height;
+            let item_area = Rect::new(list_area.x, y, list_area.width, item_height);
+            Some(ListRow::Item(i, item, item_area))
+        })
+    }
+
+    /// Scrolls the list so that the selected item is visible in a list area of the given height.
+    ///
+    /// The offset of the `state` is changed by the minimum amount that makes the selected item
+    /// fully visible, honoring the [scroll padding](List::scroll_padding). If the selected item
+    /// is taller than the area, the list is scrolled so that the item starts at the top of the
+    /// area. If nothing is selected, the offset is only clamped to the number of items.
+    ///
+    /// This is done automatically when the list is rendered, but can be useful to know the
+    /// offset beforehand, e.g. to render a scrollbar next to the list.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui::widgets::{List, ListState};
+    ///
+    /// let list = List::new(["Item 0", "Item 1", "Item 2", "Item 3"]);
+    /// let mut state = ListState::default().with_selected(Some(3));
+    /// list.scroll_to_selected(&mut state, 2);
+    /// assert_eq!(state.offset(), 2);
+    /// ```
+    pub fn scroll_to_selected(&self, state: &mut ListState, height: u16) {
+        if self.items.is_empty() {
+            state.offset = 0;
+            return;
+        }
+
+        // If the selected index is out of bounds, set it to the last item
+        if state.selected.is_some_and(|s| s >= self.items.len()) {
+            state.select(Some(self.items.len().saturating_sub(1)));
+        }
+
+        let (first_visible_index, _) =
+            self.get_items_bounds(state.selected, state.offset, height as usize);
+        state.offset = first_visible_index;
+    }
+
     /// Given an offset, calculate which items can fit in a given area
     fn get_items_bounds(
         &self,
@@ -156,12 +296,12 @@ impl List<'_> {
 
         // Calculate the last visible index and total height of the items
         // that will fit in the available space
-        for item in self.items.iter().skip(offset) {
-            if height_from_offset + item.height() > max_height {
+        for index in offset..self.items.len() {
+            if height_from_offset + self.item_height(index) > max_height {
                 break;
             }
 
-            height_from_offset += item.height();
+            height_from_offset += self.item_height(index);
 
             last_visible_index += 1;
         }
@@ -184,15 +324,16 @@ impl List<'_> {
         // the offset is still set), we still need to show this item
         while index_to_display >= last_visible_index {
             height_from_offset =
-                height_from_offset.saturating_add(self.items[last_visible_index].height());
+                height_from_offset.saturating_add(self.item_height(last_visible_index));
 
             last_visible_index += 1;
 
             // Now we need to hide previous items since we didn't have space
-            // for the selected/offset item
-            while height_from_offset > max_height {
+            // for the selected/offset item, but never the item itself, which is
+            // pinned to the top when it's taller than the area
+            while height_from_offset > max_height && first_visible_index < index_to_display {
                 height_from_offset =
-                    height_from_offset.saturating_sub(self.items[first_visible_index].height());
+                    height_from_offset.saturating_sub(self.item_height(first_visible_index));
 
                 // Remove this item to view by starting at the next item index
                 first_visible_index += 1;
@@ -205,20 +346,29 @@ impl List<'_> {
             first_visible_index -= 1;
 
             height_from_offset =
-                height_from_offset.saturating_add(self.items[first_visible_index].height());
+                height_from_offset.saturating_add(self.item_height(first_visible_index));
 
             // Don't show an item if it is beyond our viewable height
-            while height_from_offset > max_height {
+            while height_from_offset > max_height && last_visible_index > first_visible_index + 1 {
                 last_visible_index -= 1;
 
                 height_from_offset =
-                    height_from_offset.saturating_sub(self.items[last_visible_index].height());
+                    height_from_offset.saturating_sub(self.item_height(last_visible_index));
             }
         }
 
         (first_visible_index, last_visible_index)
     }
 
+    /// Returns the height of the item at the given index, including the separator that follows it
+    ///
+    /// Counting the separator after each item rather than before it keeps the height of an item
+    /// independent of the offset, at the cost of keeping one row free at the edge of the list.
+    fn item_height(&self, index: usize) -> usize {
+        let has_separator = self.separator.is_some() && index + 1 < self.items.len();
+        self.items[index].height() + usize::from(has_separator)
+    }
+
     /// Applies scroll padding to the selected index, reducing the padding value to keep the
     /// selected item on screen even with items of inconsistent sizes
     ///
@@ -245,7 +395,7 @@ impl List<'_> {
                     .saturating_add(scroll_padding)
                     .min(last_valid_index)
             {
-                height_around_selected += self.items[index].height();
+                height_around_selected += self.item_height(index);
             }
             if height_around_selected <= max_height {
                 break;
@@ -785,6 +935,48 @@ mod tests {
         }
     }
 
+    #[rstest]
+    #[case::arrow_always("▶ ", HighlightSpacing::Always, ["  Item 0", "▶ Item 1", "  Item 2"])]
+    #[case::arrow_when_selected(
+        "▶ ",
+        HighlightSpacing::WhenSelected,
+        ["  Item 0", "▶ Item 1", "  Item 2"]
+    )]
+    #[case::arrow_never("▶ ", HighlightSpacing::Never, ["Item 0  ", "Item 1  ", "Item 2  "])]
+    #[case::emoji_always("🦀 ", HighlightSpacing::Always, ["   Item 0", "🦀 Item 1", "   Item 2"])]
+    #[case::emoji_when_selected(
+        "🦀",
+        HighlightSpacing::WhenSelected,
+        ["  Item 0", "🦀Item 1", "  Item 2"]
+    )]
+    #[case::emoji_never("🦀 ", HighlightSpacing::Never, ["Item 0   ", "Item 1   ", "Item 2   "])]
+    fn multi_cell_highlight_symbol<'line, Lines>(
+        #[case] symbol: &str,
+        #[case] spacing: HighlightSpacing,
+        #[case] expected: Lines,
+    ) where
+        Lines: IntoIterator,
+        Lines::Item: Into<Line<'line>>,
+    {
+        let list = List::new(["Item 0", "Item 1", "Item 2"])
+            .highlight_symbol(symbol)
+            .highlight_spacing(spacing);
+        let mut state = ListState::default().with_selected(Some(1));
+        let expected = Buffer::with_lines(expected);
+        let buffer = stateful_widget(list, &mut state, expected.area.width, 3);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn highlight_symbol_wider_than_the_list() {
+        let list = List::new(["Item 0", "Item 1"])
+            .highlight_symbol("🦀🦀 ")
+            .highlight_spacing(HighlightSpacing::Always);
+        let mut state = ListState::default().with_selected(Some(0));
+        let buffer = stateful_widget(list, &mut state, 3, 2);
+        assert_eq!(buffer, Buffer::with_lines(["🦀 ", "   "]));
+    }
+
     #[test]
     fn repeat_highlight_symbol() {
         let list = List::new(["Item 0\nLine 2", "Item 1", "Item 2"])
@@ -840,6 +1032,71 @@ mod tests {
         assert_eq!(buffer, expected);
     }
 
+    #[rstest]
+    #[case::top_to_bottom(ListDirection::TopToBottom, [
+        "Item 0    ",
+        "Line 0.1  ",
+        "Item 1    ",
+    ])]
+    #[case::bottom_to_top(ListDirection::BottomToTop, [
+        "Line 1.1  ",
+        "Item 0    ",
+        "Line 0.1  ",
+    ])]
+    fn clips_partially_visible_multi_line_item<'line, Lines>(
+        #[case] direction: ListDirection,
+        #[case] expected: Lines,
+    ) where
+        Lines: IntoIterator,
+        Lines::Item: Into<Line<'line>>,
+    {
+        let list = List::new(["Item 0\nLine 0.1", "Item 1\nLine 1.1"]).direction(direction);
+        let buffer = widget(list, 10, 3);
+        assert_eq!(buffer, Buffer::with_lines(expected));
+    }
+
+    #[test]
+    fn clips_item_taller_than_list() {
+        let list = List::new(["Item 0\nLine 0.1\nLine 0.2", "Item 1"])
+            .block(Block::bordered())
+            .highlight_symbol(">>")
+            .highlight_style(Style::default().fg(Color::Yellow));
+        let mut state = ListState::default().with_selected(Some(0));
+        let buffer = stateful_widget(list, &mut state, 12, 4);
+        let mut expected = Buffer::with_lines([
+            "┌──────────┐",
+            "│>>Item 0  │",
+            "│  Line 0.1│",
+            "└──────────┘",
+        ]);
+        expected.set_style(Rect::new(1, 1, 10, 2), Style::default().fg(Color::Yellow));
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn pins_selected_item_taller_than_list_to_top() {
+        let list = List::new(["Item 0", "Item 1", "Item 2\nLine 2.1\nLine 2.2", "Item 3"])
+            .highlight_symbol(">>");
+        let mut state = ListState::default().with_selected(Some(2));
+        let buffer = stateful_widget(list, &mut state, 10, 2);
+        assert_eq!(buffer, Buffer::with_lines([">>Item 2  ", "  Line 2.1"]));
+        assert_eq!(state.offset, 2);
+    }
+
+    #[rstest]
+    #[case::already_visible(1, 0, 0)]
+    #[case::below(4, 0, 2)]
+    #[case::above(1, 3, 1)]
+    #[case::nudged_down(3, 1, 1)]
+    fn scroll_to_selected(#[case] selected: usize, #[case] offset: usize, #[case] expected: usize) {
+        let list = List::new(["Item 0", "Item 1", "Item 2", "Item 3", "Item 4"]);
+        let mut state = ListState::default()
+            .with_selected(Some(selected))
+            .with_offset(offset);
+        list.scroll_to_selected(&mut state, 3);
+        assert_eq!(state.offset, expected);
+    }
+
     #[test]
     fn offset_renders_shifted() {
         let list = List::new([
@@ -1267,4 +1524,141 @@ mod tests {
         StatefulWidget::render(list, single_line_buf.area, &mut single_line_buf, &mut state);
         assert_eq!(single_line_buf, Buffer::with_lines([expected]));
     }
+
+    #[rstest]
+    #[case::top_to_bottom(ListDirection::TopToBottom, ["Item 0", "------", "Item 1", "------", "Item 2", "      "])]
+    #[case::bottom_to_top(ListDirection::BottomToTop, ["      ", "Item 2", "------", "Item 1", "------", "Item 0"])]
+    fn separator(#[case] direction: ListDirection, #[case] expected: [&str; 6]) {
+        let list = List::new(["Item 0", "Item 1", "Item 2"])
+            .separator(Some(Line::from("-".repeat(10))))
+            .direction(direction);
+        let buffer = widget(list, 6, 6);
+        assert_eq!(buffer, Buffer::with_lines(expected));
+    }
+
+    #[test]
+    fn separator_style() {
+        let list = List::new(["Item 0", "Item 1"])
+            .separator(Some(Line::from("--")))
+            .separator_style(Style::new().red());
+        let buffer = widget(list, 6, 3);
+        let mut expected = Buffer::with_lines(["Item 0", "--    ", "Item 1"]);
+        expected.set_style(Rect::new(0, 1, 6, 1), Style::new().red());
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn item_style_fn() {
+        let list = List::new(["Item 0", "Item 1", "Item 2"])
+            .highlight_style(Style::new().yellow())
+            .item_style_fn(|index, selected| match (index, selected) {
+                (_, true) => Style::new().bold(),
+                (2, false) => Style::new().red(),
+                _ => Style::new(),
+            });
+        let mut state = ListState::default().with_selected(Some(1));
+        let buffer = stateful_widget(list, &mut state, 10, 3);
+        let expected = Buffer::with_lines([
+            "Item 0    ".into(),
+            "Item 1    ".yellow().bold(),
+            "Item 2    ".red(),
+        ]);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn item_style_fn_is_layered_over_the_item_style() {
+        let list = List::new([ListItem::new("Item 0").style(Style::new().red().on_green())])
+            .item_style_fn(|_, _| Style::new().blue());
+        let buffer = widget(list, 6, 1);
+        assert_eq!(buffer, Buffer::with_lines(["Item 0".blue().on_green()]));
+    }
+
+    #[test]
+    fn item_style_fn_receives_the_absolute_index() {
+        let list = List::new(["Item 0", "Item 1", "Item 2", "Item 3"]).item_style_fn(|index, _| {
+            if index % 2 == 0 {
+                Style::new().red()
+            } else {
+                Style::new().blue()
+            }
+        });
+        let mut state = ListState::default().with_offset(1);
+        let buffer = stateful_widget(list, &mut state, 6, 2);
+        assert_eq!(
+            buffer,
+            Buffer::with_lines(["Item 1".blue(), "Item 2".red()])
+        );
+    }
+
+    #[test]
+    fn item_style_fn_equality() {
+        let list = List::new(["Item 0"]).item_style_fn(|_, _| Style::new());
+        assert_eq!(list.clone(), list);
+        assert_ne!(
+            list.clone().item_style_fn(|_, _| Style::new()),
+            list.clone()
+        );
+        assert_ne!(List::new(["Item 0"]), list);
+    }
+
+    #[test]
+    fn blank_separator_between_multiline_items() {
+        let list =
+            List::new([Text::from("A\nB"), Text::from("C")]).separator(Some(Line::default()));
+        let buffer = widget(list, 3, 5);
+        assert_eq!(
+            buffer,
+            Buffer::with_lines(["A  ", "B  ", "   ", "C  ", "   "])
+        );
+    }
+
+    #[rstest]
+    #[case::first(0, ["> Item 0", "------  ", "  Item 1", "------  "])]
+    #[case::scrolled(2, ["  Item 1", "------  ", "> Item 2", "------  "])]
+    #[case::last(3, ["  Item 2", "------  ", "> Item 3", "        "])]
+    fn separator_scrolls_to_selected(#[case] selected: usize, #[case] expected: [&str; 4]) {
+        let list = List::new(["Item 0", "Item 1", "Item 2", "Item 3"])
+            .separator(Some(Line::from("------")))
+            .highlight_symbol("> ");
+        let mut state = ListState::default().with_selected(Some(selected));
+        let buffer = stateful_widget(list, &mut state, 8, 4);
+        assert_eq!(buffer, Buffer::with_lines(expected));
+    }
+
+    #[test]
+    fn accessibility_node() {
+        let list = List::new(["Item 0", "Item 1", "Item 2", "Item 3"]).block(Block::bordered());
+        let area = Rect::new(0, 0, 10, 4);
+        let state = ListState::default().with_selected(Some(3));
+        let node = StatefulWidget::accessibility_node(&list, area, &state).unwrap();
+        assert_eq!(
+            node,
+            AccessNode::new(Role::List, "", area).children([
+                AccessNode::new(Role::ListItem, "Item 2", Rect::new(1, 1, 8, 1)),
+                AccessNode::new(Role::ListItem, "Item 3", Rect::new(1, 2, 8, 1)).selected(true),
+            ])
+        );
+    }
+
+    #[test]
+    fn accessibility_node_with_separator_and_bottom_to_top() {
+        let list = List::new(["a", "b\nc", "d"])
+            .separator(Some(Line::from("-")))
+            .direction(ListDirection::BottomToTop);
+        let area = Rect::new(0, 0, 3, 4);
+        let node = Widget::accessibility_node(&list, area).unwrap();
+        let items: Vec<_> = node
+            .children
+            .iter()
+            .map(|item| (item.text.as_str(), item.area))
+            .collect();
+        assert_eq!(
+            items,
+            [
+                ("a", Rect::new(0, 3, 3, 1)),
+                ("b\nc", Rect::new(0, 0, 3, 2)),
+            ]
+        );
+    }
 }
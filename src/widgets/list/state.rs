@@ -252,11 +252,49 @@ impl ListState {
         let selected = self.selected.unwrap_or_default();
         self.select(Some(selected.saturating_sub(amount as usize)));
     }
+
+    /// Scrolls the list view by `delta` items, in response to a mouse wheel event.
+    ///
+    /// Unlike [`ListState::scroll_down_by`] and [`ListState::scroll_up_by`], this moves the offset
+    /// rather than the selection. A positive `delta` scrolls down and a negative one scrolls up,
+    /// e.g. `1` for `MouseEventKind::ScrollDown` and `-1` for `MouseEventKind::ScrollUp`.
+    ///
+    /// `viewport` is the number of items displayed at once and `total` the number of items of
+    /// the list. The offset is clamped so that the view doesn't scroll past the last item. As the
+    /// list scrolls back to the selected item when it is rendered, the selected item, if any, is
+    /// moved to stay within the view.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let mut state = ListState::default().with_selected(Some(0));
+    /// state.handle_mouse_scroll(3, 5, 20);
+    /// assert_eq!(state.offset(), 3);
+    /// assert_eq!(state.selected(), Some(3));
+    ///
+    /// state.handle_mouse_scroll(100, 5, 20);
+    /// assert_eq!(state.offset(), 15);
+    /// ```
+    pub fn handle_mouse_scroll(&mut self, delta: i32, viewport: usize, total: usize) {
+        let distance = delta.unsigned_abs() as usize;
+        let offset = if delta < 0 {
+            self.offset.saturating_sub(distance)
+        } else {
+            self.offset.saturating_add(distance)
+        };
+        self.offset = offset.min(total.saturating_sub(viewport));
+        if let Some(selected) = self.selected {
+            let last_visible = (self.offset + viewport.max(1) - 1).min(total.saturating_sub(1));
+            self.selected = Some(selected.clamp(self.offset, last_visible.max(self.offset)));
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use pretty_assertions::assert_eq;
+    use rstest::rstest;
 
     use crate::widgets::ListState;
 
@@ -338,4 +376,35 @@ mod tests {
         state.scroll_up_by(4);
         assert_eq!(state.selected, Some(0));
     }
+
+    #[rstest]
+    #[case::down(0, None, 3, 3, None)]
+    #[case::up(5, None, -2, 3, None)]
+    #[case::past_end(10, None, 50, 15, None)]
+    #[case::past_start(2, None, -5, 0, None)]
+    #[case::selection_above_view(0, Some(1), 4, 4, Some(4))]
+    #[case::selection_below_view(10, Some(14), -6, 4, Some(8))]
+    #[case::selection_in_view(0, Some(4), 2, 2, Some(4))]
+    fn handle_mouse_scroll(
+        #[case] offset: usize,
+        #[case] selected: Option<usize>,
+        #[case] delta: i32,
+        #[case] expected_offset: usize,
+        #[case] expected_selected: Option<usize>,
+    ) {
+        let mut state = ListState::default()
+            .with_offset(offset)
+            .with_selected(selected);
+        state.handle_mouse_scroll(delta, 5, 20);
+        assert_eq!(state.offset(), expected_offset);
+        assert_eq!(state.selected(), expected_selected);
+    }
+
+    #[test]
+    fn handle_mouse_scroll_short_list() {
+        let mut state = ListState::default().with_selected(Some(2));
+        state.handle_mouse_scroll(1, 5, 3);
+        assert_eq!(state.offset(), 0);
+        assert_eq!(state.selected(), Some(2));
+    }
 }
@@ -1,12 +1,19 @@
+use std::{
+    cell::Cell,
+    ops::{ControlFlow, Range},
+};
+
+use strum::{Display, EnumString};
 use unicode_width::UnicodeWidthStr;
 
 use crate::{
+    accessibility::{AccessNode, Role},
     prelude::*,
     style::Styled,
     text::StyledGrapheme,
     widgets::{
-        reflow::{LineComposer, LineTruncator, WordWrapper, WrappedLine},
-        Block,
+        reflow::{self, LineComposer, LineEllipsizer, LineTruncator, WordWrapper, WrappedLine},
+        Block, StatefulWidgetRef,
     },
 };
 
@@ -18,6 +25,71 @@ const fn get_line_offset(line_width: u16, text_area_width: u16, alignment: Align
     }
 }
 
+/// The strong direction of a grapheme, used to reorder right-to-left lines
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum GraphemeDirection {
+    LeftToRight,
+    RightToLeft,
+    Neutral,
+}
+
+impl GraphemeDirection {
+    fn of(symbol: &str) -> Self {
+        let Some(c) = symbol.chars().next() else {
+            return Self::Neutral;
+        };
+        // Hebrew, Arabic, Syriac, Thaana, NKo, Samaritan, Mandaic and their presentation forms, and
+        // the right-to-left scripts of the supplementary planes
+        let is_rtl = matches!(
+            c,
+            '\u{0590}'..='\u{08FF}'
+                | '\u{FB1D}'..='\u{FDFF}'
+                | '\u{FE70}'..='\u{FEFF}'
+                | '\u{10800}'..='\u{10FFF}'
+                | '\u{1E800}'..='\u{1EFFF}'
+        );
+        if is_rtl {
+            Self::RightToLeft
+        } else if c.is_alphanumeric() {
+            Self::LeftToRight
+        } else {
+            Self::Neutral
+        }
+    }
+}
+
+/// Returns the positions of the graphemes of a right-to-left line in visual order.
+///
+/// This is a simplification of the Unicode bidirectional algorithm: the line is reversed, except
+/// for runs of left-to-right text (e.g. latin words or numbers) which keep their order. Neutral
+/// graphemes (spaces and punctuation) between two left-to-right graphemes belong to the run.
+fn right_to_left_visual_order(line: &[StyledGrapheme<'_>]) -> Vec<usize> {
+    let directions: Vec<_> = line
+        .iter()
+        .map(|grapheme| GraphemeDirection::of(grapheme.symbol))
+        .collect();
+    let mut visual = Vec::with_capacity(line.len());
+    let mut end = line.len();
+    while end > 0 {
+        let last = end - 1;
+        let mut start = last;
+        if directions[last] == GraphemeDirection::LeftToRight {
+            // extend the run backwards up to the first left-to-right grapheme that isn't separated
+            // from it by a right-to-left grapheme
+            for (i, direction) in directions[..last].iter().enumerate().rev() {
+                match direction {
+                    GraphemeDirection::RightToLeft => break,
+                    GraphemeDirection::LeftToRight => start = i,
+                    GraphemeDirection::Neutral => {}
+                }
+            }
+        }
+        visual.extend(start..end);
+        end = start;
+    }
+    visual
+}
+
 /// A widget to display some text.
 ///
 /// It is used to display a block of text. The text can be styled and aligned. It can also be
@@ -34,7 +106,8 @@ const fn get_line_offset(line_width: u16, text_area_width: u16, alignment: Align
 /// [`alignment`] method or with the [`left_aligned`], [`right_aligned`], and [`centered`] methods.
 ///
 /// The text can be scrolled to show a specific part of the text. The scroll offset can be set with
-/// the [`scroll`] method.
+/// the [`scroll`] method, or kept in a [`ParagraphState`] which clamps it to the text when a
+/// reference to the paragraph is rendered as a [`StatefulWidget`].
 ///
 /// The text can be surrounded by a [`Block`] with a title and borders. The block can be configured
 /// with the [`block`] method.
@@ -76,7 +149,7 @@ const fn get_line_offset(line_width: u16, text_area_width: u16, alignment: Align
 ///     .alignment(Alignment::Center)
 ///     .wrap(Wrap { trim: true });
 /// ```
-#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
 pub struct Paragraph<'a> {
     /// A block to wrap the widget in
     block: Option<Block<'a>>,
@@ -84,12 +157,53 @@ pub struct Paragraph<'a> {
     style: Style,
     /// How to wrap the text
     wrap: Option<Wrap>,
+    /// How to truncate lines that are too long when the text is not wrapped
+    truncate: Option<Truncate>,
     /// The text to display
     text: Text<'a>,
     /// Scroll
     scroll: Position,
     /// Alignment of the text
     alignment: Alignment,
+    /// Direction of the text
+    direction: TextDirection,
+    /// Whether the background of each line fills the whole width of the paragraph
+    fill_line_background: bool,
+    /// The number of columns between two tab stops
+    tab_size: u16,
+    /// The characters at which words may be broken when wrapping
+    break_on: BreakSet,
+    /// The range of characters of the text to restyle, and the style to patch them with
+    selection: Option<(Range<usize>, Style)>,
+}
+
+impl Default for Paragraph<'_> {
+    fn default() -> Self {
+        Self::new(Text::default())
+    }
+}
+
+/// The direction in which the text of a [`Paragraph`] is written.
+///
+/// See [`Paragraph::direction`].
+#[derive(Debug, Default, Display, EnumString, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum TextDirection {
+    /// The text is written from left to right. This is the default.
+    #[default]
+    LeftToRight,
+    /// The text is written from right to left, e.g. Arabic or Hebrew text.
+    RightToLeft,
+}
+
+impl TextDirection {
+    /// Returns the alignment relative to this direction, i.e. `Left` is the start of the line.
+    const fn align(self, alignment: Alignment) -> Alignment {
+        match (self, alignment) {
+            (Self::RightToLeft, Alignment::Left) => Alignment::Right,
+            (Self::RightToLeft, Alignment::Right) => Alignment::Left,
+            (_, alignment) => alignment,
+        }
+    }
 }
 
 /// Describes how to wrap text across lines.
@@ -127,6 +241,182 @@ pub struct Wrap {
     pub trim: bool,
 }
 
+/// The characters, other than whitespace, at which a [`Paragraph`] may break a line when wrapping
+///
+/// A line may be broken after any character of the set, so that the character stays at the end of
+/// the first line, e.g. a path wraps after its slashes and a compound word after its hyphens. The
+/// line is not broken between consecutive break characters or before whitespace.
+///
+/// Soft hyphens (U+00AD) mark the places where a word may be hyphenated. When they are enabled
+/// with [`BreakSet::soft_hyphens`], a soft hyphen at the end of a wrapped line is displayed as a
+/// hyphen, and the other soft hyphens are not displayed. The wrapping keeps room for the hyphen
+/// at each soft hyphen.
+///
+/// The default set is empty: lines are only broken at whitespace.
+///
+/// See [`Paragraph::break_on`].
+///
+/// # Example
+///
+/// ```
+/// use ratatui::{prelude::*, widgets::*};
+///
+/// let paragraph = Paragraph::new("/usr/local/share/doc")
+///     .wrap(Wrap { trim: true })
+///     .break_on(BreakSet::new(['/', '-', '_']));
+/// ```
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub struct BreakSet {
+    /// The characters after which a line may be broken
+    chars: Vec<char>,
+    /// Whether soft hyphens are break opportunities displayed as hyphens at the end of lines
+    soft_hyphens: bool,
+}
+
+impl BreakSet {
+    /// The empty set, which only breaks lines at whitespace
+    pub(crate) const WHITESPACE: Self = Self {
+        chars: Vec::new(),
+        soft_hyphens: false,
+    };
+
+    /// Creates a set of the given break characters
+    pub fn new<T: IntoIterator<Item = char>>(chars: T) -> Self {
+        Self {
+            chars: chars.into_iter().collect(),
+            soft_hyphens: false,
+        }
+    }
+
+    /// Sets whether lines may be broken at soft hyphens (U+00AD)
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn soft_hyphens(mut self, soft_hyphens: bool) -> Self {
+        self.soft_hyphens = soft_hyphens;
+        self
+    }
+
+    /// Returns whether a line may be broken after the given grapheme
+    pub(crate) fn contains(&self, symbol: &str) -> bool {
+        let mut chars = symbol.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => {
+                self.chars.contains(&c) || (self.soft_hyphens && c == reflow::SOFT_HYPHEN)
+            }
+            _ => false,
+        }
+    }
+
+    /// Returns whether soft hyphens are displayed as hyphens at the end of lines
+    pub(crate) const fn has_soft_hyphens(&self) -> bool {
+        self.soft_hyphens
+    }
+}
+
+/// Describes how to truncate lines that are too long to fit on a single line.
+///
+/// Lines that are too long are cut so that the `ellipsis` fits at their end. The [`WordBoundary`]
+/// controls where lines may be cut. Whitespace before the ellipsis is removed, and wide characters
+/// are never cut in half.
+///
+/// ## Examples
+///
+/// ```
+/// use ratatui::{prelude::*, widgets::*};
+///
+/// let text = "The quick brown fox jumps over the lazy dog";
+///
+/// // With a window width of 20 chars:
+/// Paragraph::new(text).truncate(Truncate::default());
+/// // The quick brown fox…
+///
+/// Paragraph::new(text).truncate(Truncate {
+///     ellipsis: '>',
+///     boundary: WordBoundary::Grapheme,
+/// });
+/// // The quick brown fox>
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct Truncate {
+    /// The character appended to truncated lines
+    pub ellipsis: char,
+    /// Where truncated lines may be cut
+    pub boundary: WordBoundary,
+}
+
+impl Default for Truncate {
+    /// Truncates lines at word boundaries with a `…` ellipsis.
+    fn default() -> Self {
+        Self {
+            ellipsis: '…',
+            boundary: WordBoundary::Word,
+        }
+    }
+}
+
+/// Where a line truncated by a [`Paragraph`] may be cut.
+///
+/// When a line cannot be cut at the requested boundary, e.g. because its first word is too long to
+/// fit, it is cut at the next finer boundary.
+///
+/// See [`Truncate`].
+#[derive(Debug, Default, Display, EnumString, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum WordBoundary {
+    /// Cut the line after the last character that fits.
+    Grapheme,
+    /// Cut the line at the end of the last word that fits. This is the default.
+    #[default]
+    Word,
+    /// Cut the line at the end of the last sentence that fits, i.e. after a `.`, `!` or `?` that is
+    /// followed by whitespace.
+    Sentence,
+}
+
+/// State of a [`Paragraph`] rendered as a [`StatefulWidget`]
+///
+/// A reference to a paragraph (`&Paragraph`) can be rendered as a stateful widget, as the owned
+/// paragraph is already a [`Widget`]. The state holds the scroll offset of the paragraph, which
+/// replaces the offset set with [`Paragraph::scroll`]. Like [`Paragraph::scroll`], the offset is a
+/// `(y, x)` tuple of the number of rows and columns to scroll.
+///
+/// The offset can be freely changed, e.g. by key handlers, as it is clamped when the paragraph is
+/// rendered so that the text doesn't scroll past its end. The clamped offset is stored back in the
+/// state. The horizontal offset is clamped to 0 when the text is [wrapped](Paragraph::wrap) or
+/// [truncated](Paragraph::truncate), as it only applies to text which is cut at the edge of the
+/// area.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::{prelude::*, widgets::*};
+///
+/// # fn ui(frame: &mut Frame) {
+/// # let area = Rect::default();
+/// // This should be stored outside of the function in your application state.
+/// let mut state = ParagraphState::default();
+///
+/// // scroll down by a row, e.g. on a key press
+/// state.offset.0 += 1;
+///
+/// let paragraph = Paragraph::new("Hello\nWorld").wrap(Wrap { trim: true });
+/// frame.render_stateful_widget(&paragraph, area, &mut state);
+/// # }
+/// ```
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ParagraphState {
+    /// The `(y, x)` scroll offset, clamped to the content when the paragraph is rendered
+    pub offset: (Vertical, Horizontal),
+}
+
+impl ParagraphState {
+    /// Creates a new `ParagraphState` with the given `(y, x)` scroll offset
+    pub const fn new(offset: (Vertical, Horizontal)) -> Self {
+        Self { offset }
+    }
+}
+
 type Horizontal = u16;
 type Vertical = u16;
 
@@ -154,9 +444,15 @@ impl<'a> Paragraph<'a> {
             block: None,
             style: Style::default(),
             wrap: None,
+            truncate: None,
             text: text.into(),
             scroll: Position::ORIGIN,
             alignment: Alignment::Left,
+            direction: TextDirection::LeftToRight,
+            fill_line_background: false,
+            tab_size: reflow::DEFAULT_TAB_SIZE,
+            break_on: BreakSet::default(),
+            selection: None,
         }
     }
 
@@ -194,6 +490,75 @@ impl<'a> Paragraph<'a> {
         self
     }
 
+    /// Highlights all the occurrences of `pattern` in the text of the paragraph.
+    ///
+    /// See [`Text::highlight`] for more details.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let paragraph = Paragraph::new("Hello, world!").highlight("world", Color::Yellow);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn highlight<S: Into<Style>>(mut self, pattern: &str, style: S) -> Self {
+        self.text = self.text.highlight(pattern, style);
+        self
+    }
+
+    /// Sets the characters, other than whitespace, at which lines may be broken when wrapping
+    ///
+    /// This has no effect when the paragraph is not [wrapped](Paragraph::wrap). See [`BreakSet`]
+    /// for more details.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let paragraph = Paragraph::new("a-long-hyphenated-name")
+    ///     .wrap(Wrap { trim: true })
+    ///     .break_on(BreakSet::new(['-']));
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn break_on(mut self, break_on: BreakSet) -> Self {
+        self.break_on = break_on;
+        self
+    }
+
+    /// Sets a range of the text which is displayed as selected
+    ///
+    /// The `range` is expressed in characters of the text (not bytes), where each line break
+    /// between two lines counts as one character, i.e. it is a range of the characters of the
+    /// text's [`to_string`](ToString::to_string). The graphemes which start in the range are
+    /// patched with `style` when rendered, wherever wrapping and scrolling place them, and the
+    /// parts of the range which are not visible are ignored.
+    ///
+    /// This is useful to display a selection made with the mouse, e.g. to copy the selected text.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// // selects "world\nHello"
+    /// let paragraph = Paragraph::new("Hello world\nHello universe")
+    ///     .wrap(Wrap { trim: true })
+    ///     .selection(6..17, Style::new().reversed());
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn selection<S: Into<Style>>(mut self, range: Range<usize>, style: S) -> Self {
+        self.selection = Some((range, style.into()));
+        self
+    }
+
     /// Sets the wrapping configuration for the widget.
     ///
     /// See [`Wrap`] for more information on the different options.
@@ -210,6 +575,27 @@ impl<'a> Paragraph<'a> {
         self
     }
 
+    /// Sets the truncation configuration for the widget.
+    ///
+    /// Lines that are too long to fit are cut at a boundary and end with an ellipsis instead of
+    /// being clipped at the edge of the area. See [`Truncate`] for more information on the
+    /// different options.
+    ///
+    /// Truncation only applies to paragraphs that are not [wrapped](Self::wrap), and it ignores
+    /// the horizontal [scroll](Self::scroll) offset.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let paragraph = Paragraph::new("Hello, world!").truncate(Truncate::default());
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn truncate(mut self, truncate: Truncate) -> Self {
+        self.truncate = Some(truncate);
+        self
+    }
+
     /// Set the scroll offset for the given paragraph
     ///
     /// The scroll offset is a tuple of (y, x) offset. The y offset is the number of lines to
@@ -292,29 +678,97 @@ impl<'a> Paragraph<'a> {
         self.alignment(Alignment::Right)
     }
 
+    /// Set the direction of the text
+    ///
+    /// The default is [`TextDirection::LeftToRight`]. With [`TextDirection::RightToLeft`], the
+    /// text is wrapped (and leading whitespace is trimmed) in its logical order, then each line is
+    /// displayed from right to left. Runs of left-to-right text, such as latin words or numbers,
+    /// keep their order within the line.
+    ///
+    /// The [alignment](Self::alignment) is relative to the direction of the text: in a right to
+    /// left paragraph, [`Alignment::Left`] (the default) aligns the lines to the right, and
+    /// [`Alignment::Right`] aligns them to the left.
+    ///
+    /// Note: this is a simplification of the Unicode bidirectional algorithm, which is not fully
+    /// implemented.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let paragraph = Paragraph::new("שלום עולם").direction(TextDirection::RightToLeft);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn direction(mut self, direction: TextDirection) -> Self {
+        self.direction = direction;
+        self
+    }
+
+    /// Extends the background of each line to the full width of the paragraph
+    ///
+    /// By default, the background color of a [`Line`] only covers the cells of its text. When
+    /// this is enabled, the background color of the line's style is applied to the whole row,
+    /// including the cells before a centered or right aligned line and after the end of the text.
+    /// When the text is [wrapped](Self::wrap), every row of the line is filled. This is useful to
+    /// highlight a selected line.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let text = vec![Line::from("first"), Line::from("selected").on_blue()];
+    /// let paragraph = Paragraph::new(text).fill_line_background(true);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn fill_line_background(mut self, fill: bool) -> Self {
+        self.fill_line_background = fill;
+        self
+    }
+
+    /// Sets the number of columns between two tab stops
+    ///
+    /// Each tab character of the text is replaced by spaces up to the next tab stop when the text
+    /// is laid out. The tab stops are counted from the start of each line of the text, even when the
+    /// line is [wrapped](Self::wrap), so that the columns of the text stay aligned. The spaces are
+    /// treated like any other whitespace when wrapping and trimming, and are taken into account for
+    /// the alignment of the line. A tab size of 0 removes the tabs.
+    ///
+    /// Defaults to 8, which matches the convention of terminals.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let paragraph = Paragraph::new("name\tsize\nfoo.rs\t42").tab_size(4);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn tab_size(mut self, tab_size: u16) -> Self {
+        self.tab_size = tab_size;
+        self
+    }
+
     /// Calculates the number of lines needed to fully render.
     ///
     /// Given a max line width, this method calculates the number of lines that a paragraph will
     /// need in order to be fully rendered. For paragraphs that do not use wrapping, this count is
     /// simply the number of lines present in the paragraph.
     ///
-    /// This method will also account for the [`Block`] if one is set through [`Self::block`].
-    ///
-    /// Note: The design for text wrapping is not stable and might affect this API.
+    /// This method will also account for the [`Block`] if one is set through [`Self::block`]: the
+    /// text is wrapped at the width left inside the block, and the top and bottom of the block are
+    /// added to the count. The result is the same number of lines that rendering the paragraph in
+    /// an area of the given `width` would produce (ignoring any [scroll](Self::scroll)).
     ///
     /// # Example
     ///
-    /// ```ignore
+    /// ```rust
     /// # use ratatui::{prelude::*, widgets::*};
     /// let paragraph = Paragraph::new("Hello World")
     ///     .wrap(Wrap { trim: false });
     /// assert_eq!(paragraph.line_count(20), 1);
     /// assert_eq!(paragraph.line_count(10), 2);
     /// ```
-    #[instability::unstable(
-        feature = "rendered-line-info",
-        issue = "https://github.com/ratatui/ratatui/issues/293"
-    )]
     pub fn line_count(&self, width: u16) -> usize {
         if width < 1 {
             return 0;
@@ -325,27 +779,16 @@ impl<'a> Paragraph<'a> {
             .as_ref()
             .map(Block::vertical_space)
             .unwrap_or_default();
+        let (left, right) = self
+            .block
+            .as_ref()
+            .map(Block::horizontal_space)
+            .unwrap_or_default();
+        let text_width = width.saturating_sub(left).saturating_sub(right);
 
-        let count = if let Some(Wrap { trim }) = self.wrap {
-            let styled = self.text.iter().map(|line| {
-                let graphemes = line
-                    .spans
-                    .iter()
-                    .flat_map(|span| span.styled_graphemes(self.style));
-                let alignment = line.alignment.unwrap_or(self.alignment);
-                (graphemes, alignment)
-            });
-            let mut line_composer = WordWrapper::new(styled, width, trim);
-            let mut count = 0;
-            while line_composer.next_line().is_some() {
-                count += 1;
-            }
-            count
-        } else {
-            self.text.height()
-        };
-
-        count
+        // nothing is rendered inside the block when the text width is 0
+        self.text
+            .line_count_at_width(text_width, self.wrap, self.tab_size, &self.break_on)
             .saturating_add(top as usize)
             .saturating_add(bottom as usize)
     }
@@ -354,11 +797,9 @@ impl<'a> Paragraph<'a> {
     ///
     /// Accounts for the [`Block`] if a block is set through [`Self::block`].
     ///
-    /// Note: The design for text wrapping is not stable and might affect this API.
-    ///
     /// # Example
     ///
-    /// ```ignore
+    /// ```rust
     /// # use ratatui::{prelude::*, widgets::*};
     /// let paragraph = Paragraph::new("Hello World");
     /// assert_eq!(paragraph.line_width(), 11);
@@ -366,27 +807,138 @@ impl<'a> Paragraph<'a> {
     /// let paragraph = Paragraph::new("Hello World\nhi\nHello World!!!");
     /// assert_eq!(paragraph.line_width(), 14);
     /// ```
-    #[instability::unstable(
-        feature = "rendered-line-info",
-        issue = "https://github.com/ratatui/ratatui/issues/293"
-    )]
     pub fn line_width(&self) -> usize {
-        let width = self.text.iter().map(Line::width).max().unwrap_or_default();
         let (left, right) = self
             .block
             .as_ref()
             .map(Block::horizontal_space)
             .unwrap_or_default();
 
-        width
+        self.text_width()
             .saturating_add(left as usize)
             .saturating_add(right as usize)
     }
+
+    /// The width of the widest line of the text
+    fn text_width(&self) -> usize {
+        self.text
+            .iter()
+            .map(|line| {
+                reflow::expand_tabs(line.styled_graphemes(Style::default()), self.tab_size)
+                    .map(|(_, grapheme)| grapheme.symbol.width())
+                    .sum()
+            })
+            .max()
+            .unwrap_or_default()
+    }
+
+    /// Returns the position on the screen of a logical position in the text.
+    ///
+    /// `line` is the index of a line of the text and `column` the index of a grapheme in that line,
+    /// so `column` may be equal to the number of graphemes of the line to point after its end. The
+    /// returned position takes into account the [`Block`], [wrapping](Self::wrap),
+    /// [scrolling](Self::scroll), alignment and direction of the paragraph rendered in `area`.
+    ///
+    /// This is useful to place the cursor with
+    /// [`Frame::set_cursor_position`](crate::Frame::set_cursor_position) when building an input
+    /// field on top of a paragraph. When wrapping trims the whitespace at which a line is wrapped,
+    /// a column pointing to that whitespace is placed at the start of the next row. The end of a
+    /// line which fills its last row is placed where the next grapheme would be displayed: at the
+    /// start of the following row when the paragraph is wrapped, or on the last column otherwise.
+    ///
+    /// Returns `None` if the position is outside of the rendered area, e.g. when it is scrolled out
+    /// of view or truncated, or if the line doesn't exist.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ratatui::{layout::Position, prelude::*, widgets::*};
+    /// let paragraph = Paragraph::new("Hello World").wrap(Wrap { trim: true });
+    /// let area = Rect::new(0, 0, 8, 2);
+    /// assert_eq!(paragraph.cursor_position(area, 0, 1), Some(Position::new(1, 0)));
+    /// assert_eq!(paragraph.cursor_position(area, 0, 8), Some(Position::new(2, 1)));
+    /// assert_eq!(paragraph.cursor_position(area, 1, 0), None);
+    /// ```
+    pub fn cursor_position(&self, area: Rect, line: usize, column: usize) -> Option<Position> {
+        let area = self.block.inner_if_some(area);
+        let target = self.text.lines.get(line)?;
+        if area.is_empty() {
+            return None;
+        }
+        // the index of the first row of the line, and the offset and graphemes of each of its rows
+        let mut first_row = None;
+        let mut rows = vec![];
+        self.lay_out(area.width, self.scroll.x, |row| {
+            if row.line < line {
+                return ControlFlow::Continue(());
+            }
+            if row.line > line {
+                return ControlFlow::Break(());
+            }
+            first_row.get_or_insert(row.y);
+            let graphemes: Vec<_> = row
+                .graphemes
+                .iter()
+                .filter_map(|(x, index, grapheme)| {
+                    Some(((*index)?, *x, grapheme.symbol.width() as u16))
+                })
+                .collect();
+            rows.push((row.offset, graphemes));
+            ControlFlow::Continue(())
+        });
+        let len = target.styled_graphemes(Style::default()).count();
+        let graphemes = rows
+            .iter()
+            .enumerate()
+            .flat_map(|(row, (_, graphemes))| graphemes.iter().map(move |&g| (row, g)));
+
+        // the position of the grapheme or, when the line is wrapped, of the next grapheme
+        let found = graphemes.clone().find_map(|(row, (index, x, _))| {
+            (index == column || (self.wrap.is_some() && index > column)).then_some((row, x))
+        });
+        let (row, x) = match found {
+            Some(position) => position,
+            None if column < len && self.wrap.is_none() => return None,
+            // an empty line
+            None if len == 0 => (0, rows.first()?.0),
+            // the end of the line
+            None => {
+                let (row, (index, x, width)) = graphemes.max_by_key(|(_, (index, ..))| *index)?;
+                if self.wrap.is_none() && index + 1 < len {
+                    return None;
+                }
+                (row, x + width)
+            }
+        };
+        // a position after the end of a full row is displayed where the next grapheme would be: at
+        // the start of the next row when the line is wrapped, or on the last column otherwise
+        let (row, x) = match (x < area.width, self.wrap) {
+            (true, _) => (row, x),
+            (false, Some(_)) => {
+                let start = rows.get(row + 1).map_or_else(
+                    || {
+                        let alignment = target.alignment.unwrap_or(self.alignment);
+                        get_line_offset(1, area.width, self.direction.align(alignment))
+                    },
+                    |(offset, _)| *offset,
+                );
+                (row + 1, start)
+            }
+            (false, None) => (row, area.width - 1),
+        };
+        let y = (first_row? + row).checked_sub(self.scroll.y as usize)?;
+        let y = u16::try_from(y).ok().filter(|&y| y < area.height)?;
+        Some(Position::new(area.x + x, area.y + y))
+    }
 }
 
 impl Widget for Paragraph<'_> {
     fn render(self, area: Rect, buf: &mut Buffer) {
-        self.render_ref(area, buf);
+        WidgetRef::render_ref(&self, area, buf);
+    }
+
+    fn accessibility_node(&self, area: Rect) -> Option<AccessNode> {
+        Some(self.access_node(area))
     }
 }
 
@@ -395,63 +947,248 @@ impl WidgetRef for Paragraph<'_> {
         buf.set_style(area, self.style);
         self.block.render_ref(area, buf);
         let inner = self.block.inner_if_some(area);
-        self.render_paragraph(inner, buf);
+        self.render_paragraph(inner, buf, self.scroll);
+    }
+
+    fn accessibility_node(&self, area: Rect) -> Option<AccessNode> {
+        Some(self.access_node(area))
+    }
+}
+
+// Note: remove this when StatefulWidgetRef is stabilized and replace with the blanket impl
+impl StatefulWidget for &Paragraph<'_> {
+    type State = ParagraphState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        StatefulWidgetRef::render_ref(self, area, buf, state);
+    }
+
+    fn accessibility_node(&self, area: Rect, _state: &Self::State) -> Option<AccessNode> {
+        Some(self.access_node(area))
+    }
+}
+
+impl StatefulWidgetRef for Paragraph<'_> {
+    type State = ParagraphState;
+
+    fn render_ref(&self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        buf.set_style(area, self.style);
+        self.block.render_ref(area, buf);
+        let inner = self.block.inner_if_some(area);
+        // the text can't be measured without an area to render it in
+        if inner.is_empty() {
+            return;
+        }
+        state.offset = self.clamp_offset(state.offset, inner);
+        let (y, x) = state.offset;
+        self.render_paragraph(inner, buf, Position { x, y });
+    }
+
+    fn accessibility_node(&self, area: Rect, _state: &Self::State) -> Option<AccessNode> {
+        Some(self.access_node(area))
     }
 }
 
 impl Paragraph<'_> {
-    fn render_paragraph(&self, text_area: Rect, buf: &mut Buffer) {
+    /// Describes the paragraph as a [`Role::Paragraph`] node with the whole text, including the
+    /// lines scrolled out of view.
+    fn access_node(&self, area: Rect) -> AccessNode {
+        AccessNode::new(Role::Paragraph, self.text.to_string(), area)
+    }
+
+    /// Clamps a `(y, x)` scroll offset so that the text doesn't scroll past its end in the given
+    /// text area.
+    ///
+    /// The horizontal offset only applies to text which is neither wrapped nor truncated, so it is
+    /// clamped to 0 otherwise.
+    fn clamp_offset(
+        &self,
+        (y, x): (Vertical, Horizontal),
+        text_area: Rect,
+    ) -> (Vertical, Horizontal) {
+        let rows = self.text.line_count_at_width(
+            text_area.width,
+            self.wrap,
+            self.tab_size,
+            &self.break_on,
+        );
+        let max_y = rows.saturating_sub(text_area.height as usize);
+        let max_x = if self.wrap.is_none() && self.truncate.is_none() {
+            self.text_width().saturating_sub(text_area.width as usize)
+        } else {
+            0
+        };
+        (
+            y.min(u16::try_from(max_y).unwrap_or(u16::MAX)),
+            x.min(u16::try_from(max_x).unwrap_or(u16::MAX)),
+        )
+    }
+
+    fn render_paragraph(&self, text_area: Rect, buf: &mut Buffer, scroll: Position) {
         if text_area.is_empty() {
             return;
         }
 
         buf.set_style(text_area, self.style);
-        let styled = self.text.iter().map(|line| {
-            let graphemes = line.styled_graphemes(self.text.style);
+        let scroll_y = usize::from(scroll.y);
+        let height = usize::from(text_area.height);
+        self.lay_out(text_area.width, scroll.x, |row| {
+            let Some(y) = row.y.checked_sub(scroll_y) else {
+                return ControlFlow::Continue(());
+            };
+            let screen_y = text_area.top() + y as u16;
+            if self.fill_line_background {
+                self.fill_row_background(text_area, screen_y, buf, row.line);
+            }
+            for (x, _, StyledGrapheme { symbol, style }) in row.graphemes {
+                buf[(text_area.left() + x, screen_y)]
+                    .set_symbol(symbol)
+                    .set_style(*style);
+            }
+            if y + 1 < height {
+                ControlFlow::Continue(())
+            } else {
+                ControlFlow::Break(())
+            }
+        });
+    }
+}
+
+/// A row of the text laid out by [`Paragraph::lay_out`]
+struct LaidOutRow<'row, 'text> {
+    /// The index of the row, counted from the first row of the text
+    y: usize,
+    /// The index of the line of the text the row is laid out from
+    line: usize,
+    /// The column at which the row starts, which depends on its alignment
+    offset: u16,
+    /// The graphemes displayed on the row in visual order, each with its column and the index of
+    /// the grapheme of the line it comes from (`None` for an ellipsis)
+    graphemes: &'row [(u16, Option<usize>, StyledGrapheme<'text>)],
+}
+
+impl Paragraph<'_> {
+    /// Lays out the text in rows of the given width and calls `visit` with each row, until it
+    /// breaks.
+    ///
+    /// This is the layout used both to render the paragraph and to find the position of the
+    /// cursor, so that both always agree. `scroll_x` is the horizontal scroll of text which is
+    /// neither wrapped nor truncated.
+    fn lay_out(
+        &self,
+        width: u16,
+        scroll_x: u16,
+        mut visit: impl FnMut(LaidOutRow<'_, '_>) -> ControlFlow<()>,
+    ) {
+        // the composers read the lines on demand, so this is the line the rows are laid out from
+        let line_index = Cell::new(0);
+        // the offset in characters of the start of the line, for the selection
+        let mut line_offset = 0;
+        let styled = self.text.iter().enumerate().map(|(index, line)| {
+            line_index.set(index);
+            let mut offset = line_offset;
+            line_offset += line
+                .spans
+                .iter()
+                .map(|span| span.content.chars().count())
+                .sum::<usize>()
+                + 1;
+            let graphemes = line
+                .styled_graphemes(self.text.style)
+                .map(move |mut grapheme| {
+                    if let Some((range, style)) = &self.selection {
+                        if range.contains(&offset) {
+                            grapheme.style = grapheme.style.patch(*style);
+                        }
+                    }
+                    offset += grapheme.symbol.chars().count();
+                    grapheme
+                });
+            let graphemes = reflow::expand_tabs(graphemes, self.tab_size);
             let alignment = line.alignment.unwrap_or(self.alignment);
             (graphemes, alignment)
         });
 
         if let Some(Wrap { trim }) = self.wrap {
-            let line_composer = WordWrapper::new(styled, text_area.width, trim);
-            self.render_text(line_composer, text_area, buf);
+            let line_composer =
+                WordWrapper::new(styled, width, trim).with_break_set(self.break_on.clone());
+            self.lay_out_rows(line_composer, width, &line_index, &mut visit);
+        } else if let Some(Truncate { ellipsis, boundary }) = self.truncate {
+            let ellipsis = ellipsis.to_string();
+            let line_composer = LineEllipsizer::new(styled, width, &ellipsis, boundary);
+            self.lay_out_rows(line_composer, width, &line_index, &mut visit);
         } else {
-            let mut line_composer = LineTruncator::new(styled, text_area.width);
-            line_composer.set_horizontal_offset(self.scroll.x);
-            self.render_text(line_composer, text_area, buf);
+            let mut line_composer = LineTruncator::new(styled, width);
+            line_composer.set_horizontal_offset(scroll_x);
+            self.lay_out_rows(line_composer, width, &line_index, &mut visit);
         }
     }
-}
 
-impl<'a> Paragraph<'a> {
-    fn render_text<C: LineComposer<'a>>(&self, mut composer: C, area: Rect, buf: &mut Buffer) {
+    /// Places the graphemes of the lines composed by `composer` on their rows, see
+    /// [`Self::lay_out`].
+    fn lay_out_rows<'t, C: LineComposer<'t>>(
+        &self,
+        mut composer: C,
+        width: u16,
+        line_index: &Cell<usize>,
+        visit: &mut impl FnMut(LaidOutRow<'_, '_>) -> ControlFlow<()>,
+    ) {
+        let mut graphemes = vec![];
         let mut y = 0;
         while let Some(WrappedLine {
-            line: current_line,
-            width: current_line_width,
-            alignment: current_line_alignment,
+            line,
+            indices,
+            width: line_width,
+            alignment,
         }) = composer.next_line()
         {
-            if y >= self.scroll.y {
-                let mut x = get_line_offset(current_line_width, area.width, current_line_alignment);
-                for StyledGrapheme { symbol, style } in current_line {
-                    let width = symbol.width();
-                    if width == 0 {
-                        continue;
-                    }
-                    // If the symbol is empty, the last char which rendered last time will
-                    // leave on the line. It's a quick fix.
-                    let symbol = if symbol.is_empty() { " " } else { symbol };
-                    buf[(area.left() + x, area.top() + y - self.scroll.y)]
-                        .set_symbol(symbol)
-                        .set_style(*style);
-                    x += width as u16;
+            let alignment = self.direction.align(alignment);
+            let offset = get_line_offset(line_width, width, alignment);
+            let mut x = offset;
+            graphemes.clear();
+            let mut place = |position: usize| {
+                let grapheme: &StyledGrapheme = &line[position];
+                let grapheme_width = grapheme.symbol.width() as u16;
+                if grapheme_width > 0 {
+                    graphemes.push((x, indices[position], grapheme.clone()));
+                    x += grapheme_width;
+                }
+            };
+            match self.direction {
+                TextDirection::LeftToRight => (0..line.len()).for_each(&mut place),
+                TextDirection::RightToLeft => {
+                    right_to_left_visual_order(line)
+                        .into_iter()
+                        .for_each(&mut place);
                 }
             }
-            y += 1;
-            if y >= area.height + self.scroll.y {
-                break;
+            let row = LaidOutRow {
+                y,
+                line: line_index.get(),
+                offset,
+                graphemes: &graphemes,
+            };
+            if visit(row).is_break() {
+                return;
             }
+            y += 1;
+        }
+    }
+
+    /// Fills the row `y` of the area with the background color of the line at `line_index`
+    fn fill_row_background(&self, area: Rect, y: u16, buf: &mut Buffer, line_index: usize) {
+        let Some(line) = self.text.lines.get(line_index) else {
+            return;
+        };
+        if let Some(bg) = self.text.style.patch(line.style).bg {
+            buf.set_style(
+                Rect {
+                    y,
+                    height: 1,
+                    ..area
+                },
+                Style::new().bg(bg),
+            );
         }
     }
 }
@@ -470,6 +1207,8 @@ impl<'a> Styled for Paragraph<'a> {
 
 #[cfg(test)]
 mod test {
+    use rstest::rstest;
+
     use super::*;
     use crate::{
         backend::TestBackend,
@@ -982,6 +1721,170 @@ mod test {
         );
     }
 
+    #[test]
+    fn right_to_left() {
+        let paragraph = Paragraph::new("שלום עולם").direction(TextDirection::RightToLeft);
+        test_case(&paragraph, &Buffer::with_lines(["   םלוע םולש"]));
+
+        let paragraph = paragraph.alignment(Alignment::Right);
+        test_case(&paragraph, &Buffer::with_lines(["םלוע םולש   "]));
+
+        let paragraph = paragraph.alignment(Alignment::Center);
+        test_case(&paragraph, &Buffer::with_lines(["  םלוע םולש "]));
+    }
+
+    #[test]
+    fn right_to_left_keeps_left_to_right_runs() {
+        let paragraph = Paragraph::new("שלום abc, 123!").direction(TextDirection::RightToLeft);
+        test_case(&paragraph, &Buffer::with_lines(["  !abc, 123 םולש"]));
+    }
+
+    #[test]
+    fn right_to_left_wraps_in_logical_order() {
+        // the leading whitespace is on the right of the line
+        let paragraph = Paragraph::new("  שלום עולם")
+            .direction(TextDirection::RightToLeft)
+            .wrap(Wrap { trim: true });
+        test_case(&paragraph, &Buffer::with_lines(["  םולש", "  םלוע"]));
+
+        let paragraph = paragraph.wrap(Wrap { trim: false });
+        test_case(&paragraph, &Buffer::with_lines(["םולש  ", "  םלוע"]));
+    }
+
+    #[test]
+    fn truncate() {
+        let paragraph =
+            Paragraph::new("The quick brown fox\nlazy dog").truncate(Truncate::default());
+        test_case(
+            &paragraph,
+            &Buffer::with_lines(["The quick…  ", "lazy dog    "]),
+        );
+
+        let area = Rect::new(0, 0, 12, 2);
+        assert_eq!(
+            paragraph.cursor_position(area, 0, 4),
+            Some(layout::Position::new(4, 0))
+        );
+        assert_eq!(paragraph.cursor_position(area, 0, 12), None);
+
+        let paragraph = paragraph.truncate(Truncate {
+            ellipsis: '>',
+            boundary: WordBoundary::Grapheme,
+        });
+        test_case(
+            &paragraph,
+            &Buffer::with_lines(["The quick b>", "lazy dog    "]),
+        );
+
+        let paragraph = paragraph.right_aligned();
+        test_case(
+            &paragraph,
+            &Buffer::with_lines(["The quick b>", "    lazy dog"]),
+        );
+    }
+
+    #[test]
+    fn truncate_styles_ellipsis_like_the_text() {
+        let line = Line::from(vec!["The ".into(), "quick brown".red()]);
+        let paragraph = Paragraph::new(line).truncate(Truncate::default());
+        let mut expected = Buffer::with_lines(["The quick…  "]);
+        expected.set_style(Rect::new(4, 0, 6, 1), Color::Red);
+        test_case(&paragraph, &expected);
+    }
+
+    #[test]
+    fn truncate_double_width_chars() {
+        let paragraph = Paragraph::new("コンピュータ").truncate(Truncate::default());
+        let mut buf = Buffer::empty(Rect::new(0, 0, 6, 1));
+        paragraph.render(buf.area, &mut buf);
+        assert_eq!(buf, Buffer::with_lines(["コン… "]));
+    }
+
+    #[test]
+    fn wrap_takes_precedence_over_truncate() {
+        let paragraph = Paragraph::new("The quick brown fox")
+            .truncate(Truncate::default())
+            .wrap(Wrap { trim: true });
+        test_case(
+            &paragraph,
+            &Buffer::with_lines(["The quick   ", "brown fox   "]),
+        );
+    }
+
+    #[test]
+    fn default_tab_size() {
+        let paragraph = Paragraph::new("a\tb");
+        test_case(&paragraph, &Buffer::with_lines(["a       b "]));
+        assert_eq!(Paragraph::default().tab_size, 8);
+    }
+
+    #[test]
+    fn tab_size() {
+        let paragraph = Paragraph::new("ab\tc\nabcde\tf\n\tg").tab_size(4);
+        test_case(
+            &paragraph,
+            &Buffer::with_lines(["ab  c     ", "abcde   f ", "    g     "]),
+        );
+    }
+
+    #[test]
+    fn zero_tab_size_removes_tabs() {
+        let paragraph = Paragraph::new("a\tb").tab_size(0);
+        test_case(&paragraph, &Buffer::with_lines(["ab   "]));
+    }
+
+    #[rstest]
+    #[case::no_trim(false, ["abcdef", " g    "])]
+    #[case::trim(true, ["abcdef", "g     "])]
+    fn tabs_expand_from_the_start_of_wrapped_lines(
+        #[case] trim: bool,
+        #[case] expected: [&str; 2],
+    ) {
+        let paragraph = Paragraph::new("abcdef\tg").tab_size(4).wrap(Wrap { trim });
+        test_case(&paragraph, &Buffer::with_lines(expected));
+    }
+
+    #[test]
+    fn tabs_are_aligned() {
+        let paragraph = Paragraph::new("a\tb").tab_size(4).right_aligned();
+        test_case(&paragraph, &Buffer::with_lines(["   a   b"]));
+        let paragraph = Paragraph::new("a\tb").tab_size(4).centered();
+        test_case(&paragraph, &Buffer::with_lines(["  a   b "]));
+    }
+
+    #[test]
+    fn tabs_line_width_and_count() {
+        let paragraph = Paragraph::new("a\tb").tab_size(4);
+        assert_eq!(paragraph.line_width(), 5);
+        let paragraph = paragraph.wrap(Wrap { trim: false });
+        assert_eq!(paragraph.line_count(5), 1);
+        assert_eq!(paragraph.line_count(3), 2);
+    }
+
+    #[rstest]
+    #[case::before_tab(0, Some((0, 0)))]
+    #[case::tab(1, Some((1, 0)))]
+    #[case::after_tab(2, Some((4, 0)))]
+    #[case::end_of_line(3, Some((5, 0)))]
+    fn cursor_position_with_tabs(#[case] column: usize, #[case] expected: Option<(u16, u16)>) {
+        let paragraph = Paragraph::new("a\tb").tab_size(4);
+        let position = paragraph.cursor_position(Rect::new(0, 0, 10, 1), 0, column);
+        assert_eq!(position, expected.map(layout::Position::from));
+    }
+
+    #[test]
+    fn word_boundary_to_string() {
+        assert_eq!(WordBoundary::Grapheme.to_string(), "Grapheme");
+        assert_eq!(WordBoundary::Word.to_string(), "Word");
+        assert_eq!(WordBoundary::Sentence.to_string(), "Sentence");
+    }
+
+    #[test]
+    fn text_direction_to_string() {
+        assert_eq!(TextDirection::LeftToRight.to_string(), "LeftToRight");
+        assert_eq!(TextDirection::RightToLeft.to_string(), "RightToLeft");
+    }
+
     #[test]
     fn widgets_paragraph_count_rendered_lines() {
         let paragraph = Paragraph::new("Hello World");
@@ -1006,6 +1909,144 @@ mod test {
         assert_eq!(paragraph.line_count(6), 200);
     }
 
+    #[test]
+    fn widgets_paragraph_line_count_wraps_inside_block() {
+        let paragraph = Paragraph::new("Hello World")
+            .block(Block::bordered())
+            .wrap(Wrap { trim: true });
+        assert_eq!(paragraph.line_count(13), 3);
+        assert_eq!(paragraph.line_count(12), 4);
+        assert_eq!(paragraph.line_count(2), 2);
+    }
+
+    #[rstest]
+    #[case::trim(Wrap { trim: true })]
+    #[case::no_trim(Wrap { trim: false })]
+    fn widgets_paragraph_line_count_matches_render(#[case] wrap: Wrap) {
+        let text = "コンピュータ上で文字を扱う場合、\n  indented text that wraps\n\nlast line";
+        for width in 4..30 {
+            let paragraph = Paragraph::new(text).block(Block::bordered()).wrap(wrap);
+            let line_count = paragraph.line_count(width);
+            let area = Rect::new(0, 0, width, 100);
+            let mut buffer = Buffer::empty(area);
+            paragraph.render(area, &mut buffer);
+            // the last line of text is followed by the bottom border in the measured height
+            let last_line = (1..area.height - 1)
+                .rev()
+                .find(|&y| (1..width - 1).any(|x| buffer[(x, y)].symbol() != " "))
+                .unwrap();
+            assert_eq!(line_count, last_line as usize + 2, "width {width}");
+        }
+    }
+
+    #[test]
+    fn render_with_break_characters() {
+        let paragraph = Paragraph::new("see /usr/local/share")
+            .wrap(Wrap { trim: true })
+            .break_on(BreakSet::new(['/']));
+        assert_eq!(paragraph.line_count(9), 3);
+        test_case(
+            &paragraph,
+            &Buffer::with_lines(["see /usr/", "local/   ", "share    "]),
+        );
+
+        let paragraph = Paragraph::new("hy\u{ad}phen\u{ad}ation")
+            .wrap(Wrap { trim: true })
+            .break_on(BreakSet::default().soft_hyphens(true));
+        assert_eq!(paragraph.line_count(7), 3);
+        test_case(
+            &paragraph,
+            &Buffer::with_lines(["hy-    ", "phen-  ", "ation  "]),
+        );
+    }
+
+    #[test]
+    fn render_with_selection() {
+        let paragraph = Paragraph::new("Hello world").selection(2..7, Style::new().reversed());
+        let mut expected = Buffer::with_lines(["Hello world"]);
+        expected.set_style(Rect::new(2, 0, 5, 1), Style::new().reversed());
+        test_case(&paragraph, &expected);
+    }
+
+    #[test]
+    fn render_with_selection_across_lines() {
+        // the selection starts on the first line, includes the line break and ends on the second
+        let text = Text::from(vec![Line::from("abc"), Line::from("déf".red())]);
+        let paragraph = Paragraph::new(text).selection(1..6, Style::new().on_blue());
+        let mut expected = Buffer::with_lines(["abc", "déf"]);
+        expected.set_style(Rect::new(0, 1, 3, 1), Style::new().red());
+        expected.set_style(Rect::new(1, 0, 2, 1), Style::new().on_blue());
+        expected.set_style(Rect::new(0, 1, 2, 1), Style::new().on_blue());
+        test_case(&paragraph, &expected);
+    }
+
+    #[test]
+    fn render_with_selection_across_wrapped_lines() {
+        let paragraph = Paragraph::new("Hello world\nHello universe")
+            .wrap(Wrap { trim: true })
+            .selection(6..17, Style::new().reversed());
+        let mut expected = Buffer::with_lines(["Hello   ", "world   ", "Hello   ", "universe"]);
+        expected.set_style(Rect::new(0, 1, 5, 1), Style::new().reversed());
+        expected.set_style(Rect::new(0, 2, 5, 1), Style::new().reversed());
+        test_case(&paragraph, &expected);
+    }
+
+    #[test]
+    fn render_with_selection_scrolled() {
+        let paragraph = Paragraph::new("one\ntwo\nthree")
+            .scroll((1, 1))
+            .selection(2..10, Style::new().reversed());
+        let mut buf = Buffer::empty(Rect::new(0, 0, 4, 2));
+        paragraph.render(buf.area, &mut buf);
+        let mut expected = Buffer::with_lines(["wo  ", "hree"]);
+        expected.set_style(Rect::new(0, 0, 2, 1), Style::new().reversed());
+        expected.set_style(Rect::new(0, 1, 1, 1), Style::new().reversed());
+        assert_eq!(buf, expected);
+    }
+
+    #[rstest]
+    #[case::within_bounds((1, 2), (1, 2), ["ne 2 ", "ne 3 "])]
+    #[case::past_the_end((10, 20), (2, 2), ["ne 3 ", "ne 45"])]
+    #[case::at_the_start((0, 0), (0, 0), ["line ", "line "])]
+    fn render_stateful_clamps_offset(
+        #[case] offset: (u16, u16),
+        #[case] expected_offset: (u16, u16),
+        #[case] expected: [&str; 2],
+    ) {
+        let paragraph = Paragraph::new("line 1\nline 2\nline 3\nline 45");
+        let mut state = ParagraphState::new(offset);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 5, 2));
+        StatefulWidget::render(&paragraph, buf.area, &mut buf, &mut state);
+        assert_eq!(state.offset, expected_offset);
+        assert_eq!(buf, Buffer::with_lines(expected));
+    }
+
+    #[test]
+    fn render_stateful_clamps_wrapped_offset() {
+        let paragraph = Paragraph::new("Hello World from ratatui")
+            .block(Block::bordered())
+            .wrap(Wrap { trim: true });
+        let mut state = ParagraphState::new((10, 10));
+        let mut buf = Buffer::empty(Rect::new(0, 0, 9, 4));
+        StatefulWidget::render(&paragraph, buf.area, &mut buf, &mut state);
+        // 4 wrapped rows in 2 rows of inner area, and no horizontal scrolling when wrapping
+        assert_eq!(state.offset, (2, 0));
+        assert_eq!(
+            buf,
+            Buffer::with_lines(["┌───────┐", "│from   │", "│ratatui│", "└───────┘"])
+        );
+    }
+
+    #[test]
+    fn render_stateful_with_empty_area() {
+        let paragraph = Paragraph::new("Hello\nWorld");
+        let mut state = ParagraphState::new((1, 1));
+        let mut buf = Buffer::empty(Rect::new(0, 0, 5, 1));
+        StatefulWidget::render(&paragraph, Rect::ZERO, &mut buf, &mut state);
+        assert_eq!(state.offset, (1, 1));
+        assert_eq!(buf, Buffer::empty(Rect::new(0, 0, 5, 1)));
+    }
+
     #[test]
     fn widgets_paragraph_rendered_line_count_accounts_block() {
         let block = Block::new();
@@ -1110,6 +2151,136 @@ mod test {
         assert_eq!(paragraph.line_width(), 12);
     }
 
+    #[rstest]
+    #[case::start(0, 0, Some((0, 0)))]
+    #[case::middle(0, 4, Some((4, 0)))]
+    #[case::trimmed_whitespace(0, 5, Some((0, 1)))]
+    #[case::wrapped(0, 7, Some((1, 1)))]
+    #[case::end_of_line(0, 11, Some((5, 1)))]
+    #[case::empty_line(1, 0, Some((0, 2)))]
+    #[case::end_of_last_line(2, 7, Some((7, 3)))]
+    #[case::missing_line(3, 0, None)]
+    fn cursor_position_wrapped(
+        #[case] line: usize,
+        #[case] column: usize,
+        #[case] expected: Option<(u16, u16)>,
+    ) {
+        let paragraph = Paragraph::new("Hello World\n\nfoo bar").wrap(Wrap { trim: true });
+        let area = Rect::new(0, 0, 8, 4);
+        let expected = expected.map(layout::Position::from);
+        assert_eq!(paragraph.cursor_position(area, line, column), expected);
+    }
+
+    #[test]
+    fn cursor_position_with_repeated_spans() {
+        let word = "ab";
+        let paragraph = Paragraph::new(Line::from(vec![Span::raw(word), Span::raw(word)]));
+        let area = Rect::new(0, 0, 8, 1);
+        assert_eq!(
+            paragraph.cursor_position(area, 0, 1),
+            Some(layout::Position::new(1, 0))
+        );
+        assert_eq!(
+            paragraph.cursor_position(area, 0, 3),
+            Some(layout::Position::new(3, 0))
+        );
+    }
+
+    #[rstest]
+    #[case::before_hyphen(1, Some((1, 0)))]
+    #[case::hyphen(2, Some((2, 0)))]
+    #[case::after_hyphen(3, Some((0, 1)))]
+    #[case::second_hyphen(7, Some((4, 1)))]
+    #[case::last_row(8, Some((0, 2)))]
+    fn cursor_position_with_soft_hyphens(
+        #[case] column: usize,
+        #[case] expected: Option<(u16, u16)>,
+    ) {
+        let paragraph = Paragraph::new("hy\u{ad}phen\u{ad}ation")
+            .wrap(Wrap { trim: true })
+            .break_on(BreakSet::default().soft_hyphens(true));
+        let area = Rect::new(0, 0, 7, 3);
+        let expected = expected.map(layout::Position::from);
+        assert_eq!(paragraph.cursor_position(area, 0, column), expected);
+    }
+
+    #[test]
+    fn cursor_position_full_row_moves_to_next_row() {
+        let paragraph = Paragraph::new("abcd").wrap(Wrap { trim: false });
+        let area = Rect::new(0, 0, 4, 2);
+        assert_eq!(
+            paragraph.cursor_position(area, 0, 4),
+            Some(layout::Position::new(0, 1))
+        );
+    }
+
+    #[test]
+    fn cursor_position_accounts_for_block_and_scroll() {
+        let paragraph = Paragraph::new("Hello World\n\nfoo bar")
+            .wrap(Wrap { trim: true })
+            .block(Block::bordered())
+            .scroll((1, 0));
+        let area = Rect::new(10, 10, 10, 6);
+        assert_eq!(paragraph.cursor_position(area, 0, 0), None);
+        assert_eq!(
+            paragraph.cursor_position(area, 0, 6),
+            Some(layout::Position::new(11, 11))
+        );
+        assert_eq!(
+            paragraph.cursor_position(area, 2, 1),
+            Some(layout::Position::new(12, 13))
+        );
+    }
+
+    #[rstest]
+    #[case::scrolled_out(1, None)]
+    #[case::first_visible(2, Some((0, 0)))]
+    #[case::truncated(10, None)]
+    #[case::end_of_line(11, None)]
+    fn cursor_position_truncated(#[case] column: usize, #[case] expected: Option<(u16, u16)>) {
+        let paragraph = Paragraph::new("Hello World").scroll((0, 2));
+        let area = Rect::new(0, 0, 8, 1);
+        let expected = expected.map(layout::Position::from);
+        assert_eq!(paragraph.cursor_position(area, 0, column), expected);
+    }
+
+    #[rstest]
+    #[case::left_wrapped("abcd efghi", Alignment::Left, Some(Wrap { trim: true }), (0, 2))]
+    #[case::centered_wrapped("abcd efghi", Alignment::Center, Some(Wrap { trim: true }), (2, 2))]
+    #[case::right_wrapped("abcd efghi", Alignment::Right, Some(Wrap { trim: true }), (4, 2))]
+    #[case::left("abcde", Alignment::Left, None, (4, 0))]
+    #[case::right("abcde", Alignment::Right, None, (4, 0))]
+    fn cursor_position_end_of_full_row(
+        #[case] text: &str,
+        #[case] alignment: Alignment,
+        #[case] wrap: Option<Wrap>,
+        #[case] expected: (u16, u16),
+    ) {
+        let paragraph = Paragraph {
+            wrap,
+            ..Paragraph::new(text).alignment(alignment)
+        };
+        let area = Rect::new(0, 0, 5, 3);
+        assert_eq!(
+            paragraph.cursor_position(area, 0, text.len()),
+            Some(layout::Position::from(expected))
+        );
+    }
+
+    #[test]
+    fn cursor_position_aligned() {
+        let paragraph = Paragraph::new("foo").centered();
+        let area = Rect::new(0, 0, 8, 1);
+        assert_eq!(
+            paragraph.cursor_position(area, 0, 0),
+            Some(layout::Position::new(3, 0))
+        );
+        assert_eq!(
+            paragraph.cursor_position(area, 0, 3),
+            Some(layout::Position::new(6, 0))
+        );
+    }
+
     #[test]
     fn left_aligned() {
         let p = Paragraph::new("Hello, world!").left_aligned();
@@ -1148,4 +2319,90 @@ mod test {
         expected.set_style(Rect::new(1, 1, 11, 1), Style::default().fg(Color::Green));
         assert_eq!(buf, expected);
     }
+
+    #[test]
+    fn highlight() {
+        let paragraph = Paragraph::new("a cat\na dog").highlight("a", Color::Yellow);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 5, 2));
+        paragraph.render(buf.area, &mut buf);
+
+        let mut expected = Buffer::with_lines(["a cat", "a dog"]);
+        for (x, y) in [(0, 0), (3, 0), (0, 1)] {
+            expected[(x, y)].set_fg(Color::Yellow);
+        }
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn fill_line_background() {
+        let text = vec![
+            Line::from("ab").on_blue(),
+            Line::from("cd").on_red().centered(),
+            Line::from("ef").right_aligned(),
+            Line::from("").on_green(),
+        ];
+        let paragraph = Paragraph::new(text).fill_line_background(true);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 6, 4));
+        paragraph.render(buf.area, &mut buf);
+
+        let mut expected = Buffer::with_lines(["ab    ", "  cd  ", "    ef", "      "]);
+        expected.set_style(Rect::new(0, 0, 6, 1), Style::new().on_blue());
+        expected.set_style(Rect::new(0, 1, 6, 1), Style::new().on_red());
+        expected.set_style(Rect::new(0, 3, 6, 1), Style::new().on_green());
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn fill_line_background_wrapped() {
+        let text = vec![Line::from("aaa bbb").on_blue(), Line::from("ccc")];
+        let paragraph = Paragraph::new(text)
+            .wrap(Wrap { trim: true })
+            .fill_line_background(true);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 5, 3));
+        paragraph.render(buf.area, &mut buf);
+
+        let mut expected = Buffer::with_lines(["aaa  ", "bbb  ", "ccc  "]);
+        expected.set_style(Rect::new(0, 0, 5, 2), Style::new().on_blue());
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn fill_line_background_scrolled() {
+        let text = vec![Line::from("a"), Line::from("b").on_blue()];
+        let paragraph = Paragraph::new(text)
+            .scroll((1, 0))
+            .fill_line_background(true);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 3, 1));
+        paragraph.render(buf.area, &mut buf);
+
+        let mut expected = Buffer::with_lines(["b  "]);
+        expected.set_style(Rect::new(0, 0, 3, 1), Style::new().on_blue());
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn line_background_is_not_filled_by_default() {
+        let paragraph = Paragraph::new(Line::from("ab").on_blue());
+        let mut buf = Buffer::empty(Rect::new(0, 0, 4, 1));
+        paragraph.render(buf.area, &mut buf);
+
+        let mut expected = Buffer::with_lines(["ab  "]);
+        expected.set_style(Rect::new(0, 0, 2, 1), Style::new().on_blue());
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn accessibility_node() {
+        let paragraph = Paragraph::new("Hello\nworld").block(Block::bordered());
+        let area = Rect::new(1, 2, 10, 4);
+        let expected = AccessNode::new(Role::Paragraph, "Hello\nworld", area);
+        assert_eq!(
+            Widget::accessibility_node(&paragraph, area),
+            Some(expected.clone())
+        );
+        assert_eq!(
+            StatefulWidget::accessibility_node(&&paragraph, area, &ParagraphState::default()),
+            Some(expected)
+        );
+    }
 }
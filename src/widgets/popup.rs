@@ -0,0 +1,221 @@
+use crate::{
+    layout::Flex,
+    prelude::*,
+    widgets::{Block, Clear},
+};
+
+/// A widget which displays its content in a centered area over what was previously rendered.
+///
+/// The popup computes an area of the given [width](Popup::width) and [height](Popup::height)
+/// centered within the area it is rendered to, [clears](Clear) it, renders the optional
+/// [`Block`] and finally renders the content inside of the block.
+///
+/// The size can be any [`Constraint`], e.g. a [`Length`](Constraint::Length) for an absolute size
+/// or a [`Percentage`](Constraint::Percentage) of the parent area. A size larger than the parent
+/// area is clamped to the parent area. The default size is half of the parent area in both
+/// directions.
+///
+/// The area of the popup can be retrieved with [`Popup::area`], e.g. to position the cursor in the
+/// popup.
+///
+/// # Example
+///
+/// ```
+/// use ratatui::{prelude::*, widgets::*};
+///
+/// # fn ui(frame: &mut Frame) {
+/// let popup = Popup::new(Paragraph::new("Are you sure?"))
+///     .width(Constraint::Percentage(60))
+///     .height(5)
+///     .block(Block::bordered().title("Confirm"));
+/// frame.render_widget(popup, frame.area());
+/// # }
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Popup<'a, W> {
+    /// The widget rendered inside the popup
+    content: W,
+    /// The width of the popup
+    width: Constraint,
+    /// The height of the popup
+    height: Constraint,
+    /// A block to wrap the content in
+    block: Option<Block<'a>>,
+}
+
+impl<'a, W> Popup<'a, W> {
+    /// Creates a new popup displaying the given widget
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui::{prelude::*, widgets::*};
+    ///
+    /// let popup = Popup::new(Paragraph::new("Hello, world!"));
+    /// ```
+    pub const fn new(content: W) -> Self {
+        Self {
+            content,
+            width: Constraint::Percentage(50),
+            height: Constraint::Percentage(50),
+            block: None,
+        }
+    }
+
+    /// Sets the width of the popup
+    ///
+    /// `width` accepts any type that is convertible to [`Constraint`], e.g. a `u16` for an
+    /// absolute width.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn width<C: Into<Constraint>>(mut self, width: C) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the popup
+    ///
+    /// `height` accepts any type that is convertible to [`Constraint`], e.g. a `u16` for an
+    /// absolute height.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn height<C: Into<Constraint>>(mut self, height: C) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Surrounds the content of the popup with a [`Block`]
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn block(mut self, block: Block<'a>) -> Self {
+        self.block = Some(block);
+        self
+    }
+
+    /// Returns the area of the popup when rendered in the given parent area
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui::{prelude::*, widgets::*};
+    ///
+    /// let popup = Popup::new(Clear).width(20).height(4);
+    /// assert_eq!(popup.area(Rect::new(0, 0, 40, 10)), Rect::new(10, 3, 20, 4));
+    /// ```
+    pub fn area(&self, parent: Rect) -> Rect {
+        let [area] = Layout::vertical([self.height])
+            .flex(Flex::Center)
+            .areas(parent);
+        let [area] = Layout::horizontal([self.width])
+            .flex(Flex::Center)
+            .areas(area);
+        area
+    }
+
+    /// Clears the area of the popup and renders the block, returning the area of the content
+    fn render_frame(&self, area: Rect, buf: &mut Buffer) -> Rect {
+        let area = self.area(area);
+        Clear.render(area, buf);
+        self.block.render_ref(area, buf);
+        self.block.inner_if_some(area)
+    }
+}
+
+impl<W: Widget> Widget for Popup<'_, W> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let inner = self.render_frame(area, buf);
+        self.content.render(inner, buf);
+    }
+}
+
+impl<W: WidgetRef> WidgetRef for Popup<'_, W> {
+    fn render_ref(&self, area: Rect, buf: &mut Buffer) {
+        let inner = self.render_frame(area, buf);
+        self.content.render_ref(inner, buf);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::widgets::Paragraph;
+
+    #[test]
+    fn new() {
+        let popup = Popup::new(Clear);
+        assert_eq!(
+            popup,
+            Popup {
+                content: Clear,
+                width: Constraint::Percentage(50),
+                height: Constraint::Percentage(50),
+                block: None,
+            }
+        );
+    }
+
+    #[rstest]
+    #[case::length(Constraint::Length(4), Constraint::Length(2), Rect::new(3, 2, 4, 2))]
+    #[case::percentage(
+        Constraint::Percentage(50),
+        Constraint::Percentage(50),
+        Rect::new(3, 2, 5, 3)
+    )]
+    #[case::wider_than_parent(
+        Constraint::Length(20),
+        Constraint::Length(2),
+        Rect::new(0, 2, 10, 2)
+    )]
+    #[case::taller_than_parent(Constraint::Length(4), Constraint::Length(9), Rect::new(3, 0, 4, 6))]
+    fn area(#[case] width: Constraint, #[case] height: Constraint, #[case] expected: Rect) {
+        let popup = Popup::new(Clear).width(width).height(height);
+        assert_eq!(popup.area(Rect::new(0, 0, 10, 6)), expected);
+    }
+
+    #[test]
+    fn area_is_relative_to_parent() {
+        let popup = Popup::new(Clear).width(2).height(2);
+        assert_eq!(popup.area(Rect::new(5, 10, 4, 4)), Rect::new(6, 11, 2, 2));
+    }
+
+    #[test]
+    fn render() {
+        let mut buf = Buffer::with_lines(["xxxxxxxxxx"; 5]);
+        Popup::new(Paragraph::new("hi"))
+            .width(6)
+            .height(3)
+            .block(Block::bordered())
+            .render(buf.area, &mut buf);
+        let expected = Buffer::with_lines([
+            "xxxxxxxxxx",
+            "xx┌────┐xx",
+            "xx│hi  │xx",
+            "xx└────┘xx",
+            "xxxxxxxxxx",
+        ]);
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn render_without_block() {
+        let mut buf = Buffer::with_lines(["xxxxxx"; 3]);
+        Popup::new(Paragraph::new("ab"))
+            .width(4)
+            .height(1)
+            .render(buf.area, &mut buf);
+        assert_eq!(buf, Buffer::with_lines(["xxxxxx", "xab  x", "xxxxxx"]));
+    }
+
+    #[test]
+    fn render_ref() {
+        let mut buf = Buffer::with_lines(["xxxxxx"; 3]);
+        let popup = Popup::new(Paragraph::new("ab")).width(4).height(1);
+        popup.render_ref(buf.area, &mut buf);
+        assert_eq!(buf, Buffer::with_lines(["xxxxxx", "xab  x", "xxxxxx"]));
+    }
+}
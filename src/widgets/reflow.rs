@@ -1,9 +1,16 @@
-use std::{collections::VecDeque, mem};
+use std::{collections::VecDeque, iter, mem};
 
 use unicode_segmentation::UnicodeSegmentation;
 use unicode_width::UnicodeWidthStr;
 
-use crate::{layout::Alignment, text::StyledGrapheme};
+use crate::{
+    layout::Alignment,
+    text::StyledGrapheme,
+    widgets::{BreakSet, WordBoundary},
+};
+
+/// The soft hyphen (U+00AD), which marks where a word may be hyphenated
+pub const SOFT_HYPHEN: char = '\u{ad}';
 
 /// A state machine to pack styled symbols into lines.
 /// Cannot implement it as Iterator since it yields slices of the internal buffer (need streaming
@@ -15,41 +22,50 @@ pub trait LineComposer<'a> {
 pub struct WrappedLine<'lend, 'text> {
     /// One line reflowed to the correct width
     pub line: &'lend [StyledGrapheme<'text>],
+    /// The index in its input line of each grapheme of `line`, or `None` for a grapheme added by
+    /// the composer (e.g. an ellipsis)
+    pub indices: &'lend [Option<usize>],
     /// The width of the line
     pub width: u16,
     /// Whether the line was aligned left or right
     pub alignment: Alignment,
 }
 
+/// A grapheme of an input line along with its index in the line
+type IndexedGrapheme<'a> = (usize, StyledGrapheme<'a>);
+
 /// A state machine that wraps lines on word boundaries.
 #[derive(Debug, Default, Clone)]
 pub struct WordWrapper<'a, O, I>
 where
     // Outer iterator providing the individual lines
     O: Iterator<Item = (I, Alignment)>,
-    // Inner iterator providing the styled symbols of a line Each line consists of an alignment and
-    // a series of symbols
-    I: Iterator<Item = StyledGrapheme<'a>>,
+    // Inner iterator providing the styled symbols of a line along with their index in the line.
+    // Each line consists of an alignment and a series of symbols
+    I: Iterator<Item = IndexedGrapheme<'a>>,
 {
     /// The given, unprocessed lines
     input_lines: O,
     max_line_width: u16,
-    wrapped_lines: VecDeque<Vec<StyledGrapheme<'a>>>,
+    wrapped_lines: VecDeque<Vec<IndexedGrapheme<'a>>>,
     current_alignment: Alignment,
     current_line: Vec<StyledGrapheme<'a>>,
+    current_indices: Vec<Option<usize>>,
     /// Removes the leading whitespace from lines
     trim: bool,
+    /// The characters after which lines may be broken, in addition to whitespace
+    break_set: BreakSet,
 
     // These are cached allocations that hold no state across next_line invocations
-    pending_word: Vec<StyledGrapheme<'a>>,
-    pending_whitespace: VecDeque<StyledGrapheme<'a>>,
-    pending_line_pool: Vec<Vec<StyledGrapheme<'a>>>,
+    pending_word: Vec<IndexedGrapheme<'a>>,
+    pending_whitespace: VecDeque<IndexedGrapheme<'a>>,
+    pending_line_pool: Vec<Vec<IndexedGrapheme<'a>>>,
 }
 
 impl<'a, O, I> WordWrapper<'a, O, I>
 where
     O: Iterator<Item = (I, Alignment)>,
-    I: Iterator<Item = StyledGrapheme<'a>>,
+    I: Iterator<Item = IndexedGrapheme<'a>>,
 {
     pub const fn new(lines: O, max_line_width: u16, trim: bool) -> Self {
         Self {
@@ -58,7 +74,9 @@ where
             wrapped_lines: VecDeque::new(),
             current_alignment: Alignment::Left,
             current_line: vec![],
+            current_indices: vec![],
             trim,
+            break_set: BreakSet::WHITESPACE,
 
             pending_word: Vec::new(),
             pending_line_pool: Vec::new(),
@@ -66,29 +84,60 @@ where
         }
     }
 
+    /// Sets the characters after which lines may be broken, in addition to whitespace
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn with_break_set(mut self, break_set: BreakSet) -> Self {
+        self.break_set = break_set;
+        self
+    }
+
     /// Split an input line (`line_symbols`) into wrapped lines
     /// and cache them to be emitted later
-    fn process_input(&mut self, line_symbols: impl IntoIterator<Item = StyledGrapheme<'a>>) {
+    #[allow(clippy::too_many_lines)]
+    fn process_input(&mut self, line_symbols: impl IntoIterator<Item = IndexedGrapheme<'a>>) {
         let mut pending_line = self.pending_line_pool.pop().unwrap_or_default();
         let mut line_width = 0;
         let mut word_width = 0;
         let mut whitespace_width = 0;
         let mut non_whitespace_previous = false;
+        let mut break_previous = false;
+        let first_wrapped_line = self.wrapped_lines.len();
 
         self.pending_word.clear();
         self.pending_whitespace.clear();
         pending_line.clear();
 
-        for grapheme in line_symbols {
+        for (index, grapheme) in line_symbols {
             let is_whitespace = grapheme.is_whitespace();
-            let symbol_width = grapheme.symbol.width() as u16;
+            let is_break = !is_whitespace && self.break_set.contains(grapheme.symbol);
+            // soft hyphens are zero width, but are displayed as a hyphen at the end of a line
+            let symbol_width = if is_break && is_soft_hyphen(grapheme.symbol) {
+                1
+            } else {
+                grapheme.symbol.width() as u16
+            };
+
+            // a soft hyphen before whitespace never ends a line with a hyphen
+            if is_whitespace
+                && self
+                    .pending_word
+                    .last()
+                    .is_some_and(|(_, g)| is_soft_hyphen(g.symbol))
+                && self.break_set.has_soft_hyphens()
+            {
+                self.pending_word.pop();
+                word_width -= 1;
+            }
 
             // ignore symbols wider than line limit
             if symbol_width > self.max_line_width {
                 continue;
             }
 
-            let word_found = non_whitespace_previous && is_whitespace;
+            // a word ends before whitespace, or after a break character which isn't followed by
+            // another one
+            let word_found = (non_whitespace_previous && is_whitespace)
+                || (break_previous && !is_whitespace && !is_break);
             // current word would overflow after removing whitespace
             let trimmed_overflow = pending_line.is_empty()
                 && self.trim
@@ -131,7 +180,7 @@ where
                 line_width = 0;
 
                 // remove whitespace up to the end of line
-                while let Some(grapheme) = self.pending_whitespace.front() {
+                while let Some((_, grapheme)) = self.pending_whitespace.front() {
                     let width = grapheme.symbol.width() as u16;
 
                     if width > remaining_width {
@@ -152,13 +201,14 @@ where
             // append symbol to a pending buffer
             if is_whitespace {
                 whitespace_width += symbol_width;
-                self.pending_whitespace.push_back(grapheme);
+                self.pending_whitespace.push_back((index, grapheme));
             } else {
                 word_width += symbol_width;
-                self.pending_word.push(grapheme);
+                self.pending_word.push((index, grapheme));
             }
 
             non_whitespace_previous = !is_whitespace;
+            break_previous = is_break;
         }
 
         // append remaining text parts
@@ -182,12 +232,34 @@ where
         if self.wrapped_lines.is_empty() {
             self.wrapped_lines.push_back(vec![]);
         }
+        if self.break_set.has_soft_hyphens() {
+            self.hyphenate(first_wrapped_line);
+        }
     }
 
-    fn replace_current_line(&mut self, line: Vec<StyledGrapheme<'a>>) {
-        let cache = mem::replace(&mut self.current_line, line);
-        if cache.capacity() > 0 {
-            self.pending_line_pool.push(cache);
+    /// Displays the soft hyphens at the end of the wrapped lines from `first_line` onwards as
+    /// hyphens, and removes the other soft hyphens
+    fn hyphenate(&mut self, first_line: usize) {
+        let last_line = self.wrapped_lines.len() - 1;
+        for (index, line) in self.wrapped_lines.iter_mut().enumerate().skip(first_line) {
+            if index < last_line {
+                if let Some((_, last)) = line.last_mut().filter(|(_, g)| is_soft_hyphen(g.symbol)) {
+                    last.symbol = "-";
+                }
+            }
+            line.retain(|(_, grapheme)| !is_soft_hyphen(grapheme.symbol));
+        }
+    }
+
+    fn replace_current_line(&mut self, mut line: Vec<IndexedGrapheme<'a>>) {
+        self.current_line.clear();
+        self.current_indices.clear();
+        for (index, grapheme) in line.drain(..) {
+            self.current_line.push(grapheme);
+            self.current_indices.push(Some(index));
+        }
+        if line.capacity() > 0 {
+            self.pending_line_pool.push(line);
         }
     }
 }
@@ -195,7 +267,7 @@ where
 impl<'a, O, I> LineComposer<'a> for WordWrapper<'a, O, I>
 where
     O: Iterator<Item = (I, Alignment)>,
-    I: Iterator<Item = StyledGrapheme<'a>>,
+    I: Iterator<Item = IndexedGrapheme<'a>>,
 {
     #[allow(clippy::too_many_lines)]
     fn next_line<'lend>(&'lend mut self) -> Option<WrappedLine<'lend, 'a>> {
@@ -208,12 +280,13 @@ where
             if let Some(line) = self.wrapped_lines.pop_front() {
                 let line_width = line
                     .iter()
-                    .map(|grapheme| grapheme.symbol.width() as u16)
+                    .map(|(_, grapheme)| grapheme.symbol.width() as u16)
                     .sum();
 
                 self.replace_current_line(line);
                 return Some(WrappedLine {
                     line: &self.current_line,
+                    indices: &self.current_indices,
                     width: line_width,
                     alignment: self.current_alignment,
                 });
@@ -227,20 +300,26 @@ where
     }
 }
 
+/// Returns whether the grapheme is a soft hyphen
+fn is_soft_hyphen(symbol: &str) -> bool {
+    symbol.chars().eq([SOFT_HYPHEN])
+}
+
 /// A state machine that truncates overhanging lines.
 #[derive(Debug, Default, Clone)]
 pub struct LineTruncator<'a, O, I>
 where
     // Outer iterator providing the individual lines
     O: Iterator<Item = (I, Alignment)>,
-    // Inner iterator providing the styled symbols of a line Each line consists of an alignment and
-    // a series of symbols
-    I: Iterator<Item = StyledGrapheme<'a>>,
+    // Inner iterator providing the styled symbols of a line along with their index in the line.
+    // Each line consists of an alignment and a series of symbols
+    I: Iterator<Item = IndexedGrapheme<'a>>,
 {
     /// The given, unprocessed lines
     input_lines: O,
     max_line_width: u16,
     current_line: Vec<StyledGrapheme<'a>>,
+    current_indices: Vec<Option<usize>>,
     /// Record the offset to skip render
     horizontal_offset: u16,
 }
@@ -248,7 +327,7 @@ where
 impl<'a, O, I> LineTruncator<'a, O, I>
 where
     O: Iterator<Item = (I, Alignment)>,
-    I: Iterator<Item = StyledGrapheme<'a>>,
+    I: Iterator<Item = IndexedGrapheme<'a>>,
 {
     pub const fn new(lines: O, max_line_width: u16) -> Self {
         Self {
@@ -256,6 +335,7 @@ where
             max_line_width,
             horizontal_offset: 0,
             current_line: vec![],
+            current_indices: vec![],
         }
     }
 
@@ -267,7 +347,7 @@ where
 impl<'a, O, I> LineComposer<'a> for LineTruncator<'a, O, I>
 where
     O: Iterator<Item = (I, Alignment)>,
-    I: Iterator<Item = StyledGrapheme<'a>>,
+    I: Iterator<Item = IndexedGrapheme<'a>>,
 {
     fn next_line<'lend>(&'lend mut self) -> Option<WrappedLine<'lend, 'a>> {
         if self.max_line_width == 0 {
@@ -275,6 +355,7 @@ where
         }
 
         self.current_line.truncate(0);
+        self.current_indices.truncate(0);
         let mut current_line_width = 0;
 
         let mut lines_exhausted = true;
@@ -284,7 +365,7 @@ where
             lines_exhausted = false;
             current_alignment = *alignment;
 
-            for StyledGrapheme { symbol, style } in current_line {
+            for (index, StyledGrapheme { symbol, style }) in current_line {
                 // Ignore characters wider that the total max width.
                 if symbol.width() as u16 > self.max_line_width {
                     continue;
@@ -310,6 +391,7 @@ where
                 };
                 current_line_width += symbol.width() as u16;
                 self.current_line.push(StyledGrapheme { symbol, style });
+                self.current_indices.push(Some(index));
             }
         }
 
@@ -318,6 +400,7 @@ where
         } else {
             Some(WrappedLine {
                 line: &self.current_line,
+                indices: &self.current_indices,
                 width: current_line_width,
                 alignment: current_alignment,
             })
@@ -325,6 +408,192 @@ where
     }
 }
 
+/// A state machine that truncates overhanging lines at a boundary and marks them with an ellipsis.
+#[derive(Debug, Default, Clone)]
+pub struct LineEllipsizer<'a, O, I>
+where
+    // Outer iterator providing the individual lines
+    O: Iterator<Item = (I, Alignment)>,
+    // Inner iterator providing the styled symbols of a line along with their index in the line.
+    // Each line consists of an alignment and a series of symbols
+    I: Iterator<Item = IndexedGrapheme<'a>>,
+{
+    /// The given, unprocessed lines
+    input_lines: O,
+    max_line_width: u16,
+    current_line: Vec<StyledGrapheme<'a>>,
+    current_indices: Vec<Option<usize>>,
+    /// The symbol appended to truncated lines
+    ellipsis: &'a str,
+    /// Where truncated lines may be cut
+    boundary: WordBoundary,
+}
+
+impl<'a, O, I> LineEllipsizer<'a, O, I>
+where
+    O: Iterator<Item = (I, Alignment)>,
+    I: Iterator<Item = IndexedGrapheme<'a>>,
+{
+    pub const fn new(
+        lines: O,
+        max_line_width: u16,
+        ellipsis: &'a str,
+        boundary: WordBoundary,
+    ) -> Self {
+        Self {
+            input_lines: lines,
+            max_line_width,
+            current_line: vec![],
+            current_indices: vec![],
+            ellipsis,
+            boundary,
+        }
+    }
+
+    /// Returns the number of graphemes of the current line to keep when it is truncated so that
+    /// its width is at most `max_width`.
+    fn cut_index(&self, max_width: u16) -> usize {
+        let line = &self.current_line;
+        let mut width = 0;
+        // wide graphemes that do not fit completely are left out
+        let fits = line
+            .iter()
+            .take_while(|grapheme| {
+                width += grapheme.symbol.width() as u16;
+                width <= max_width
+            })
+            .count();
+        let at_word_end =
+            |index: usize| line.get(index).map_or(true, StyledGrapheme::is_whitespace);
+        let word_cut = || {
+            if at_word_end(fits) {
+                return fits;
+            }
+            line[..fits]
+                .iter()
+                .rposition(StyledGrapheme::is_whitespace)
+                .unwrap_or(fits)
+        };
+        match self.boundary {
+            WordBoundary::Grapheme => fits,
+            WordBoundary::Word => word_cut(),
+            WordBoundary::Sentence => line[..fits]
+                .iter()
+                .enumerate()
+                .rev()
+                .find(|(index, grapheme)| {
+                    matches!(grapheme.symbol, "." | "!" | "?") && at_word_end(index + 1)
+                })
+                .map_or_else(word_cut, |(index, _)| index + 1),
+        }
+    }
+}
+
+impl<'a, O, I> LineComposer<'a> for LineEllipsizer<'a, O, I>
+where
+    O: Iterator<Item = (I, Alignment)>,
+    I: Iterator<Item = IndexedGrapheme<'a>>,
+{
+    fn next_line<'lend>(&'lend mut self) -> Option<WrappedLine<'lend, 'a>> {
+        if self.max_line_width == 0 {
+            return None;
+        }
+
+        let (line, alignment) = self.input_lines.next()?;
+        self.current_line.clear();
+        self.current_indices.clear();
+        for (index, grapheme) in line {
+            self.current_line.push(grapheme);
+            self.current_indices.push(Some(index));
+        }
+        let line_width = self
+            .current_line
+            .iter()
+            .map(|grapheme| grapheme.symbol.width() as u16)
+            .fold(0, u16::saturating_add);
+
+        let ellipsis_width = self.ellipsis.width() as u16;
+        let width = if line_width <= self.max_line_width {
+            line_width
+        } else if ellipsis_width > self.max_line_width {
+            // There is no room for the ellipsis, so the line is cut at the last grapheme that fits
+            let cut = self.cut_index(self.max_line_width);
+            self.current_line.truncate(cut);
+            self.current_indices.truncate(cut);
+            self.current_line
+                .iter()
+                .map(|grapheme| grapheme.symbol.width() as u16)
+                .sum()
+        } else {
+            let cut = self.cut_index(self.max_line_width - ellipsis_width);
+            // the ellipsis uses the style of the text it follows
+            let style = self.current_line[cut.saturating_sub(1)].style;
+            self.current_line.truncate(cut);
+            self.current_indices.truncate(cut);
+            while self
+                .current_line
+                .last()
+                .is_some_and(StyledGrapheme::is_whitespace)
+            {
+                self.current_line.pop();
+                self.current_indices.pop();
+            }
+            self.current_line
+                .push(StyledGrapheme::new(self.ellipsis, style));
+            self.current_indices.push(None);
+            self.current_line
+                .iter()
+                .map(|grapheme| grapheme.symbol.width() as u16)
+                .sum()
+        };
+
+        Some(WrappedLine {
+            line: &self.current_line,
+            indices: &self.current_indices,
+            width,
+            alignment,
+        })
+    }
+}
+
+/// The default number of columns between two tab stops, matching the convention of terminals
+pub const DEFAULT_TAB_SIZE: u16 = 8;
+
+/// Returns the number of columns of a tab at `column`, which extends to the next tab stop
+///
+/// A tab size of 0 removes the tabs.
+pub fn tab_width(column: usize, tab_size: u16) -> usize {
+    let tab_size = usize::from(tab_size);
+    if tab_size == 0 {
+        return 0;
+    }
+    tab_size - column % tab_size
+}
+
+/// Replaces the tabs of a line with spaces up to the next tab stop
+///
+/// The columns are counted from the start of the line, so the tab stops don't depend on how the
+/// line is wrapped afterwards. The spaces have the style of the tab they replace. Each grapheme is
+/// yielded along with the index of the grapheme of the line it comes from, which the spaces of a
+/// tab share.
+pub fn expand_tabs<'a>(
+    graphemes: impl Iterator<Item = StyledGrapheme<'a>>,
+    tab_size: u16,
+) -> impl Iterator<Item = (usize, StyledGrapheme<'a>)> {
+    let mut column = 0;
+    graphemes.enumerate().flat_map(move |(index, grapheme)| {
+        let (grapheme, count) = if grapheme.symbol == "\t" {
+            let width = tab_width(column, tab_size);
+            column += width;
+            (StyledGrapheme::new(" ", grapheme.style), width)
+        } else {
+            column += grapheme.symbol.width();
+            (grapheme, 1)
+        };
+        iter::repeat((index, grapheme)).take(count)
+    })
+}
+
 /// This function will return a str slice which start at specified offset.
 /// As src is a unicode str, start offset has to be calculated with each character.
 fn trim_offset(src: &str, mut offset: usize) -> &str {
@@ -344,9 +613,11 @@ fn trim_offset(src: &str, mut offset: usize) -> &str {
 
 #[cfg(test)]
 mod test {
+    use rstest::rstest;
+
     use super::*;
     use crate::{
-        style::Style,
+        style::{Style, Stylize},
         text::{Line, Text},
     };
 
@@ -354,6 +625,7 @@ mod test {
     enum Composer {
         WordWrapper { trim: bool },
         LineTruncator,
+        LineEllipsizer { boundary: WordBoundary },
     }
 
     fn run_composer<'a>(
@@ -365,7 +637,8 @@ mod test {
         let styled_lines = text.iter().map(|line| {
             (
                 line.iter()
-                    .flat_map(|span| span.styled_graphemes(Style::default())),
+                    .flat_map(|span| span.styled_graphemes(Style::default()))
+                    .enumerate(),
                 line.alignment.unwrap_or(Alignment::Left),
             )
         });
@@ -375,6 +648,12 @@ mod test {
                 Box::new(WordWrapper::new(styled_lines, text_area_width, trim))
             }
             Composer::LineTruncator => Box::new(LineTruncator::new(styled_lines, text_area_width)),
+            Composer::LineEllipsizer { boundary } => Box::new(LineEllipsizer::new(
+                styled_lines,
+                text_area_width,
+                "…",
+                boundary,
+            )),
         };
         let mut lines = vec![];
         let mut widths = vec![];
@@ -383,6 +662,7 @@ mod test {
             line: styled,
             width,
             alignment,
+            ..
         }) = composer.next_line()
         {
             let line = styled
@@ -718,4 +998,207 @@ mod test {
         let (word_wrapper, _, _) = run_composer(Composer::WordWrapper { trim: true }, line, width);
         assert_eq!(word_wrapper, ["foo", "bar"]);
     }
+
+    #[test]
+    fn line_composer_ellipsizer_short_lines() {
+        let text = "abc\nde f";
+        for boundary in [
+            WordBoundary::Grapheme,
+            WordBoundary::Word,
+            WordBoundary::Sentence,
+        ] {
+            let (lines, widths, _) = run_composer(Composer::LineEllipsizer { boundary }, text, 4);
+            assert_eq!(lines, ["abc", "de f"]);
+            assert_eq!(widths, [3, 4]);
+        }
+    }
+
+    #[test]
+    fn line_composer_ellipsizer_grapheme_boundary() {
+        let boundary = WordBoundary::Grapheme;
+        let text = "The quick brown fox";
+        let (lines, widths, _) = run_composer(Composer::LineEllipsizer { boundary }, text, 12);
+        assert_eq!(lines, ["The quick b…"]);
+        assert_eq!(widths, [12]);
+    }
+
+    #[test]
+    fn line_composer_ellipsizer_word_boundary() {
+        let boundary = WordBoundary::Word;
+        let text = "The quick brown fox";
+        let (lines, widths, _) = run_composer(Composer::LineEllipsizer { boundary }, text, 12);
+        assert_eq!(lines, ["The quick…"]);
+        assert_eq!(widths, [10]);
+
+        // the cut is at the end of a word, and the whitespace before the ellipsis is removed
+        let (lines, _, _) = run_composer(Composer::LineEllipsizer { boundary }, text, 11);
+        assert_eq!(lines, ["The quick…"]);
+
+        // a single word that is too long is cut at the last grapheme that fits
+        let (lines, _, _) = run_composer(Composer::LineEllipsizer { boundary }, "abcdefghij", 5);
+        assert_eq!(lines, ["abcd…"]);
+    }
+
+    #[test]
+    fn line_composer_ellipsizer_sentence_boundary() {
+        let boundary = WordBoundary::Sentence;
+        let text = "Hi there. How are you?";
+        let (lines, widths, _) = run_composer(Composer::LineEllipsizer { boundary }, text, 15);
+        assert_eq!(lines, ["Hi there.…"]);
+        assert_eq!(widths, [10]);
+
+        // without a sentence end, the line is cut at a word boundary
+        let text = "No sentence end here";
+        let (lines, _, _) = run_composer(Composer::LineEllipsizer { boundary }, text, 12);
+        assert_eq!(lines, ["No sentence…"]);
+
+        // a dot that does not end a sentence is not a boundary
+        let text = "Version 1.2 is out";
+        let (lines, _, _) = run_composer(Composer::LineEllipsizer { boundary }, text, 14);
+        assert_eq!(lines, ["Version 1.2…"]);
+    }
+
+    #[test]
+    fn line_composer_ellipsizer_double_width_chars() {
+        let boundary = WordBoundary::Word;
+        let text = "コンピュータ";
+        let (lines, widths, _) = run_composer(Composer::LineEllipsizer { boundary }, text, 6);
+        assert_eq!(lines, ["コン…"]);
+        assert_eq!(widths, [5]);
+    }
+
+    #[test]
+    fn line_composer_ellipsizer_only_fits_ellipsis() {
+        let boundary = WordBoundary::Word;
+        let (lines, widths, _) = run_composer(Composer::LineEllipsizer { boundary }, "abc", 1);
+        assert_eq!(lines, ["…"]);
+        assert_eq!(widths, [1]);
+    }
+
+    #[rstest]
+    #[case::start_of_line("\tb", 4, "    b")]
+    #[case::middle_of_tab_stop("a\tb", 4, "a   b")]
+    #[case::end_of_tab_stop("abcd\te", 4, "abcd    e")]
+    #[case::consecutive_tabs("a\t\tb", 4, "a       b")]
+    #[case::default_tab_size("a\tb", DEFAULT_TAB_SIZE, "a       b")]
+    #[case::wide_chars("日\tb", 4, "日  b")]
+    #[case::zero_tab_size("a\tb", 0, "ab")]
+    fn expand_tabs(#[case] text: &str, #[case] tab_size: u16, #[case] expected: &str) {
+        let line = Line::from(text);
+        let expanded: String =
+            super::expand_tabs(line.styled_graphemes(Style::default()), tab_size)
+                .map(|(_, grapheme)| grapheme.symbol)
+                .collect();
+        assert_eq!(expanded, expected);
+    }
+
+    #[test]
+    fn expand_tabs_keeps_style() {
+        let line = Line::from(vec!["a".into(), "\t".red(), "b".into()]);
+        let expanded: Vec<_> =
+            super::expand_tabs(line.styled_graphemes(Style::default()), 4).collect();
+        assert_eq!(
+            expanded,
+            [
+                (0, StyledGrapheme::new("a", Style::default())),
+                (1, StyledGrapheme::new(" ", Style::new().red())),
+                (1, StyledGrapheme::new(" ", Style::new().red())),
+                (1, StyledGrapheme::new(" ", Style::new().red())),
+                (2, StyledGrapheme::new("b", Style::default())),
+            ]
+        );
+    }
+
+    /// Returns the indices of the graphemes of each line laid out by the composer
+    fn composed_indices(which: Composer, text: &str, width: u16) -> Vec<Vec<Option<usize>>> {
+        let line = Line::from(text);
+        let graphemes = line.styled_graphemes(Style::default()).enumerate();
+        let lines = iter::once((graphemes, Alignment::Left));
+        let mut composer: Box<dyn LineComposer> = match which {
+            Composer::WordWrapper { trim } => Box::new(
+                WordWrapper::new(lines, width, trim)
+                    .with_break_set(BreakSet::default().soft_hyphens(true)),
+            ),
+            Composer::LineTruncator => Box::new(LineTruncator::new(lines, width)),
+            Composer::LineEllipsizer { boundary } => {
+                Box::new(LineEllipsizer::new(lines, width, "…", boundary))
+            }
+        };
+        let mut indices = vec![];
+        while let Some(WrappedLine {
+            line, indices: i, ..
+        }) = composer.next_line()
+        {
+            assert_eq!(line.len(), i.len());
+            indices.push(i.to_vec());
+        }
+        indices
+    }
+
+    #[rstest]
+    #[case::trimmed_whitespace(Composer::WordWrapper { trim: true }, "ab  cd", 3, &[&[Some(0), Some(1)][..], &[Some(4), Some(5)]])]
+    #[case::hyphen(Composer::WordWrapper { trim: true }, "ab\u{ad}cd", 3, &[&[Some(0), Some(1), Some(2)][..], &[Some(3), Some(4)]])]
+    #[case::removed_soft_hyphen(Composer::WordWrapper { trim: true }, "a\u{ad}b", 5, &[&[Some(0), Some(2)][..]])]
+    #[case::truncated(Composer::LineTruncator, "abcd", 2, &[&[Some(0), Some(1)][..]])]
+    #[case::ellipsis(Composer::LineEllipsizer { boundary: WordBoundary::Grapheme }, "abcd", 3, &[&[Some(0), Some(1), None][..]])]
+    fn line_composer_indices(
+        #[case] which: Composer,
+        #[case] text: &str,
+        #[case] width: u16,
+        #[case] expected: &[&[Option<usize>]],
+    ) {
+        assert_eq!(composed_indices(which, text, width), expected);
+    }
+
+    /// Wraps the text at the given width, breaking words at the characters of `break_set`
+    fn wrap_with_break_set(text: &str, width: u16, break_set: BreakSet) -> Vec<String> {
+        let line = Line::from(text);
+        let graphemes = line.styled_graphemes(Style::default()).enumerate();
+        let mut composer = WordWrapper::new(iter::once((graphemes, Alignment::Left)), width, true)
+            .with_break_set(break_set);
+        let mut lines = vec![];
+        while let Some(WrappedLine { line, width, .. }) = composer.next_line() {
+            let line: String = line.iter().map(|grapheme| grapheme.symbol).collect();
+            assert_eq!(usize::from(width), line.width());
+            lines.push(line);
+        }
+        lines
+    }
+
+    #[rstest]
+    #[case::whitespace_only("ab/cdef", 5, "", &["ab/cd", "ef"])]
+    #[case::slash("/usr/local/share", 8, "/", &["/usr/", "local/", "share"])]
+    #[case::hyphen("well-known-fact", 11, "-", &["well-known-", "fact"])]
+    #[case::several_characters("snake_case/path", 8, "/_", &["snake_", "case/", "path"])]
+    #[case::consecutive_break_characters("ab--cd", 4, "-", &["ab--", "cd"])]
+    #[case::before_whitespace("ab- cd", 3, "-", &["ab-", "cd"])]
+    #[case::long_word("abcdefgh/ij", 4, "/", &["abcd", "efgh", "/ij"])]
+    fn word_wrapper_break_characters(
+        #[case] text: &str,
+        #[case] width: u16,
+        #[case] break_chars: &str,
+        #[case] expected: &[&str],
+    ) {
+        let lines = wrap_with_break_set(text, width, BreakSet::new(break_chars.chars()));
+        assert_eq!(lines, expected);
+    }
+
+    #[rstest]
+    #[case::hyphenated("hy\u{ad}phen\u{ad}ation", 7, &["hy-", "phen-", "ation"])]
+    #[case::fits("hy\u{ad}phen", 10, &["hyphen"])]
+    #[case::trailing("ab\u{ad} cd\u{ad}", 3, &["ab", "cd"])]
+    fn word_wrapper_soft_hyphens(
+        #[case] text: &str,
+        #[case] width: u16,
+        #[case] expected: &[&str],
+    ) {
+        let lines = wrap_with_break_set(text, width, BreakSet::default().soft_hyphens(true));
+        assert_eq!(lines, expected);
+    }
+
+    #[test]
+    fn word_wrapper_keeps_soft_hyphens_when_disabled() {
+        let lines = wrap_with_break_set("ab\u{ad}cd", 10, BreakSet::default());
+        assert_eq!(lines, ["ab\u{ad}cd"]);
+    }
 }
@@ -0,0 +1,247 @@
+/// A scroll offset into some content displayed in a viewport
+///
+/// The offset is the index of the first line (or column) of the content that is visible in the
+/// viewport. `ScrollOffset` provides the clamping arithmetic needed by key handlers, so that
+/// scrolling never goes before the start of the content or past the point where the last line of
+/// the content is at the bottom of the viewport.
+///
+/// The methods that move the offset towards the end of the content take the maximum offset, which
+/// can be computed from the lengths of the content and of the viewport with
+/// [`ScrollOffset::max_offset`]. The lengths are in whatever unit the widget scrolls by (lines,
+/// rows, items, columns, ...).
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::widgets::ScrollOffset;
+///
+/// let content_length = 100;
+/// let viewport_length = 10;
+/// let max = ScrollOffset::max_offset(content_length, viewport_length);
+///
+/// let mut offset = ScrollOffset::default();
+/// offset.scroll_down(3, max);
+/// assert_eq!(offset.get(), 3);
+/// offset.page_down(viewport_length, max);
+/// assert_eq!(offset.get(), 13);
+/// offset.scroll_to_bottom(max);
+/// assert_eq!(offset.get(), 90);
+/// offset.page_down(viewport_length, max);
+/// assert_eq!(offset.get(), 90);
+/// offset.page_up(viewport_length);
+/// assert_eq!(offset.get(), 80);
+/// ```
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ScrollOffset {
+    offset: usize,
+}
+
+impl ScrollOffset {
+    /// Creates a new `ScrollOffset` at the given offset
+    pub const fn new(offset: usize) -> Self {
+        Self { offset }
+    }
+
+    /// Returns the largest offset at which the viewport is still filled with content
+    ///
+    /// This is the offset at which the last line of the content is displayed at the bottom of the
+    /// viewport. It is zero when the content fits in the viewport.
+    pub const fn max_offset(content_length: usize, viewport_length: usize) -> usize {
+        content_length.saturating_sub(viewport_length)
+    }
+
+    /// Returns the offset
+    pub const fn get(self) -> usize {
+        self.offset
+    }
+
+    /// Sets the offset, clamped to `max`
+    pub fn set(&mut self, offset: usize, max: usize) {
+        self.offset = offset.min(max);
+    }
+
+    /// Clamps the offset to `max`
+    ///
+    /// This is useful when the content shrinks or the viewport grows, e.g. on resize.
+    pub fn clamp_to(&mut self, max: usize) {
+        self.offset = self.offset.min(max);
+    }
+
+    /// Scrolls towards the start of the content by `amount`, stopping at the start
+    pub fn scroll_up(&mut self, amount: usize) {
+        self.offset = self.offset.saturating_sub(amount);
+    }
+
+    /// Scrolls towards the end of the content by `amount`, stopping at `max`
+    pub fn scroll_down(&mut self, amount: usize, max: usize) {
+        self.offset = self.offset.saturating_add(amount).min(max);
+    }
+
+    /// Scrolls towards the start of the content by a page
+    ///
+    /// A page is the length of the viewport, so that the line that was at the top of the viewport
+    /// is displayed just below the bottom of the viewport. An empty viewport scrolls by one line.
+    pub fn page_up(&mut self, viewport_length: usize) {
+        self.scroll_up(viewport_length.max(1));
+    }
+
+    /// Scrolls towards the end of the content by a page, stopping at `max`
+    ///
+    /// A page is the length of the viewport, so that the line that was just below the bottom of the
+    /// viewport is displayed at the top of the viewport. An empty viewport scrolls by one line.
+    pub fn page_down(&mut self, viewport_length: usize, max: usize) {
+        self.scroll_down(viewport_length.max(1), max);
+    }
+
+    /// Scrolls to the start of the content
+    pub fn scroll_to_top(&mut self) {
+        self.offset = 0;
+    }
+
+    /// Scrolls to the end of the content
+    pub fn scroll_to_bottom(&mut self, max: usize) {
+        self.offset = max;
+    }
+}
+
+impl From<usize> for ScrollOffset {
+    fn from(offset: usize) -> Self {
+        Self::new(offset)
+    }
+}
+
+impl From<ScrollOffset> for usize {
+    fn from(offset: ScrollOffset) -> Self {
+        offset.get()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case::empty_content(0, 10, 0)]
+    #[case::content_fits(5, 10, 0)]
+    #[case::content_fills_viewport(10, 10, 0)]
+    #[case::one_more_than_viewport(11, 10, 1)]
+    #[case::long_content(100, 10, 90)]
+    #[case::empty_viewport(100, 0, 100)]
+    fn max_offset(
+        #[case] content_length: usize,
+        #[case] viewport_length: usize,
+        #[case] expected: usize,
+    ) {
+        assert_eq!(
+            ScrollOffset::max_offset(content_length, viewport_length),
+            expected
+        );
+    }
+
+    #[rstest]
+    #[case::within_bounds(5, 3, 2)]
+    #[case::to_start(3, 3, 0)]
+    #[case::past_start(2, 3, 0)]
+    #[case::at_start(0, 1, 0)]
+    #[case::max_amount(5, usize::MAX, 0)]
+    fn scroll_up(#[case] offset: usize, #[case] amount: usize, #[case] expected: usize) {
+        let mut offset = ScrollOffset::new(offset);
+        offset.scroll_up(amount);
+        assert_eq!(offset.get(), expected);
+    }
+
+    #[rstest]
+    #[case::within_bounds(2, 3, 10, 5)]
+    #[case::to_max(7, 3, 10, 10)]
+    #[case::past_max(8, 3, 10, 10)]
+    #[case::at_max(10, 1, 10, 10)]
+    #[case::beyond_max(15, 1, 10, 10)]
+    #[case::content_fits(0, 1, 0, 0)]
+    #[case::max_amount(5, usize::MAX, 10, 10)]
+    fn scroll_down(
+        #[case] offset: usize,
+        #[case] amount: usize,
+        #[case] max: usize,
+        #[case] expected: usize,
+    ) {
+        let mut offset = ScrollOffset::new(offset);
+        offset.scroll_down(amount, max);
+        assert_eq!(offset.get(), expected);
+    }
+
+    #[rstest]
+    #[case::full_page(25, 10, 15)]
+    #[case::partial_page(5, 10, 0)]
+    #[case::at_start(0, 10, 0)]
+    #[case::empty_viewport(5, 0, 4)]
+    fn page_up(#[case] offset: usize, #[case] viewport_length: usize, #[case] expected: usize) {
+        let mut offset = ScrollOffset::new(offset);
+        offset.page_up(viewport_length);
+        assert_eq!(offset.get(), expected);
+    }
+
+    #[rstest]
+    #[case::full_page(0, 10, 90, 10)]
+    #[case::partial_page(85, 10, 90, 90)]
+    #[case::at_max(90, 10, 90, 90)]
+    #[case::content_fits(0, 10, 0, 0)]
+    #[case::empty_viewport(5, 0, 90, 6)]
+    fn page_down(
+        #[case] offset: usize,
+        #[case] viewport_length: usize,
+        #[case] max: usize,
+        #[case] expected: usize,
+    ) {
+        let mut offset = ScrollOffset::new(offset);
+        offset.page_down(viewport_length, max);
+        assert_eq!(offset.get(), expected);
+    }
+
+    #[test]
+    fn page_down_then_page_up_returns_to_start() {
+        let max = ScrollOffset::max_offset(35, 10);
+        let mut offset = ScrollOffset::default();
+        let pages: Vec<_> = (0..4)
+            .map(|_| {
+                offset.page_down(10, max);
+                offset.get()
+            })
+            .collect();
+        assert_eq!(pages, [10, 20, 25, 25]);
+        offset.page_up(10);
+        offset.page_up(10);
+        offset.page_up(10);
+        assert_eq!(offset.get(), 0);
+    }
+
+    #[test]
+    fn set_and_clamp() {
+        let mut offset = ScrollOffset::default();
+        offset.set(20, 10);
+        assert_eq!(offset.get(), 10);
+        offset.set(5, 10);
+        assert_eq!(offset.get(), 5);
+        offset.clamp_to(3);
+        assert_eq!(offset.get(), 3);
+        offset.clamp_to(10);
+        assert_eq!(offset.get(), 3);
+    }
+
+    #[test]
+    fn scroll_to_top_and_bottom() {
+        let mut offset = ScrollOffset::new(5);
+        offset.scroll_to_bottom(10);
+        assert_eq!(offset.get(), 10);
+        offset.scroll_to_top();
+        assert_eq!(offset.get(), 0);
+    }
+
+    #[test]
+    fn conversions() {
+        assert_eq!(ScrollOffset::from(4), ScrollOffset::new(4));
+        assert_eq!(usize::from(ScrollOffset::new(4)), 4);
+    }
+}
@@ -0,0 +1,395 @@
+use unicode_width::UnicodeWidthStr;
+
+use crate::{
+    layout::{Position, Size},
+    prelude::*,
+    widgets::{Scrollbar, ScrollbarOrientation, ScrollbarState, StatefulWidgetRef},
+};
+
+/// A widget that displays a window of a larger virtual buffer.
+///
+/// Widgets are rendered into the internal buffer of the `ScrollView` with
+/// [`ScrollView::render_widget`], using coordinates relative to the top left corner of the
+/// content. When the `ScrollView` is rendered, the part of the content at the offset of the
+/// [`ScrollViewState`] is copied to the target area. The offset is clamped so that the viewport
+/// never extends past the content.
+///
+/// A vertical and a horizontal [`Scrollbar`] are displayed when the content is taller or wider
+/// than the viewport. This can be disabled with [`ScrollView::scrollbars`].
+///
+/// Multi-width symbols which are only partially visible at the edges of the viewport are replaced
+/// by blank cells rather than being cut in half.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::{
+///     layout::Size,
+///     prelude::*,
+///     widgets::{Paragraph, ScrollView, ScrollViewState},
+/// };
+///
+/// # fn ui(frame: &mut Frame) {
+/// // This should be stored outside of the function in your application state.
+/// let mut state = ScrollViewState::default();
+///
+/// let mut scroll_view = ScrollView::new(Size::new(100, 50));
+/// scroll_view.render_widget(Paragraph::new("Hello"), Rect::new(0, 0, 10, 1));
+/// scroll_view.render_widget(Paragraph::new("World"), Rect::new(90, 49, 10, 1));
+/// frame.render_stateful_widget(scroll_view, frame.area(), &mut state);
+/// # }
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct ScrollView {
+    buf: Buffer,
+    scrollbars: bool,
+}
+
+/// State of the [`ScrollView`] widget
+///
+/// The state holds the offset of the top left corner of the viewport in the content of the
+/// [`ScrollView`]. The offset can be set beyond the content (e.g. with
+/// [`ScrollViewState::scroll_to_bottom`]), in which case it is clamped when the [`ScrollView`] is
+/// rendered.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::{layout::Position, widgets::ScrollViewState};
+///
+/// let mut state = ScrollViewState::default().with_offset(Position::new(0, 10));
+/// state.scroll_down();
+/// assert_eq!(state.offset(), Position::new(0, 11));
+/// ```
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ScrollViewState {
+    pub(crate) offset: Position,
+}
+
+impl ScrollView {
+    /// Creates a new `ScrollView` with content of the given size.
+    pub fn new(size: Size) -> Self {
+        Self {
+            buf: Buffer::empty(Rect::new(0, 0, size.width, size.height)),
+            scrollbars: true,
+        }
+    }
+
+    /// Sets whether the scrollbars are displayed when the content is larger than the viewport.
+    ///
+    /// Scrollbars are displayed by default.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn scrollbars(mut self, scrollbars: bool) -> Self {
+        self.scrollbars = scrollbars;
+        self
+    }
+
+    /// Returns the size of the content.
+    pub const fn size(&self) -> Size {
+        self.buf.area.as_size()
+    }
+
+    /// Returns the area of the content, which starts at `(0, 0)`.
+    pub const fn area(&self) -> Rect {
+        self.buf.area
+    }
+
+    /// Returns the internal buffer holding the content.
+    pub const fn buf(&self) -> &Buffer {
+        &self.buf
+    }
+
+    /// Returns a mutable reference to the internal buffer holding the content.
+    pub fn buf_mut(&mut self) -> &mut Buffer {
+        &mut self.buf
+    }
+
+    /// Renders a widget into the content at the given area.
+    ///
+    /// The area is relative to the top left corner of the content.
+    pub fn render_widget<W: Widget>(&mut self, widget: W, area: Rect) {
+        widget.render(area, &mut self.buf);
+    }
+
+    /// Renders a stateful widget into the content at the given area.
+    ///
+    /// The area is relative to the top left corner of the content.
+    pub fn render_stateful_widget<W: StatefulWidget>(
+        &mut self,
+        widget: W,
+        area: Rect,
+        state: &mut W::State,
+    ) {
+        widget.render(area, &mut self.buf, state);
+    }
+
+    /// Returns the area of the viewport and whether the vertical and horizontal scrollbars are
+    /// visible.
+    fn layout(&self, area: Rect) -> (Rect, bool, bool) {
+        let size = self.size();
+        let mut viewport = area;
+        let (mut vertical, mut horizontal) = (false, false);
+        if self.scrollbars {
+            // showing one scrollbar reduces the viewport, which may require the other one
+            for _ in 0..2 {
+                if !vertical && size.height > viewport.height {
+                    vertical = true;
+                    viewport.width = viewport.width.saturating_sub(1);
+                }
+                if !horizontal && size.width > viewport.width {
+                    horizontal = true;
+                    viewport.height = viewport.height.saturating_sub(1);
+                }
+            }
+        }
+        (viewport, vertical, horizontal)
+    }
+
+    /// Copies the visible window of the content to the viewport, blanking the multi-width symbols
+    /// that straddle its edges.
+    fn render_content(&self, offset: Position, viewport: Rect, buf: &mut Buffer) {
+        let size = self.size();
+        let right = size.width.min(offset.x.saturating_add(viewport.width));
+        let bottom = size.height.min(offset.y.saturating_add(viewport.height));
+        for y in offset.y..bottom {
+            // the first column which is not hidden by a multi-width symbol on its left
+            let mut visible_from = 0;
+            for x in 0..right {
+                let cell = &self.buf[(x, y)];
+                let end = x.saturating_add(cell.symbol().width() as u16);
+                if x >= offset.x {
+                    let target = &mut buf[(viewport.x + x - offset.x, viewport.y + y - offset.y)];
+                    *target = cell.clone();
+                    if x < visible_from || end > right {
+                        target.set_symbol(" ");
+                    }
+                }
+                visible_from = visible_from.max(end);
+            }
+        }
+    }
+}
+
+impl StatefulWidget for ScrollView {
+    type State = ScrollViewState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        StatefulWidgetRef::render_ref(&self, area, buf, state);
+    }
+}
+
+// Note: remove this when StatefulWidgetRef is stabilized and replace with the blanket impl
+impl StatefulWidget for &ScrollView {
+    type State = ScrollViewState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        StatefulWidgetRef::render_ref(self, area, buf, state);
+    }
+}
+
+impl StatefulWidgetRef for ScrollView {
+    type State = ScrollViewState;
+
+    fn render_ref(&self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        let area = area.intersection(buf.area);
+        if area.is_empty() {
+            return;
+        }
+        let (viewport, vertical, horizontal) = self.layout(area);
+        let size = self.size();
+        let max_offset = Position::new(
+            size.width.saturating_sub(viewport.width),
+            size.height.saturating_sub(viewport.height),
+        );
+        state.offset.x = state.offset.x.min(max_offset.x);
+        state.offset.y = state.offset.y.min(max_offset.y);
+
+        self.render_content(state.offset, viewport, buf);
+
+        if vertical {
+            let scrollbar_area = Rect {
+                height: viewport.height,
+                ..area
+            };
+            let mut scrollbar_state = ScrollbarState::new(usize::from(max_offset.y) + 1)
+                .position(usize::from(state.offset.y))
+                .viewport_content_length(usize::from(viewport.height));
+            Scrollbar::new(ScrollbarOrientation::VerticalRight).render(
+                scrollbar_area,
+                buf,
+                &mut scrollbar_state,
+            );
+        }
+        if horizontal {
+            let scrollbar_area = Rect {
+                width: viewport.width,
+                ..area
+            };
+            let mut scrollbar_state = ScrollbarState::new(usize::from(max_offset.x) + 1)
+                .position(usize::from(state.offset.x))
+                .viewport_content_length(usize::from(viewport.width));
+            Scrollbar::new(ScrollbarOrientation::HorizontalBottom).render(
+                scrollbar_area,
+                buf,
+                &mut scrollbar_state,
+            );
+        }
+    }
+}
+
+impl ScrollViewState {
+    /// Creates a new `ScrollViewState` with the given offset.
+    pub const fn new(offset: Position) -> Self {
+        Self { offset }
+    }
+
+    /// Sets the offset of the viewport in the content.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn with_offset(mut self, offset: Position) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    /// Returns the offset of the viewport in the content.
+    pub const fn offset(self) -> Position {
+        self.offset
+    }
+
+    /// Sets the offset of the viewport in the content.
+    pub fn set_offset(&mut self, offset: Position) {
+        self.offset = offset;
+    }
+
+    /// Scrolls the viewport up by one row.
+    pub fn scroll_up(&mut self) {
+        self.offset.y = self.offset.y.saturating_sub(1);
+    }
+
+    /// Scrolls the viewport down by one row.
+    ///
+    /// The offset is clamped to the content when the [`ScrollView`] is rendered.
+    pub fn scroll_down(&mut self) {
+        self.offset.y = self.offset.y.saturating_add(1);
+    }
+
+    /// Scrolls the viewport left by one column.
+    pub fn scroll_left(&mut self) {
+        self.offset.x = self.offset.x.saturating_sub(1);
+    }
+
+    /// Scrolls the viewport right by one column.
+    ///
+    /// The offset is clamped to the content when the [`ScrollView`] is rendered.
+    pub fn scroll_right(&mut self) {
+        self.offset.x = self.offset.x.saturating_add(1);
+    }
+
+    /// Scrolls the viewport to the top of the content.
+    pub fn scroll_to_top(&mut self) {
+        self.offset.y = 0;
+    }
+
+    /// Scrolls the viewport to the bottom of the content.
+    ///
+    /// The offset is clamped to the content when the [`ScrollView`] is rendered.
+    pub fn scroll_to_bottom(&mut self) {
+        self.offset.y = u16::MAX;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    fn scroll_view() -> ScrollView {
+        let mut scroll_view = ScrollView::new(Size::new(6, 4)).scrollbars(false);
+        scroll_view.render_widget(
+            Text::from(vec![
+                "abcdef".into(),
+                "ghijkl".into(),
+                "mnopqr".into(),
+                "stuvwx".into(),
+            ]),
+            Rect::new(0, 0, 6, 4),
+        );
+        scroll_view
+    }
+
+    #[rstest]
+    #[case::origin(Position::new(0, 0), ["abcd", "ghij"])]
+    #[case::offset(Position::new(1, 2), ["nopq", "tuvw"])]
+    #[case::clamped(Position::new(10, 10), ["opqr", "uvwx"])]
+    fn render(#[case] offset: Position, #[case] expected: [&str; 2]) {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 4, 2));
+        let mut state = ScrollViewState::new(offset);
+        scroll_view().render(buf.area, &mut buf, &mut state);
+        assert_eq!(buf, Buffer::with_lines(expected));
+    }
+
+    #[test]
+    fn render_clamps_offset() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 4, 2));
+        let mut state = ScrollViewState::default();
+        state.scroll_to_bottom();
+        state.scroll_right();
+        state.scroll_right();
+        state.scroll_right();
+        scroll_view().render(buf.area, &mut buf, &mut state);
+        assert_eq!(state.offset(), Position::new(2, 2));
+    }
+
+    #[test]
+    fn render_content_smaller_than_area() {
+        let mut buf = Buffer::with_lines(["xxxxxxx"; 5]);
+        let mut state = ScrollViewState::new(Position::new(3, 3));
+        scroll_view().render(buf.area, &mut buf, &mut state);
+        let expected = Buffer::with_lines(["abcdefx", "ghijklx", "mnopqrx", "stuvwxx", "xxxxxxx"]);
+        assert_eq!(buf, expected);
+        assert_eq!(state.offset(), Position::new(0, 0));
+    }
+
+    #[test]
+    fn render_with_scrollbars() {
+        let mut scroll_view = scroll_view().scrollbars(true);
+        scroll_view.buf_mut()[(0, 0)].set_symbol("A");
+        let mut buf = Buffer::empty(Rect::new(0, 0, 5, 4));
+        let mut state = ScrollViewState::default();
+        scroll_view.render(buf.area, &mut buf, &mut state);
+        let expected = Buffer::with_lines(["Abcd▲", "ghij█", "mnop▼", "◄█═► "]);
+        assert_eq!(buf, expected);
+    }
+
+    #[rstest]
+    #[case::left_edge(Position::new(1, 0), [" b好"])]
+    #[case::right_edge(Position::new(0, 0), ["你b "])]
+    fn render_blanks_clipped_wide_symbols(#[case] offset: Position, #[case] expected: [&str; 1]) {
+        let mut scroll_view = ScrollView::new(Size::new(7, 1)).scrollbars(false);
+        scroll_view.render_widget(Line::from("你b好cd"), Rect::new(0, 0, 7, 1));
+        let mut buf = Buffer::empty(Rect::new(0, 0, 4, 1));
+        let mut state = ScrollViewState::new(offset);
+        scroll_view.render(buf.area, &mut buf, &mut state);
+        assert_eq!(buf, Buffer::with_lines(expected));
+    }
+
+    #[test]
+    fn scroll() {
+        let mut state = ScrollViewState::default();
+        state.scroll_up();
+        state.scroll_left();
+        assert_eq!(state.offset(), Position::new(0, 0));
+        state.scroll_down();
+        state.scroll_right();
+        assert_eq!(state.offset(), Position::new(1, 1));
+        state.scroll_to_bottom();
+        assert_eq!(state.offset(), Position::new(1, u16::MAX));
+        state.scroll_to_top();
+        assert_eq!(state.offset(), Position::new(1, 0));
+    }
+}
@@ -14,6 +14,7 @@ use unicode_width::UnicodeWidthStr;
 use crate::{
     prelude::*,
     symbols::scrollbar::{Set, DOUBLE_HORIZONTAL, DOUBLE_VERTICAL},
+    widgets::StatefulWidgetRef,
 };
 
 /// A widget to display a scrollbar
@@ -85,6 +86,8 @@ pub struct Scrollbar<'a> {
     begin_style: Style,
     end_symbol: Option<&'a str>,
     end_style: Style,
+    auto_hide: bool,
+    min_thumb_length: u16,
 }
 
 /// This is the position of the scrollbar around a given area.
@@ -195,6 +198,8 @@ impl<'a> Scrollbar<'a> {
             begin_style: Style::new(),
             end_symbol: Some(symbols.end),
             end_style: Style::new(),
+            auto_hide: false,
+            min_thumb_length: 1,
         }
     }
 
@@ -289,6 +294,9 @@ impl<'a> Scrollbar<'a> {
     ///
     /// See [`Scrollbar`] for a visual example of what this represents.
     ///
+    /// Pass `None` to hide this symbol and let the track extend to the edge of the area. This is
+    /// independent of the symbol at the end of the scrollbar.
+    ///
     /// This is a fluent setter method which must be chained or used as it consumes self
     #[must_use = "method moves the value of self and returns the modified value"]
     pub const fn begin_symbol(mut self, begin_symbol: Option<&'a str>) -> Self {
@@ -314,6 +322,9 @@ impl<'a> Scrollbar<'a> {
     ///
     /// See [`Scrollbar`] for a visual example of what this represents.
     ///
+    /// Pass `None` to hide this symbol and let the track extend to the edge of the area. This is
+    /// independent of the symbol at the beginning of the scrollbar.
+    ///
     /// This is a fluent setter method which must be chained or used as it consumes self
     #[must_use = "method moves the value of self and returns the modified value"]
     pub const fn end_symbol(mut self, end_symbol: Option<&'a str>) -> Self {
@@ -335,6 +346,40 @@ impl<'a> Scrollbar<'a> {
         self
     }
 
+    /// Hides the scrollbar when all of the content fits in the viewport.
+    ///
+    /// When enabled, nothing is rendered if [`ScrollbarState::content_length`] is less than or
+    /// equal to the viewport length (either [`ScrollbarState::viewport_content_length`] or the
+    /// size of the area the scrollbar is rendered in). Defaults to `false`.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::widgets::{Scrollbar, ScrollbarOrientation};
+    ///
+    /// let scrollbar = Scrollbar::new(ScrollbarOrientation::VerticalRight).auto_hide(true);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn auto_hide(mut self, auto_hide: bool) -> Self {
+        self.auto_hide = auto_hide;
+        self
+    }
+
+    /// Sets the minimum length of the thumb in cells.
+    ///
+    /// The thumb shrinks as the content grows relative to the viewport. This sets a lower bound on
+    /// its length so that it stays easy to see and grab. The length is capped to the length of the
+    /// track and is never less than one cell. Defaults to `1`.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn min_thumb_length(mut self, min_thumb_length: u16) -> Self {
+        self.min_thumb_length = min_thumb_length;
+        self
+    }
+
     /// Sets the symbols used for the various parts of the scrollbar from a [`Set`].
     ///
     /// ```text
@@ -473,15 +518,64 @@ impl ScrollbarState {
             }
         }
     }
+
+    /// Moves the scroll position by `delta`, keeping it within the content.
+    ///
+    /// A positive `delta` scrolls forward and a negative one scrolls backward. This is intended
+    /// for mouse wheel events, e.g. with a `delta` of `1` for `MouseEventKind::ScrollDown` and
+    /// `-1` for `MouseEventKind::ScrollUp`, or a larger amount to scroll several lines at once.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::ScrollbarState;
+    ///
+    /// let mut state = ScrollbarState::new(10).position(5);
+    /// state.handle_scroll(3);
+    /// assert_eq!(state, ScrollbarState::new(10).position(8));
+    /// state.handle_scroll(3);
+    /// assert_eq!(state, ScrollbarState::new(10).position(9));
+    /// state.handle_scroll(-20);
+    /// assert_eq!(state, ScrollbarState::new(10).position(0));
+    /// ```
+    pub fn handle_scroll(&mut self, delta: i32) {
+        let distance = delta.unsigned_abs() as usize;
+        self.position = if delta < 0 {
+            self.position.saturating_sub(distance)
+        } else {
+            self.position.saturating_add(distance)
+        }
+        .min(self.content_length.saturating_sub(1));
+    }
 }
 
 impl<'a> StatefulWidget for Scrollbar<'a> {
     type State = ScrollbarState;
 
     fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        StatefulWidgetRef::render_ref(&self, area, buf, state);
+    }
+}
+
+// Note: remove this when StatefulWidgetRef is stabilized and replace with the blanket impl
+impl StatefulWidget for &Scrollbar<'_> {
+    type State = ScrollbarState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        StatefulWidgetRef::render_ref(self, area, buf, state);
+    }
+}
+
+impl StatefulWidgetRef for Scrollbar<'_> {
+    type State = ScrollbarState;
+
+    fn render_ref(&self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
         if state.content_length == 0 || self.track_length_excluding_arrow_heads(area) == 0 {
             return;
         }
+        if self.auto_hide && state.content_length <= self.viewport_length(state, area) {
+            return;
+        }
 
         let mut bar = self.bar_symbols(area, state);
         let area = self.scollbar_area(area);
@@ -496,6 +590,66 @@ impl<'a> StatefulWidget for Scrollbar<'a> {
 }
 
 impl Scrollbar<'_> {
+    /// Returns the scroll position to jump to when the scrollbar rendered in `area` with the given
+    /// `state` is clicked at `position`.
+    ///
+    /// Clicking the track moves the start of the thumb to the clicked cell, and clicking the begin
+    /// or end symbols scrolls by one. Returns `None` if the position is not on the scrollbar or if
+    /// the scrollbar is not rendered, e.g. when the content is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::{prelude::*, widgets::*};
+    ///
+    /// let scrollbar = Scrollbar::new(ScrollbarOrientation::VerticalRight)
+    ///     .begin_symbol(None)
+    ///     .end_symbol(None);
+    /// let area = Rect::new(0, 0, 10, 10);
+    /// let mut state = ScrollbarState::new(100);
+    /// if let Some(position) = scrollbar.position_at(area, Position::new(9, 5), &state) {
+    ///     state = state.position(position);
+    /// }
+    /// assert_eq!(state, ScrollbarState::new(100).position(55));
+    /// ```
+    #[must_use = "returns the position to scroll to"]
+    pub fn position_at(
+        &self,
+        area: Rect,
+        position: Position,
+        state: &ScrollbarState,
+    ) -> Option<usize> {
+        let track_length = self.track_length_excluding_arrow_heads(area);
+        if area.is_empty() || state.content_length == 0 || track_length == 0 {
+            return None;
+        }
+        if self.auto_hide && state.content_length <= self.viewport_length(state, area) {
+            return None;
+        }
+        let bar_area = self.scollbar_area(area);
+        if !bar_area.contains(position) {
+            return None;
+        }
+        let offset = if self.orientation.is_vertical() {
+            position.y - bar_area.y
+        } else {
+            position.x - bar_area.x
+        };
+        let begin_length = self.begin_symbol.map_or(0, |s| s.width() as u16);
+        let max_position = state.content_length.saturating_sub(1);
+        if offset < begin_length {
+            return Some(state.position.min(max_position).saturating_sub(1));
+        }
+        let track_offset = offset - begin_length;
+        if track_offset >= track_length {
+            return Some(state.position.saturating_add(1).min(max_position));
+        }
+        // the inverse of the start of the thumb in `part_lengths`
+        let max_viewport_position = max_position + self.viewport_length(state, area);
+        let jump = f64::from(track_offset) * max_viewport_position as f64 / f64::from(track_length);
+        Some((jump.round() as usize).min(max_position))
+    }
+
     /// Returns an iterator over the symbols and styles of the scrollbar.
     fn bar_symbols(
         &self,
@@ -554,7 +708,11 @@ impl Scrollbar<'_> {
         let thumb_start = thumb_start.round().clamp(0.0, track_length - 1.0) as usize;
         let thumb_end = thumb_end.round().clamp(0.0, track_length) as usize;
 
-        let thumb_length = thumb_end.saturating_sub(thumb_start).max(1);
+        // Grow the thumb to the minimum length, moving it back from the end of the track if it no
+        // longer fits after its start position.
+        let min_thumb_length = (self.min_thumb_length.max(1) as usize).min(track_length as usize);
+        let thumb_length = thumb_end.saturating_sub(thumb_start).max(min_thumb_length);
+        let thumb_start = thumb_start.min(track_length as usize - thumb_length);
         let track_end_length = (track_length as usize).saturating_sub(thumb_start + thumb_length);
 
         (thumb_start, thumb_length, track_end_length)
@@ -1043,4 +1201,155 @@ mod tests {
         scrollbar_no_arrows.render(buffer.area, &mut buffer, &mut state);
         assert_eq!(buffer, Buffer::with_lines([expected]));
     }
+
+    #[rstest]
+    #[case::empty("          ", 0)]
+    #[case::one_line("          ", 1)]
+    #[case::one_less_than_viewport("          ", 9)]
+    #[case::fits_viewport("          ", 10)]
+    #[case::one_more_than_viewport("#####-----", 11)]
+    fn render_scrollbar_auto_hide(
+        #[case] expected: &str,
+        #[case] content_length: usize,
+        scrollbar_no_arrows: Scrollbar,
+    ) {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 1));
+        let mut state = ScrollbarState::new(content_length);
+        scrollbar_no_arrows
+            .auto_hide(true)
+            .render(buffer.area, &mut buffer, &mut state);
+        assert_eq!(buffer, Buffer::with_lines([expected]));
+    }
+
+    #[rstest]
+    #[case::fits_viewport("          ", 4)]
+    #[case::one_more_than_viewport("#####-----", 5)]
+    fn render_scrollbar_auto_hide_with_viewport_content_length(
+        #[case] expected: &str,
+        #[case] content_length: usize,
+        scrollbar_no_arrows: Scrollbar,
+    ) {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 1));
+        let mut state = ScrollbarState::new(content_length).viewport_content_length(4);
+        scrollbar_no_arrows
+            .auto_hide(true)
+            .render(buffer.area, &mut buffer, &mut state);
+        assert_eq!(buffer, Buffer::with_lines([expected]));
+    }
+
+    #[rstest]
+    #[case::position_0("##---", 0)]
+    #[case::position_20("-##--", 20)]
+    #[case::position_50("--##-", 50)]
+    #[case::position_90("---##", 90)]
+    #[case::position_out_of_bounds("---##", 100)]
+    fn render_scrollbar_min_thumb_length(
+        #[case] expected: &str,
+        #[case] position: usize,
+        scrollbar_no_arrows: Scrollbar,
+    ) {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 5, 1));
+        let mut state = ScrollbarState::new(100)
+            .position(position)
+            .viewport_content_length(2);
+        scrollbar_no_arrows
+            .min_thumb_length(2)
+            .render(buffer.area, &mut buffer, &mut state);
+        assert_eq!(buffer, Buffer::with_lines([expected]));
+    }
+
+    #[rstest]
+    #[case::zero("#----", 0)]
+    #[case::track_length("#####", 5)]
+    #[case::longer_than_track("#####", 10)]
+    fn render_scrollbar_min_thumb_length_bounds(
+        #[case] expected: &str,
+        #[case] min_thumb_length: u16,
+        scrollbar_no_arrows: Scrollbar,
+    ) {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 5, 1));
+        let mut state = ScrollbarState::new(100).viewport_content_length(2);
+        scrollbar_no_arrows
+            .min_thumb_length(min_thumb_length)
+            .render(buffer.area, &mut buffer, &mut state);
+        assert_eq!(buffer, Buffer::with_lines([expected]));
+    }
+
+    #[rstest]
+    #[case::begin_only("<#####----", Some("<"), None)]
+    #[case::end_only("#####---->", None, Some(">"))]
+    fn render_scrollbar_with_one_arrow(
+        #[case] expected: &str,
+        #[case] begin_symbol: Option<&str>,
+        #[case] end_symbol: Option<&str>,
+        scrollbar_no_arrows: Scrollbar,
+    ) {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 1));
+        let mut state = ScrollbarState::new(10);
+        scrollbar_no_arrows
+            .begin_symbol(begin_symbol)
+            .end_symbol(end_symbol)
+            .render(buffer.area, &mut buffer, &mut state);
+        assert_eq!(buffer, Buffer::with_lines([expected]));
+    }
+
+    #[rstest]
+    #[case::forward(5, 3, 8)]
+    #[case::backward(5, -3, 2)]
+    #[case::past_end(5, 10, 9)]
+    #[case::past_start(5, -10, 0)]
+    #[case::none(5, 0, 5)]
+    fn handle_scroll(#[case] position: usize, #[case] delta: i32, #[case] expected: usize) {
+        let mut state = ScrollbarState::new(10).position(position);
+        state.handle_scroll(delta);
+        assert_eq!(state, ScrollbarState::new(10).position(expected));
+    }
+
+    #[test]
+    fn handle_scroll_empty_content() {
+        let mut state = ScrollbarState::new(0);
+        state.handle_scroll(1);
+        assert_eq!(state, ScrollbarState::new(0));
+    }
+
+    #[rstest]
+    #[case::begin_symbol(0, Some(4))]
+    #[case::track_start(1, Some(0))]
+    #[case::track_middle(5, Some(15))]
+    #[case::track_end(8, Some(19))]
+    #[case::end_symbol(9, Some(6))]
+    #[case::outside(10, None)]
+    fn position_at(#[case] y: u16, #[case] expected: Option<usize>) {
+        // 8 cells of track for 20 items and a viewport of 10 items: 29 / 8 positions per cell
+        let scrollbar = Scrollbar::new(ScrollbarOrientation::VerticalRight);
+        let state = ScrollbarState::new(20).position(5);
+        let area = Rect::new(0, 0, 4, 10);
+        assert_eq!(
+            scrollbar.position_at(area, Position::new(3, y), &state),
+            expected
+        );
+    }
+
+    #[rstest]
+    #[case::other_column(Position::new(2, 3), None)]
+    #[case::horizontal_track(Position::new(2, 9), Some(7))]
+    fn position_at_horizontal(#[case] position: Position, #[case] expected: Option<usize>) {
+        let scrollbar = Scrollbar::new(ScrollbarOrientation::HorizontalBottom)
+            .begin_symbol(None)
+            .end_symbol(None);
+        let state = ScrollbarState::new(11);
+        let area = Rect::new(0, 0, 4, 10);
+        assert_eq!(scrollbar.position_at(area, position, &state), expected);
+    }
+
+    #[test]
+    fn position_at_empty_content() {
+        let scrollbar = Scrollbar::new(ScrollbarOrientation::VerticalRight);
+        let area = Rect::new(0, 0, 4, 10);
+        let state = ScrollbarState::new(0);
+        assert_eq!(
+            scrollbar.position_at(area, Position::new(3, 5), &state),
+            None
+        );
+    }
 }
@@ -15,6 +15,8 @@ use crate::{prelude::*, style::Styled, widgets::Block};
 ///
 /// - [`Sparkline::block`] wraps the sparkline in a [`Block`]
 /// - [`Sparkline::data`] defines the dataset, you'll almost always want to use it
+/// - [`Sparkline::signed_data`] defines a dataset that can have negative values
+/// - [`Sparkline::baseline`] sets the value from which signed bars are drawn
 /// - [`Sparkline::max`] sets the maximum value of bars
 /// - [`Sparkline::direction`] sets the render direction
 ///
@@ -38,6 +40,10 @@ pub struct Sparkline<'a> {
     style: Style,
     /// A slice of the data to display
     data: &'a [u64],
+    /// A slice of signed data to display instead of `data`
+    signed_data: Option<&'a [i64]>,
+    /// The value from which signed bars are drawn up or down
+    baseline: i64,
     /// The maximum value to take to compute the maximum bar height (if nothing is specified, the
     /// widget uses the max of the dataset)
     max: Option<u64>,
@@ -94,6 +100,44 @@ impl<'a> Sparkline<'a> {
     #[must_use = "method moves the value of self and returns the modified value"]
     pub const fn data(mut self, data: &'a [u64]) -> Self {
         self.data = data;
+        self.signed_data = None;
+        self
+    }
+
+    /// Sets a dataset that can contain negative values for the sparkline.
+    ///
+    /// Values greater than the [`baseline`](Self::baseline) are drawn as bars going up from the
+    /// baseline, and values less than the baseline are drawn as bars going down from it. The rows
+    /// of the sparkline are split between both sides in proportion to the largest value on each
+    /// side, and both sides use the same scale.
+    ///
+    /// This replaces any data set with [`Sparkline::data`], and vice versa.
+    ///
+    /// The bars going down use the same [bar set](Self::bar_set) as the bars going up, with the
+    /// [`Modifier::REVERSED`] modifier for the cells that are only partially filled.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// # fn ui(frame: &mut Frame) {
+    /// # let area = Rect::default();
+    /// let sparkline = Sparkline::default().signed_data(&[-2, -1, 0, 1, 2]);
+    /// frame.render_widget(sparkline, area);
+    /// # }
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn signed_data(mut self, data: &'a [i64]) -> Self {
+        self.signed_data = Some(data);
+        self
+    }
+
+    /// Sets the value from which the bars of the [signed data](Self::signed_data) are drawn.
+    ///
+    /// Defaults to `0`. This has no effect on data set with [`Sparkline::data`].
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn baseline(mut self, baseline: i64) -> Self {
+        self.baseline = baseline;
         self
     }
 
@@ -101,6 +145,9 @@ impl<'a> Sparkline<'a> {
     ///
     /// Every bar will be scaled accordingly. If no max is given, this will be the max in the
     /// dataset.
+    ///
+    /// For [signed data](Self::signed_data), this is the largest distance from the
+    /// [baseline](Self::baseline) on each side that has values.
     #[must_use = "method moves the value of self and returns the modified value"]
     pub const fn max(mut self, max: u64) -> Self {
         self.max = Some(max);
@@ -149,7 +196,11 @@ impl WidgetRef for Sparkline<'_> {
     fn render_ref(&self, area: Rect, buf: &mut Buffer) {
         self.block.render_ref(area, buf);
         let inner = self.block.inner_if_some(area);
-        self.render_sparkline(inner, buf);
+        if let Some(data) = self.signed_data {
+            self.render_signed_sparkline(data, inner, buf);
+        } else {
+            self.render_sparkline(inner, buf);
+        }
     }
 }
 
@@ -177,21 +228,8 @@ impl Sparkline<'_> {
             .collect::<Vec<u64>>();
         for j in (0..spark_area.height).rev() {
             for (i, d) in data.iter_mut().enumerate() {
-                let symbol = match *d {
-                    0 => self.bar_set.empty,
-                    1 => self.bar_set.one_eighth,
-                    2 => self.bar_set.one_quarter,
-                    3 => self.bar_set.three_eighths,
-                    4 => self.bar_set.half,
-                    5 => self.bar_set.five_eighths,
-                    6 => self.bar_set.three_quarters,
-                    7 => self.bar_set.seven_eighths,
-                    _ => self.bar_set.full,
-                };
-                let x = match self.direction {
-                    RenderDirection::LeftToRight => spark_area.left() + i as u16,
-                    RenderDirection::RightToLeft => spark_area.right() - i as u16 - 1,
-                };
+                let symbol = self.symbol(*d);
+                let x = self.column(spark_area, i);
                 buf[(x, spark_area.top() + j)]
                     .set_symbol(symbol)
                     .set_style(self.style);
@@ -204,6 +242,103 @@ impl Sparkline<'_> {
             }
         }
     }
+
+    fn render_signed_sparkline(&self, data: &[i64], spark_area: Rect, buf: &mut Buffer) {
+        if spark_area.is_empty() {
+            return;
+        }
+
+        let data = &data[..min(spark_area.width as usize, data.len())];
+        let deviation = |value: i64| i128::from(value) - i128::from(self.baseline);
+        let max_above = data.iter().map(|&v| deviation(v)).max().unwrap_or(0).max(0);
+        let max_below = data
+            .iter()
+            .map(|&v| -deviation(v))
+            .max()
+            .unwrap_or(0)
+            .max(0);
+        let (above, below) = match self.max {
+            Some(max) => (
+                if max_above > 0 { i128::from(max) } else { 0 },
+                if max_below > 0 { i128::from(max) } else { 0 },
+            ),
+            None => (max_above, max_below),
+        };
+        let range = above + below;
+        if range == 0 {
+            for i in 0..data.len() {
+                let x = self.column(spark_area, i);
+                for y in spark_area.top()..spark_area.bottom() {
+                    buf[(x, y)]
+                        .set_symbol(self.bar_set.empty)
+                        .set_style(self.style);
+                }
+            }
+            return;
+        }
+
+        let height = i128::from(spark_area.height);
+        // the row just above the baseline, counted from the top of the area
+        let mut rows_above = (height * above + range / 2) / range;
+        // each side that has data keeps at least a row, so that its values are drawn even when they
+        // are small compared to the other side
+        if above > 0 {
+            rows_above = rows_above.max(1);
+        }
+        if below > 0 {
+            rows_above = rows_above.min(height - 1);
+        }
+        let rows_above = rows_above as u16;
+        for (i, &value) in data.iter().enumerate() {
+            let x = self.column(spark_area, i);
+            // length of the bar in eighths of a cell, clamped to the area
+            let ticks = (deviation(value).abs() * height * 8 / range).min(height * 8) as u64;
+            for row in 0..spark_area.height {
+                let cell = &mut buf[(x, spark_area.top() + row)];
+                cell.set_style(self.style);
+                if deviation(value) >= 0 && row < rows_above {
+                    let offset = u64::from(rows_above - row - 1) * 8;
+                    cell.set_symbol(self.symbol(ticks.saturating_sub(offset)));
+                } else if deviation(value) < 0 && row >= rows_above {
+                    let offset = u64::from(row - rows_above) * 8;
+                    let filled = ticks.saturating_sub(offset).min(8);
+                    if filled == 0 || filled == 8 {
+                        cell.set_symbol(self.symbol(filled));
+                    } else {
+                        // the bar set only fills cells from the bottom, so the top of the cell is
+                        // drawn by reversing the colors of the complementary symbol
+                        cell.set_symbol(self.symbol(8 - filled))
+                            .set_style(self.style.add_modifier(Modifier::REVERSED));
+                    }
+                } else {
+                    cell.set_symbol(self.bar_set.empty);
+                }
+            }
+        }
+    }
+
+    /// Returns the symbol of a cell filled with the given number of eighths
+    const fn symbol(&self, eighths: u64) -> &str {
+        match eighths {
+            0 => self.bar_set.empty,
+            1 => self.bar_set.one_eighth,
+            2 => self.bar_set.one_quarter,
+            3 => self.bar_set.three_eighths,
+            4 => self.bar_set.half,
+            5 => self.bar_set.five_eighths,
+            6 => self.bar_set.three_quarters,
+            7 => self.bar_set.seven_eighths,
+            _ => self.bar_set.full,
+        }
+    }
+
+    /// Returns the x coordinate of the column of the value at the given index
+    const fn column(&self, spark_area: Rect, index: usize) -> u16 {
+        match self.direction {
+            RenderDirection::LeftToRight => spark_area.left() + index as u16,
+            RenderDirection::RightToLeft => spark_area.right() - index as u16 - 1,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -285,6 +420,48 @@ mod tests {
         assert_eq!(buffer, Buffer::with_lines(["xxx█▇▆▅▄▃▂▁ "]));
     }
 
+    #[test]
+    fn it_renders_signed_data() {
+        let widget = Sparkline::default().signed_data(&[-2, -1, 0, 1, 2]);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 5, 2));
+        widget.render(buffer.area, &mut buffer);
+        let mut expected = Buffer::with_lines(["   ▄█", "█▄   "]);
+        expected[(1, 1)].set_style(Modifier::REVERSED);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn it_renders_signed_data_around_baseline() {
+        let widget = Sparkline::default().signed_data(&[3, 5, 9]).baseline(5);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 3, 2));
+        widget.render(buffer.area, &mut buffer);
+        let mut expected = Buffer::with_lines(["  █", "▃  "]);
+        expected[(0, 1)].set_style(Modifier::REVERSED);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn it_renders_small_positive_signed_data() {
+        let widget = Sparkline::default().signed_data(&[-10, 1]);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 2, 4));
+        widget.render(buffer.area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines([" ▂", "█ ", "█ ", "█ "]));
+    }
+
+    #[test]
+    fn it_renders_signed_data_on_baseline_as_empty() {
+        let widget = Sparkline::default().signed_data(&[3, 3]).baseline(3);
+        let buffer = render(widget, 4);
+        assert_eq!(buffer, Buffer::with_lines(["  xx"]));
+    }
+
+    #[test]
+    fn data_replaces_signed_data() {
+        let widget = Sparkline::default().signed_data(&[-1, 1]).data(&[0, 8]);
+        let buffer = render(widget, 3);
+        assert_eq!(buffer, Buffer::with_lines([" █x"]));
+    }
+
     #[test]
     fn can_be_stylized() {
         assert_eq!(
@@ -3,9 +3,11 @@ mod highlight_spacing;
 mod row;
 mod table;
 mod table_state;
+mod virtual_table;
 
 pub use cell::*;
 pub use highlight_spacing::*;
 pub use row::*;
 pub use table::*;
 pub use table_state::*;
+pub use virtual_table::*;
@@ -1,4 +1,8 @@
-use crate::{prelude::*, style::Styled};
+use crate::{
+    prelude::*,
+    style::Styled,
+    widgets::{Paragraph, Wrap},
+};
 
 /// A [`Cell`] contains the [`Text`] to be displayed in a [`Row`] of a [`Table`].
 ///
@@ -40,7 +44,7 @@ use crate::{prelude::*, style::Styled};
 /// [`Table`]: super::Table
 #[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
 pub struct Cell<'a> {
-    content: Text<'a>,
+    pub(crate) content: Text<'a>,
     style: Style,
 }
 
@@ -136,6 +140,29 @@ impl Cell<'_> {
         buf.set_style(area, self.style);
         self.content.render_ref(area, buf);
     }
+
+    /// Renders the content word-wrapped to the width of the area
+    pub(crate) fn render_wrapped(&self, area: Rect, buf: &mut Buffer) {
+        buf.set_style(area, self.style);
+        self.wrapped().render(area, buf);
+    }
+
+    /// Returns the number of lines of the content word-wrapped to the given width
+    pub(crate) fn wrapped_height(&self, width: u16) -> u16 {
+        u16::try_from(self.wrapped().line_count(width)).unwrap_or(u16::MAX)
+    }
+
+    fn wrapped(&self) -> Paragraph<'_> {
+        Paragraph::new(self.content.clone())
+            .style(self.content.style)
+            .alignment(self.content.alignment.unwrap_or_default())
+            .wrap(Wrap { trim: true })
+    }
+
+    /// Returns the width of the widest line of the content
+    pub(crate) fn content_width(&self) -> usize {
+        self.content.width()
+    }
 }
 
 impl<'a, T> From<T> for Cell<'a>
@@ -221,16 +221,6 @@ impl<'a> Row<'a> {
     }
 }
 
-// private methods for rendering
-impl Row<'_> {
-    /// Returns the total height of the row.
-    pub(crate) const fn height_with_margin(&self) -> u16 {
-        self.height
-            .saturating_add(self.top_margin)
-            .saturating_add(self.bottom_margin)
-    }
-}
-
 impl<'a> Styled for Row<'a> {
     type Item = Self;
 
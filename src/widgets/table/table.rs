@@ -1,9 +1,13 @@
 use itertools::Itertools;
 
-#[allow(unused_imports)] // `Cell` is used in the doc comment but not the code
-use super::Cell;
-use super::{HighlightSpacing, Row, TableState};
-use crate::{layout::Flex, prelude::*, style::Styled, widgets::Block};
+use super::{Cell, HighlightSpacing, Row, TableState};
+use crate::{
+    accessibility::{AccessNode, Role},
+    layout::Flex,
+    prelude::*,
+    style::Styled,
+    widgets::Block,
+};
 
 /// A widget to display data in formatted columns.
 ///
@@ -45,6 +49,7 @@ use crate::{layout::Flex, prelude::*, style::Styled, widgets::Block};
 /// - [`Table::header`] sets the header row of the [`Table`].
 /// - [`Table::footer`] sets the footer row of the [`Table`].
 /// - [`Table::widths`] sets the width constraints of each column.
+/// - [`Table::auto_widths`] computes the width of each column from its content.
 /// - [`Table::column_spacing`] sets the spacing between each column.
 /// - [`Table::block`] wraps the table in a [`Block`] widget.
 /// - [`Table::style`] sets the base style of the widget.
@@ -136,6 +141,9 @@ use crate::{layout::Flex, prelude::*, style::Styled, widgets::Block};
 /// of `Row`s.  This will create a table with column widths evenly dividing the space available.
 /// These default columns widths can be overridden using the `Table::widths` method.
 ///
+/// To display a large number of rows without building all of them, wrap the table in a
+/// [`VirtualTable`](super::VirtualTable), which only requests the rows it displays.
+///
 /// ```rust
 /// use ratatui::{prelude::*, widgets::*};
 ///
@@ -198,8 +206,8 @@ pub struct Table<'a> {
     /// Data to display in each row
     rows: Vec<Row<'a>>,
 
-    /// Optional header
-    header: Option<Row<'a>>,
+    /// Header rows, displayed from top to bottom
+    header: Vec<Row<'a>>,
 
     /// Optional footer
     footer: Option<Row<'a>>,
@@ -207,6 +215,9 @@ pub struct Table<'a> {
     /// Width constraints for each column
     widths: Vec<Constraint>,
 
+    /// Whether the widths of the columns are computed from their content
+    auto_widths: bool,
+
     /// Space between each column
     column_spacing: u16,
 
@@ -227,15 +238,25 @@ pub struct Table<'a> {
 
     /// Controls how to distribute extra space among the columns
     flex: Flex,
+
+    /// Number of leading columns which stay in place when scrolling horizontally
+    frozen_columns: usize,
+
+    /// The width below which each column is not shrunk
+    min_widths: Vec<u16>,
+
+    /// Whether the content of the cells is word-wrapped to the width of their column
+    cell_wrap: bool,
 }
 
 impl<'a> Default for Table<'a> {
     fn default() -> Self {
         Self {
             rows: Vec::new(),
-            header: None,
+            header: Vec::new(),
             footer: None,
             widths: Vec::new(),
+            auto_widths: false,
             column_spacing: 1,
             block: None,
             style: Style::new(),
@@ -243,6 +264,9 @@ impl<'a> Default for Table<'a> {
             highlight_symbol: Text::default(),
             highlight_spacing: HighlightSpacing::default(),
             flex: Flex::Start,
+            frozen_columns: 0,
+            min_widths: Vec::new(),
+            cell_wrap: false,
         }
     }
 }
@@ -320,7 +344,9 @@ impl<'a> Table<'a> {
 
     /// Sets the header row
     ///
-    /// The `header` parameter is a [`Row`] which will be displayed at the top of the [`Table`]
+    /// The `header` parameter is a [`Row`] which will be displayed at the top of the [`Table`]. The
+    /// header stays in place when the rows are scrolled, and can't be selected. It replaces any
+    /// header rows set with [`Table::headers`].
     ///
     /// This is a fluent setter method which must be chained or used as it consumes self
     ///
@@ -336,13 +362,44 @@ impl<'a> Table<'a> {
     /// ```
     #[must_use = "method moves the value of self and returns the modified value"]
     pub fn header(mut self, header: Row<'a>) -> Self {
-        self.header = Some(header);
+        self.header = vec![header];
+        self
+    }
+
+    /// Sets several header rows
+    ///
+    /// The `headers` parameter accepts any value that can be converted into an iterator of
+    /// [`Row`]s (e.g. to group the columns under a row of headings). The rows are displayed from
+    /// top to bottom at the top of the [`Table`], and like a single [header](Table::header) they
+    /// stay in place when the rows are scrolled and can't be selected.
+    ///
+    /// The header rows are taken into account when computing the widths of the columns from their
+    /// content (see [`Table::auto_widths`]).
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let table = Table::default().headers([
+    ///     Row::new(["Name", "Score", ""]),
+    ///     Row::new(["", "Min", "Max"]).bottom_margin(1),
+    /// ]);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn headers<T>(mut self, headers: T) -> Self
+    where
+        T: IntoIterator<Item = Row<'a>>,
+    {
+        self.header = headers.into_iter().collect();
         self
     }
 
     /// Sets the footer row
     ///
-    /// The `footer` parameter is a [`Row`] which will be displayed at the bottom of the [`Table`]
+    /// The `footer` parameter is a [`Row`] which will be displayed at the bottom of the [`Table`].
+    /// The footer stays in place when the rows are scrolled, and can't be selected.
     ///
     /// This is a fluent setter method which must be chained or used as it consumes self
     ///
@@ -396,6 +453,33 @@ impl<'a> Table<'a> {
         self
     }
 
+    /// Compute the widths of the columns from their content.
+    ///
+    /// Each column is as wide as its widest cell across the header, the footer and all the rows.
+    /// The width of a multi-line cell is the width of its widest line. Any space left over is
+    /// distributed according to the [`Flex`] set with [`Table::flex`]. When the content is wider
+    /// than the available space, every column is shrunk in proportion to its width.
+    ///
+    /// This overrides the constraints set with [`Table::widths`].
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let rows = [
+    ///     Row::new(vec!["Cell1", "A longer cell"]),
+    ///     Row::new(vec!["Cell3", "Cell4"]),
+    /// ];
+    /// let table = Table::default().rows(rows).auto_widths();
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn auto_widths(mut self) -> Self {
+        self.auto_widths = true;
+        self
+    }
+
     /// Set the spacing between columns
     ///
     /// This is a fluent setter method which must be chained or used as it consumes self
@@ -571,12 +655,103 @@ impl<'a> Table<'a> {
         self.flex = flex;
         self
     }
+
+    /// Set the number of leading columns which stay in place when scrolling horizontally
+    ///
+    /// The first `count` columns are always displayed, and the other columns are displayed
+    /// starting from the [horizontal offset](TableState::x_offset) of the state. The width of the
+    /// frozen columns is reserved first, and the scrolled columns share the remaining space.
+    ///
+    /// This is useful for wide tables where a column such as an identifier or a name should stay
+    /// visible.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// # fn ui(frame: &mut Frame) {
+    /// # let area = Rect::default();
+    /// let rows = [Row::new(["1", "Alice", "a", "b", "c"])];
+    /// let table = Table::new(rows, [Constraint::Length(5); 5]).frozen_columns(2);
+    ///
+    /// // display the ID and name, followed by the columns from the fourth one
+    /// let mut state = TableState::default().with_x_offset(1);
+    /// frame.render_stateful_widget(table, area, &mut state);
+    /// # }
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn frozen_columns(mut self, count: usize) -> Self {
+        self.frozen_columns = count;
+        self
+    }
+
+    /// Set the minimum width of each column
+    ///
+    /// The columns are laid out according to their [widths](Table::widths), but a column is never
+    /// shrunk below its minimum width when space is tight: its width is raised to the minimum and
+    /// the other columns share the remaining space. When the minimum widths of the columns don't
+    /// all fit, the rightmost columns are clipped instead of squishing all of them, and can be
+    /// brought into view by scrolling horizontally with the [state](TableState::x_offset).
+    ///
+    /// The minimum widths apply to the columns in order, and columns without a minimum width can
+    /// shrink to 0 as before. This is a hard floor on top of the constraints, unlike
+    /// [`Constraint::Min`] which may still be shrunk to fit other constraints.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let rows = [Row::new(["1", "Alice", "alice@example.com"])];
+    /// let table = Table::new(rows, [Constraint::Fill(1); 3]).min_widths([2, 5, 10]);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn min_widths<I>(mut self, min_widths: I) -> Self
+    where
+        I: IntoIterator<Item = u16>,
+    {
+        self.min_widths = min_widths.into_iter().collect();
+        self
+    }
+
+    /// Set whether the content of the cells is word-wrapped to fit the width of their column
+    ///
+    /// When enabled, the lines of each cell that are wider than the column are wrapped at word
+    /// boundaries, and each row (including the header and footer) grows to the height of its
+    /// tallest wrapped cell. The [height](Row::height) of a row becomes its minimum height.
+    /// Scrolling and selection take the grown heights into account.
+    ///
+    /// The height of a row depends on the width of the columns, so rows may change height when the
+    /// table is resized. Measuring the wrapped cells is slower than using fixed heights, so this is
+    /// disabled by default.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let rows = [Row::new(["1", "A long description which doesn't fit on one line"])];
+    /// let table = Table::new(rows, [Constraint::Length(2), Constraint::Fill(1)]).cell_wrap(true);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn cell_wrap(mut self, cell_wrap: bool) -> Self {
+        self.cell_wrap = cell_wrap;
+        self
+    }
 }
 
 impl Widget for Table<'_> {
     fn render(self, area: Rect, buf: &mut Buffer) {
         WidgetRef::render_ref(&self, area, buf);
     }
+
+    fn accessibility_node(&self, area: Rect) -> Option<AccessNode> {
+        Some(self.access_node(area, &TableState::default()))
+    }
 }
 
 impl WidgetRef for Table<'_> {
@@ -584,6 +759,10 @@ impl WidgetRef for Table<'_> {
         let mut state = TableState::default();
         StatefulWidget::render(self, area, buf, &mut state);
     }
+
+    fn accessibility_node(&self, area: Rect) -> Option<AccessNode> {
+        Some(self.access_node(area, &TableState::default()))
+    }
 }
 
 impl StatefulWidget for Table<'_> {
@@ -592,6 +771,10 @@ impl StatefulWidget for Table<'_> {
     fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
         StatefulWidget::render(&self, area, buf, state);
     }
+
+    fn accessibility_node(&self, area: Rect, state: &Self::State) -> Option<AccessNode> {
+        Some(self.access_node(area, state))
+    }
 }
 
 // Note: remove this when StatefulWidgetRef is stabilized and replace with the blanket impl
@@ -600,6 +783,10 @@ impl StatefulWidget for &Table<'_> {
     fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
         StatefulWidgetRef::render_ref(self, area, buf, state);
     }
+
+    fn accessibility_node(&self, area: Rect, state: &Self::State) -> Option<AccessNode> {
+        Some(self.access_node(area, state))
+    }
 }
 
 impl StatefulWidgetRef for Table<'_> {
@@ -613,17 +800,12 @@ impl StatefulWidgetRef for Table<'_> {
             return;
         }
 
-        if state.selected.is_some_and(|s| s >= self.rows.len()) {
-            state.select(Some(self.rows.len().saturating_sub(1)));
-        }
-
-        if self.rows.is_empty() {
-            state.select(None);
-        }
+        self.clamp_state(state);
 
         let selection_width = self.selection_width(state);
-        let columns_widths = self.get_columns_widths(table_area.width, selection_width);
-        let (header_area, rows_area, footer_area) = self.layout(table_area);
+        let columns_widths =
+            self.get_columns_widths(table_area.width, selection_width, state.x_offset);
+        let (header_area, rows_area, footer_area) = self.layout(table_area, &columns_widths);
 
         self.render_header(header_area, buf, &columns_widths);
 
@@ -638,17 +820,121 @@ impl StatefulWidgetRef for Table<'_> {
 
         self.render_footer(footer_area, buf, &columns_widths);
     }
+
+    fn accessibility_node(&self, area: Rect, state: &Self::State) -> Option<AccessNode> {
+        Some(self.access_node(area, state))
+    }
 }
 
 // private methods for rendering
 impl Table<'_> {
+    /// Clamps the selected row to the rows and the horizontal offset to the scrolled columns
+    fn clamp_state(&self, state: &mut TableState) {
+        if state.selected.is_some_and(|s| s >= self.rows.len()) {
+            state.select(Some(self.rows.len().saturating_sub(1)));
+        }
+
+        if self.rows.is_empty() {
+            state.select(None);
+        }
+
+        // keep at least one of the scrolled columns visible
+        let scrolled_columns = self.column_count().saturating_sub(self.frozen_columns);
+        state.x_offset = state.x_offset.min(scrolled_columns.saturating_sub(1));
+    }
+
+    /// Describes the table as a [`Role::Table`] node with a child for the header, each row which
+    /// is displayed when the table is rendered with the given state, and the footer.
+    ///
+    /// The rows and cells are laid out the same way as in `render_ref`. The columns scrolled out
+    /// of view are left out.
+    fn access_node(&self, area: Rect, state: &TableState) -> AccessNode {
+        let node = AccessNode::new(Role::Table, "", area);
+        let table_area = self.block.inner_if_some(area);
+        if table_area.is_empty() {
+            return node;
+        }
+        let mut state = state.clone();
+        self.clamp_state(&mut state);
+        let selection_width = self.selection_width(&state);
+        let columns_widths =
+            self.get_columns_widths(table_area.width, selection_width, state.x_offset);
+        let (header_area, body_area, footer_area) = self.layout(table_area, &columns_widths);
+
+        let mut children = Vec::new();
+        for (header, area) in self.header_rows(header_area, &columns_widths) {
+            children.push(Self::row_node(Role::Header, header, area, &columns_widths));
+        }
+        if !self.rows.is_empty() {
+            let (start, end) = self.get_row_bounds(
+                state.selected,
+                state.offset,
+                body_area.height,
+                &columns_widths,
+            );
+            let mut y_offset = 0;
+            for (i, row) in self.rows.iter().enumerate().take(end).skip(start) {
+                let row_area = Rect::new(
+                    body_area.x,
+                    body_area.y + y_offset + row.top_margin,
+                    body_area.width,
+                    self.row_height(row, &columns_widths),
+                )
+                .intersection(body_area);
+                let is_selected = state.selected == Some(i);
+                children.push(
+                    Self::row_node(Role::Row, row, row_area, &columns_widths).selected(is_selected),
+                );
+                y_offset += self.row_height_with_margin(row, &columns_widths);
+            }
+        }
+        if let Some(ref footer) = self.footer {
+            children.push(Self::row_node(
+                Role::Footer,
+                footer,
+                footer_area,
+                &columns_widths,
+            ));
+        }
+        node.children(children)
+    }
+
+    /// Describes a row as a node with a [`Role::Cell`] child for each visible cell, and the text
+    /// of these cells separated by spaces.
+    fn row_node(role: Role, row: &Row, area: Rect, column_widths: &[(u16, u16)]) -> AccessNode {
+        let cells: Vec<_> = column_widths
+            .iter()
+            .zip(row.cells.iter())
+            .filter(|((_, width), _)| *width > 0)
+            .map(|((x, width), cell)| {
+                let cell_area = Rect::new(area.x + x, area.y, *width, area.height);
+                AccessNode::new(Role::Cell, cell.content.to_string(), cell_area)
+            })
+            .collect();
+        let text = cells.iter().map(|cell| cell.text.as_str()).join(" ");
+        AccessNode::new(role, text, area).children(cells)
+    }
+
     /// Splits the table area into a header, rows area and a footer
-    fn layout(&self, area: Rect) -> (Rect, Rect, Rect) {
-        let header_top_margin = self.header.as_ref().map_or(0, |h| h.top_margin);
-        let header_height = self.header.as_ref().map_or(0, |h| h.height);
-        let header_bottom_margin = self.header.as_ref().map_or(0, |h| h.bottom_margin);
+    ///
+    /// The header area spans all the header rows, without the top margin of the first one and the
+    /// bottom margin of the last one.
+    fn layout(&self, area: Rect, columns_widths: &[(u16, u16)]) -> (Rect, Rect, Rect) {
+        let header_top_margin = self.header.first().map_or(0, |h| h.top_margin);
+        let header_bottom_margin = self.header.last().map_or(0, |h| h.bottom_margin);
+        let header_height = self
+            .header
+            .iter()
+            .fold(0u16, |height, h| {
+                height.saturating_add(self.row_height_with_margin(h, columns_widths))
+            })
+            .saturating_sub(header_top_margin)
+            .saturating_sub(header_bottom_margin);
         let footer_top_margin = self.footer.as_ref().map_or(0, |h| h.top_margin);
-        let footer_height = self.footer.as_ref().map_or(0, |f| f.height);
+        let footer_height = self
+            .footer
+            .as_ref()
+            .map_or(0, |f| self.row_height(f, columns_widths));
         let footer_bottom_margin = self.footer.as_ref().map_or(0, |h| h.bottom_margin);
         let layout = Layout::vertical([
             Constraint::Length(header_top_margin),
@@ -664,11 +950,35 @@ impl Table<'_> {
         (header_area, rows_area, footer_area)
     }
 
+    /// Returns each header row with its area, clipped to the header area
+    fn header_rows<'b>(
+        &'b self,
+        area: Rect,
+        column_widths: &'b [(u16, u16)],
+    ) -> impl Iterator<Item = (&'b Row<'b>, Rect)> {
+        let mut y = area.y;
+        self.header.iter().enumerate().map(move |(i, header)| {
+            if i > 0 {
+                y = y.saturating_add(header.top_margin);
+            }
+            let height = self.row_height(header, column_widths);
+            let row_area = Rect::new(area.x, y, area.width, height).intersection(area);
+            y = y
+                .saturating_add(height)
+                .saturating_add(header.bottom_margin);
+            (header, row_area)
+        })
+    }
+
     fn render_header(&self, area: Rect, buf: &mut Buffer, column_widths: &[(u16, u16)]) {
-        if let Some(ref header) = self.header {
+        for (header, area) in self.header_rows(area, column_widths) {
             buf.set_style(area, header.style);
             for ((x, width), cell) in column_widths.iter().zip(header.cells.iter()) {
-                cell.render(Rect::new(area.x + x, area.y, *width, area.height), buf);
+                self.render_cell(
+                    cell,
+                    Rect::new(area.x + x, area.y, *width, area.height),
+                    buf,
+                );
             }
         }
     }
@@ -677,7 +987,11 @@ impl Table<'_> {
         if let Some(ref footer) = self.footer {
             buf.set_style(area, footer.style);
             for ((x, width), cell) in column_widths.iter().zip(footer.cells.iter()) {
-                cell.render(Rect::new(area.x + x, area.y, *width, area.height), buf);
+                self.render_cell(
+                    cell,
+                    Rect::new(area.x + x, area.y, *width, area.height),
+                    buf,
+                );
             }
         }
     }
@@ -696,7 +1010,7 @@ impl Table<'_> {
         }
 
         let (start_index, end_index) =
-            self.get_row_bounds(state.selected, state.offset, area.height);
+            self.get_row_bounds(state.selected, state.offset, area.height, columns_widths);
         state.offset = start_index;
 
         let mut y_offset = 0;
@@ -711,7 +1025,8 @@ impl Table<'_> {
                 area.x,
                 area.y + y_offset + row.top_margin,
                 area.width,
-                row.height_with_margin() - row.top_margin,
+                self.row_height(row, columns_widths)
+                    .saturating_add(row.bottom_margin),
             );
             buf.set_style(row_area, row.style);
 
@@ -725,7 +1040,8 @@ impl Table<'_> {
                 highlight_symbol.render_ref(selection_area, buf);
             };
             for ((x, width), cell) in columns_widths.iter().zip(row.cells.iter()) {
-                cell.render(
+                self.render_cell(
+                    cell,
                     Rect::new(row_area.x + x, row_area.y, *width, row_area.height),
                     buf,
                 );
@@ -733,7 +1049,7 @@ impl Table<'_> {
             if is_selected {
                 buf.set_style(row_area, self.highlight_style);
             }
-            y_offset += row.height_with_margin();
+            y_offset += self.row_height_with_margin(row, columns_widths);
         }
     }
 
@@ -741,30 +1057,186 @@ impl Table<'_> {
     ///
     /// Returns (x, width). When self.widths is empty, it is assumed `.widths()` has not been called
     /// and a default of equal widths is returned.
-    fn get_columns_widths(&self, max_width: u16, selection_width: u16) -> Vec<(u16, u16)> {
-        let widths = if self.widths.is_empty() {
-            let col_count = self
-                .rows
-                .iter()
-                .chain(self.header.iter())
-                .chain(self.footer.iter())
-                .map(|r| r.cells.len())
-                .max()
-                .unwrap_or(0);
+    ///
+    /// The frozen columns are laid out first, then the other columns from `x_offset` in the
+    /// remaining space. The columns scrolled out of view have a width of 0.
+    fn get_columns_widths(
+        &self,
+        max_width: u16,
+        selection_width: u16,
+        x_offset: usize,
+    ) -> Vec<(u16, u16)> {
+        let widths = if self.auto_widths {
+            self.content_widths(max_width.saturating_sub(selection_width))
+        } else if self.widths.is_empty() {
+            let col_count = self.column_count();
             // Divide the space between each column equally
             vec![Constraint::Length(max_width / col_count.max(1) as u16); col_count]
         } else {
             self.widths.clone()
         };
         // this will always allocate a selection area
-        let [_selection_area, columns_area] =
+        let [_selection_area, mut columns_area] =
             Layout::horizontal([Constraint::Length(selection_width), Constraint::Fill(0)])
                 .areas(Rect::new(0, 0, max_width, 1));
-        let rects = Layout::horizontal(widths)
-            .flex(self.flex)
-            .spacing(self.column_spacing)
-            .split(columns_area);
-        rects.iter().map(|c| (c.x, c.width)).collect()
+
+        let (frozen, scrolled) = widths.split_at(self.frozen_columns.min(widths.len()));
+        let x_offset = x_offset.min(scrolled.len());
+        let min_widths = |start: usize| self.min_widths.get(start..).unwrap_or_default();
+        let mut columns = Vec::with_capacity(widths.len());
+        if !frozen.is_empty() {
+            let rects = self.layout_columns(
+                Flex::default(),
+                frozen
+                    .iter()
+                    .copied()
+                    .chain([Constraint::Fill(1)])
+                    .collect(),
+                // the remaining space has no minimum width of its own, so that the scrolled
+                // columns are clipped from the right rather than hidden altogether
+                &self.min_widths[..frozen.len().min(self.min_widths.len())],
+                columns_area,
+            );
+            let (last, frozen_rects) = rects.split_last().expect("the fill constraint is last");
+            columns.extend(frozen_rects.iter().map(|c| (c.x, c.width)));
+            columns_area = *last;
+        }
+        columns.extend(std::iter::repeat((columns_area.x, 0)).take(x_offset));
+        let rects = self.layout_columns(
+            self.flex,
+            scrolled[x_offset..].to_vec(),
+            min_widths(frozen.len() + x_offset),
+            columns_area,
+        );
+        columns.extend(rects.iter().map(|c| (c.x, c.width)));
+        columns
+    }
+
+    /// Lays out columns with the given constraints so that no column is narrower than its minimum
+    /// width.
+    ///
+    /// The columns which don't fit at their minimum width are clipped from the right, and have a
+    /// width of 0 at the end of the area. The first column is always kept.
+    fn layout_columns(
+        &self,
+        flex: Flex,
+        mut constraints: Vec<Constraint>,
+        min_widths: &[u16],
+        area: Rect,
+    ) -> Vec<Rect> {
+        let layout = |constraints: &[Constraint]| {
+            Layout::horizontal(constraints)
+                .flex(flex)
+                .spacing(self.column_spacing)
+                .split(area)
+        };
+        if min_widths.iter().all(|&width| width == 0) {
+            return layout(&constraints).to_vec();
+        }
+        let column_count = constraints.len();
+        let mut min_widths = min_widths.to_vec();
+        min_widths.resize(column_count, 0);
+        let mut total_width = 0u16;
+        let mut fitting = 0;
+        for (index, &min_width) in min_widths.iter().enumerate() {
+            let spacing = if index == 0 { 0 } else { self.column_spacing };
+            let width = total_width
+                .saturating_add(spacing)
+                .saturating_add(min_width);
+            if index > 0 && width > area.width {
+                break;
+            }
+            total_width = width;
+            fitting += 1;
+        }
+        constraints.truncate(fitting);
+        min_widths.truncate(fitting);
+
+        let mut rects = layout(&constraints).to_vec();
+        if rects
+            .iter()
+            .zip(&min_widths)
+            .any(|(rect, &min)| rect.width < min)
+        {
+            // raise the columns which are too narrow, then take the overflow from the columns
+            // with the most width above their minimum
+            let mut widths: Vec<u16> = rects
+                .iter()
+                .zip(&min_widths)
+                .map(|(rect, &min)| rect.width.max(min).min(area.width))
+                .collect();
+            let spacing = self
+                .column_spacing
+                .saturating_mul(fitting.saturating_sub(1) as u16);
+            let mut total = widths.iter().sum::<u16>().saturating_add(spacing);
+            while total > area.width {
+                let Some((index, _)) = widths
+                    .iter()
+                    .zip(&min_widths)
+                    .enumerate()
+                    .filter(|(_, (&width, &min))| width > min)
+                    .max_by_key(|(index, (&width, &min))| (width - min, *index))
+                else {
+                    break;
+                };
+                widths[index] -= 1;
+                total -= 1;
+            }
+            let mut x = rects[0]
+                .x
+                .min(area.right().saturating_sub(total))
+                .max(area.x);
+            for (rect, width) in rects.iter_mut().zip(widths) {
+                rect.x = x;
+                rect.width = width.min(area.right().saturating_sub(x));
+                x = x.saturating_add(width).saturating_add(self.column_spacing);
+            }
+        }
+        let clipped = Rect::new(area.right(), area.y, 0, area.height);
+        rects.resize(column_count, clipped);
+        rects
+    }
+
+    /// Returns the number of columns of the table
+    fn column_count(&self) -> usize {
+        if self.auto_widths || self.widths.is_empty() {
+            self.rows
+                .iter()
+                .chain(self.header.iter())
+                .chain(self.footer.iter())
+                .map(|r| r.cells.len())
+                .max()
+                .unwrap_or(0)
+        } else {
+            self.widths.len()
+        }
+    }
+
+    /// Returns a length constraint for each column that fits its content in the given width
+    fn content_widths(&self, max_width: u16) -> Vec<Constraint> {
+        let mut widths: Vec<usize> = vec![];
+        for row in self.rows.iter().chain(&self.header).chain(&self.footer) {
+            if widths.len() < row.cells.len() {
+                widths.resize(row.cells.len(), 0);
+            }
+            for (width, cell) in widths.iter_mut().zip(&row.cells) {
+                *width = (*width).max(cell.content_width());
+            }
+        }
+        let spacing = usize::from(self.column_spacing) * widths.len().saturating_sub(1);
+        let available = usize::from(max_width).saturating_sub(spacing);
+        let total: usize = widths.iter().sum();
+        widths
+            .into_iter()
+            .map(|width| {
+                let width = if total > available {
+                    width * available / total
+                } else {
+                    width
+                };
+                Constraint::Length(width as u16)
+            })
+            .collect()
     }
 
     fn get_row_bounds(
@@ -772,16 +1244,19 @@ impl Table<'_> {
         selected: Option<usize>,
         offset: usize,
         max_height: u16,
+        columns_widths: &[(u16, u16)],
     ) -> (usize, usize) {
         let offset = offset.min(self.rows.len().saturating_sub(1));
         let mut start = offset;
         let mut end = offset;
         let mut height = 0;
+        let height_with_margin =
+            |index: usize| self.row_height_with_margin(&self.rows[index], columns_widths);
         for item in self.rows.iter().skip(offset) {
-            if height + item.height > max_height {
+            if height + self.row_height(item, columns_widths) > max_height {
                 break;
             }
-            height += item.height_with_margin();
+            height += self.row_height_with_margin(item, columns_widths);
             end += 1;
         }
 
@@ -794,10 +1269,10 @@ impl Table<'_> {
 
         // scroll down until the selected row is visible
         while selected >= end {
-            height = height.saturating_add(self.rows[end].height_with_margin());
+            height = height.saturating_add(height_with_margin(end));
             end += 1;
             while height > max_height {
-                height = height.saturating_sub(self.rows[start].height_with_margin());
+                height = height.saturating_sub(height_with_margin(start));
                 start += 1;
             }
         }
@@ -805,15 +1280,44 @@ impl Table<'_> {
         // scroll up until the selected row is visible
         while selected < start {
             start -= 1;
-            height = height.saturating_add(self.rows[start].height_with_margin());
+            height = height.saturating_add(height_with_margin(start));
             while height > max_height {
                 end -= 1;
-                height = height.saturating_sub(self.rows[end].height_with_margin());
+                height = height.saturating_sub(height_with_margin(end));
             }
         }
         (start, end)
     }
 
+    /// Returns the height of a row, grown to fit its wrapped cells when `cell_wrap` is enabled
+    fn row_height(&self, row: &Row, columns_widths: &[(u16, u16)]) -> u16 {
+        if !self.cell_wrap {
+            return row.height;
+        }
+        columns_widths
+            .iter()
+            .zip(row.cells.iter())
+            .filter(|((_, width), _)| *width > 0)
+            .map(|((_, width), cell)| cell.wrapped_height(*width))
+            .fold(row.height, u16::max)
+    }
+
+    /// Returns the height of a row including its top and bottom margins
+    fn row_height_with_margin(&self, row: &Row, columns_widths: &[(u16, u16)]) -> u16 {
+        self.row_height(row, columns_widths)
+            .saturating_add(row.top_margin)
+            .saturating_add(row.bottom_margin)
+    }
+
+    /// Renders a cell, wrapping its content when `cell_wrap` is enabled
+    fn render_cell(&self, cell: &Cell, area: Rect, buf: &mut Buffer) {
+        if self.cell_wrap {
+            cell.render_wrapped(area, buf);
+        } else {
+            cell.render(area, buf);
+        }
+    }
+
     /// Returns the width of the selection column if a row is selected, or the `highlight_spacing`
     /// is set to show the column always, otherwise 0.
     fn selection_width(&self, state: &TableState) -> u16 {
@@ -876,7 +1380,7 @@ mod tests {
         let widths = [Constraint::Percentage(100)];
         let table = Table::new(rows.clone(), widths);
         assert_eq!(table.rows, rows);
-        assert_eq!(table.header, None);
+        assert_eq!(table.header, []);
         assert_eq!(table.footer, None);
         assert_eq!(table.widths, widths);
         assert_eq!(table.column_spacing, 1);
@@ -892,7 +1396,7 @@ mod tests {
     fn default() {
         let table = Table::default();
         assert_eq!(table.rows, []);
-        assert_eq!(table.header, None);
+        assert_eq!(table.header, []);
         assert_eq!(table.footer, None);
         assert_eq!(table.widths, []);
         assert_eq!(table.column_spacing, 1);
@@ -970,7 +1474,17 @@ mod tests {
     fn header() {
         let header = Row::new(vec![Cell::from("")]);
         let table = Table::default().header(header.clone());
-        assert_eq!(table.header, Some(header));
+        assert_eq!(table.header, [header]);
+    }
+
+    #[test]
+    fn headers() {
+        let headers = [Row::new(["a"]), Row::new(["b"])];
+        let table = Table::default().headers(headers.clone());
+        assert_eq!(table.header, headers);
+        // a single header replaces the header rows
+        let table = table.header(Row::new(["c"]));
+        assert_eq!(table.header, [Row::new(["c"])]);
     }
 
     #[test]
@@ -1144,6 +1658,62 @@ mod tests {
             assert_eq!(buf, expected);
         }
 
+        #[test]
+        fn render_with_multiple_headers() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 15, 5));
+            let headers = [
+                Row::new(vec!["Group", ""]).style(Style::new().bold()),
+                Row::new(vec!["Head1", "Head2"]).top_margin(1),
+            ];
+            let rows = vec![
+                Row::new(vec!["Cell1", "Cell2"]),
+                Row::new(vec!["Cell3", "Cell4"]),
+            ];
+            let table = Table::new(rows, [Constraint::Length(5); 2]).headers(headers);
+            Widget::render(table, Rect::new(0, 0, 15, 5), &mut buf);
+            #[rustfmt::skip]
+            let mut expected = Buffer::with_lines([
+                "Group          ",
+                "               ",
+                "Head1 Head2    ",
+                "Cell1 Cell2    ",
+                "Cell3 Cell4    ",
+            ]);
+            expected.set_style(Rect::new(0, 0, 15, 1), Style::new().bold());
+            assert_eq!(buf, expected);
+        }
+
+        #[test]
+        fn render_with_multiple_headers_and_footer_when_scrolled() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 15, 5));
+            let headers = [
+                Row::new(vec!["Group", ""]),
+                Row::new(vec!["Head1", "Head2"]),
+            ];
+            let footer = Row::new(vec!["Foot1", "Foot2"]);
+            let rows = vec![
+                Row::new(vec!["Cell1", "Cell2"]),
+                Row::new(vec!["Cell3", "Cell4"]),
+                Row::new(vec!["Cell5", "Cell6"]),
+            ];
+            let table = Table::new(rows, [Constraint::Length(5); 2])
+                .headers(headers)
+                .footer(footer);
+            let mut state = TableState::new().with_selected(Some(2));
+            StatefulWidget::render(table, Rect::new(0, 0, 15, 5), &mut buf, &mut state);
+            // the headers and the footer stay in place, the selected row is scrolled into view
+            #[rustfmt::skip]
+            let expected = Buffer::with_lines([
+                "Group          ",
+                "Head1 Head2    ",
+                "Cell3 Cell4    ",
+                "Cell5 Cell6    ",
+                "Foot1 Foot2    ",
+            ]);
+            assert_eq!(buf, expected);
+            assert_eq!(state.offset, 1);
+        }
+
         #[test]
         fn render_with_footer() {
             let mut buf = Buffer::empty(Rect::new(0, 0, 15, 3));
@@ -1258,6 +1828,47 @@ mod tests {
             Widget::render(table, Rect::new(0, 0, 20, 3), &mut buf);
         }
 
+        #[rstest]
+        #[case::no_scroll(0, ["ID Name A B  C", "1  Bob  a b  c"])]
+        #[case::scrolled(1, ["ID Name B  C  ", "1  Bob  b  c  "])]
+        #[case::last_column(2, ["ID Name C     ", "1  Bob  c     "])]
+        #[case::beyond_last_column(10, ["ID Name C     ", "1  Bob  c     "])]
+        fn render_with_frozen_columns(#[case] x_offset: usize, #[case] expected: [&str; 2]) {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 14, 2));
+            let table = Table::new(
+                [Row::new(["1", "Bob", "a", "b", "c"])],
+                [2, 4, 2, 2, 2].map(Constraint::Length),
+            )
+            .header(Row::new(["ID", "Name", "A", "B", "C"]))
+            .frozen_columns(2);
+            let mut state = TableState::new().with_x_offset(x_offset);
+            StatefulWidget::render(table, buf.area, &mut buf, &mut state);
+            assert_eq!(buf, Buffer::with_lines(expected));
+            assert_eq!(state.x_offset(), x_offset.min(2));
+        }
+
+        #[test]
+        fn render_scrolled_without_frozen_columns() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 8, 1));
+            let table = Table::new([Row::new(["a", "b", "c"])], [Constraint::Length(2); 3]);
+            let mut state = TableState::new().with_x_offset(1);
+            StatefulWidget::render(table, buf.area, &mut buf, &mut state);
+            assert_eq!(buf, Buffer::with_lines(["b  c    "]));
+        }
+
+        #[test]
+        fn frozen_columns_are_reserved_first() {
+            let table = Table::default()
+                .widths([
+                    Constraint::Length(4),
+                    Constraint::Min(10),
+                    Constraint::Min(10),
+                ])
+                .frozen_columns(1);
+            assert_eq!(table.get_columns_widths(12, 0, 0), [(0, 4), (5, 3), (9, 3)]);
+            assert_eq!(table.get_columns_widths(12, 2, 1), [(2, 4), (7, 0), (7, 5)]);
+        }
+
         #[test]
         fn render_with_selected() {
             let mut buf = Buffer::empty(Rect::new(0, 0, 15, 3));
@@ -1278,6 +1889,58 @@ mod tests {
             assert_eq!(buf, expected);
         }
 
+        #[test]
+        fn render_with_cell_wrap() {
+            let rows = vec![Row::new(["1", "one two three"]), Row::new(["2", "four"])];
+            let table = Table::new(rows, [Constraint::Length(1), Constraint::Length(7)])
+                .header(Row::new(["#", "long header"]))
+                .cell_wrap(true);
+            let mut buf = Buffer::empty(Rect::new(0, 0, 9, 7));
+            Widget::render(table, buf.area, &mut buf);
+            #[rustfmt::skip]
+            let expected = Buffer::with_lines([
+                "# long   ",
+                "  header ",
+                "1 one two",
+                "  three  ",
+                "2 four   ",
+                "         ",
+                "         ",
+            ]);
+            assert_eq!(buf, expected);
+        }
+
+        #[test]
+        fn render_with_cell_wrap_keeps_row_height_as_minimum() {
+            let rows = vec![Row::new(["a", "b c"]).height(3), Row::new(["d", "e"])];
+            let table =
+                Table::new(rows, [Constraint::Length(1), Constraint::Length(1)]).cell_wrap(true);
+            let mut buf = Buffer::empty(Rect::new(0, 0, 3, 4));
+            Widget::render(table, buf.area, &mut buf);
+            assert_eq!(buf, Buffer::with_lines(["a b", "  c", "   ", "d e"]));
+        }
+
+        #[test]
+        fn render_with_cell_wrap_scrolls_to_selection() {
+            let rows = vec![
+                Row::new(["a a a"]),
+                Row::new(["b b b"]),
+                Row::new(["c"]),
+                Row::new(["d d d"]),
+            ];
+            let table = Table::new(rows, [Constraint::Length(3)])
+                .highlight_symbol(">")
+                .highlight_style(Style::new().red())
+                .cell_wrap(true);
+            let mut buf = Buffer::empty(Rect::new(0, 0, 4, 4));
+            let mut state = TableState::new().with_selected(3);
+            StatefulWidget::render(table, buf.area, &mut buf, &mut state);
+            let expected =
+                Buffer::with_lines([" c  ".into(), ">d d".red(), " d  ".red(), "    ".into()]);
+            assert_eq!(buf, expected);
+            assert_eq!(state.offset(), 2);
+        }
+
         /// Note that this includes a regression test for a bug where the table would not render the
         /// correct rows when there is no selection.
         /// <https://github.com/ratatui/ratatui/issues/1179>
@@ -1311,21 +1974,23 @@ mod tests {
 
     // test how constraints interact with table column width allocation
     mod column_widths {
+        use rstest::rstest;
+
         use super::*;
 
         #[test]
         fn length_constraint() {
             // without selection, more than needed width
             let table = Table::default().widths([Length(4), Length(4)]);
-            assert_eq!(table.get_columns_widths(20, 0), [(0, 4), (5, 4)]);
+            assert_eq!(table.get_columns_widths(20, 0, 0), [(0, 4), (5, 4)]);
 
             // with selection, more than needed width
             let table = Table::default().widths([Length(4), Length(4)]);
-            assert_eq!(table.get_columns_widths(20, 3), [(3, 4), (8, 4)]);
+            assert_eq!(table.get_columns_widths(20, 3, 0), [(3, 4), (8, 4)]);
 
             // without selection, less than needed width
             let table = Table::default().widths([Length(4), Length(4)]);
-            assert_eq!(table.get_columns_widths(7, 0), [(0, 3), (4, 3)]);
+            assert_eq!(table.get_columns_widths(7, 0, 0), [(0, 3), (4, 3)]);
 
             // with selection, less than needed width
             // <--------7px-------->
@@ -1334,26 +1999,26 @@ mod tests {
             // └────────┘x└────────┘
             // column spacing (i.e. `x`) is always prioritized
             let table = Table::default().widths([Length(4), Length(4)]);
-            assert_eq!(table.get_columns_widths(7, 3), [(3, 2), (6, 1)]);
+            assert_eq!(table.get_columns_widths(7, 3, 0), [(3, 2), (6, 1)]);
         }
 
         #[test]
         fn max_constraint() {
             // without selection, more than needed width
             let table = Table::default().widths([Max(4), Max(4)]);
-            assert_eq!(table.get_columns_widths(20, 0), [(0, 4), (5, 4)]);
+            assert_eq!(table.get_columns_widths(20, 0, 0), [(0, 4), (5, 4)]);
 
             // with selection, more than needed width
             let table = Table::default().widths([Max(4), Max(4)]);
-            assert_eq!(table.get_columns_widths(20, 3), [(3, 4), (8, 4)]);
+            assert_eq!(table.get_columns_widths(20, 3, 0), [(3, 4), (8, 4)]);
 
             // without selection, less than needed width
             let table = Table::default().widths([Max(4), Max(4)]);
-            assert_eq!(table.get_columns_widths(7, 0), [(0, 3), (4, 3)]);
+            assert_eq!(table.get_columns_widths(7, 0, 0), [(0, 3), (4, 3)]);
 
             // with selection, less than needed width
             let table = Table::default().widths([Max(4), Max(4)]);
-            assert_eq!(table.get_columns_widths(7, 3), [(3, 2), (6, 1)]);
+            assert_eq!(table.get_columns_widths(7, 3, 0), [(3, 2), (6, 1)]);
         }
 
         #[test]
@@ -1364,42 +2029,42 @@ mod tests {
 
             // without selection, more than needed width
             let table = Table::default().widths([Min(4), Min(4)]);
-            assert_eq!(table.get_columns_widths(20, 0), [(0, 10), (11, 9)]);
+            assert_eq!(table.get_columns_widths(20, 0, 0), [(0, 10), (11, 9)]);
 
             // with selection, more than needed width
             let table = Table::default().widths([Min(4), Min(4)]);
-            assert_eq!(table.get_columns_widths(20, 3), [(3, 8), (12, 8)]);
+            assert_eq!(table.get_columns_widths(20, 3, 0), [(3, 8), (12, 8)]);
 
             // without selection, less than needed width
             // allocates spacer
             let table = Table::default().widths([Min(4), Min(4)]);
-            assert_eq!(table.get_columns_widths(7, 0), [(0, 3), (4, 3)]);
+            assert_eq!(table.get_columns_widths(7, 0, 0), [(0, 3), (4, 3)]);
 
             // with selection, less than needed width
             // always allocates selection and spacer
             let table = Table::default().widths([Min(4), Min(4)]);
-            assert_eq!(table.get_columns_widths(7, 3), [(3, 2), (6, 1)]);
+            assert_eq!(table.get_columns_widths(7, 3, 0), [(3, 2), (6, 1)]);
         }
 
         #[test]
         fn percentage_constraint() {
             // without selection, more than needed width
             let table = Table::default().widths([Percentage(30), Percentage(30)]);
-            assert_eq!(table.get_columns_widths(20, 0), [(0, 6), (7, 6)]);
+            assert_eq!(table.get_columns_widths(20, 0, 0), [(0, 6), (7, 6)]);
 
             // with selection, more than needed width
             let table = Table::default().widths([Percentage(30), Percentage(30)]);
-            assert_eq!(table.get_columns_widths(20, 3), [(3, 5), (9, 5)]);
+            assert_eq!(table.get_columns_widths(20, 3, 0), [(3, 5), (9, 5)]);
 
             // without selection, less than needed width
             // rounds from positions: [0.0, 0.0, 2.1, 3.1, 5.2, 7.0]
             let table = Table::default().widths([Percentage(30), Percentage(30)]);
-            assert_eq!(table.get_columns_widths(7, 0), [(0, 2), (3, 2)]);
+            assert_eq!(table.get_columns_widths(7, 0, 0), [(0, 2), (3, 2)]);
 
             // with selection, less than needed width
             // rounds from positions: [0.0, 3.0, 5.1, 6.1, 7.0, 7.0]
             let table = Table::default().widths([Percentage(30), Percentage(30)]);
-            assert_eq!(table.get_columns_widths(7, 3), [(3, 1), (5, 1)]);
+            assert_eq!(table.get_columns_widths(7, 3, 0), [(3, 1), (5, 1)]);
         }
 
         #[test]
@@ -1407,22 +2072,22 @@ mod tests {
             // without selection, more than needed width
             // rounds from positions: [0.00, 0.00, 6.67, 7.67, 14.33]
             let table = Table::default().widths([Ratio(1, 3), Ratio(1, 3)]);
-            assert_eq!(table.get_columns_widths(20, 0), [(0, 7), (8, 6)]);
+            assert_eq!(table.get_columns_widths(20, 0, 0), [(0, 7), (8, 6)]);
 
             // with selection, more than needed width
             // rounds from positions: [0.00, 3.00, 10.67, 17.33, 20.00]
             let table = Table::default().widths([Ratio(1, 3), Ratio(1, 3)]);
-            assert_eq!(table.get_columns_widths(20, 3), [(3, 6), (10, 5)]);
+            assert_eq!(table.get_columns_widths(20, 3, 0), [(3, 6), (10, 5)]);
 
             // without selection, less than needed width
             // rounds from positions: [0.00, 2.33, 3.33, 5.66, 7.00]
             let table = Table::default().widths([Ratio(1, 3), Ratio(1, 3)]);
-            assert_eq!(table.get_columns_widths(7, 0), [(0, 2), (3, 3)]);
+            assert_eq!(table.get_columns_widths(7, 0, 0), [(0, 2), (3, 3)]);
 
             // with selection, less than needed width
             // rounds from positions: [0.00, 3.00, 5.33, 6.33, 7.00, 7.00]
             let table = Table::default().widths([Ratio(1, 3), Ratio(1, 3)]);
-            assert_eq!(table.get_columns_widths(7, 3), [(3, 1), (5, 2)]);
+            assert_eq!(table.get_columns_widths(7, 3, 0), [(3, 1), (5, 2)]);
         }
 
         /// When more width is available than requested, the behavior is controlled by flex
@@ -1430,7 +2095,7 @@ mod tests {
         fn underconstrained_flex() {
             let table = Table::default().widths([Min(10), Min(10), Min(1)]);
             assert_eq!(
-                table.get_columns_widths(62, 0),
+                table.get_columns_widths(62, 0, 0),
                 &[(0, 20), (21, 20), (42, 20)]
             );
 
@@ -1438,7 +2103,7 @@ mod tests {
                 .widths([Min(10), Min(10), Min(1)])
                 .flex(Flex::Legacy);
             assert_eq!(
-                table.get_columns_widths(62, 0),
+                table.get_columns_widths(62, 0, 0),
                 &[(0, 10), (11, 10), (22, 40)]
             );
 
@@ -1446,7 +2111,7 @@ mod tests {
                 .widths([Min(10), Min(10), Min(1)])
                 .flex(Flex::SpaceBetween);
             assert_eq!(
-                table.get_columns_widths(62, 0),
+                table.get_columns_widths(62, 0, 0),
                 &[(0, 20), (21, 20), (42, 20)]
             );
         }
@@ -1457,7 +2122,7 @@ mod tests {
         fn underconstrained_segment_size() {
             let table = Table::default().widths([Min(10), Min(10), Min(1)]);
             assert_eq!(
-                table.get_columns_widths(62, 0),
+                table.get_columns_widths(62, 0, 0),
                 &[(0, 20), (21, 20), (42, 20)]
             );
 
@@ -1465,7 +2130,7 @@ mod tests {
                 .widths([Min(10), Min(10), Min(1)])
                 .flex(Flex::Legacy);
             assert_eq!(
-                table.get_columns_widths(62, 0),
+                table.get_columns_widths(62, 0, 0),
                 &[(0, 10), (11, 10), (22, 40)]
             );
         }
@@ -1482,7 +2147,7 @@ mod tests {
                 .footer(Row::new(vec!["h", "i"]))
                 .column_spacing(0);
             assert_eq!(
-                table.get_columns_widths(30, 0),
+                table.get_columns_widths(30, 0, 0),
                 &[(0, 10), (10, 10), (20, 10)]
             );
         }
@@ -1493,7 +2158,7 @@ mod tests {
                 .rows(vec![])
                 .header(Row::new(vec!["f", "g"]))
                 .column_spacing(0);
-            assert_eq!(table.get_columns_widths(10, 0), [(0, 5), (5, 5)]);
+            assert_eq!(table.get_columns_widths(10, 0, 0), [(0, 5), (5, 5)]);
         }
 
         #[test]
@@ -1502,7 +2167,104 @@ mod tests {
                 .rows(vec![])
                 .footer(Row::new(vec!["h", "i"]))
                 .column_spacing(0);
-            assert_eq!(table.get_columns_widths(10, 0), [(0, 5), (5, 5)]);
+            assert_eq!(table.get_columns_widths(10, 0, 0), [(0, 5), (5, 5)]);
+        }
+
+        #[test]
+        fn auto_widths() {
+            let table = Table::default()
+                .rows(vec![
+                    Row::new(vec![Text::from("a"), Text::from("bb\nbbbbb")]),
+                    Row::new(vec![Text::from("ccc"), Text::from("日本")]),
+                ])
+                .header(Row::new(vec!["dd", "e", "ffff"]))
+                .widths([Length(1), Length(1)])
+                .auto_widths();
+            assert_eq!(
+                table.get_columns_widths(20, 0, 0),
+                [(0, 3), (4, 5), (10, 4)]
+            );
+            assert_eq!(
+                table.get_columns_widths(20, 3, 0),
+                [(3, 3), (7, 5), (13, 4)]
+            );
+        }
+
+        #[test]
+        fn auto_widths_shrink_proportionally() {
+            let table = Table::default()
+                .rows(vec![Row::new(vec!["aaaa", "bbbbbbbb"])])
+                .auto_widths();
+            assert_eq!(table.get_columns_widths(7, 0, 0), [(0, 2), (3, 4)]);
+        }
+
+        #[rstest]
+        #[case::enough_space(20, [(0, 6), (7, 6), (14, 6)])]
+        #[case::raised_to_minimum(12, [(0, 3), (4, 5), (10, 2)])]
+        #[case::exactly_minimums(10, [(0, 2), (3, 5), (9, 1)])]
+        #[case::last_column_clipped(9, [(0, 3), (4, 5), (9, 0)])]
+        #[case::only_first_column(4, [(0, 4), (4, 0), (4, 0)])]
+        #[case::first_column_wider_than_area(1, [(0, 1), (1, 0), (1, 0)])]
+        fn min_widths(#[case] width: u16, #[case] expected: [(u16, u16); 3]) {
+            let table = Table::default()
+                .widths([Fill(1), Fill(1), Fill(1)])
+                .min_widths([2, 5, 1]);
+            assert_eq!(table.get_columns_widths(width, 0, 0), expected);
+        }
+
+        #[test]
+        fn min_widths_with_length_constraints() {
+            let table = Table::default()
+                .widths([Length(8), Length(8), Length(8)])
+                .min_widths([0, 6]);
+            // without minimum widths, each column would be squished to 3 or 4 cells
+            assert_eq!(
+                table.get_columns_widths(12, 0, 0),
+                [(0, 2), (3, 6), (10, 2)]
+            );
+        }
+
+        #[test]
+        fn min_widths_clipped_columns_are_scrolled_into_view() {
+            let table = Table::default()
+                .widths([Fill(1), Fill(1), Fill(1)])
+                .min_widths([4, 4, 4]);
+            assert_eq!(table.get_columns_widths(9, 0, 0), [(0, 4), (5, 4), (9, 0)]);
+            assert_eq!(table.get_columns_widths(9, 0, 1), [(0, 0), (0, 4), (5, 4)]);
+        }
+
+        #[test]
+        fn min_widths_with_frozen_columns() {
+            let table = Table::default()
+                .widths([Fill(1), Fill(1), Fill(1)])
+                .frozen_columns(1)
+                .min_widths([3, 4, 4]);
+            assert_eq!(table.get_columns_widths(9, 0, 0), [(0, 4), (5, 4), (9, 0)]);
+            assert_eq!(table.get_columns_widths(9, 0, 1), [(0, 4), (5, 0), (5, 4)]);
+        }
+
+        #[test]
+        fn min_widths_with_frozen_columns_scrolled_past_wide_column() {
+            let table = Table::new([Row::new(["id", "wide_col", "c2"])], [Fill(1); 3])
+                .frozen_columns(1)
+                .min_widths([3, 8, 2]);
+            assert_eq!(table.get_columns_widths(9, 0, 1), [(0, 4), (5, 0), (5, 4)]);
+            let mut buf = Buffer::empty(Rect::new(0, 0, 9, 1));
+            let mut state = TableState::default().with_x_offset(1);
+            StatefulWidget::render(table, buf.area, &mut buf, &mut state);
+            assert_eq!(buf, Buffer::with_lines(["id   c2  "]));
+        }
+
+        #[test]
+        fn render_with_min_widths() {
+            let table = Table::new(
+                [Row::new(["a", "bbbb", "cccc"])],
+                [Fill(1), Fill(1), Fill(1)],
+            )
+            .min_widths([1, 4, 4]);
+            let mut buf = Buffer::empty(Rect::new(0, 0, 8, 1));
+            Widget::render(table, buf.area, &mut buf);
+            assert_eq!(buf, Buffer::with_lines(["a   bbbb"]));
         }
 
         #[track_caller]
@@ -1871,4 +2633,35 @@ mod tests {
                 .remove_modifier(Modifier::CROSSED_OUT)
         );
     }
+
+    #[test]
+    fn accessibility_node() {
+        let table = Table::new(
+            [
+                Row::new(["a", "b"]),
+                Row::new(["c", "d"]),
+                Row::new(["e", "f"]),
+            ],
+            [Length(2), Length(2)],
+        )
+        .header(Row::new(["H1", "H2"]))
+        .highlight_symbol(">");
+        let area = Rect::new(0, 0, 6, 3);
+        let state = TableState::new().with_selected(Some(2));
+        let node = StatefulWidget::accessibility_node(&table, area, &state).unwrap();
+        let row = |role, text: &str, y, cells: [&str; 2]| {
+            AccessNode::new(role, text, Rect::new(0, y, 6, 1)).children([
+                AccessNode::new(Role::Cell, cells[0], Rect::new(1, y, 2, 1)),
+                AccessNode::new(Role::Cell, cells[1], Rect::new(4, y, 2, 1)),
+            ])
+        };
+        assert_eq!(
+            node,
+            AccessNode::new(Role::Table, "", area).children([
+                row(Role::Header, "H1 H2", 0, ["H1", "H2"]),
+                row(Role::Row, "c d", 1, ["c", "d"]),
+                row(Role::Row, "e f", 2, ["e", "f"]).selected(true),
+            ])
+        );
+    }
 }
@@ -5,12 +5,15 @@
 /// shifted to ensure that the selected row is visible. This will modify the [`TableState`] object
 /// passed to the [`Frame::render_stateful_widget`] method.
 ///
-/// The state consists of two fields:
+/// The state consists of three fields:
 /// - [`offset`]: the index of the first row to be displayed
 /// - [`selected`]: the index of the selected row, which can be `None` if no row is selected
+/// - [`x_offset`]: the number of columns scrolled horizontally, not counting the
+///   [frozen columns](crate::widgets::Table::frozen_columns)
 ///
 /// [`offset`]: TableState::offset()
 /// [`selected`]: TableState::selected()
+/// [`x_offset`]: TableState::x_offset()
 ///
 /// See the `table` example and the `recipe` and `traceroute` tabs in the demo2 example in the
 /// [Examples] directory for a more in depth example of the various configuration options and for
@@ -49,6 +52,16 @@
 pub struct TableState {
     pub(crate) offset: usize,
     pub(crate) selected: Option<usize>,
+    /// Skipped when zero, so that the state of a table which is not scrolled horizontally keeps
+    /// the same serialized form as before the horizontal scrolling was added
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "is_zero"))]
+    pub(crate) x_offset: usize,
+}
+
+#[cfg(feature = "serde")]
+#[allow(clippy::trivially_copy_pass_by_ref)] // serde passes the field by reference
+const fn is_zero(value: &usize) -> bool {
+    *value == 0
 }
 
 impl TableState {
@@ -64,6 +77,7 @@ impl TableState {
         Self {
             offset: 0,
             selected: None,
+            x_offset: 0,
         }
     }
 
@@ -102,6 +116,22 @@ impl TableState {
         self
     }
 
+    /// Sets the number of columns scrolled horizontally
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let state = TableState::new().with_x_offset(2);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn with_x_offset(mut self, x_offset: usize) -> Self {
+        self.x_offset = x_offset;
+        self
+    }
+
     /// Index of the first row to be displayed
     ///
     /// # Examples
@@ -128,6 +158,35 @@ impl TableState {
         &mut self.offset
     }
 
+    /// Number of columns scrolled horizontally
+    ///
+    /// The [frozen columns](crate::widgets::Table::frozen_columns) are always displayed, and the
+    /// other columns are displayed starting from the column at this offset after them.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let state = TableState::new();
+    /// assert_eq!(state.x_offset(), 0);
+    /// ```
+    pub const fn x_offset(&self) -> usize {
+        self.x_offset
+    }
+
+    /// Mutable reference to the number of columns scrolled horizontally
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let mut state = TableState::default();
+    /// *state.x_offset_mut() = 1;
+    /// ```
+    pub fn x_offset_mut(&mut self) -> &mut usize {
+        &mut self.x_offset
+    }
+
     /// Index of the selected row
     ///
     /// Returns `None` if no row is selected
@@ -277,6 +336,37 @@ impl TableState {
         let selected = self.selected.unwrap_or_default();
         self.select(Some(selected.saturating_sub(amount as usize)));
     }
+
+    /// Scrolls right by a specified `amount` of columns in the table.
+    ///
+    /// Note: until the table is rendered, the number of columns is not known, so the offset may
+    /// go beyond the last column and will be corrected when the table is rendered
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let mut state = TableState::default();
+    /// state.scroll_right_by(2);
+    /// ```
+    pub fn scroll_right_by(&mut self, amount: u16) {
+        self.x_offset = self.x_offset.saturating_add(amount as usize);
+    }
+
+    /// Scrolls left by a specified `amount` of columns in the table.
+    ///
+    /// The offset stops at the first column after the frozen columns.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let mut state = TableState::default();
+    /// state.scroll_left_by(2);
+    /// ```
+    pub fn scroll_left_by(&mut self, amount: u16) {
+        self.x_offset = self.x_offset.saturating_sub(amount as usize);
+    }
 }
 
 #[cfg(test)]
@@ -288,6 +378,7 @@ mod tests {
         let state = TableState::new();
         assert_eq!(state.offset, 0);
         assert_eq!(state.selected, None);
+        assert_eq!(state.x_offset, 0);
     }
 
     #[test]
@@ -302,6 +393,30 @@ mod tests {
         assert_eq!(state.selected, Some(1));
     }
 
+    #[test]
+    fn with_x_offset() {
+        let state = TableState::new().with_x_offset(2);
+        assert_eq!(state.x_offset, 2);
+    }
+
+    #[test]
+    fn x_offset_mut() {
+        let mut state = TableState::new();
+        *state.x_offset_mut() = 1;
+        assert_eq!(state.x_offset(), 1);
+    }
+
+    #[test]
+    fn scroll_horizontally() {
+        let mut state = TableState::new();
+        state.scroll_right_by(3);
+        assert_eq!(state.x_offset, 3);
+        state.scroll_left_by(1);
+        assert_eq!(state.x_offset, 2);
+        state.scroll_left_by(5);
+        assert_eq!(state.x_offset, 0);
+    }
+
     #[test]
     fn offset() {
         let state = TableState::new();
@@ -0,0 +1,311 @@
+use std::{fmt, ops::Range};
+
+use super::{Row, Table, TableState};
+use crate::{prelude::*, widgets::StatefulWidgetRef};
+
+/// A [`Table`] which builds only the rows it displays.
+///
+/// A [`Table`] owns all of its rows, so they must all be built before each render even though only
+/// a screen's worth of them is displayed. A `VirtualTable` instead knows the total number of rows
+/// and asks a provider for the rows in a given range of indices when it is rendered. This makes it
+/// possible to display a very large number of rows (e.g. the lines of a log file) without
+/// materializing them.
+///
+/// The rows requested are driven by the [`TableState`]: the offset and the selected row determine
+/// which rows are visible. At most as many rows as the height of the render area are requested,
+/// starting at the first row which may be displayed. The provider may return fewer rows than
+/// requested, in which case the table ends early.
+///
+/// The header, footer, widths and styles are taken from the wrapped [`Table`], whose own rows are
+/// ignored. As only the displayed rows are known, [`Table::auto_widths`] fits the columns to the
+/// displayed rows, so the widths may change while scrolling.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::{prelude::*, widgets::*};
+///
+/// # fn ui(frame: &mut Frame) {
+/// # let area = Rect::default();
+/// let table = Table::default()
+///     .header(Row::new(["Line", "Message"]))
+///     .widths([Constraint::Length(8), Constraint::Fill(1)]);
+/// let virtual_table = VirtualTable::new(table, 1_000_000, |range| {
+///     range
+///         .map(|i| Row::new([i.to_string(), format!("message {i}")]))
+///         .collect()
+/// });
+///
+/// // This should be stored outside of the function in your application state.
+/// let mut state = TableState::new().with_selected(Some(500_000));
+/// frame.render_stateful_widget(&virtual_table, area, &mut state);
+/// # }
+/// ```
+///
+/// Rows can also be streamed from an iterator by skipping to the start of the range:
+///
+/// ```rust
+/// # use ratatui::{prelude::*, widgets::*};
+/// # let lines = ["a"; 3];
+/// let virtual_table = VirtualTable::new(Table::default(), lines.len(), |range| {
+///     lines
+///         .iter()
+///         .skip(range.start)
+///         .take(range.len())
+///         .map(|line| Row::new([*line]))
+///         .collect()
+/// });
+/// ```
+#[derive(Clone)]
+pub struct VirtualTable<'a, F> {
+    table: Table<'a>,
+    len: usize,
+    provider: F,
+}
+
+impl<F> fmt::Debug for VirtualTable<'_, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("VirtualTable")
+            .field("table", &self.table)
+            .field("len", &self.len)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<'a, F> VirtualTable<'a, F>
+where
+    F: Fn(Range<usize>) -> Vec<Row<'a>>,
+{
+    /// Creates a new `VirtualTable` with `len` rows provided by `provider`.
+    ///
+    /// The `table` configures the header, footer, widths and styles of the table. The `provider`
+    /// is called with a range of row indices within `0..len` when the table is rendered and
+    /// returns the rows in that range.
+    pub const fn new(table: Table<'a>, len: usize, provider: F) -> Self {
+        Self {
+            table,
+            len,
+            provider,
+        }
+    }
+
+    /// Returns the total number of rows of the table.
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the table has no rows.
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the range of rows which may be displayed in a rows area of the given height.
+    ///
+    /// This mirrors the scrolling of [`Table`]: the offset is kept unless the selected row is
+    /// above it, in which case the selected row becomes the first row, or below the rows which
+    /// can fit, in which case the selected row becomes the last row. As each row is at least one
+    /// line tall, no more than `height` rows are displayed.
+    fn visible_range(&self, state: &TableState, height: u16) -> Range<usize> {
+        if self.len == 0 {
+            return 0..0;
+        }
+        let height = usize::from(height);
+        let offset = state.offset.min(self.len - 1);
+        let start = match state.selected.map(|selected| selected.min(self.len - 1)) {
+            Some(selected) if selected < offset => selected,
+            Some(selected) if selected >= offset + height => (selected + 1).saturating_sub(height),
+            _ => offset,
+        };
+        start..(start + height).min(self.len)
+    }
+}
+
+impl<'a, F> StatefulWidget for VirtualTable<'a, F>
+where
+    F: Fn(Range<usize>) -> Vec<Row<'a>>,
+{
+    type State = TableState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        StatefulWidgetRef::render_ref(&self, area, buf, state);
+    }
+}
+
+// Note: remove this when StatefulWidgetRef is stabilized and replace with the blanket impl
+impl<'a, F> StatefulWidget for &VirtualTable<'a, F>
+where
+    F: Fn(Range<usize>) -> Vec<Row<'a>>,
+{
+    type State = TableState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        StatefulWidgetRef::render_ref(self, area, buf, state);
+    }
+}
+
+impl<'a, F> StatefulWidgetRef for VirtualTable<'a, F>
+where
+    F: Fn(Range<usize>) -> Vec<Row<'a>>,
+{
+    type State = TableState;
+
+    fn render_ref(&self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        let range = if area.is_empty() {
+            0..0
+        } else {
+            self.visible_range(state, area.height)
+        };
+        let start = range.start;
+        let rows = if range.is_empty() {
+            Vec::new()
+        } else {
+            let len = range.len();
+            let mut rows = (self.provider)(range);
+            rows.truncate(len);
+            rows
+        };
+
+        // render the rows as a table starting at the first requested row, then translate the
+        // offset and selection back to indices of the whole table
+        let mut window_state = TableState {
+            offset: 0,
+            selected: state.selected.map(|selected| {
+                selected
+                    .min(self.len.saturating_sub(1))
+                    .saturating_sub(start)
+            }),
+            x_offset: state.x_offset,
+        };
+        let table = self.table.clone().rows(rows);
+        StatefulWidgetRef::render_ref(&table, area, buf, &mut window_state);
+        if area.is_empty() {
+            return;
+        }
+        state.offset = start + window_state.offset;
+        state.selected = if self.len == 0 {
+            None
+        } else {
+            window_state.selected.map(|selected| start + selected)
+        };
+        state.x_offset = window_state.x_offset;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+
+    use rstest::rstest;
+
+    use super::*;
+
+    fn numbered_rows(range: Range<usize>) -> Vec<Row<'static>> {
+        range.map(|i| Row::new([i.to_string()])).collect()
+    }
+
+    #[test]
+    fn debug_skips_provider() {
+        let table = VirtualTable::new(Table::default(), 3, |range: Range<usize>| {
+            range.map(|i| Row::new([i.to_string()])).collect()
+        });
+        let debug = format!("{table:?}");
+        assert!(debug.starts_with("VirtualTable { table: Table {"));
+        assert!(debug.ends_with(", len: 3, .. }"));
+    }
+
+    #[test]
+    fn requests_only_visible_rows() {
+        let requests = RefCell::new(Vec::new());
+        let table = VirtualTable::new(
+            Table::default().widths([Constraint::Length(5)]),
+            1_000_000,
+            |range| {
+                requests.borrow_mut().push(range.clone());
+                numbered_rows(range)
+            },
+        );
+        let mut buf = Buffer::empty(Rect::new(0, 0, 5, 3));
+        let mut state = TableState::new().with_offset(500);
+        StatefulWidget::render(&table, buf.area, &mut buf, &mut state);
+        assert_eq!(buf, Buffer::with_lines(["500  ", "501  ", "502  "]));
+        assert_eq!(*requests.borrow(), vec![500..503]);
+        assert_eq!(state.offset(), 500);
+    }
+
+    #[rstest]
+    #[case::no_selection(4, None, ["4 ", "5 ", "6 "], 4)]
+    #[case::selection_visible(4, Some(5), ["  4", ">>5", "  6"], 4)]
+    #[case::selection_above(4, Some(1), [">>1", "  2", "  3"], 1)]
+    #[case::selection_below(4, Some(8), ["  6", "  7", ">>8"], 6)]
+    #[case::selection_past_end(4, Some(20), ["  7", "  8", ">>9"], 7)]
+    #[case::offset_past_end(20, None, ["9 ", "  ", "  "], 9)]
+    fn scrolls_to_selection(
+        #[case] offset: usize,
+        #[case] selected: Option<usize>,
+        #[case] expected: [&str; 3],
+        #[case] expected_offset: usize,
+    ) {
+        let table = VirtualTable::new(
+            Table::default()
+                .widths([Constraint::Length(1)])
+                .highlight_symbol(">>"),
+            10,
+            numbered_rows,
+        );
+        let width = expected[0].len() as u16;
+        let mut buf = Buffer::empty(Rect::new(0, 0, width, 3));
+        let mut state = TableState::new()
+            .with_offset(offset)
+            .with_selected(selected);
+        StatefulWidget::render(&table, buf.area, &mut buf, &mut state);
+        assert_eq!(buf, Buffer::with_lines(expected));
+        assert_eq!(state.offset(), expected_offset);
+        assert_eq!(state.selected(), selected.map(|s| s.min(9)));
+    }
+
+    #[test]
+    fn renders_header_and_footer() {
+        let table = VirtualTable::new(
+            Table::default()
+                .header(Row::new(["head"]))
+                .footer(Row::new(["foot"]))
+                .widths([Constraint::Length(4)]),
+            100,
+            numbered_rows,
+        );
+        let mut buf = Buffer::empty(Rect::new(0, 0, 4, 4));
+        let mut state = TableState::new().with_selected(Some(10));
+        StatefulWidget::render(&table, buf.area, &mut buf, &mut state);
+        assert_eq!(buf, Buffer::with_lines(["head", "9   ", "10  ", "foot"]));
+        assert_eq!(state.offset(), 9);
+    }
+
+    #[test]
+    fn tall_rows() {
+        let table = VirtualTable::new(
+            Table::default().widths([Constraint::Length(2)]),
+            10,
+            |range| {
+                range
+                    .map(|i| Row::new([format!("{i}")]).height(2))
+                    .collect()
+            },
+        );
+        let mut buf = Buffer::empty(Rect::new(0, 0, 2, 4));
+        let mut state = TableState::new().with_selected(Some(3));
+        StatefulWidget::render(&table, buf.area, &mut buf, &mut state);
+        assert_eq!(buf, Buffer::with_lines(["2 ", "  ", "3 ", "  "]));
+        assert_eq!(state.offset(), 2);
+    }
+
+    #[test]
+    fn empty() {
+        let table = VirtualTable::new(Table::default(), 0, |_| unreachable!());
+        assert!(table.is_empty());
+        let mut buf = Buffer::empty(Rect::new(0, 0, 4, 2));
+        let mut state = TableState::new().with_selected(Some(3));
+        StatefulWidget::render(&table, buf.area, &mut buf, &mut state);
+        assert_eq!(buf, Buffer::empty(buf.area));
+        assert_eq!(state.selected(), None);
+    }
+}
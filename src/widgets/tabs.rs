@@ -1,6 +1,10 @@
+use std::{iter, ops::Range};
+
 use crate::{prelude::*, style::Styled, widgets::Block};
 
 const DEFAULT_HIGHLIGHT_STYLE: Style = Style::new().add_modifier(Modifier::REVERSED);
+const DEFAULT_OVERFLOW_LEFT: &str = "‹";
+const DEFAULT_OVERFLOW_RIGHT: &str = "›";
 
 /// A widget that displays a horizontal set of Tabs with a single tab selected.
 ///
@@ -11,6 +15,13 @@ const DEFAULT_HIGHLIGHT_STYLE: Style = Style::new().add_modifier(Modifier::REVER
 ///
 /// The divider defaults to |, and padding defaults to a singular space on each side.
 ///
+/// By default, tabs that don't fit in the width of the area are truncated. With [`Tabs::wrap`],
+/// they flow onto the next rows instead. [`Tabs::line_count`] returns the number of rows needed.
+///
+/// When rendered as a [`StatefulWidget`] with a [`TabsState`], the tabs scroll horizontally to keep
+/// the selected tab visible instead, and [overflow indicators](Tabs::overflow_indicators) are
+/// shown on the sides which have hidden tabs.
+///
 /// # Example
 ///
 /// ```
@@ -51,6 +62,12 @@ pub struct Tabs<'a> {
     padding_left: Line<'a>,
     /// Tab Right Padding
     padding_right: Line<'a>,
+    /// Whether tabs that don't fit in a row flow onto the next rows
+    wrap: bool,
+    /// Indicator shown on the left when tabs are scrolled out of view
+    overflow_left: Span<'a>,
+    /// Indicator shown on the right when tabs are scrolled out of view
+    overflow_right: Span<'a>,
 }
 
 impl<'a> Tabs<'a> {
@@ -98,6 +115,9 @@ impl<'a> Tabs<'a> {
             divider: Span::raw(symbols::line::VERTICAL),
             padding_left: Line::from(" "),
             padding_right: Line::from(" "),
+            wrap: false,
+            overflow_left: Span::raw(DEFAULT_OVERFLOW_LEFT),
+            overflow_right: Span::raw(DEFAULT_OVERFLOW_RIGHT),
         }
     }
 
@@ -235,6 +255,75 @@ impl<'a> Tabs<'a> {
         self.padding_left = padding.into();
         self
     }
+
+    /// Sets whether tabs that don't fit in a row flow onto the next rows.
+    ///
+    /// When wrapping, a tab is moved to the next row if it doesn't fit entirely in the current
+    /// one, and the divider is only rendered between tabs of the same row. Rows that don't fit in
+    /// the height of the area are not rendered. Use [`Tabs::line_count`] to know the height needed
+    /// to render all the tabs.
+    ///
+    /// Defaults to `false`, which truncates the tabs at the right edge of the area.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ratatui::{prelude::*, widgets::Tabs};
+    /// let tabs = Tabs::new(vec!["Tab 1", "Tab 2", "Tab 3"]).wrap(true);
+    /// assert_eq!(tabs.line_count(16), 2);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn wrap(mut self, wrap: bool) -> Self {
+        self.wrap = wrap;
+        self
+    }
+
+    /// Sets the indicators shown when tabs are scrolled out of view.
+    ///
+    /// The indicators are only used when the tabs are rendered with a [`TabsState`] and are not
+    /// [wrapped](Tabs::wrap). The `left` indicator is shown when there are hidden tabs on the left
+    /// and the `right` indicator when there are hidden tabs on the right.
+    ///
+    /// Defaults to `‹` and `›`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ratatui::{prelude::*, widgets::Tabs};
+    /// let tabs = Tabs::new(vec!["Tab 1", "Tab 2"]).overflow_indicators("<", ">".dim());
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn overflow_indicators<T, U>(mut self, left: T, right: U) -> Self
+    where
+        T: Into<Span<'a>>,
+        U: Into<Span<'a>>,
+    {
+        self.overflow_left = left.into();
+        self.overflow_right = right.into();
+        self
+    }
+
+    /// Returns the number of rows needed to render all the tabs at the given width.
+    ///
+    /// This is always `1` when the tabs are not [wrapped](Tabs::wrap). The space taken by the
+    /// [block](Tabs::block), if any, is included.
+    pub fn line_count(&self, width: u16) -> usize {
+        let (top, bottom) = self
+            .block
+            .as_ref()
+            .map(Block::vertical_space)
+            .unwrap_or_default();
+        let (left, right) = self
+            .block
+            .as_ref()
+            .map(Block::horizontal_space)
+            .unwrap_or_default();
+        let tabs_width = width.saturating_sub(left).saturating_sub(right);
+        self.rows(tabs_width)
+            .len()
+            .saturating_add(top as usize)
+            .saturating_add(bottom as usize)
+    }
 }
 
 impl<'a> Styled for Tabs<'a> {
@@ -251,7 +340,7 @@ impl<'a> Styled for Tabs<'a> {
 
 impl Widget for Tabs<'_> {
     fn render(self, area: Rect, buf: &mut Buffer) {
-        self.render_ref(area, buf);
+        WidgetRef::render_ref(&self, area, buf);
     }
 }
 
@@ -260,20 +349,190 @@ impl WidgetRef for Tabs<'_> {
         buf.set_style(area, self.style);
         self.block.render_ref(area, buf);
         let inner = self.block.inner_if_some(area);
-        self.render_tabs(inner, buf);
+        self.render_tabs(inner, buf, self.selected);
+    }
+}
+
+impl StatefulWidget for Tabs<'_> {
+    type State = TabsState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        StatefulWidgetRef::render_ref(&self, area, buf, state);
+    }
+}
+
+// Note: remove this when StatefulWidgetRef is stabilized and replace with the blanket impl
+impl StatefulWidget for &Tabs<'_> {
+    type State = TabsState;
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        StatefulWidgetRef::render_ref(self, area, buf, state);
+    }
+}
+
+impl StatefulWidgetRef for Tabs<'_> {
+    type State = TabsState;
+
+    fn render_ref(&self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        buf.set_style(area, self.style);
+        self.block.render_ref(area, buf);
+        let inner = self.block.inner_if_some(area);
+
+        if self.titles.is_empty() {
+            state.offset = 0;
+            return;
+        }
+        let last_index = self.titles.len() - 1;
+        if let Some(selected) = state.selected.as_mut() {
+            *selected = (*selected).min(last_index);
+        }
+        let selected = state.selected.unwrap_or(self.selected);
+        if self.wrap {
+            self.render_tabs(inner, buf, selected);
+            return;
+        }
+        if inner.is_empty() {
+            return;
+        }
+
+        state.offset = state.offset.min(last_index);
+        if selected <= last_index {
+            self.scroll_to(selected, inner.width, &mut state.offset);
+        }
+        let start = state.offset;
+        let mut tabs_area = inner;
+        let left_width = if start > 0 {
+            self.overflow_left.width()
+        } else {
+            0
+        };
+        let available = usize::from(inner.width).saturating_sub(left_width);
+        let mut end = self.visible_end(start, available);
+
+        // The indicators are rendered first, so that the tabs are rendered between them
+        if end <= last_index {
+            let available = available.saturating_sub(self.overflow_right.width());
+            end = self.visible_end(start, available).max(start + 1);
+            let right_width = (self.overflow_right.width() as u16).min(tabs_area.width);
+            tabs_area.width -= right_width;
+            buf.set_span(
+                tabs_area.right(),
+                tabs_area.top(),
+                &self.overflow_right,
+                right_width,
+            );
+        }
+        if start > 0 {
+            let left_width = (left_width as u16).min(tabs_area.width);
+            buf.set_span(
+                tabs_area.x,
+                tabs_area.top(),
+                &self.overflow_left,
+                left_width,
+            );
+            tabs_area.x += left_width;
+            tabs_area.width -= left_width;
+        }
+        self.render_row(start..end, tabs_area, buf, selected);
     }
 }
 
 impl Tabs<'_> {
-    fn render_tabs(&self, tabs_area: Rect, buf: &mut Buffer) {
+    fn render_tabs(&self, tabs_area: Rect, buf: &mut Buffer, selected: usize) {
         if tabs_area.is_empty() {
             return;
         }
 
-        let mut x = tabs_area.left();
-        let titles_length = self.titles.len();
+        for (tabs, y) in self.rows(tabs_area.width).into_iter().zip(tabs_area.rows()) {
+            self.render_row(tabs, y, buf, selected);
+        }
+    }
+
+    /// Returns the width of the tab at the given index, including its padding
+    fn tab_width(&self, index: usize) -> usize {
+        self.padding_left.width() + self.titles[index].width() + self.padding_right.width()
+    }
+
+    /// Returns the end of the range of tabs starting at `start` which fit entirely in `width`
+    fn visible_end(&self, start: usize, width: usize) -> usize {
+        let mut used = 0;
+        for i in start..self.titles.len() {
+            used += self.tab_width(i);
+            if i > start {
+                used += self.divider.width();
+            }
+            if used > width {
+                return i;
+            }
+        }
+        self.titles.len()
+    }
+
+    /// Adjusts the offset so that the selected tab is entirely visible, if possible
+    fn scroll_to(&self, selected: usize, width: u16, offset: &mut usize) {
+        if selected < *offset {
+            *offset = selected;
+            return;
+        }
+        let has_hidden_tabs_after = selected < self.titles.len() - 1;
+        let right_width = if has_hidden_tabs_after {
+            self.overflow_right.width()
+        } else {
+            0
+        };
+        while *offset < selected {
+            let left_width = if *offset > 0 {
+                self.overflow_left.width()
+            } else {
+                0
+            };
+            let available = usize::from(width).saturating_sub(left_width + right_width);
+            if self.visible_end(*offset, available) > selected {
+                break;
+            }
+            *offset += 1;
+        }
+    }
+
+    /// Splits the tabs into the rows they are rendered on.
+    ///
+    /// Without wrapping, all the tabs are on a single row.
+    fn rows(&self, width: u16) -> Vec<Range<usize>> {
+        if !self.wrap {
+            return iter::once(0..self.titles.len()).collect();
+        }
+        let padding_width = self.padding_left.width() + self.padding_right.width();
+        let divider_width = self.divider.width();
+        let mut rows = vec![];
+        let mut start = 0;
+        let mut row_width = 0;
         for (i, title) in self.titles.iter().enumerate() {
-            let last_title = titles_length - 1 == i;
+            let tab_width = padding_width + title.width();
+            if i > start && row_width + divider_width + tab_width > usize::from(width) {
+                rows.push(start..i);
+                start = i;
+                row_width = tab_width;
+            } else if i > start {
+                row_width += divider_width + tab_width;
+            } else {
+                row_width = tab_width;
+            }
+        }
+        rows.push(start..self.titles.len());
+        rows
+    }
+
+    /// Renders the given tabs on a single row, truncating them at the right edge of the row
+    fn render_row(&self, tabs: Range<usize>, tabs_area: Rect, buf: &mut Buffer, selected: usize) {
+        let mut x = tabs_area.left();
+        let last_index = tabs.end.saturating_sub(1);
+        for (i, title) in self
+            .titles
+            .iter()
+            .enumerate()
+            .take(tabs.end)
+            .skip(tabs.start)
+        {
+            let last_title = last_index == i;
             let remaining_width = tabs_area.right().saturating_sub(x);
 
             if remaining_width == 0 {
@@ -290,7 +549,7 @@ impl Tabs<'_> {
 
             // Title
             let pos = buf.set_line(x, tabs_area.top(), title, remaining_width);
-            if i == self.selected {
+            if i == selected {
                 buf.set_style(
                     Rect {
                         x,
@@ -330,6 +589,136 @@ where
     }
 }
 
+/// State of the [`Tabs`] widget
+///
+/// When the tabs are rendered as a stateful widget, the selected tab will be highlighted and the
+/// tabs will be scrolled horizontally to ensure that the selected tab is visible. This will modify
+/// the [`TabsState`] object passed to the
+/// [`Frame::render_stateful_widget`](crate::terminal::Frame::render_stateful_widget) method.
+///
+/// The state consists of two fields:
+/// - [`offset`]: the index of the first visible tab
+/// - [`selected`]: the index of the selected tab. When this is `None`, the tab set with
+///   [`Tabs::select`] is highlighted instead.
+///
+/// [`offset`]: TabsState::offset()
+/// [`selected`]: TabsState::selected()
+///
+/// # Example
+///
+/// ```rust
+/// # use ratatui::{prelude::*, widgets::*};
+/// # fn ui(frame: &mut Frame) {
+/// # let area = Rect::default();
+/// let tabs = Tabs::new((1..=20).map(|i| format!("Tab {i}")));
+///
+/// // This should be stored outside of the function in your application state.
+/// let mut state = TabsState::default();
+///
+/// state.select(Some(12)); // select the thirteenth tab (0-indexed)
+///
+/// frame.render_stateful_widget(tabs, area, &mut state);
+/// # }
+/// ```
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TabsState {
+    offset: usize,
+    selected: Option<usize>,
+}
+
+impl TabsState {
+    /// Sets the index of the first visible tab
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let state = TabsState::default().with_offset(1);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn with_offset(mut self, offset: usize) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    /// Sets the index of the selected tab
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let state = TabsState::default().with_selected(Some(1));
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn with_selected(mut self, selected: Option<usize>) -> Self {
+        self.selected = selected;
+        self
+    }
+
+    /// Index of the first visible tab
+    pub const fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Mutable reference to the index of the first visible tab
+    pub fn offset_mut(&mut self) -> &mut usize {
+        &mut self.offset
+    }
+
+    /// Index of the selected tab
+    ///
+    /// Returns `None` if no tab is selected
+    pub const fn selected(&self) -> Option<usize> {
+        self.selected
+    }
+
+    /// Mutable reference to the index of the selected tab
+    pub fn selected_mut(&mut self) -> &mut Option<usize> {
+        &mut self.selected
+    }
+
+    /// Sets the index of the selected tab
+    ///
+    /// Set to `None` if no tab is selected. This will also reset the offset to `0`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let mut state = TabsState::default();
+    /// state.select(Some(1));
+    /// ```
+    pub fn select(&mut self, index: Option<usize>) {
+        self.selected = index;
+        if index.is_none() {
+            self.offset = 0;
+        }
+    }
+
+    /// Selects the next tab or the first one if no tab is selected
+    ///
+    /// Note: until the tabs are rendered, the number of tabs is not known, so the index may be
+    /// past the last tab and will be corrected when the tabs are rendered
+    pub fn select_next(&mut self) {
+        let next = self.selected.map_or(0, |i| i.saturating_add(1));
+        self.select(Some(next));
+    }
+
+    /// Selects the previous tab or the last one if no tab is selected
+    ///
+    /// Note: until the tabs are rendered, the number of tabs is not known, so the index is set to
+    /// `usize::MAX` and will be corrected when the tabs are rendered
+    pub fn select_previous(&mut self) {
+        let previous = self.selected.map_or(usize::MAX, |i| i.saturating_sub(1));
+        self.select(Some(previous));
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -354,6 +743,9 @@ mod tests {
                 divider: Span::raw(symbols::line::VERTICAL),
                 padding_right: Line::from(" "),
                 padding_left: Line::from(" "),
+                wrap: false,
+                overflow_left: Span::raw("‹"),
+                overflow_right: Span::raw("›"),
             }
         );
     }
@@ -381,10 +773,108 @@ mod tests {
     #[track_caller]
     fn test_case(tabs: Tabs, area: Rect, expected: &Buffer) {
         let mut buffer = Buffer::empty(area);
-        tabs.render(area, &mut buffer);
+        Widget::render(tabs, area, &mut buffer);
         assert_eq!(&buffer, expected);
     }
 
+    #[track_caller]
+    fn render_scrolled(tabs: &Tabs, width: u16, state: &mut TabsState) -> Buffer {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, width, 1));
+        StatefulWidget::render(tabs, buffer.area, &mut buffer, state);
+        buffer
+    }
+
+    #[test]
+    fn render_scrolled_to_selected() {
+        let tabs = Tabs::new(["Tab1", "Tab2", "Tab3", "Tab4", "Tab5"]);
+        let mut state = TabsState::default();
+        let mut expected = Buffer::with_lines([" Tab1 │ Tab2      ›"]);
+        expected.set_style(Rect::new(1, 0, 4, 1), DEFAULT_HIGHLIGHT_STYLE);
+        assert_eq!(render_scrolled(&tabs, 19, &mut state), expected);
+        assert_eq!(state.offset(), 0);
+
+        state.select(Some(2));
+        let mut expected = Buffer::with_lines(["‹ Tab2 │ Tab3     ›"]);
+        expected.set_style(Rect::new(9, 0, 4, 1), DEFAULT_HIGHLIGHT_STYLE);
+        assert_eq!(render_scrolled(&tabs, 19, &mut state), expected);
+        assert_eq!(state.offset(), 1);
+
+        state.select(Some(4));
+        let mut expected = Buffer::with_lines(["‹ Tab4 │ Tab5      "]);
+        expected.set_style(Rect::new(9, 0, 4, 1), DEFAULT_HIGHLIGHT_STYLE);
+        assert_eq!(render_scrolled(&tabs, 19, &mut state), expected);
+        assert_eq!(state.offset(), 3);
+
+        // scrolling back only moves the offset as far as needed
+        state.select(Some(1));
+        let mut expected = Buffer::with_lines(["‹ Tab2 │ Tab3     ›"]);
+        expected.set_style(Rect::new(2, 0, 4, 1), DEFAULT_HIGHLIGHT_STYLE);
+        assert_eq!(render_scrolled(&tabs, 19, &mut state), expected);
+        assert_eq!(state.offset(), 1);
+    }
+
+    #[test]
+    fn render_scrolled_without_overflow() {
+        let tabs = Tabs::new(["Tab1", "Tab2"]).select(1);
+        let mut state = TabsState::default();
+        let mut expected = Buffer::with_lines([" Tab1 │ Tab2   "]);
+        expected.set_style(Rect::new(8, 0, 4, 1), DEFAULT_HIGHLIGHT_STYLE);
+        assert_eq!(render_scrolled(&tabs, 15, &mut state), expected);
+        assert_eq!(state, TabsState::default());
+    }
+
+    #[test]
+    fn render_scrolled_custom_indicators() {
+        let tabs = Tabs::new(["Tab1", "Tab2", "Tab3"])
+            .overflow_indicators("<<", ">>")
+            .divider("--")
+            .padding("", "");
+        let mut state = TabsState::default().with_selected(Some(1));
+        let mut expected = Buffer::with_lines(["<<Tab2  >>"]);
+        expected.set_style(Rect::new(2, 0, 4, 1), DEFAULT_HIGHLIGHT_STYLE);
+        assert_eq!(render_scrolled(&tabs, 10, &mut state), expected);
+    }
+
+    #[test]
+    fn render_scrolled_truncates_wide_tab() {
+        let tabs = Tabs::new(["Tab1", "A very long tab", "Tab3"]);
+        let mut state = TabsState::default().with_selected(Some(1));
+        let mut expected = Buffer::with_lines(["‹ A very lo›"]);
+        expected.set_style(Rect::new(2, 0, 9, 1), DEFAULT_HIGHLIGHT_STYLE);
+        assert_eq!(render_scrolled(&tabs, 12, &mut state), expected);
+        assert_eq!(state.offset(), 1);
+    }
+
+    #[test]
+    fn render_scrolled_clamps_state() {
+        let tabs = Tabs::new(["Tab1", "Tab2"]);
+        let mut state = TabsState::default().with_offset(10).with_selected(Some(10));
+        render_scrolled(&tabs, 20, &mut state);
+        assert_eq!(
+            state,
+            TabsState::default().with_offset(1).with_selected(Some(1))
+        );
+
+        let mut state = TabsState::default().with_offset(3);
+        render_scrolled(&Tabs::default(), 20, &mut state);
+        assert_eq!(state.offset(), 0);
+    }
+
+    #[test]
+    fn state_navigation() {
+        let mut state = TabsState::default().with_offset(2);
+        state.select_next();
+        assert_eq!(state.selected(), Some(0));
+        state.select_next();
+        assert_eq!(state.selected(), Some(1));
+        state.select_previous();
+        assert_eq!(state.selected(), Some(0));
+        state.select(None);
+        assert_eq!(state.offset(), 0);
+        state.select_previous();
+        assert_eq!(state.selected(), Some(usize::MAX));
+    }
+
     #[test]
     fn render_default() {
         let tabs = Tabs::new(vec!["Tab1", "Tab2", "Tab3", "Tab4"]);
@@ -412,6 +902,36 @@ mod tests {
         test_case(tabs, Rect::new(0, 0, 30, 1), &expected);
     }
 
+    #[test]
+    fn render_wrapped() {
+        let tabs = Tabs::new(vec!["Tab1", "Tab2", "Tab3", "Tab4", "Tab5"])
+            .select(3)
+            .wrap(true);
+        let mut expected = Buffer::with_lines([" Tab1 │ Tab2 │ Tab3 ", " Tab4 │ Tab5        "]);
+        // fourth tab selected, on the second row
+        expected.set_style(Rect::new(1, 1, 4, 1), DEFAULT_HIGHLIGHT_STYLE);
+        test_case(tabs, Rect::new(0, 0, 20, 2), &expected);
+    }
+
+    #[test]
+    fn render_wrapped_clips_extra_rows() {
+        let tabs = Tabs::new(vec!["Tab1", "Tab2", "Tab3", "Tab4"]).wrap(true);
+        let mut expected = Buffer::with_lines([" Tab1 │ Tab2 "]);
+        expected.set_style(Rect::new(1, 0, 4, 1), DEFAULT_HIGHLIGHT_STYLE);
+        test_case(tabs.clone(), Rect::new(0, 0, 13, 1), &expected);
+        assert_eq!(tabs.line_count(13), 2);
+        assert_eq!(tabs.line_count(6), 4);
+    }
+
+    #[test]
+    fn line_count() {
+        let tabs = Tabs::new(vec!["Tab1", "Tab2", "Tab3"]);
+        assert_eq!(tabs.clone().line_count(5), 1);
+        assert_eq!(tabs.clone().wrap(true).line_count(20), 1);
+        assert_eq!(tabs.clone().wrap(true).line_count(19), 2);
+        assert_eq!(tabs.wrap(true).block(Block::bordered()).line_count(22), 3);
+    }
+
     #[test]
     fn render_with_block() {
         let tabs =
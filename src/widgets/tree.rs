@@ -0,0 +1,548 @@
+use std::{collections::HashSet, hash::Hash};
+
+use unicode_width::UnicodeWidthStr;
+
+use self::state::VisibleNode;
+pub use self::{item::TreeItem, state::TreeState};
+use crate::{
+    prelude::*,
+    style::Styled,
+    widgets::{block::BlockExt, Block},
+};
+
+mod item;
+mod state;
+
+/// Connector drawn in front of a node which has siblings below it
+const BRANCH: &str = "├─ ";
+/// Connector drawn in front of the last child of a node
+const LAST_BRANCH: &str = "└─ ";
+/// Indentation below a node which has siblings below it
+const VERTICAL: &str = "│  ";
+/// Indentation below the last child of a node
+const EMPTY: &str = "   ";
+
+/// A widget to display hierarchical data and allow selection
+///
+/// A [`Tree`] is a collection of [`TreeItem`]s, each of which can have children. The children of
+/// a node are only displayed when the node is expanded in the [`TreeState`]. The nodes are
+/// connected using box drawing characters and nodes with children are prefixed with a symbol that
+/// shows whether they are expanded or collapsed.
+///
+/// ```text
+/// ▼ src
+/// ├─ ▶ widgets
+/// ├─ lib.rs
+/// └─ main.rs
+/// ▶ tests
+/// ```
+///
+/// [`Tree`] implements [`StatefulWidget`] and so it can be drawn using
+/// [`Frame::render_stateful_widget`](crate::terminal::Frame::render_stateful_widget). The
+/// [`TreeState`] tracks the expanded nodes and the selection and provides methods to navigate the
+/// tree.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::{prelude::*, widgets::*};
+///
+/// # fn ui(frame: &mut Frame, state: &mut TreeState<&'static str>) {
+/// let items = [
+///     TreeItem::new("src", "src").children([
+///         TreeItem::new("lib.rs", "lib.rs"),
+///         TreeItem::new("main.rs", "main.rs"),
+///     ]),
+///     TreeItem::new("Cargo.toml", "Cargo.toml"),
+/// ];
+/// let tree = Tree::new(items)
+///     .block(Block::bordered().title("Files"))
+///     .highlight_style(Style::new().reversed());
+///
+/// frame.render_stateful_widget(tree, frame.area(), state);
+/// # }
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Tree<'a, Id> {
+    /// The root items of the tree
+    items: Vec<TreeItem<'a, Id>>,
+    /// Optional block to wrap the tree in
+    block: Option<Block<'a>>,
+    /// Base style of the widget
+    style: Style,
+    /// Style used to render the selected node
+    highlight_style: Style,
+    /// Symbol in front of the selected node
+    highlight_symbol: Option<&'a str>,
+    /// Symbol in front of expanded nodes
+    expanded_symbol: &'a str,
+    /// Symbol in front of collapsed nodes
+    collapsed_symbol: &'a str,
+}
+
+impl<'a, Id> Tree<'a, Id> {
+    /// Creates a new tree from the given root [`TreeItem`]s
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let tree = Tree::new([TreeItem::new(1, "Item 1"), TreeItem::new(2, "Item 2")]);
+    /// ```
+    pub fn new<T>(items: T) -> Self
+    where
+        T: IntoIterator<Item = TreeItem<'a, Id>>,
+    {
+        Self {
+            items: items.into_iter().collect(),
+            block: None,
+            style: Style::new(),
+            highlight_style: Style::new(),
+            highlight_symbol: None,
+            expanded_symbol: "▼ ",
+            collapsed_symbol: "▶ ",
+        }
+    }
+
+    /// Wraps the tree with a custom [`Block`] widget
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn block(mut self, block: Block<'a>) -> Self {
+        self.block = Some(block);
+        self
+    }
+
+    /// Sets the base style of the widget
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets the style of the selected node
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn highlight_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.highlight_style = style.into();
+        self
+    }
+
+    /// Sets the symbol to be displayed in front of the selected node
+    ///
+    /// When a symbol is set, every row is indented by the width of the symbol so that the tree
+    /// does not shift when the selection changes.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn highlight_symbol(mut self, highlight_symbol: &'a str) -> Self {
+        self.highlight_symbol = Some(highlight_symbol);
+        self
+    }
+
+    /// Sets the symbol displayed in front of expanded nodes
+    ///
+    /// Defaults to `"▼ "`.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn expanded_symbol(mut self, symbol: &'a str) -> Self {
+        self.expanded_symbol = symbol;
+        self
+    }
+
+    /// Sets the symbol displayed in front of collapsed nodes
+    ///
+    /// Defaults to `"▶ "`.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn collapsed_symbol(mut self, symbol: &'a str) -> Self {
+        self.collapsed_symbol = symbol;
+        self
+    }
+}
+
+/// A visible row of the tree
+struct Row<'t, 'a, Id> {
+    item: &'t TreeItem<'a, Id>,
+    parent: Option<&'t Id>,
+    /// The connectors drawn in front of the item
+    prefix: String,
+}
+
+impl<'a, Id: Clone + Eq + Hash> Tree<'a, Id> {
+    /// Returns the rows which are visible given the expanded nodes
+    fn rows<'t>(&'t self, expanded: &HashSet<Id>) -> Vec<Row<'t, 'a, Id>> {
+        let mut rows = Vec::new();
+        push_rows(&mut rows, &self.items, None, None, expanded);
+        rows
+    }
+
+    /// Moves the selection to the closest visible ancestor when the selected node is hidden in a
+    /// collapsed subtree
+    fn reveal_selected(&self, state: &mut TreeState<Id>) {
+        let Some(selected) = state.selected.as_ref() else {
+            return;
+        };
+        let Some(path) = path_to(&self.items, selected) else {
+            return;
+        };
+        if let Some(collapsed) = path.iter().find(|id| !state.expanded.contains(id)) {
+            if *collapsed != selected {
+                state.selected = Some((*collapsed).clone());
+            }
+        }
+    }
+}
+
+/// Appends the rows of `items` and their expanded descendants
+///
+/// `indent` contains the connectors of the ancestors, or is `None` for the root items.
+fn push_rows<'t, 'a, Id: Eq + Hash>(
+    rows: &mut Vec<Row<'t, 'a, Id>>,
+    items: &'t [TreeItem<'a, Id>],
+    parent: Option<&'t Id>,
+    indent: Option<&str>,
+    expanded: &HashSet<Id>,
+) {
+    for (i, item) in items.iter().enumerate() {
+        let is_last = i + 1 == items.len();
+        let prefix = indent.map_or_else(String::new, |indent| {
+            let connector = if is_last { LAST_BRANCH } else { BRANCH };
+            format!("{indent}{connector}")
+        });
+        rows.push(Row {
+            item,
+            parent,
+            prefix,
+        });
+        if !item.children.is_empty() && expanded.contains(&item.id) {
+            let child_indent = indent.map_or_else(String::new, |indent| {
+                let connector = if is_last { EMPTY } else { VERTICAL };
+                format!("{indent}{connector}")
+            });
+            push_rows(
+                rows,
+                &item.children,
+                Some(&item.id),
+                Some(&child_indent),
+                expanded,
+            );
+        }
+    }
+}
+
+/// Returns the identifiers of the nodes from the root to the node with the given identifier
+fn path_to<'t, Id: Eq>(items: &'t [TreeItem<'_, Id>], id: &Id) -> Option<Vec<&'t Id>> {
+    items.iter().find_map(|item| {
+        if &item.id == id {
+            return Some(vec![&item.id]);
+        }
+        let mut path = path_to(&item.children, id)?;
+        path.insert(0, &item.id);
+        Some(path)
+    })
+}
+
+impl<Id: Clone + Eq + Hash> Widget for Tree<'_, Id> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        WidgetRef::render_ref(&self, area, buf);
+    }
+}
+
+impl<Id: Clone + Eq + Hash> WidgetRef for Tree<'_, Id> {
+    fn render_ref(&self, area: Rect, buf: &mut Buffer) {
+        let mut state = TreeState::default();
+        StatefulWidgetRef::render_ref(self, area, buf, &mut state);
+    }
+}
+
+impl<Id: Clone + Eq + Hash> StatefulWidget for Tree<'_, Id> {
+    type State = TreeState<Id>;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        StatefulWidgetRef::render_ref(&self, area, buf, state);
+    }
+}
+
+// Note: remove this when StatefulWidgetRef is stabilized and replace with the blanket impl
+impl<Id: Clone + Eq + Hash> StatefulWidget for &Tree<'_, Id> {
+    type State = TreeState<Id>;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        StatefulWidgetRef::render_ref(self, area, buf, state);
+    }
+}
+
+impl<Id: Clone + Eq + Hash> StatefulWidgetRef for Tree<'_, Id> {
+    type State = TreeState<Id>;
+
+    fn render_ref(&self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        buf.set_style(area, self.style);
+        self.block.render_ref(area, buf);
+        let area = self.block.inner_if_some(area);
+
+        self.reveal_selected(state);
+        let rows = self.rows(&state.expanded);
+        state.visible = rows
+            .iter()
+            .map(|row| VisibleNode {
+                id: row.item.id.clone(),
+                parent: row.parent.cloned(),
+            })
+            .collect();
+        if area.is_empty() {
+            return;
+        }
+
+        // keep the selected row in view and avoid blank space at the end after collapsing nodes
+        let height = area.height as usize;
+        let selected = state
+            .selected
+            .as_ref()
+            .and_then(|id| rows.iter().position(|row| &row.item.id == id));
+        state.offset = state.offset.min(rows.len().saturating_sub(height));
+        if let Some(selected) = selected {
+            state.offset = state
+                .offset
+                .clamp((selected + 1).saturating_sub(height), selected);
+        }
+
+        let highlight_symbol = self.highlight_symbol.unwrap_or("");
+        let blank_symbol = " ".repeat(highlight_symbol.width());
+        for (i, row) in rows.iter().enumerate().skip(state.offset).take(height) {
+            let row_area = Rect {
+                y: area.y + (i - state.offset) as u16,
+                height: 1,
+                ..area
+            };
+            let is_selected = selected == Some(i);
+            let style = self.style.patch(row.item.style);
+            buf.set_style(row_area, style);
+
+            let symbol = if is_selected {
+                highlight_symbol
+            } else {
+                &blank_symbol
+            };
+            let node_symbol = match (
+                row.item.children.is_empty(),
+                state.is_expanded(&row.item.id),
+            ) {
+                (true, _) => "",
+                (false, true) => self.expanded_symbol,
+                (false, false) => self.collapsed_symbol,
+            };
+            let mut x = row_area.x;
+            for part in [symbol, row.prefix.as_str(), node_symbol] {
+                let max_width = row_area.right().saturating_sub(x) as usize;
+                (x, _) = buf.set_stringn(x, row_area.y, part, max_width, Style::new());
+            }
+            let content_area = Rect {
+                x,
+                width: row_area.right().saturating_sub(x),
+                ..row_area
+            };
+            row.item.content.render_ref(content_area, buf);
+
+            if is_selected {
+                buf.set_style(row_area, self.highlight_style);
+            }
+        }
+    }
+}
+
+impl<Id> Styled for Tree<'_, Id> {
+    type Item = Self;
+
+    fn style(&self) -> Style {
+        self.style
+    }
+
+    fn set_style<S: Into<Style>>(self, style: S) -> Self::Item {
+        self.style(style)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    /// ```text
+    /// a
+    /// ├─ b
+    /// │  ├─ c
+    /// │  └─ d
+    /// └─ e
+    /// f
+    /// ```
+    fn tree() -> Tree<'static, char> {
+        Tree::new([
+            TreeItem::new('a', "a").children([
+                TreeItem::new('b', "b")
+                    .children([TreeItem::new('c', "c"), TreeItem::new('d', "d")]),
+                TreeItem::new('e', "e"),
+            ]),
+            TreeItem::new('f', "f"),
+        ])
+    }
+
+    fn render(tree: &Tree<'_, char>, state: &mut TreeState<char>, height: u16) -> Buffer {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 10, height));
+        StatefulWidget::render(tree, buf.area, &mut buf, state);
+        buf
+    }
+
+    #[test]
+    fn render_collapsed() {
+        let mut state = TreeState::default();
+        let buf = render(&tree(), &mut state, 3);
+        assert_eq!(
+            buf,
+            Buffer::with_lines(["▶ a       ", "f         ", "          "])
+        );
+    }
+
+    #[test]
+    fn render_expanded() {
+        let mut state = TreeState::default();
+        state.expand('a');
+        state.expand('b');
+        let buf = render(&tree(), &mut state, 6);
+        #[rustfmt::skip]
+        let expected = Buffer::with_lines([
+            "▼ a       ",
+            "├─ ▼ b    ",
+            "│  ├─ c   ",
+            "│  └─ d   ",
+            "└─ e      ",
+            "f         ",
+        ]);
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn render_last_child_connectors() {
+        let tree = Tree::new([TreeItem::new('a', "a")
+            .children([TreeItem::new('b', "b")
+                .children([TreeItem::new('c', "c"), TreeItem::new('d', "d")])])]);
+        let mut state = TreeState::default();
+        state.expand('a');
+        state.expand('b');
+        let buf = render(&tree, &mut state, 4);
+        assert_eq!(
+            buf,
+            Buffer::with_lines(["▼ a       ", "└─ ▼ b    ", "   ├─ c   ", "   └─ d   "])
+        );
+    }
+
+    #[test]
+    fn render_selected() {
+        let tree = tree()
+            .highlight_symbol(">")
+            .highlight_style(Style::new().bold());
+        let mut state = TreeState::default().with_selected(Some('f'));
+        let buf = render(&tree, &mut state, 2);
+        let mut expected = Buffer::with_lines([" ▶ a      ", ">f        "]);
+        expected.set_style(Rect::new(0, 1, 10, 1), Style::new().bold());
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn render_updates_visible_nodes() {
+        let mut state = TreeState::default();
+        state.expand('a');
+        render(&tree(), &mut state, 1);
+        let ids: Vec<_> = state.visible.iter().map(|node| node.id).collect();
+        assert_eq!(ids, ['a', 'b', 'e', 'f']);
+
+        state.select_last();
+        state.select_previous();
+        state.select_previous();
+        assert_eq!(state.selected(), Some(&'b'));
+        state.select_parent();
+        assert_eq!(state.selected(), Some(&'a'));
+    }
+
+    #[test]
+    fn scrolls_to_selected() {
+        let mut state = TreeState::default().with_selected(Some('e'));
+        state.expand('a');
+        state.expand('b');
+        let buf = render(&tree(), &mut state, 2);
+        assert_eq!(state.offset(), 3);
+        assert_eq!(buf, Buffer::with_lines(["│  └─ d   ", "└─ e      "]));
+
+        state.select(Some('a'));
+        render(&tree(), &mut state, 2);
+        assert_eq!(state.offset(), 0);
+    }
+
+    #[test]
+    fn collapsing_clamps_offset() {
+        let mut state = TreeState::default().with_offset(4);
+        state.expand('a');
+        state.expand('b');
+        render(&tree(), &mut state, 2);
+        assert_eq!(state.offset(), 4);
+
+        state.collapse(&'b');
+        let buf = render(&tree(), &mut state, 2);
+        assert_eq!(state.offset(), 2);
+        assert_eq!(buf, Buffer::with_lines(["└─ e      ", "f         "]));
+    }
+
+    #[test]
+    fn collapsing_selects_visible_ancestor() {
+        let mut state = TreeState::default().with_selected(Some('d'));
+        state.expand('a');
+        state.expand('b');
+        render(&tree(), &mut state, 6);
+
+        state.collapse(&'a');
+        render(&tree(), &mut state, 6);
+        assert_eq!(state.selected(), Some(&'a'));
+    }
+
+    #[test]
+    fn render_in_block() {
+        let tree = tree().block(Block::bordered());
+        let mut state = TreeState::default();
+        let buf = render(&tree, &mut state, 4);
+        assert_eq!(
+            buf,
+            Buffer::with_lines(["┌────────┐", "│▶ a     │", "│f       │", "└────────┘",])
+        );
+    }
+
+    #[test]
+    fn render_truncates_content() {
+        let tree =
+            Tree::new([TreeItem::new(0, "abc").children([TreeItem::new(1, "a very long name")])]);
+        let mut state = TreeState::default();
+        state.expand(0);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 6, 2));
+        StatefulWidget::render(tree, buf.area, &mut buf, &mut state);
+        assert_eq!(buf, Buffer::with_lines(["▼ abc", "└─ a v"]));
+    }
+
+    #[test]
+    fn render_empty_area() {
+        let mut state = TreeState::default();
+        let mut buf = Buffer::empty(Rect::new(0, 0, 0, 0));
+        StatefulWidget::render(tree(), buf.area, &mut buf, &mut state);
+        assert_eq!(state.visible.len(), 2);
+    }
+}
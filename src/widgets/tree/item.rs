@@ -0,0 +1,145 @@
+use crate::{prelude::*, style::Styled};
+
+/// A single node in a [`Tree`]
+///
+/// Each item has an identifier, a single [`Line`] of content and any number of children. The
+/// identifier is used by the [`TreeState`] to track which nodes are expanded and which node is
+/// selected, so it must be unique within the tree. Any type that is [`Clone`], [`Eq`] and
+/// [`Hash`] can be used, e.g. a `&str`, a number or a [`PathBuf`](std::path::PathBuf).
+///
+/// You can set the style of an item with [`TreeItem::style`] or using the [`Stylize`] trait.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ratatui::{prelude::*, widgets::*};
+/// let item = TreeItem::new("src", "src").children([
+///     TreeItem::new("lib.rs", "lib.rs"),
+///     TreeItem::new("main.rs", "main.rs").bold(),
+/// ]);
+/// ```
+///
+/// [`Tree`]: crate::widgets::Tree
+/// [`TreeState`]: crate::widgets::TreeState
+/// [`Stylize`]: crate::style::Stylize
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct TreeItem<'a, Id> {
+    pub(crate) id: Id,
+    pub(crate) content: Line<'a>,
+    pub(crate) style: Style,
+    pub(crate) children: Vec<Self>,
+}
+
+impl<'a, Id> TreeItem<'a, Id> {
+    /// Creates a new [`TreeItem`] without children
+    ///
+    /// The `content` parameter accepts any value that can be converted into [`Line`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let item = TreeItem::new(1, "Item 1");
+    /// ```
+    pub fn new<T>(id: Id, content: T) -> Self
+    where
+        T: Into<Line<'a>>,
+    {
+        Self {
+            id,
+            content: content.into(),
+            style: Style::default(),
+            children: Vec::new(),
+        }
+    }
+
+    /// Sets the children of the item
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let item = TreeItem::new(1, "Parent").children([TreeItem::new(2, "Child")]);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn children<T>(mut self, children: T) -> Self
+    where
+        T: IntoIterator<Item = Self>,
+    {
+        self.children = children.into_iter().collect();
+        self
+    }
+
+    /// Sets the item style
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// This [`Style`] can be overridden by the [`Style`] of the [`Line`] content.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let item = TreeItem::new(1, "Item 1").style(Style::new().red().italic());
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Returns the identifier of the item
+    pub const fn id(&self) -> &Id {
+        &self.id
+    }
+
+    /// Returns the children of the item
+    pub fn child_items(&self) -> &[Self] {
+        &self.children
+    }
+}
+
+impl<Id> Styled for TreeItem<'_, Id> {
+    type Item = Self;
+
+    fn style(&self) -> Style {
+        self.style
+    }
+
+    fn set_style<S: Into<Style>>(self, style: S) -> Self::Item {
+        self.style(style)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn new() {
+        let item = TreeItem::new(1, "Item");
+        assert_eq!(item.id(), &1);
+        assert_eq!(item.content, Line::raw("Item"));
+        assert_eq!(item.style, Style::default());
+        assert!(item.child_items().is_empty());
+    }
+
+    #[test]
+    fn children() {
+        let item = TreeItem::new(1, "Parent").children([TreeItem::new(2, "Child")]);
+        assert_eq!(item.child_items(), [TreeItem::new(2, "Child")]);
+    }
+
+    #[test]
+    fn stylize() {
+        let item = TreeItem::new(1, "Item").red().on_white();
+        assert_eq!(item.style, Style::new().red().on_white());
+    }
+}
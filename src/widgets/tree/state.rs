@@ -0,0 +1,356 @@
+use std::{collections::HashSet, hash::Hash};
+
+/// State of the [`Tree`] widget
+///
+/// The state tracks which nodes are expanded and which node is selected, using the identifiers of
+/// the [`TreeItem`]s, and the offset of the first visible row. When the tree is rendered as a
+/// stateful widget, the selected node will be highlighted and the offset will be adjusted to
+/// ensure that the selected node is visible.
+///
+/// The navigation methods ([`select_next`], [`select_previous`], [`select_parent`], ...) move the
+/// selection through the nodes which were visible when the tree was last rendered. Collapsed
+/// subtrees are skipped. Until the tree has been rendered, these methods have no effect.
+///
+/// [`select_next`]: TreeState::select_next
+/// [`select_previous`]: TreeState::select_previous
+/// [`select_parent`]: TreeState::select_parent
+///
+/// # Example
+///
+/// ```rust
+/// # use ratatui::{prelude::*, widgets::*};
+/// # fn ui(frame: &mut Frame) {
+/// # let area = Rect::default();
+/// let tree = Tree::new([TreeItem::new("src", "src").children([TreeItem::new("lib.rs", "lib.rs")])]);
+///
+/// // This should be stored outside of the function in your application state.
+/// let mut state = TreeState::default();
+///
+/// state.expand("src");
+/// state.select(Some("lib.rs"));
+///
+/// frame.render_stateful_widget(tree, area, &mut state);
+/// # }
+/// ```
+///
+/// [`Tree`]: crate::widgets::Tree
+/// [`TreeItem`]: crate::widgets::TreeItem
+#[derive(Debug, Clone)]
+pub struct TreeState<Id> {
+    pub(crate) offset: usize,
+    pub(crate) selected: Option<Id>,
+    pub(crate) expanded: HashSet<Id>,
+    /// The nodes which were visible when the tree was last rendered, in display order
+    pub(crate) visible: Vec<VisibleNode<Id>>,
+}
+
+/// A node which was visible when the tree was last rendered
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub(crate) struct VisibleNode<Id> {
+    pub(crate) id: Id,
+    pub(crate) parent: Option<Id>,
+}
+
+impl<Id> Default for TreeState<Id> {
+    fn default() -> Self {
+        Self {
+            offset: 0,
+            selected: None,
+            expanded: HashSet::new(),
+            visible: Vec::new(),
+        }
+    }
+}
+
+impl<Id: Eq + Hash> PartialEq for TreeState<Id> {
+    fn eq(&self, other: &Self) -> bool {
+        self.offset == other.offset
+            && self.selected == other.selected
+            && self.expanded == other.expanded
+            && self.visible == other.visible
+    }
+}
+
+impl<Id: Eq + Hash> Eq for TreeState<Id> {}
+
+impl<Id: Clone + Eq + Hash> TreeState<Id> {
+    /// Sets the index of the first row to be displayed
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let state = TreeState::<u32>::default().with_offset(1);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn with_offset(mut self, offset: usize) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    /// Sets the identifier of the selected node
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let state = TreeState::default().with_selected(Some(1));
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn with_selected(mut self, selected: Option<Id>) -> Self {
+        self.selected = selected;
+        self
+    }
+
+    /// Index of the first row to be displayed
+    pub const fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Mutable reference to the index of the first row to be displayed
+    pub fn offset_mut(&mut self) -> &mut usize {
+        &mut self.offset
+    }
+
+    /// Identifier of the selected node
+    ///
+    /// Returns `None` if no node is selected
+    pub const fn selected(&self) -> Option<&Id> {
+        self.selected.as_ref()
+    }
+
+    /// Sets the identifier of the selected node
+    ///
+    /// Set to `None` if no node is selected. This will also reset the offset to `0`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let mut state = TreeState::default();
+    /// state.select(Some("src"));
+    /// ```
+    pub fn select(&mut self, id: Option<Id>) {
+        if id.is_none() {
+            self.offset = 0;
+        }
+        self.selected = id;
+    }
+
+    /// Returns whether the node with the given identifier is expanded
+    pub fn is_expanded(&self, id: &Id) -> bool {
+        self.expanded.contains(id)
+    }
+
+    /// Expands the node with the given identifier so that its children are displayed
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let mut state = TreeState::default();
+    /// state.expand("src");
+    /// assert!(state.is_expanded(&"src"));
+    /// ```
+    pub fn expand(&mut self, id: Id) {
+        self.expanded.insert(id);
+    }
+
+    /// Collapses the node with the given identifier so that its children are hidden
+    ///
+    /// If the selected node is inside the collapsed subtree, the selection moves to the collapsed
+    /// node when the tree is next rendered.
+    pub fn collapse(&mut self, id: &Id) {
+        self.expanded.remove(id);
+    }
+
+    /// Expands the selected node if it is collapsed or collapses it if it is expanded
+    ///
+    /// Does nothing if no node is selected.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let mut state = TreeState::default().with_selected(Some("src"));
+    /// state.toggle();
+    /// assert!(state.is_expanded(&"src"));
+    /// state.toggle();
+    /// assert!(!state.is_expanded(&"src"));
+    /// ```
+    pub fn toggle(&mut self) {
+        let Some(id) = self.selected.clone() else {
+            return;
+        };
+        if !self.expanded.remove(&id) {
+            self.expanded.insert(id);
+        }
+    }
+
+    /// Selects the next visible node or the first one if no node is selected
+    ///
+    /// The selection stays on the last node when the end of the tree is reached.
+    pub fn select_next(&mut self) {
+        let next = self
+            .selected_index()
+            .map_or(0, |i| i.saturating_add(1).min(self.visible.len() - 1));
+        self.select_index(next);
+    }
+
+    /// Selects the previous visible node or the last one if no node is selected
+    ///
+    /// The selection stays on the first node when the start of the tree is reached.
+    pub fn select_previous(&mut self) {
+        let previous = self
+            .selected_index()
+            .map_or(usize::MAX, |i| i.saturating_sub(1));
+        self.select_index(previous);
+    }
+
+    /// Selects the first visible node
+    pub fn select_first(&mut self) {
+        self.select_index(0);
+    }
+
+    /// Selects the last visible node
+    pub fn select_last(&mut self) {
+        self.select_index(usize::MAX);
+    }
+
+    /// Selects the parent of the selected node
+    ///
+    /// Does nothing if no node is selected or if the selected node is at the root of the tree.
+    pub fn select_parent(&mut self) {
+        let parent = self
+            .selected_index()
+            .and_then(|i| self.visible[i].parent.clone());
+        if parent.is_some() {
+            self.selected = parent;
+        }
+    }
+
+    /// Returns the index of the selected node among the visible nodes
+    fn selected_index(&self) -> Option<usize> {
+        let selected = self.selected.as_ref()?;
+        self.visible.iter().position(|node| &node.id == selected)
+    }
+
+    /// Selects the visible node at the given index, clamped to the last visible node
+    fn select_index(&mut self, index: usize) {
+        let index = index.min(self.visible.len().saturating_sub(1));
+        if let Some(node) = self.visible.get(index) {
+            self.selected = Some(node.id.clone());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    /// A state with the visible nodes of the following tree:
+    ///
+    /// ```text
+    /// a
+    /// ├─ b
+    /// │  └─ c
+    /// └─ d
+    /// e
+    /// ```
+    fn state() -> TreeState<char> {
+        let node = |id, parent| VisibleNode { id, parent };
+        TreeState {
+            visible: vec![
+                node('a', None),
+                node('b', Some('a')),
+                node('c', Some('b')),
+                node('d', Some('a')),
+                node('e', None),
+            ],
+            ..TreeState::default()
+        }
+    }
+
+    #[test]
+    fn select() {
+        let mut state = TreeState::default().with_offset(3);
+        state.select(Some(2));
+        assert_eq!(state.selected(), Some(&2));
+        assert_eq!(state.offset(), 3);
+
+        state.select(None);
+        assert_eq!(state.selected(), None);
+        assert_eq!(state.offset(), 0);
+    }
+
+    #[test]
+    fn expand_collapse_toggle() {
+        let mut state = TreeState::default();
+        state.toggle(); // nothing selected
+        assert!(state.expanded.is_empty());
+
+        state.expand('a');
+        assert!(state.is_expanded(&'a'));
+        state.collapse(&'a');
+        assert!(!state.is_expanded(&'a'));
+
+        state.select(Some('b'));
+        state.toggle();
+        assert!(state.is_expanded(&'b'));
+        state.toggle();
+        assert!(!state.is_expanded(&'b'));
+    }
+
+    #[test]
+    fn navigation() {
+        let mut state = state();
+        state.select_next();
+        assert_eq!(state.selected(), Some(&'a'));
+
+        state.select_previous(); // should not go above the first node
+        assert_eq!(state.selected(), Some(&'a'));
+
+        state.select_next();
+        state.select_next();
+        assert_eq!(state.selected(), Some(&'c'));
+
+        state.select_last();
+        assert_eq!(state.selected(), Some(&'e'));
+
+        state.select_next(); // should not go below the last node
+        assert_eq!(state.selected(), Some(&'e'));
+
+        state.select_first();
+        assert_eq!(state.selected(), Some(&'a'));
+
+        let mut state = self::state();
+        state.select_previous();
+        assert_eq!(state.selected(), Some(&'e'));
+    }
+
+    #[test]
+    fn select_parent() {
+        let mut state = state().with_selected(Some('c'));
+        state.select_parent();
+        assert_eq!(state.selected(), Some(&'b'));
+        state.select_parent();
+        assert_eq!(state.selected(), Some(&'a'));
+        state.select_parent(); // root nodes have no parent
+        assert_eq!(state.selected(), Some(&'a'));
+    }
+
+    #[test]
+    fn navigation_before_render() {
+        let mut state = TreeState::<char>::default();
+        state.select_next();
+        state.select_last();
+        state.select_parent();
+        assert_eq!(state.selected(), None);
+    }
+}
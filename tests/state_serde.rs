@@ -197,3 +197,11 @@ fn scrolled_state_deserialize() {
     let mut state: AppState = serde_json::from_str(SCROLLED_STATE_REPR).unwrap();
     assert_buffer(&mut state, SCROLLED_STATE_BUFFER);
 }
+
+#[test]
+fn table_x_offset_roundtrip() {
+    let state = TableState::new().with_x_offset(2);
+    let repr = serde_json::to_string(&state).unwrap();
+    assert_eq!(repr, r#"{"offset":0,"selected":null,"x_offset":2}"#);
+    assert_eq!(serde_json::from_str::<TableState>(&repr).unwrap(), state);
+}
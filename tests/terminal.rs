@@ -1,8 +1,11 @@
-use std::error::Error;
+use std::{cell::Cell, error::Error};
 
 use ratatui::{
+    accessibility::{AccessNode, Role},
     backend::{Backend, TestBackend},
+    buffer::Buffer,
     layout::Rect,
+    text::{Line, LineSize},
     widgets::{Block, Paragraph, Widget},
     Terminal, TerminalOptions, Viewport,
 };
@@ -48,6 +51,68 @@ fn terminal_draw_returns_the_completed_frame() -> Result<(), Box<dyn Error>> {
     Ok(())
 }
 
+#[test]
+fn terminal_draw_collects_the_accessibility_tree() -> Result<(), Box<dyn Error>> {
+    let backend = TestBackend::new(10, 3);
+    let mut terminal = Terminal::new(backend)?;
+    terminal.enable_accessibility();
+    terminal.draw(|f| {
+        f.render_widget(Block::bordered(), f.area());
+        f.render_widgets(
+            [Paragraph::new("one"), Paragraph::new("two")],
+            [Rect::new(0, 0, 10, 1), Rect::new(0, 1, 10, 1)],
+        );
+        assert_eq!(
+            f.accessibility_tree(),
+            [
+                AccessNode::new(Role::Paragraph, "one", Rect::new(0, 0, 10, 1)),
+                AccessNode::new(Role::Paragraph, "two", Rect::new(0, 1, 10, 1)),
+            ]
+        );
+    })?;
+    terminal.draw(|f| assert!(f.accessibility_tree().is_empty()))?;
+    Ok(())
+}
+
+#[test]
+fn terminal_draw_does_not_collect_the_accessibility_tree_by_default() -> Result<(), Box<dyn Error>>
+{
+    let backend = TestBackend::new(10, 3);
+    let mut terminal = Terminal::new(backend)?;
+    terminal.draw(|f| {
+        f.render_widget(Paragraph::new("one"), f.area());
+        assert!(f.accessibility_tree().is_empty());
+    })?;
+    Ok(())
+}
+
+#[test]
+fn terminal_draw_autoresize_leaves_no_stale_cells() -> Result<(), Box<dyn Error>> {
+    let backend = TestBackend::new(6, 3);
+    let mut terminal = Terminal::new(backend)?;
+    terminal.draw(|f| {
+        Paragraph::new("abcdef\nghijkl\nmnopqr").render(f.area(), f.buffer_mut());
+    })?;
+
+    // shrink
+    terminal.backend_mut().resize(4, 2);
+    terminal.draw(|f| {
+        Paragraph::new("1234\n5678").render(f.area(), f.buffer_mut());
+    })?;
+    terminal.backend().assert_buffer_lines(["1234", "5678"]);
+
+    // grow
+    terminal.backend_mut().resize(6, 3);
+    let frame = terminal.draw(|f| {
+        Paragraph::new("x").render(f.area(), f.buffer_mut());
+    })?;
+    assert_eq!(frame.area, Rect::new(0, 0, 6, 3));
+    terminal
+        .backend()
+        .assert_buffer_lines(["x     ", "      ", "      "]);
+    Ok(())
+}
+
 #[test]
 fn terminal_draw_increments_frame_count() -> Result<(), Box<dyn Error>> {
     let backend = TestBackend::new(10, 10);
@@ -73,6 +138,88 @@ fn terminal_draw_increments_frame_count() -> Result<(), Box<dyn Error>> {
     Ok(())
 }
 
+#[test]
+fn terminal_draw_with_stats_counts_changed_cells() -> Result<(), Box<dyn Error>> {
+    let backend = TestBackend::new(10, 10);
+    let mut terminal = Terminal::new(backend)?;
+    let (frame, stats) = terminal.draw_with_stats(|f| {
+        f.render_widget(Paragraph::new("Test"), f.area());
+    })?;
+    assert_eq!(frame.count, 0);
+    assert_eq!(stats.cells_changed, 4);
+    // the test backend doesn't keep track of the bytes written
+    assert_eq!(stats.bytes_written, None);
+    let (_, stats) = terminal.draw_with_stats(|f| {
+        f.render_widget(Paragraph::new("Text"), f.area());
+    })?;
+    assert_eq!(stats.cells_changed, 1);
+    let (frame, stats) = terminal.draw_with_stats(|f| {
+        f.render_widget(Paragraph::new("Text"), f.area());
+    })?;
+    assert_eq!(frame.count, 2);
+    assert_eq!(stats.cells_changed, 0);
+    Ok(())
+}
+
+#[test]
+fn terminal_draw_render_cached_reuses_cells() -> Result<(), Box<dyn Error>> {
+    let backend = TestBackend::new(10, 2);
+    let mut terminal = Terminal::new(backend)?;
+    terminal.enable_accessibility();
+    let renders = Cell::new(0);
+    let paragraph = |text: &'static str| {
+        renders.set(renders.get() + 1);
+        Paragraph::new(text)
+    };
+    let area = Rect::new(0, 0, 10, 1);
+
+    let frame = terminal.draw(|f| f.render_cached(1, area, || paragraph("one")))?;
+    assert_eq!(
+        frame.buffer,
+        &Buffer::with_lines(["one       ", "          "])
+    );
+    assert_eq!(renders.get(), 1);
+
+    // the same key reuses the cells of the previous frame
+    let frame = terminal.draw(|f| {
+        f.render_cached(1, area, || paragraph("unused"));
+        assert_eq!(f.accessibility_tree().len(), 1);
+    })?;
+    assert_eq!(
+        frame.buffer,
+        &Buffer::with_lines(["one       ", "          "])
+    );
+    assert_eq!(renders.get(), 1);
+
+    // another key renders the widget again
+    let frame = terminal.draw(|f| f.render_cached(2, area, || paragraph("two")))?;
+    assert_eq!(
+        frame.buffer,
+        &Buffer::with_lines(["two       ", "          "])
+    );
+    assert_eq!(renders.get(), 2);
+
+    // another area renders the widget again
+    let other_area = Rect::new(0, 1, 10, 1);
+    let frame = terminal.draw(|f| f.render_cached(2, other_area, || paragraph("two")))?;
+    assert_eq!(
+        frame.buffer,
+        &Buffer::with_lines(["          ", "two       "])
+    );
+    assert_eq!(renders.get(), 3);
+
+    // the cells of an area which was not rendered during the previous frame are dropped
+    terminal.draw(|f| f.render_cached(2, area, || paragraph("two")))?;
+    assert_eq!(renders.get(), 4);
+
+    // even when rendering the previous frame failed
+    let failed = terminal.try_draw(|_| Err(std::io::Error::other("failed")));
+    assert!(failed.is_err());
+    terminal.draw(|f| f.render_cached(2, area, || paragraph("two")))?;
+    assert_eq!(renders.get(), 5);
+    Ok(())
+}
+
 #[test]
 fn terminal_insert_before_moves_viewport() -> Result<(), Box<dyn Error>> {
     // When we have a terminal with 5 lines, and a single line viewport, if we insert a
@@ -282,3 +429,23 @@ fn terminal_insert_before_large_viewport() -> Result<(), Box<dyn Error>> {
 
     Ok(())
 }
+
+#[test]
+fn terminal_draw_updates_line_sizes() -> Result<(), Box<dyn Error>> {
+    let backend = TestBackend::new(6, 2);
+    let mut terminal = Terminal::new(backend)?;
+    terminal.draw(|f| {
+        let line = Line::from("abc").size(LineSize::DoubleWidth);
+        f.render_widget(line, f.area());
+    })?;
+    assert_eq!(
+        terminal.backend().buffer().line_size(0),
+        LineSize::DoubleWidth
+    );
+    assert_eq!(terminal.backend().buffer().line_size(1), LineSize::Normal);
+
+    terminal.draw(|f| f.render_widget(Line::from("abc"), f.area()))?;
+    assert_eq!(terminal.backend().buffer().line_size(0), LineSize::Normal);
+    terminal.backend().assert_buffer_lines(["abc   ", "      "]);
+    Ok(())
+}
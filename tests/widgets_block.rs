@@ -55,27 +55,28 @@ fn widgets_block_titles_overlap() {
         terminal.backend().assert_buffer_lines(expected);
     }
 
-    // Left overrides the center
+    // Left reserves its space before the center, which is moved aside
     test_case(
         Block::new()
             .title(Title::from("aaaaa").alignment(Alignment::Left))
             .title(Title::from("bbb").alignment(Alignment::Center))
             .title(Title::from("ccc").alignment(Alignment::Right)),
         Rect::new(0, 0, 10, 1),
-        ["aaaaab ccc"],
+        ["aaaaa bbb "],
     );
 
-    // Left alignment overrides the center alignment which overrides the right alignment
+    // Left alignment takes precedence over the center alignment, which takes precedence over the
+    // right alignment
     test_case(
         Block::new()
             .title(Title::from("aaaaa").alignment(Alignment::Left))
             .title(Title::from("bbbbb").alignment(Alignment::Center))
             .title(Title::from("ccccc").alignment(Alignment::Right)),
         Rect::new(0, 0, 11, 1),
-        ["aaaaabbbccc"],
+        ["aaaaa bbbbb"],
     );
 
-    // Multiple left alignment overrides the center alignment and the right alignment
+    // Multiple left titles take precedence over the center alignment and the right alignment
     test_case(
         Block::new()
             .title(Title::from("aaaaa").alignment(Alignment::Left))
@@ -83,16 +84,25 @@ fn widgets_block_titles_overlap() {
             .title(Title::from("bbbbb").alignment(Alignment::Center))
             .title(Title::from("ccccc").alignment(Alignment::Right)),
         Rect::new(0, 0, 11, 1),
-        ["aaaaabaaaaa"],
+        ["aaaaa aaaaa"],
     );
 
-    // The right alignment doesn't override the center alignment, but pierces through it
+    // The right alignment is truncated to the space left after the center alignment
     test_case(
         Block::new()
             .title(Title::from("bbbbb").alignment(Alignment::Center))
             .title(Title::from("ccccccccccc").alignment(Alignment::Right)),
         Rect::new(0, 0, 11, 1),
-        ["cccbbbbbccc"],
+        ["   bbbbb cc"],
+    );
+
+    // A long left title truncates the right title instead of colliding with it
+    test_case(
+        Block::new()
+            .title(Title::from("aaaaaaa").alignment(Alignment::Left))
+            .title(Title::from("ccccc").alignment(Alignment::Right)),
+        Rect::new(0, 0, 10, 1),
+        ["aaaaaaa cc"],
     );
 }
 
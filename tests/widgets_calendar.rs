@@ -1,8 +1,11 @@
 #![cfg(feature = "widget-calendar")]
+use std::collections::HashMap;
+
 use ratatui::{
     backend::TestBackend,
     buffer::Buffer,
-    style::Style,
+    layout::Rect,
+    style::{Color, Style},
     widgets::{
         calendar::{CalendarEventStore, Monthly},
         Widget,
@@ -111,3 +114,25 @@ fn show_combo() {
     ]);
     test_render(c, 21, 7, &expected);
 }
+
+#[test]
+fn events_by_count() {
+    let date = |day| Date::from_calendar_date(2023, Month::January, day).unwrap();
+    let mut events = CalendarEventStore::default();
+    events.add(date(2), Style::default().bg(Color::Blue));
+    let counts = HashMap::from([(date(1), 1), (date(2), 2), (date(3), 2)]);
+    let c = Monthly::new(date(1), events)
+        .events_by_count(counts)
+        .color_ramp([Color::Green, Color::Red]);
+    let mut expected = Buffer::with_lines([
+        "  1  2  3  4  5  6  7",
+        "  8  9 10 11 12 13 14",
+        " 15 16 17 18 19 20 21",
+        " 22 23 24 25 26 27 28",
+        " 29 30 31",
+    ]);
+    expected.set_style(Rect::new(1, 0, 2, 1), Style::default().bg(Color::Green));
+    expected.set_style(Rect::new(4, 0, 2, 1), Style::default().bg(Color::Blue));
+    expected.set_style(Rect::new(7, 0, 2, 1), Style::default().bg(Color::Red));
+    test_render(c, 21, 5, &expected);
+}